@@ -298,7 +298,7 @@ where
     fn pay_for_cost(
         &mut self,
         mut cost: M::Num,
-        receive: impl FnOnce(&mut Self, &M::Num, &M::Num, &M::Num) -> crate::Result<()>,
+        receive: impl FnOnce(&mut Self, &M::Num, &M::Num, &mut M::Num) -> crate::Result<()>,
         step: InsolventCloseStep,
     ) -> crate::Result<()> {
         let (paid_in_collateral_amount, paid_in_secondary_amount) =
@@ -307,7 +307,7 @@ where
             self,
             &paid_in_collateral_amount,
             &paid_in_secondary_amount,
-            &cost,
+            &mut cost,
         )?;
         if !cost.is_zero() {
             return Err(crate::Error::InsufficientFundsToPayForCosts(step));
@@ -315,6 +315,46 @@ where
         Ok(())
     }
 
+    /// Let the market cover the unpaid negative pnl (bad debt) of an insolvent close.
+    ///
+    /// The unpaid value is reduced by the value covered by the market.
+    fn cover_unpaid_pnl(&mut self, unpaid_value: &mut M::Num) -> crate::Result<()> {
+        use num_traits::{CheckedMul, CheckedSub};
+
+        let is_output_token_long = self.state.is_output_token_long;
+        let price = self.state.output_token_price().pick_price(true).clone();
+        let unpaid_amount = unpaid_value
+            .checked_round_up_div(&price)
+            .ok_or(crate::Error::Computation("calculating unpaid pnl amount"))?;
+        let covered_amount = self
+            .market
+            .on_insufficient_pnl_payment(&unpaid_amount, is_output_token_long)?;
+        if covered_amount.is_zero() {
+            return Ok(());
+        }
+        if covered_amount > unpaid_amount {
+            return Err(crate::Error::Computation(
+                "covered amount exceeds the unpaid pnl amount",
+            ));
+        }
+        self.market
+            .apply_delta(is_output_token_long, &covered_amount.to_signed()?)?;
+        if covered_amount == unpaid_amount {
+            *unpaid_value = Zero::zero();
+        } else {
+            let covered_value = covered_amount
+                .checked_mul(&price)
+                .ok_or(crate::Error::Computation("calculating covered pnl value"))?;
+            *unpaid_value =
+                unpaid_value
+                    .checked_sub(&covered_value)
+                    .ok_or(crate::Error::Computation(
+                        "calculating remaining unpaid pnl",
+                    ))?;
+        }
+        Ok(())
+    }
+
     fn pay_to_primary_pool(
         &mut self,
         collateral_token_amount: &M::Signed,
@@ -388,11 +428,15 @@ where
         if pnl.is_negative() {
             self.pay_for_cost(
                 pnl.unsigned_abs(),
-                |processor, paid_in_collateral_amount, paid_in_secondary_output_amount, _| {
+                |processor, paid_in_collateral_amount, paid_in_secondary_output_amount, cost| {
                     processor.pay_to_primary_pool(
                         &paid_in_collateral_amount.to_signed()?,
                         &paid_in_secondary_output_amount.to_signed()?,
-                    )
+                    )?;
+                    if !cost.is_zero() && processor.is_insolvent_close_allowed {
+                        processor.cover_unpaid_pnl(cost)?;
+                    }
+                    Ok(())
                 },
                 InsolventCloseStep::Pnl,
             )?;
@@ -110,6 +110,8 @@ impl<T> DecreasePositionParams<T> {
     }
 
     /// Whether insolvent close is allowed.
+    ///
+    /// Note that it only applies to a full close.
     pub fn is_insolvent_close_allowed(&self) -> bool {
         self.flags.is_insolvent_close_allowed
     }
@@ -133,7 +135,7 @@ impl<T> DecreasePositionParams<T> {
 /// Decrease Position Flags.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct DecreasePositionFlags {
-    /// Whether insolvent close is allowed.
+    /// Whether insolvent close is allowed. Only applies to a full close.
     pub is_insolvent_close_allowed: bool,
     /// Whether the order is a liquidation order.
     pub is_liquidation_order: bool,
@@ -154,9 +156,6 @@ impl DecreasePositionFlags {
             }
         }
 
-        let is_full_close = *size_in_usd == *size_delta_usd;
-        self.is_insolvent_close_allowed = is_full_close && self.is_insolvent_close_allowed;
-
         Ok(())
     }
 }
@@ -341,9 +340,6 @@ where
 
     #[allow(clippy::type_complexity)]
     fn process_collateral(&mut self) -> crate::Result<ProcessCollateralResult<P::Num>> {
-        // is_insolvent_close_allowed => is_full_close
-        debug_assert!(!self.params.is_insolvent_close_allowed() || self.is_full_close());
-
         let ExecutionParams {
             price_impact,
             price_impact_diff,
@@ -371,6 +367,11 @@ where
 
         let remaining_collateral_amount = self.position.collateral_amount().clone();
 
+        // Insolvent close is only allowed for a full close, including a partial close
+        // upgraded to a full close.
+        let is_insolvent_close_allowed =
+            self.params.is_insolvent_close_allowed() && self.is_full_close();
+
         let processor = CollateralProcessor::new(
             self.position.market_mut(),
             is_output_token_long,
@@ -378,7 +379,7 @@ where
             are_pnl_and_collateral_tokens_the_same,
             &self.params.prices,
             remaining_collateral_amount,
-            self.params.is_insolvent_close_allowed(),
+            is_insolvent_close_allowed,
         );
 
         let mut result = {
@@ -468,6 +469,53 @@ where
         })
     }
 
+    /// Cap the total negative price impact charged to a liquidated position.
+    ///
+    /// The price impact diff is reduced first. The part exceeding the cap is not charged
+    /// to the position.
+    ///
+    /// The cap is applied to `size_delta_usd`, so for a partial liquidation it is bounded by
+    /// the liquidated size rather than the size of the whole position. Since the partial
+    /// liquidation size is computed without the cap, capping only leaves more collateral in
+    /// the remaining position.
+    fn cap_liquidation_price_impact(
+        &self,
+        impact: &mut P::Signed,
+        impact_diff: &mut P::Num,
+    ) -> crate::Result<()> {
+        use crate::num::UnsignedAbs;
+        use num_traits::Signed;
+
+        if !impact.is_negative() {
+            return Ok(());
+        }
+
+        let params = self.position.market().position_params()?;
+        let Some(factor) = params.max_position_impact_factor_for_liquidation_execution() else {
+            return Ok(());
+        };
+
+        let max_impact = crate::utils::apply_factor(&self.size_delta_usd, factor).ok_or(
+            crate::Error::Computation("calculating max negative position impact for liquidation"),
+        )?;
+        let charged_impact = impact.unsigned_abs();
+        if charged_impact >= max_impact {
+            *impact = max_impact.to_opposite_signed()?;
+            *impact_diff = Zero::zero();
+        } else {
+            let max_impact_diff =
+                max_impact
+                    .checked_sub(&charged_impact)
+                    .ok_or(crate::Error::Computation(
+                        "calculating max price impact diff for liquidation",
+                    ))?;
+            if *impact_diff > max_impact_diff {
+                *impact_diff = max_impact_diff;
+            }
+        }
+        Ok(())
+    }
+
     fn get_execution_params(&self) -> crate::Result<ExecutionParams<P::Num>> {
         let index_token_price = &self.params.prices.index_token_price;
         let size_delta_usd = &self.size_delta_usd;
@@ -482,11 +530,16 @@ where
             });
         }
 
-        let (price_impact, price_impact_diff_usd) = self.position.capped_position_price_impact(
-            index_token_price,
-            &self.size_delta_usd.to_opposite_signed()?,
-            true,
-        )?;
+        let (mut price_impact, mut price_impact_diff_usd) =
+            self.position.capped_position_price_impact(
+                index_token_price,
+                &self.size_delta_usd.to_opposite_signed()?,
+                true,
+            )?;
+
+        if self.params.is_liquidation_order() {
+            self.cap_liquidation_price_impact(&mut price_impact.value, &mut price_impact_diff_usd)?;
+        }
 
         let execution_price = utils::get_execution_price_for_decrease(
             index_token_price,
@@ -729,8 +782,10 @@ struct ExecutionParams<T: Unsigned> {
 mod tests {
     use crate::{
         market::LiquidityMarketMutExt,
-        test::{TestMarket, TestPosition},
-        MarketAction,
+        pool::Balance,
+        position::{InsolventCloseStep, PositionState},
+        test::{TestMarket, TestMarketConfig, TestPosition},
+        BaseMarket, MarketAction,
     };
 
     use super::*;
@@ -784,4 +839,234 @@ mod tests {
         println!("{market:#?}");
         Ok(())
     }
+
+    /// Close a position whose loss exceeds its collateral with the given insurance fund.
+    fn insolvent_close(
+        insurance_fund: u64,
+    ) -> crate::Result<(TestMarket<u64, 9>, DecreasePositionReport<u64, i64>)> {
+        let mut market = TestMarket::<u64, 9>::default();
+        let prices = Prices::new_for_test(120, 120, 1);
+        market.deposit(1_000_000_000, 0, prices)?.execute()?;
+        market.deposit(0, 1_000_000_000, prices)?.execute()?;
+        market.set_insurance_fund(true, insurance_fund);
+        let mut position = TestPosition::long(true);
+        let _ = position
+            .ops(&mut market)
+            .increase(
+                Prices::new_for_test(120, 120, 1),
+                100_000_000,
+                80_000_000_000,
+                None,
+            )?
+            .execute()?;
+        let report = position
+            .ops(&mut market)
+            .decrease(
+                Prices::new_for_test(100, 100, 1),
+                80_000_000_000,
+                None,
+                0,
+                DecreasePositionFlags {
+                    is_insolvent_close_allowed: true,
+                    is_liquidation_order: true,
+                    is_cap_size_delta_usd_allowed: false,
+                },
+            )?
+            .execute()?;
+        Ok((market, *report))
+    }
+
+    #[test]
+    fn cover_unpaid_pnl_with_insurance_fund() -> crate::Result<()> {
+        // Empty fund: the unpaid pnl is left as bad debt.
+        let (empty, report) = insolvent_close(0)?;
+        assert!(matches!(
+            report.insolvent_close_step(),
+            Some(InsolventCloseStep::Pnl)
+        ));
+        let pool_amount = empty.liquidity_pool()?.long_amount()?;
+
+        // Partial cover: the whole fund is drawn into the pool, and the rest is left as bad debt.
+        let (partial, report) = insolvent_close(1_000)?;
+        assert!(matches!(
+            report.insolvent_close_step(),
+            Some(InsolventCloseStep::Pnl)
+        ));
+        assert_eq!(*partial.insurance_fund(true), 0);
+        assert_eq!(
+            partial.liquidity_pool()?.long_amount()?,
+            pool_amount + 1_000
+        );
+
+        // Full cover: only the unpaid amount is drawn, and the pnl is no longer left unpaid.
+        let fund = 1_000_000_000;
+        let (full, report) = insolvent_close(fund)?;
+        assert!(!matches!(
+            report.insolvent_close_step(),
+            Some(InsolventCloseStep::Pnl)
+        ));
+        let drawn_amount = fund - *full.insurance_fund(true);
+        assert!(drawn_amount > 1_000);
+        assert_eq!(
+            full.liquidity_pool()?.long_amount()?,
+            pool_amount + drawn_amount
+        );
+        Ok(())
+    }
+
+    fn liquidatable_position(
+        market: &mut TestMarket<u64, 9>,
+        index_price: u64,
+    ) -> crate::Result<TestPosition<u64, 9>> {
+        let prices = Prices::new_for_test(120, 120, 1);
+        market.deposit(100_000_000_000, 0, prices)?.execute()?;
+        market.deposit(0, 100_000_000_000, prices)?.execute()?;
+        let mut position = TestPosition::long(false);
+        let _ = position
+            .ops(market)
+            .increase(prices, 10_000_000_000, 400_000_000_000, None)?
+            .execute()?;
+        let prices = Prices::new_for_test(index_price, index_price, 1);
+        assert!(position
+            .ops(market)
+            .check_liquidatable(&prices, true)?
+            .is_some());
+        Ok(position)
+    }
+
+    #[test]
+    fn partial_liquidation() -> crate::Result<()> {
+        let buffer_factor = 5_000_000;
+        let flags = DecreasePositionFlags {
+            is_insolvent_close_allowed: true,
+            is_liquidation_order: true,
+            is_cap_size_delta_usd_allowed: false,
+        };
+
+        let mut market = TestMarket::<u64, 9>::default();
+        let prices = Prices::new_for_test(118, 118, 1);
+        let mut position = liquidatable_position(&mut market, 118)?;
+        let size_in_usd = *position.ops(&mut market).size_in_usd();
+        let size_delta_usd = position
+            .ops(&mut market)
+            .partial_liquidation_size_delta_usd(&prices, &buffer_factor)?
+            .expect("must be partially liquidatable");
+        assert!(size_delta_usd < size_in_usd);
+
+        let report = position
+            .ops(&mut market)
+            .decrease(prices, size_delta_usd, None, 0, flags)?
+            .execute()?;
+        assert!(!report.should_remove());
+        assert_eq!(*report.size_delta_usd(), size_delta_usd);
+        assert!(report.insolvent_close_step().is_none());
+
+        // The remaining position is restored to health.
+        let ops = position.ops(&mut market);
+        assert_eq!(*ops.size_in_usd(), size_in_usd - size_delta_usd);
+        assert!(ops.check_liquidatable(&prices, true)?.is_none());
+
+        // Fall back to a full liquidation if the position cannot be restored.
+        let mut market = TestMarket::<u64, 9>::default();
+        let prices = Prices::new_for_test(110, 110, 1);
+        let mut position = liquidatable_position(&mut market, 110)?;
+        assert!(position
+            .ops(&mut market)
+            .partial_liquidation_size_delta_usd(&prices, &buffer_factor)?
+            .is_none());
+        let size_in_usd = *position.ops(&mut market).size_in_usd();
+        let report = position
+            .ops(&mut market)
+            .decrease(prices, size_in_usd, None, 0, flags)?
+            .execute()?;
+        assert!(report.should_remove());
+        Ok(())
+    }
+
+    /// Partially liquidate a long position while the market is skewed to the short side,
+    /// so that the liquidation is charged a negative price impact.
+    ///
+    /// Returns the report and the remaining collateral amount of the position.
+    fn skewed_partial_liquidation(
+        cap: Option<u64>,
+    ) -> crate::Result<(DecreasePositionReport<u64, i64>, u64)> {
+        let mut config = TestMarketConfig::<u64, 9>::default();
+        config.position_params = config
+            .position_params
+            .with_max_position_impact_factor_for_liquidation_execution(cap);
+        let mut market = TestMarket::<u64, 9>::with_config(config);
+        let prices = Prices::new_for_test(120, 120, 1);
+        market.deposit(100_000_000_000, 0, prices)?.execute()?;
+        market.deposit(0, 10_000_000_000_000, prices)?.execute()?;
+        let mut short = TestPosition::short(false);
+        let _ = short
+            .ops(&mut market)
+            .increase(prices, 100_000_000_000, 2_000_000_000_000, None)?
+            .execute()?;
+        let mut position = TestPosition::long(false);
+        let _ = position
+            .ops(&mut market)
+            .increase(prices, 10_000_000_000, 400_000_000_000, None)?
+            .execute()?;
+
+        let prices = Prices::new_for_test(118, 118, 1);
+        assert!(position
+            .ops(&mut market)
+            .check_liquidatable(&prices, true)?
+            .is_some());
+        let size_in_usd = *position.ops(&mut market).size_in_usd();
+        let size_delta_usd = position
+            .ops(&mut market)
+            .partial_liquidation_size_delta_usd(&prices, &5_000_000)?
+            .expect("must be partially liquidatable");
+        assert!(size_delta_usd < size_in_usd);
+
+        let report = position
+            .ops(&mut market)
+            .decrease(
+                prices,
+                size_delta_usd,
+                None,
+                0,
+                DecreasePositionFlags {
+                    is_insolvent_close_allowed: true,
+                    is_liquidation_order: true,
+                    is_cap_size_delta_usd_allowed: false,
+                },
+            )?
+            .execute()?;
+        assert!(!report.should_remove());
+        let collateral_amount = *position.ops(&mut market).collateral_amount();
+        Ok((*report, collateral_amount))
+    }
+
+    #[test]
+    fn cap_price_impact_for_partial_liquidation() -> crate::Result<()> {
+        use crate::num::UnsignedAbs;
+        use num_traits::Signed;
+
+        let total_impact = |report: &DecreasePositionReport<u64, i64>| {
+            report.price_impact_value().unsigned_abs() + report.price_impact_diff()
+        };
+
+        let (uncapped, uncapped_collateral_amount) = skewed_partial_liquidation(None)?;
+        assert!(uncapped.price_impact_value().is_negative());
+
+        // 0.0001%
+        let factor = 1_000;
+        let (capped, capped_collateral_amount) = skewed_partial_liquidation(Some(factor))?;
+        assert_eq!(capped.size_delta_usd(), uncapped.size_delta_usd());
+
+        // The cap is relative to the liquidated size rather than the size of the whole position.
+        let max_impact = crate::utils::apply_factor::<_, 9>(capped.size_delta_usd(), &factor)
+            .expect("must not overflow");
+        assert!(total_impact(&uncapped) > max_impact);
+        assert_eq!(total_impact(&capped), max_impact);
+        assert_eq!(*capped.price_impact_diff(), 0);
+
+        // The excess is not charged to the position, leaving the remaining position
+        // with more collateral than without the cap.
+        assert!(capped_collateral_amount > uncapped_collateral_amount);
+        Ok(())
+    }
 }
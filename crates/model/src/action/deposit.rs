@@ -23,6 +23,7 @@ pub struct Deposit<M: BaseMarket<DECIMALS>, const DECIMALS: u8> {
     // to avoid introducing breaking changes, but it should be added
     // in the future when the timing is right.
     include_virtual_inventory_impact: bool,
+    min_fee_value: M::Num,
 }
 
 /// Deposit params.
@@ -171,6 +172,7 @@ impl<const DECIMALS: u8, M: LiquidityMarketMut<DECIMALS>> Deposit<M, DECIMALS> {
                 prices,
             },
             include_virtual_inventory_impact: true,
+            min_fee_value: Zero::zero(),
         })
     }
 
@@ -180,6 +182,44 @@ impl<const DECIMALS: u8, M: LiquidityMarketMut<DECIMALS>> Deposit<M, DECIMALS> {
         self
     }
 
+    /// Configures the min fee value to charge for the deposit (defaults to `0`).
+    ///
+    /// The min fee value is split between the long and short tokens by their values.
+    pub fn with_min_fee_value(mut self, min_fee_value: M::Num) -> Self {
+        self.min_fee_value = min_fee_value;
+        self
+    }
+
+    /// Get the min fee amount to charge for the given side.
+    fn min_fee_amount(&self, is_long_token: bool) -> crate::Result<M::Num> {
+        if self.min_fee_value.is_zero() {
+            return Ok(Zero::zero());
+        }
+        let params = &self.params;
+        let total_value = params
+            .long_token_amount
+            .checked_mul(params.long_token_price().pick_price(false))
+            .zip(
+                params
+                    .short_token_amount
+                    .checked_mul(params.short_token_price().pick_price(false)),
+            )
+            .and_then(|(long_value, short_value)| long_value.checked_add(&short_value))
+            .ok_or(crate::Error::Computation(
+                "deposit: calculating total value",
+            ))?;
+        let amount = if is_long_token {
+            &params.long_token_amount
+        } else {
+            &params.short_token_amount
+        };
+        self.min_fee_value
+            .checked_mul_div(amount, &total_value)
+            .ok_or(crate::Error::Computation(
+                "deposit: calculating min fee amount",
+            ))
+    }
+
     /// Get the price impact USD value.
     fn price_impact(&self) -> crate::Result<PriceImpactWithDeltas<M::Num>> {
         let delta = self.market.liquidity_pool()?.pool_delta_with_amounts(
@@ -216,13 +256,15 @@ impl<const DECIMALS: u8, M: LiquidityMarketMut<DECIMALS>> Deposit<M, DECIMALS> {
     /// The `amount` will become the amount after fees.
     fn charge_fees(
         &self,
+        is_long_token: bool,
         balance_change: BalanceChange,
         amount: &mut M::Num,
     ) -> crate::Result<Fees<M::Num>> {
+        let min_fee_amount = self.min_fee_amount(is_long_token)?;
         let (amount_after_fees, fees) = self
             .market
             .swap_fee_params()?
-            .apply_fees(balance_change, amount)
+            .apply_fees_with_min_fee_amount(balance_change, amount, &min_fee_amount)
             .ok_or(crate::Error::Computation("apply fees"))?;
         *amount = amount_after_fees;
         Ok(fees)
@@ -250,7 +292,7 @@ impl<const DECIMALS: u8, M: LiquidityMarketMut<DECIMALS>> Deposit<M, DECIMALS> {
             opposite_price,
         } = self.params.reassign_values(is_long_token);
 
-        let fees = self.charge_fees(balance_change, &mut amount)?;
+        let fees = self.charge_fees(is_long_token, balance_change, &mut amount)?;
         self.market.claimable_fee_pool_mut()?.apply_delta_amount(
             is_long_token,
             &fees
@@ -496,6 +538,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn min_fee_value() -> crate::Result<()> {
+        let mut market = TestMarket::<u64, 9>::default();
+        let prices = Prices::new_for_test(120, 120, 1);
+        // The min fee value is `10` which is `1/12` of the long token amount.
+        let report = market
+            .deposit(1_000_000_000, 0, prices)?
+            .with_min_fee_value(10_000_000_000)
+            .execute()?;
+        let fees = report.long_token_fees();
+        assert_eq!(
+            fees.fee_amount_for_pool() + fees.fee_amount_for_receiver(),
+            83_333_333
+        );
+        Ok(())
+    }
+
     #[cfg(feature = "u128")]
     #[test]
     fn basic_u128() -> Result<(), crate::Error> {
@@ -2,7 +2,9 @@ use num_traits::{CheckedAdd, CheckedDiv, CheckedNeg, Signed, Zero};
 use std::fmt;
 
 use crate::{
-    market::{BaseMarketExt, BaseMarketMutExt, PerpMarketExt, PositionImpactMarketMutExt},
+    market::{
+        BaseMarket, BaseMarketExt, BaseMarketMutExt, PerpMarketExt, PositionImpactMarketMutExt,
+    },
     num::Unsigned,
     params::fee::PositionFees,
     pool::delta::PriceImpact,
@@ -445,6 +447,12 @@ where
     type Report = IncreasePositionReport<P::Num, P::Signed>;
 
     fn execute(mut self) -> crate::Result<Self::Report> {
+        if !self.params.size_delta_usd.is_zero() && !self.position.market().enforce_reserves()? {
+            return Err(crate::Error::InvalidArgument(
+                "increasing positions is not allowed while reserves are not enforced",
+            ));
+        }
+
         self.initialize_position_if_empty()?;
 
         let ExecutionParamsWithPriceImpact {
@@ -565,7 +573,7 @@ struct ExecutionParamsWithPriceImpact<T: Unsigned> {
 mod tests {
     use crate::{
         market::LiquidityMarketMutExt,
-        test::{TestMarket, TestPosition},
+        test::{TestMarket, TestMarketConfig, TestPosition},
         MarketAction,
     };
 
@@ -592,4 +600,27 @@ mod tests {
         println!("{position:#?}");
         Ok(())
     }
+
+    #[test]
+    fn increase_is_rejected_while_reserves_are_not_enforced() -> crate::Result<()> {
+        let mut market = TestMarket::<u64, 9>::with_config(TestMarketConfig {
+            enforce_reserves: false,
+            ..Default::default()
+        });
+        let prices = Prices::new_for_test(120, 120, 1);
+        market.deposit(1_000_000_000, 0, prices)?.execute()?;
+        market.deposit(0, 1_000_000_000, prices)?.execute()?;
+        let mut position = TestPosition::long(true);
+        let result = position
+            .ops(&mut market)
+            .increase(
+                Prices::new_for_test(123, 123, 1),
+                100_000_000,
+                8_000_000_000,
+                None,
+            )?
+            .execute();
+        assert!(result.is_err());
+        Ok(())
+    }
 }
@@ -127,6 +127,12 @@ impl<const DECIMALS: u8, M: SwapMarketMut<DECIMALS>> Swap<M, DECIMALS> {
                 ))?;
 
         // Calculate final amounts && apply delta to price impact pool.
+        //
+        // Note: prices are unit prices, i.e. the values of the smallest units of the tokens,
+        // so amounts of tokens with different decimals are normalized to the common precision
+        // of values by multiplying by the prices. The conversion from `token_in` to `token_out`
+        // is performed with `checked_mul_div`, which keeps the full precision of the product and
+        // only rounds down once to the smallest unit of `token_out`.
         let mut token_in_amount;
         let token_out_amount;
         let pool_amount_out;
@@ -557,6 +563,10 @@ where
     fn ignore_open_interest_for_usage_factor(&self) -> crate::Result<bool> {
         self.market.ignore_open_interest_for_usage_factor()
     }
+
+    fn enforce_reserves(&self) -> crate::Result<bool> {
+        self.market.enforce_reserves()
+    }
 }
 
 #[cfg(test)]
@@ -706,6 +716,77 @@ mod tests {
         Ok(())
     }
 
+    /// A test for a swap path across tokens with different decimals.
+    #[cfg(feature = "u128")]
+    #[test]
+    fn cross_decimals_swap_path() -> crate::Result<()> {
+        use crate::{
+            params::{FeeParams, PriceImpactParams},
+            test::TestMarketConfig,
+        };
+
+        // Unit prices of the tokens:
+        // - token A: 6 decimals, $1.
+        // - token B: 9 decimals, $150.
+        // - token C: 2 decimals, $0.5.
+        const PRICE_A: u128 = 10u128.pow(14);
+        const PRICE_B: u128 = 150 * 10u128.pow(11);
+        const PRICE_C: u128 = 5 * 10u128.pow(17);
+
+        // Disable fees and price impact so that the outputs are only affected by rounding.
+        let config = TestMarketConfig::<u128, 20> {
+            swap_impact_params: PriceImpactParams::builder()
+                .exponent(2 * 10u128.pow(20))
+                .positive_factor(0)
+                .negative_factor(0)
+                .build(),
+            swap_fee_params: FeeParams::builder()
+                .fee_receiver_factor(0)
+                .positive_impact_fee_factor(0)
+                .negative_impact_fee_factor(0)
+                .build(),
+            ..Default::default()
+        };
+
+        // Market 1: B as long token and A as short token.
+        let mut market_1 = TestMarket::<u128, 20>::with_config(config.clone());
+        let prices_1 = Prices::new_for_test(PRICE_B, PRICE_B, PRICE_A);
+        market_1
+            .deposit(1_000 * 10u128.pow(9), 150_000 * 10u128.pow(6), prices_1)?
+            .execute()?;
+
+        // Market 2: B as long token and C as short token.
+        let mut market_2 = TestMarket::<u128, 20>::with_config(config);
+        let prices_2 = Prices::new_for_test(PRICE_B, PRICE_B, PRICE_C);
+        market_2
+            .deposit(1_000 * 10u128.pow(9), 300_000 * 10u128.pow(2), prices_2)?
+            .execute()?;
+
+        // Swap 12.345678 A for B, then swap the output B for C.
+        let amount_a = 12_345_678;
+        let report = market_1.swap(false, amount_a, prices_1)?.execute()?;
+        let amount_b = *report.token_out_amount();
+        assert_eq!(amount_b, 82_304_520);
+        let report = market_2.swap(true, amount_b, prices_2)?.execute()?;
+        let amount_c = *report.token_out_amount();
+        // The output is 24.691356 C rounded down to the smallest unit of C,
+        // which is the same as swapping A for C directly.
+        assert_eq!(amount_c, 2_469);
+        assert_eq!(amount_c, amount_a * PRICE_A / PRICE_C);
+
+        // Swap the output C back to A along the reversed path.
+        let report = market_2.swap(false, amount_c, prices_2)?.execute()?;
+        let amount_b = *report.token_out_amount();
+        assert_eq!(amount_b, 82_300_000);
+        let report = market_1.swap(true, amount_b, prices_1)?.execute()?;
+        let amount_a_back = *report.token_out_amount();
+        // Only the value lost in rounding down to the smallest unit of C is not returned.
+        assert_eq!(amount_a_back, 12_345_000);
+        assert_eq!(amount_a_back, amount_c * PRICE_C / PRICE_A);
+
+        Ok(())
+    }
+
     /// A test for small amount.
     #[test]
     fn small_amount_swap() -> crate::Result<()> {
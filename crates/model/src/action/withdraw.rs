@@ -6,7 +6,7 @@ use crate::{
     price::{Price, Prices},
     utils, BalanceExt, PnlFactorKind, PoolExt,
 };
-use num_traits::{CheckedAdd, CheckedDiv, Signed, Zero};
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, Signed, Zero};
 
 use super::MarketAction;
 
@@ -15,6 +15,7 @@ use super::MarketAction;
 pub struct Withdrawal<M: BaseMarket<DECIMALS>, const DECIMALS: u8> {
     market: M,
     params: WithdrawParams<M::Num>,
+    min_fee_value: M::Num,
 }
 
 /// Withdraw params.
@@ -117,9 +118,18 @@ impl<const DECIMALS: u8, M: LiquidityMarketMut<DECIMALS>> Withdrawal<M, DECIMALS
                 market_token_amount,
                 prices,
             },
+            min_fee_value: Zero::zero(),
         })
     }
 
+    /// Configures the min fee value to charge for the withdrawal (defaults to `0`).
+    ///
+    /// The min fee value is split between the long and short tokens by their values.
+    pub fn with_min_fee_value(mut self, min_fee_value: M::Num) -> Self {
+        self.min_fee_value = min_fee_value;
+        self
+    }
+
     fn output_amounts(&self) -> crate::Result<(M::Num, M::Num)> {
         let pool_value = self.market.pool_value(
             &self.params.prices,
@@ -172,11 +182,49 @@ impl<const DECIMALS: u8, M: LiquidityMarketMut<DECIMALS>> Withdrawal<M, DECIMALS
         Ok((long_token_amount, short_token_amount))
     }
 
-    fn charge_fees(&self, amount: &mut M::Num) -> crate::Result<Fees<M::Num>> {
+    /// Get the min fee amounts to charge for the given output amounts.
+    fn min_fee_amounts(
+        &self,
+        long_token_amount: &M::Num,
+        short_token_amount: &M::Num,
+    ) -> crate::Result<(M::Num, M::Num)> {
+        if self.min_fee_value.is_zero() {
+            return Ok((Zero::zero(), Zero::zero()));
+        }
+        let total_value = long_token_amount
+            .checked_mul(self.params.long_token_price().pick_price(true))
+            .zip(short_token_amount.checked_mul(self.params.short_token_price().pick_price(true)))
+            .and_then(|(long_value, short_value)| long_value.checked_add(&short_value))
+            .ok_or(crate::Error::Computation(
+                "withdrawal: calculating total value",
+            ))?;
+        if total_value.is_zero() {
+            return Ok((Zero::zero(), Zero::zero()));
+        }
+        let long_min_fee_amount = self
+            .min_fee_value
+            .checked_mul_div(long_token_amount, &total_value)
+            .ok_or(crate::Error::Computation(
+                "withdrawal: calculating min fee amount for long token",
+            ))?;
+        let short_min_fee_amount = self
+            .min_fee_value
+            .checked_mul_div(short_token_amount, &total_value)
+            .ok_or(crate::Error::Computation(
+                "withdrawal: calculating min fee amount for short token",
+            ))?;
+        Ok((long_min_fee_amount, short_min_fee_amount))
+    }
+
+    fn charge_fees(
+        &self,
+        amount: &mut M::Num,
+        min_fee_amount: &M::Num,
+    ) -> crate::Result<Fees<M::Num>> {
         let (amount_after_fees, fees) = self
             .market
             .swap_fee_params()?
-            .apply_fees(BalanceChange::Worsened, amount)
+            .apply_fees_with_min_fee_amount(BalanceChange::Worsened, amount, min_fee_amount)
             .ok_or(crate::Error::Computation("apply fees"))?;
         *amount = amount_after_fees;
         Ok(fees)
@@ -188,8 +236,10 @@ impl<const DECIMALS: u8, M: LiquidityMarketMut<DECIMALS>> MarketAction for Withd
 
     fn execute(mut self) -> crate::Result<Self::Report> {
         let (mut long_token_amount, mut short_token_amount) = self.output_amounts()?;
-        let long_token_fees = self.charge_fees(&mut long_token_amount)?;
-        let short_token_fees = self.charge_fees(&mut short_token_amount)?;
+        let (long_min_fee_amount, short_min_fee_amount) =
+            self.min_fee_amounts(&long_token_amount, &short_token_amount)?;
+        let long_token_fees = self.charge_fees(&mut long_token_amount, &long_min_fee_amount)?;
+        let short_token_fees = self.charge_fees(&mut short_token_amount, &short_min_fee_amount)?;
         // Apply claimable fees delta.
         let pool = self.market.claimable_fee_pool_mut()?;
         pool.apply_delta_amount(
@@ -72,6 +72,9 @@ pub trait BaseMarket<const DECIMALS: u8> {
 
     /// Returns whether ignore open interest for usage factor.
     fn ignore_open_interest_for_usage_factor(&self) -> crate::Result<bool>;
+
+    /// Returns whether reserves are enforced.
+    fn enforce_reserves(&self) -> crate::Result<bool>;
 }
 
 /// Base Market trait for mutable access.
@@ -167,6 +170,10 @@ impl<M: BaseMarket<DECIMALS>, const DECIMALS: u8> BaseMarket<DECIMALS> for &mut
     fn ignore_open_interest_for_usage_factor(&self) -> crate::Result<bool> {
         (**self).ignore_open_interest_for_usage_factor()
     }
+
+    fn enforce_reserves(&self) -> crate::Result<bool> {
+        (**self).enforce_reserves()
+    }
 }
 
 impl<M: BaseMarketMut<DECIMALS>, const DECIMALS: u8> BaseMarketMut<DECIMALS> for &mut M {
@@ -369,6 +376,10 @@ pub trait BaseMarketExt<const DECIMALS: u8>: BaseMarket<DECIMALS> {
 
     /// Validate reserve.
     fn validate_reserve(&self, prices: &Prices<Self::Num>, is_long: bool) -> crate::Result<()> {
+        if !self.enforce_reserves()? {
+            return Ok(());
+        }
+
         let pool_value = self.pool_value_without_pnl_for_one_side(prices, is_long, false)?;
 
         let max_reserved_value =
@@ -1,6 +1,6 @@
 use std::ops::DerefMut;
 
-use num_traits::{CheckedAdd, Signed};
+use num_traits::{CheckedAdd, Signed, Zero};
 
 use crate::{
     action::update_funding_state::UpdateFundingState,
@@ -120,6 +120,20 @@ pub trait PerpMarketMut<const DECIMALS: u8>:
     ) -> crate::Result<()> {
         Ok(())
     }
+
+    /// Insufficient pnl payment callback.
+    ///
+    /// Called during an insolvent close when the collateral of the position is not enough
+    /// to cover its negative pnl. Returns the amount of collateral tokens covered by the market
+    /// (e.g. by an insurance fund), which will be added to the pool and deducted from the
+    /// unpaid pnl. The close is no longer treated as insolvent if it is fully covered.
+    fn on_insufficient_pnl_payment(
+        &mut self,
+        _unpaid_amount: &Self::Num,
+        _is_collateral_token_long: bool,
+    ) -> crate::Result<Self::Num> {
+        Ok(Zero::zero())
+    }
 }
 
 impl<M: PerpMarket<DECIMALS>, const DECIMALS: u8> PerpMarket<DECIMALS> for &mut M {
@@ -225,6 +239,14 @@ impl<M: PerpMarketMut<DECIMALS>, const DECIMALS: u8> PerpMarketMut<DECIMALS> for
             is_collateral_token_long,
         )
     }
+
+    fn on_insufficient_pnl_payment(
+        &mut self,
+        unpaid_amount: &Self::Num,
+        is_collateral_token_long: bool,
+    ) -> crate::Result<Self::Num> {
+        (**self).on_insufficient_pnl_payment(unpaid_amount, is_collateral_token_long)
+    }
 }
 
 /// Extension trait for [`PerpMarket`].
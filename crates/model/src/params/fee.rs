@@ -78,7 +78,25 @@ impl<T> FeeParams<T> {
     where
         T: FixedPointOps<DECIMALS>,
     {
-        let fee_amount = self.fee(balance_change, amount)?;
+        self.apply_fees_with_min_fee_amount(balance_change, amount, &Zero::zero())
+    }
+
+    /// Apply fees to `amount`, charging at least `min_fee_amount`.
+    /// - `DECIMALS` is the decimals of the parameters.
+    ///
+    /// Returns `None` if the computation fails, otherwise `amount` after fees and the fees are returned.
+    pub fn apply_fees_with_min_fee_amount<const DECIMALS: u8>(
+        &self,
+        balance_change: BalanceChange,
+        amount: &T,
+        min_fee_amount: &T,
+    ) -> Option<(T, Fees<T>)>
+    where
+        T: FixedPointOps<DECIMALS>,
+    {
+        let fee_amount = self
+            .fee(balance_change, amount)?
+            .max(min_fee_amount.clone());
         let fee_receiver_amount = self.receiver_fee(&fee_amount)?;
         let fees = Fees {
             fee_amount_for_pool: fee_amount.checked_sub(&fee_receiver_amount)?,
@@ -404,6 +422,11 @@ pub struct LiquidationFeeParams<T> {
 }
 
 impl<T> LiquidationFeeParams<T> {
+    /// Get liquidation fee factor.
+    pub fn factor(&self) -> &T {
+        &self.factor
+    }
+
     pub(crate) fn fee<const DECIMALS: u8>(
         &self,
         size_delta_usd: &T,
@@ -9,6 +9,8 @@ pub struct PositionParams<T> {
     max_positive_position_impact_factor: T,
     max_negative_position_impact_factor: T,
     max_position_impact_factor_for_liquidations: T,
+    #[builder(default, setter(strip_option))]
+    max_position_impact_factor_for_liquidation_execution: Option<T>,
 }
 
 impl<T> PositionParams<T> {
@@ -28,9 +30,19 @@ impl<T> PositionParams<T> {
             max_positive_position_impact_factor,
             max_negative_position_impact_factor,
             max_position_impact_factor_for_liquidations,
+            max_position_impact_factor_for_liquidation_execution: None,
         }
     }
 
+    /// Set the max position impact factor for liquidation execution.
+    pub fn with_max_position_impact_factor_for_liquidation_execution(
+        mut self,
+        factor: Option<T>,
+    ) -> Self {
+        self.max_position_impact_factor_for_liquidation_execution = factor;
+        self
+    }
+
     /// Get min position size usd.
     pub fn min_position_size_usd(&self) -> &T {
         &self.min_position_size_usd
@@ -60,6 +72,15 @@ impl<T> PositionParams<T> {
     pub fn max_position_impact_factor_for_liquidations(&self) -> &T {
         &self.max_position_impact_factor_for_liquidations
     }
+
+    /// Get max position impact factor for liquidation execution.
+    ///
+    /// Bounds the total negative price impact (including the price impact diff)
+    /// charged to a position when it is liquidated. Returns `None` if there is no such cap.
+    pub fn max_position_impact_factor_for_liquidation_execution(&self) -> Option<&T> {
+        self.max_position_impact_factor_for_liquidation_execution
+            .as_ref()
+    }
 }
 
 /// Position Impact Distribution Parameters.
@@ -458,6 +458,38 @@ pub trait PositionExt<const DECIMALS: u8>: Position<DECIMALS> {
         prices: &Prices<Self::Num>,
         should_validate_min_collateral_usd: bool,
     ) -> crate::Result<Option<LiquidatableReason>> {
+        let size_in_usd = self.size_in_usd();
+
+        let remaining_collateral_value = self.remaining_collateral_value_for_liquidation(prices)?;
+
+        let params = self.market().position_params()?;
+
+        match check_collateral(
+            size_in_usd,
+            params.min_collateral_factor(),
+            should_validate_min_collateral_usd.then(|| params.min_collateral_value()),
+            false,
+            &remaining_collateral_value,
+        )? {
+            CheckCollateralResult::Sufficient => Ok(None),
+            CheckCollateralResult::Zero | CheckCollateralResult::Negative => {
+                Ok(Some(LiquidatableReason::NotPositive))
+            }
+            CheckCollateralResult::MinCollateralForLeverage => {
+                Ok(Some(LiquidatableReason::MinCollateralForLeverage))
+            }
+            CheckCollateralResult::MinCollateral => Ok(Some(LiquidatableReason::MinCollateral)),
+        }
+    }
+
+    /// Get the remaining collateral value after closing the whole position,
+    /// which is used to determine whether the position is liquidatable.
+    ///
+    /// Liquidation fees are not included.
+    fn remaining_collateral_value_for_liquidation(
+        &self,
+        prices: &Prices<Self::Num>,
+    ) -> crate::Result<Self::Signed> {
         use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
 
         let size_in_usd = self.size_in_usd();
@@ -510,24 +542,84 @@ pub trait PositionExt<const DECIMALS: u8>: Position<DECIMALS> {
                 "calculating remaining collateral value",
             ))?;
 
+        Ok(remaining_collateral_value)
+    }
+
+    /// Calculate the size delta (in USD) of a partial liquidation that restores the
+    /// collateral of the position to `min_collateral_factor + buffer_factor`,
+    /// accounting for the liquidation fees and the realized pnl of the closed size.
+    ///
+    /// Returns `None` if a partial liquidation cannot restore the position to health,
+    /// in which case the position should be fully liquidated.
+    fn partial_liquidation_size_delta_usd(
+        &self,
+        prices: &Prices<Self::Num>,
+        buffer_factor: &Self::Num,
+    ) -> crate::Result<Option<Self::Num>> {
+        use num_traits::{CheckedAdd, CheckedSub};
+
+        let size_in_usd = self.size_in_usd();
+        let remaining_collateral_value = self.remaining_collateral_value_for_liquidation(prices)?;
+        if !remaining_collateral_value.is_positive() {
+            return Ok(None);
+        }
+        let remaining_collateral_value = remaining_collateral_value.unsigned_abs();
+
         let params = self.market().position_params()?;
+        let target_factor = params
+            .min_collateral_factor()
+            .checked_add(buffer_factor)
+            .ok_or(crate::Error::Computation(
+                "calculating target collateral factor",
+            ))?;
+        let required_value = crate::utils::apply_factor(size_in_usd, &target_factor).ok_or(
+            crate::Error::Computation("calculating required collateral value"),
+        )?;
+        let Some(value_to_restore) = required_value
+            .checked_sub(&remaining_collateral_value)
+            .filter(|value| !value.is_zero())
+        else {
+            return Ok(None);
+        };
 
-        match check_collateral(
-            size_in_usd,
-            params.min_collateral_factor(),
-            should_validate_min_collateral_usd.then(|| params.min_collateral_value()),
-            false,
-            &remaining_collateral_value,
-        )? {
-            CheckCollateralResult::Sufficient => Ok(None),
-            CheckCollateralResult::Zero | CheckCollateralResult::Negative => {
-                Ok(Some(LiquidatableReason::NotPositive))
-            }
-            CheckCollateralResult::MinCollateralForLeverage => {
-                Ok(Some(LiquidatableReason::MinCollateralForLeverage))
-            }
-            CheckCollateralResult::MinCollateral => Ok(Some(LiquidatableReason::MinCollateral)),
+        // The negative pnl, the position fees and the pending borrowing and funding fees are
+        // already deducted from the remaining collateral value, and they are realized from
+        // the collateral in the same way by the partial close. However, the realized positive
+        // pnl of the closed size is paid out instead of being kept as collateral.
+        let (pnl, _, _) = self.pnl_value(prices, size_in_usd)?;
+        let realized_pnl_factor = if pnl.is_positive() {
+            crate::utils::div_to_factor(&pnl.unsigned_abs(), size_in_usd, true)
+                .ok_or(crate::Error::Computation("calculating realized pnl factor"))?
+        } else {
+            Zero::zero()
+        };
+
+        // Each unit of size closed releases `target_factor` of required collateral while
+        // paying `liquidation_fee_factor` of liquidation fees and paying out
+        // `realized_pnl_factor` of positive pnl.
+        let liquidation_fee_factor = self.market().liquidation_fee_params()?.factor().clone();
+        let Some(factor) = target_factor
+            .checked_sub(&liquidation_fee_factor)
+            .and_then(|factor| factor.checked_sub(&realized_pnl_factor))
+            .filter(|factor| !factor.is_zero())
+        else {
+            return Ok(None);
+        };
+        let size_delta_usd = crate::utils::div_to_factor(&value_to_restore, &factor, true).ok_or(
+            crate::Error::Computation("calculating partial liquidation size"),
+        )?;
+
+        let Some(remaining_size) = size_in_usd
+            .checked_sub(&size_delta_usd)
+            .filter(|size| !size.is_zero())
+        else {
+            return Ok(None);
+        };
+        if remaining_size < *params.min_position_size_usd() {
+            return Ok(None);
         }
+
+        Ok(Some(size_delta_usd))
     }
 
     /// Get position price impact.
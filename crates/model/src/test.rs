@@ -148,6 +148,7 @@ pub struct TestMarket<T: Unsigned, const DECIMALS: u8> {
     claimable_funding_amount_per_size: (TestPool<T>, TestPool<T>),
     collateral_sum: (TestPool<T>, TestPool<T>),
     total_borrowing: TestPool<T>,
+    insurance_fund: (T, T),
     clock: Clock,
     clocks: HashMap<ClockKind, Instant>,
 }
@@ -180,6 +181,7 @@ impl<T: Unsigned, const DECIMALS: u8> TestMarket<T, DECIMALS> {
             claimable_funding_amount_per_size: Default::default(),
             collateral_sum: Default::default(),
             total_borrowing: Default::default(),
+            insurance_fund: Default::default(),
             clocks: Default::default(),
             clock: Default::default(),
         }
@@ -189,6 +191,24 @@ impl<T: Unsigned, const DECIMALS: u8> TestMarket<T, DECIMALS> {
     pub fn move_clock_forward(&mut self, duration: Duration) {
         self.clock.move_forward(duration);
     }
+
+    /// Get the insurance fund of the given side.
+    pub fn insurance_fund(&self, is_long: bool) -> &T {
+        if is_long {
+            &self.insurance_fund.0
+        } else {
+            &self.insurance_fund.1
+        }
+    }
+
+    /// Set the insurance fund of the given side.
+    pub fn set_insurance_fund(&mut self, is_long: bool, amount: T) {
+        if is_long {
+            self.insurance_fund.0 = amount;
+        } else {
+            self.insurance_fund.1 = amount;
+        }
+    }
 }
 
 impl TestMarket<u64, 9> {
@@ -260,6 +280,8 @@ pub struct TestMarketConfig<T, const DECIMALS: u8> {
     pub min_collateral_factor_for_oi: T,
     /// Ignore open interest for usage factor.
     pub ignore_open_interest_for_usage_factor: bool,
+    /// Enforce reserves.
+    pub enforce_reserves: bool,
     /// Liquidation fee params.
     pub liquidation_fee_params: LiquidationFeeParams<T>,
 }
@@ -336,6 +358,7 @@ impl Default for TestMarketConfig<u64, 9> {
             // min collateral factor of 0.005 when open interest is $83,000,000
             min_collateral_factor_for_oi: 5 * 10u64.pow(6) / 83_000_000,
             ignore_open_interest_for_usage_factor: false,
+            enforce_reserves: true,
             liquidation_fee_params: LiquidationFeeParams::builder()
                 .factor(2_000_000)
                 .receiver_factor(370_000_000)
@@ -419,6 +442,7 @@ impl Default for TestMarketConfig<u128, 20> {
             // min collateral factor of 0.005 when open interest is $83,000,000
             min_collateral_factor_for_oi: 5 * 10u128.pow(17) / 83_000_000,
             ignore_open_interest_for_usage_factor: false,
+            enforce_reserves: true,
             liquidation_fee_params: LiquidationFeeParams::builder()
                 .factor(200_000_000_000_000_000)
                 .receiver_factor(37_000_000_000_000_000_000)
@@ -545,6 +569,10 @@ where
     fn ignore_open_interest_for_usage_factor(&self) -> crate::Result<bool> {
         Ok(self.config.ignore_open_interest_for_usage_factor)
     }
+
+    fn enforce_reserves(&self) -> crate::Result<bool> {
+        Ok(self.config.enforce_reserves)
+    }
 }
 
 impl<T, const DECIMALS: u8> BaseMarketMut<DECIMALS> for TestMarket<T, DECIMALS>
@@ -835,6 +863,23 @@ where
     fn just_passed_in_seconds_for_funding(&mut self) -> crate::Result<u64> {
         self.just_passed_in_seconds(ClockKind::Funding)
     }
+
+    fn on_insufficient_pnl_payment(
+        &mut self,
+        unpaid_amount: &Self::Num,
+        is_collateral_token_long: bool,
+    ) -> crate::Result<Self::Num> {
+        let fund = if is_collateral_token_long {
+            &mut self.insurance_fund.0
+        } else {
+            &mut self.insurance_fund.1
+        };
+        let drawn_amount = fund.clone().min(unpaid_amount.clone());
+        *fund = fund
+            .checked_sub(&drawn_amount)
+            .ok_or(crate::Error::Computation("drawing from insurance fund"))?;
+        Ok(drawn_amount)
+    }
 }
 
 /// Test Position
@@ -103,6 +103,7 @@ impl Pools {
 enum MarketConfigFlag {
     SkipBorrowingFeeForSmallerSide,
     IgnoreOpenInterestForUsageFactor,
+    EnforceReserves,
 }
 
 type MarketConfigFlags = Bitmap<{ constants::NUM_MARKET_CONFIG_FLAGS }>;
@@ -266,6 +267,7 @@ impl MarketModel {
             market_token: self.meta.market_token_mint,
             collateral_token,
             state: Zeroable::zeroed(),
+            cost_basis_value: 0,
             reserved: Zeroable::zeroed(),
         };
         PositionModel::new(self, Arc::new(position))
@@ -391,6 +393,10 @@ impl gmsol_model::BaseMarket<{ constants::MARKET_DECIMALS }> for MarketModel {
             .config
             .flag(MarketConfigFlag::IgnoreOpenInterestForUsageFactor))
     }
+
+    fn enforce_reserves(&self) -> gmsol_model::Result<bool> {
+        Ok(self.config.flag(MarketConfigFlag::EnforceReserves))
+    }
 }
 
 impl gmsol_model::SwapMarket<{ constants::MARKET_DECIMALS }> for MarketModel {
@@ -1,7 +1,10 @@
 use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
 use gmsol_programs::{
     anchor_lang::system_program,
-    gmsol_store::client::{accounts, args},
+    gmsol_store::{
+        client::{accounts, args},
+        types::CloseReason,
+    },
 };
 use gmsol_solana_utils::{AtomicGroup, IntoAtomicGroup};
 use typed_builder::TypedBuilder;
@@ -70,6 +73,18 @@ pub struct CloseOrderHint {
     pub should_unwrap_native_token: bool,
     /// Callback.
     pub callback: Option<Callback>,
+    /// Market token.
+    ///
+    /// Required if the order is pending and queued for fair execution.
+    #[cfg_attr(serde, serde(default))]
+    #[builder(default, setter(into))]
+    pub market_token: Option<StringPubkey>,
+    /// Position.
+    ///
+    /// Required if the order is recorded as a resting order of the position.
+    #[cfg_attr(serde, serde(default))]
+    #[builder(default, setter(into))]
+    pub position: Option<StringPubkey>,
 }
 
 impl IntoAtomicGroup for CloseOrder {
@@ -90,6 +105,10 @@ impl IntoAtomicGroup for CloseOrder {
         let final_output_token = hint.final_output_token.as_deref().copied();
         let long_token = hint.long_token.as_deref().copied();
         let short_token = hint.short_token.as_deref().copied();
+        let market = hint
+            .market_token
+            .as_ref()
+            .map(|token| self.program.find_market_address(token));
 
         let CallbackParams {
             callback_authority,
@@ -103,6 +122,7 @@ impl IntoAtomicGroup for CloseOrder {
             .program
             .instruction(args::CloseOrderV2 {
                 reason: self.reason,
+                close_reason: CloseReason::Unspecified,
             })
             .accounts(
                 accounts::CloseOrderV2 {
@@ -170,6 +190,9 @@ impl IntoAtomicGroup for CloseOrder {
                     callback_program,
                     callback_shared_data_account,
                     callback_partitioned_data_account,
+                    position: hint.position.as_deref().copied(),
+                    rent_recipient: None,
+                    market,
                 },
                 true,
             )
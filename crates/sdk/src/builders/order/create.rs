@@ -375,6 +375,7 @@ impl IntoAtomicGroup for CreateOrder {
             acceptable_price: self.params.acceptable_price,
             should_unwrap_native_token: self.unwrap_native_on_receive,
             valid_from_ts: self.params.valid_from_ts,
+            pay_execution_fee_from_collateral: None,
         };
 
         if self.kind.is_increase() {
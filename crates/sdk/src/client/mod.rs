@@ -503,6 +503,11 @@ impl<C: Clone + Deref<Target = impl Signer>> Client<C> {
         crate::pda::find_gt_exchange_address(vault, owner, self.store_program_id()).0
     }
 
+    /// Find PDA for the custom price feed counter of a token.
+    pub fn find_price_feed_counter_address(&self, store: &Pubkey, token: &Pubkey) -> Pubkey {
+        crate::pda::find_price_feed_counter_address(store, token, self.store_program_id()).0
+    }
+
     /// Find PDA for custom price feed.
     pub fn find_price_feed_address(
         &self,
@@ -4,7 +4,7 @@ use anchor_spl::associated_token::get_associated_token_address;
 use gmsol_programs::gmsol_store::{
     accounts::Deposit,
     client::{accounts, args},
-    types::CreateDepositParams,
+    types::{CloseReason, CreateDepositParams},
     ID,
 };
 use gmsol_solana_utils::{
@@ -26,7 +26,7 @@ use crate::{
     builders::utils::{generate_nonce, get_ata_or_owner},
     client::{
         feeds_parser::{FeedAddressMap, FeedsParser},
-        ops::token_account::TokenAccountOps,
+        ops::{token_account::TokenAccountOps, user::UserOps},
         pull_oracle::{FeedIds, PullOraclePriceConsumer},
         Client,
     },
@@ -290,6 +290,7 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> CreateDepositBuilder<'a, C> {
                     system_program: system_program::ID,
                     token_program: token_program_id,
                     associated_token_program: anchor_spl::associated_token::ID,
+                    user: client.find_user_address(store, &owner),
                 },
                 &ID,
                 client.store_program_id(),
@@ -330,7 +331,7 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> CreateDepositBuilder<'a, C> {
                     }))
                     .collect::<Vec<_>>(),
             );
-        let builder = prepare.merge(create);
+        let builder = prepare.merge(client.prepare_user(store)?).merge(create);
         Ok((builder, deposit))
     }
 }
@@ -356,6 +357,7 @@ pub struct CloseDepositHint {
     initial_long_token_account: Option<Pubkey>,
     initial_short_token_account: Option<Pubkey>,
     should_unwrap_native_token: bool,
+    is_pending_action_recorded: bool,
 }
 
 impl CloseDepositHint {
@@ -381,6 +383,10 @@ impl CloseDepositHint {
                 .header
                 .flags
                 .get_flag(ActionFlag::ShouldUnwrapNativeToken),
+            is_pending_action_recorded: deposit
+                .header
+                .flags
+                .get_flag(ActionFlag::PendingActionRecorded),
         }
     }
 }
@@ -473,12 +479,17 @@ where
                     system_program: system_program::ID,
                     event_authority: client.store_event_authority(),
                     program: *client.store_program_id(),
+                    rent_recipient: None,
+                    user: hint
+                        .is_pending_action_recorded
+                        .then(|| client.find_user_address(store, &owner)),
                 },
                 &ID,
                 client.store_program_id(),
             ))
             .anchor_args(args::CloseDeposit {
                 reason: self.reason.clone(),
+                close_reason: CloseReason::Unspecified,
             }))
     }
 }
@@ -512,6 +523,7 @@ pub struct ExecuteDepositHint {
     initial_long_token: Option<Pubkey>,
     initial_short_token: Option<Pubkey>,
     should_unwrap_native_token: bool,
+    is_pending_action_recorded: bool,
     virtual_inventories: BTreeSet<Pubkey>,
 }
 
@@ -532,6 +544,7 @@ impl ExecuteDepositHint {
             initial_long_token_account,
             initial_short_token_account,
             should_unwrap_native_token,
+            is_pending_action_recorded,
         } = CloseDepositHint::new(deposit);
         let swap: SwapActionParams = deposit.swap.into();
         Ok(Self {
@@ -546,6 +559,7 @@ impl ExecuteDepositHint {
             initial_long_token_escrow: initial_long_token_account,
             initial_short_token_escrow: initial_short_token_account,
             should_unwrap_native_token,
+            is_pending_action_recorded,
             virtual_inventories,
         })
     }
@@ -721,6 +735,7 @@ where
                     initial_long_token_account: hint.initial_long_token_escrow,
                     initial_short_token_account: hint.initial_short_token_escrow,
                     should_unwrap_native_token: hint.should_unwrap_native_token,
+                    is_pending_action_recorded: hint.is_pending_action_recorded,
                 })
                 .reason("executed")
                 .build()
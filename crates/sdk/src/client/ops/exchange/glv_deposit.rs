@@ -7,7 +7,7 @@ use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 use gmsol_programs::gmsol_store::{
     accounts::{Glv, GlvDeposit},
     client::{accounts, args},
-    types::CreateGlvDepositParams,
+    types::{CloseReason, CreateGlvDepositParams},
     ID,
 };
 use gmsol_solana_utils::{
@@ -32,7 +32,7 @@ use crate::{
     builders::utils::{generate_nonce, get_ata_or_owner_with_program_id},
     client::{
         feeds_parser::{FeedAddressMap, FeedsParser},
-        ops::{glv::split_to_accounts, token_account::TokenAccountOps},
+        ops::{glv::split_to_accounts, token_account::TokenAccountOps, user::UserOps},
         pull_oracle::{FeedIds, PullOraclePriceConsumer},
     },
     pda::NonceBytes,
@@ -368,6 +368,7 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> CreateGlvDepositBuilder<'a, C>
                     token_program: token_program_id,
                     glv_token_program: glv_token_program_id,
                     associated_token_program: anchor_spl::associated_token::ID,
+                    user: self.client.find_user_address(&self.store, &owner),
                 },
                 &ID,
                 self.client.store_program_id(),
@@ -406,7 +407,12 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> CreateGlvDepositBuilder<'a, C>
                     .collect::<Vec<_>>(),
             );
 
-        Ok((prepare.merge(create), glv_deposit))
+        Ok((
+            prepare
+                .merge(self.client.prepare_user(&self.store)?)
+                .merge(create),
+            glv_deposit,
+        ))
     }
 }
 
@@ -433,6 +439,7 @@ pub struct CloseGlvDepositHint {
     initial_short_token_escrow: Option<Pubkey>,
     glv_token_escrow: Pubkey,
     should_unwrap_native_token: bool,
+    is_pending_action_recorded: bool,
 }
 
 impl CloseGlvDepositHint {
@@ -454,6 +461,10 @@ impl CloseGlvDepositHint {
                 .header
                 .flags
                 .get_flag(ActionFlag::ShouldUnwrapNativeToken),
+            is_pending_action_recorded: glv_deposit
+                .header
+                .flags
+                .get_flag(ActionFlag::PendingActionRecorded),
         }
     }
 }
@@ -562,12 +573,17 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> CloseGlvDepositBuilder<'a, C> {
                     associated_token_program: anchor_spl::associated_token::ID,
                     event_authority: self.client.store_event_authority(),
                     program: *self.client.store_program_id(),
+                    rent_recipient: None,
+                    user: hint
+                        .is_pending_action_recorded
+                        .then(|| self.client.find_user_address(&hint.store, &hint.owner)),
                 },
                 &ID,
                 self.client.store_program_id(),
             ))
             .anchor_args(args::CloseGlvDeposit {
                 reason: self.reason.clone(),
+                close_reason: CloseReason::Unspecified,
             });
 
         Ok(rpc)
@@ -608,6 +624,7 @@ pub struct ExecuteGlvDepositHint {
     /// Feeds.
     pub feeds: TokensWithFeed,
     should_unwrap_native_token: bool,
+    is_pending_action_recorded: bool,
     virtual_inventories: BTreeSet<Pubkey>,
 }
 
@@ -634,6 +651,7 @@ impl ExecuteGlvDepositHint {
             initial_short_token_escrow,
             glv_token_escrow,
             should_unwrap_native_token,
+            is_pending_action_recorded,
         } = CloseGlvDepositHint::new(glv_deposit);
         let glv_market_tokens = glv.market_tokens().collect();
         let swap = glv_deposit.swap.into();
@@ -661,6 +679,7 @@ impl ExecuteGlvDepositHint {
                 .to_feeds(token_map)
                 .map_err(crate::Error::custom)?,
             should_unwrap_native_token,
+            is_pending_action_recorded,
             virtual_inventories,
         })
     }
@@ -895,6 +914,7 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> MakeBundleBuilder<'a, C>
                     initial_short_token_escrow: hint.initial_short_token_escrow,
                     glv_token_escrow: hint.glv_token_escrow,
                     should_unwrap_native_token: hint.should_unwrap_native_token,
+                    is_pending_action_recorded: hint.is_pending_action_recorded,
                 })
                 .build()
                 .await
@@ -8,7 +8,7 @@ use anchor_spl::associated_token::get_associated_token_address;
 use gmsol_programs::gmsol_store::{
     accounts::{GlvShift, Store},
     client::{accounts, args},
-    types::CreateShiftParams,
+    types::{CloseReason, CreateShiftParams},
     ID,
 };
 use gmsol_solana_utils::{
@@ -234,23 +234,29 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> CloseGlvShiftBuilder<'a, C> {
         let rpc = self
             .client
             .store_transaction()
-            .anchor_accounts(accounts::CloseGlvShift {
-                authority,
-                funder: hint.funder,
-                store: hint.store,
-                store_wallet: self.client.find_store_wallet_address(&hint.store),
-                glv: hint.owner,
-                glv_shift: self.glv_shift,
-                from_market_token: hint.from_market_token,
-                to_market_token: hint.to_market_token,
-                system_program: system_program::ID,
-                token_program: anchor_spl::token::ID,
-                associated_token_program: anchor_spl::associated_token::ID,
-                event_authority: self.client.store_event_authority(),
-                program: *self.client.store_program_id(),
-            })
+            .accounts(fix_optional_account_metas(
+                accounts::CloseGlvShift {
+                    authority,
+                    funder: hint.funder,
+                    store: hint.store,
+                    store_wallet: self.client.find_store_wallet_address(&hint.store),
+                    glv: hint.owner,
+                    glv_shift: self.glv_shift,
+                    from_market_token: hint.from_market_token,
+                    to_market_token: hint.to_market_token,
+                    system_program: system_program::ID,
+                    token_program: anchor_spl::token::ID,
+                    associated_token_program: anchor_spl::associated_token::ID,
+                    event_authority: self.client.store_event_authority(),
+                    program: *self.client.store_program_id(),
+                    rent_recipient: None,
+                },
+                &ID,
+                self.client.store_program_id(),
+            ))
             .anchor_args(args::CloseGlvShift {
                 reason: self.reason.clone(),
+                close_reason: CloseReason::Unspecified,
             });
 
         Ok(rpc)
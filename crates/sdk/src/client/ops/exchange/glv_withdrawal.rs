@@ -7,7 +7,7 @@ use anchor_spl::associated_token::get_associated_token_address_with_program_id;
 use gmsol_programs::gmsol_store::{
     accounts::{Glv, GlvWithdrawal},
     client::{accounts, args},
-    types::CreateGlvWithdrawalParams,
+    types::{CloseReason, CreateGlvWithdrawalParams},
     ID,
 };
 use gmsol_solana_utils::{
@@ -32,7 +32,7 @@ use crate::{
     builders::utils::{generate_nonce, get_ata_or_owner_with_program_id},
     client::{
         feeds_parser::{FeedAddressMap, FeedsParser},
-        ops::{glv::split_to_accounts, token_account::TokenAccountOps},
+        ops::{glv::split_to_accounts, token_account::TokenAccountOps, user::UserOps},
         pull_oracle::{FeedIds, PullOraclePriceConsumer},
     },
     pda::NonceBytes,
@@ -312,6 +312,7 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> CreateGlvWithdrawalBuilder<'a,
                 token_program: token_program_id,
                 glv_token_program: glv_token_program_id,
                 associated_token_program: anchor_spl::associated_token::ID,
+                user: self.client.find_user_address(&self.store, &owner),
             })
             .anchor_args(args::CreateGlvWithdrawal {
                 nonce,
@@ -345,7 +346,12 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> CreateGlvWithdrawalBuilder<'a,
                     .collect::<Vec<_>>(),
             );
 
-        Ok((prepare.merge(create), glv_withdrawal))
+        Ok((
+            prepare
+                .merge(self.client.prepare_user(&self.store)?)
+                .merge(create),
+            glv_withdrawal,
+        ))
     }
 }
 
@@ -372,6 +378,7 @@ pub struct CloseGlvWithdrawalHint {
     final_short_token_escrow: Pubkey,
     glv_token_escrow: Pubkey,
     should_unwrap_native_token: bool,
+    is_pending_action_recorded: bool,
 }
 
 impl CloseGlvWithdrawalHint {
@@ -393,6 +400,10 @@ impl CloseGlvWithdrawalHint {
                 .header
                 .flags
                 .get_flag(ActionFlag::ShouldUnwrapNativeToken),
+            is_pending_action_recorded: glv_withdrawal
+                .header
+                .flags
+                .get_flag(ActionFlag::PendingActionRecorded),
         }
     }
 }
@@ -471,34 +482,43 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> CloseGlvWithdrawalBuilder<'a, C
         let rpc = self
             .client
             .store_transaction()
-            .anchor_accounts(accounts::CloseGlvWithdrawal {
-                executor: payer,
-                store: hint.store,
-                store_wallet: self.client.find_store_wallet_address(&hint.store),
-                owner: hint.owner,
-                receiver: hint.receiver,
-                glv_withdrawal: self.glv_withdrawal,
-                market_token: hint.market_token,
-                final_long_token: hint.final_long_token,
-                final_short_token: hint.final_short_token,
-                glv_token: hint.glv_token,
-                market_token_escrow: hint.market_token_escrow,
-                final_long_token_escrow: hint.final_long_token_escrow,
-                final_short_token_escrow: hint.final_short_token_escrow,
-                market_token_ata,
-                final_long_token_ata,
-                final_short_token_ata,
-                glv_token_escrow: hint.glv_token_escrow,
-                glv_token_ata,
-                system_program: system_program::ID,
-                token_program: token_program_id,
-                glv_token_program: glv_token_program_id,
-                associated_token_program: anchor_spl::associated_token::ID,
-                event_authority: self.client.store_event_authority(),
-                program: *self.client.store_program_id(),
-            })
+            .accounts(fix_optional_account_metas(
+                accounts::CloseGlvWithdrawal {
+                    executor: payer,
+                    store: hint.store,
+                    store_wallet: self.client.find_store_wallet_address(&hint.store),
+                    owner: hint.owner,
+                    receiver: hint.receiver,
+                    glv_withdrawal: self.glv_withdrawal,
+                    market_token: hint.market_token,
+                    final_long_token: hint.final_long_token,
+                    final_short_token: hint.final_short_token,
+                    glv_token: hint.glv_token,
+                    market_token_escrow: hint.market_token_escrow,
+                    final_long_token_escrow: hint.final_long_token_escrow,
+                    final_short_token_escrow: hint.final_short_token_escrow,
+                    market_token_ata,
+                    final_long_token_ata,
+                    final_short_token_ata,
+                    glv_token_escrow: hint.glv_token_escrow,
+                    glv_token_ata,
+                    system_program: system_program::ID,
+                    token_program: token_program_id,
+                    glv_token_program: glv_token_program_id,
+                    associated_token_program: anchor_spl::associated_token::ID,
+                    event_authority: self.client.store_event_authority(),
+                    program: *self.client.store_program_id(),
+                    rent_recipient: None,
+                    user: hint
+                        .is_pending_action_recorded
+                        .then(|| self.client.find_user_address(&hint.store, &hint.owner)),
+                },
+                &ID,
+                self.client.store_program_id(),
+            ))
             .anchor_args(args::CloseGlvWithdrawal {
                 reason: self.reason.clone(),
+                close_reason: CloseReason::Unspecified,
             });
         Ok(rpc)
     }
@@ -8,7 +8,7 @@ use anchor_spl::associated_token::get_associated_token_address;
 use gmsol_programs::gmsol_store::{
     accounts::{Market, Order, Position, Store, UserHeader},
     client::{accounts, args},
-    types::{CreateOrderParams, DecreasePositionSwapType},
+    types::{CloseReason, CreateOrderParams, DecreasePositionSwapType},
     ID,
 };
 use gmsol_solana_utils::{
@@ -103,6 +103,7 @@ pub struct CreateOrderBuilder<'a, C> {
     long_token_account: Option<Pubkey>,
     short_token_account: Option<Pubkey>,
     should_unwrap_native_token: bool,
+    pay_execution_fee_from_collateral: bool,
     receiver: Pubkey,
     callback: Option<Callback>,
     alts: HashMap<Pubkey, Vec<Pubkey>>,
@@ -142,6 +143,7 @@ where
             long_token_account: None,
             short_token_account: None,
             should_unwrap_native_token: true,
+            pay_execution_fee_from_collateral: false,
             receiver: client.payer(),
             callback: None,
             alts: Default::default(),
@@ -242,6 +244,13 @@ where
         self
     }
 
+    /// Set whether to pay the execution fee from the position's collateral.
+    /// Defaults to `false`.
+    pub fn pay_execution_fee_from_collateral(&mut self, enable: bool) -> &mut Self {
+        self.pay_execution_fee_from_collateral = enable;
+        self
+    }
+
     /// Set receiver.
     /// Defaults to the payer.
     pub fn receiver(&mut self, receiver: Pubkey) -> &mut Self {
@@ -407,6 +416,9 @@ where
             acceptable_price: self.params.acceptable_price,
             should_unwrap_native_token: self.should_unwrap_native_token,
             valid_from_ts: self.params.valid_from_ts,
+            pay_execution_fee_from_collateral: self
+                .pay_execution_fee_from_collateral
+                .then_some(true),
         };
         Ok(params)
     }
@@ -752,6 +764,9 @@ pub struct ExecuteOrderHint {
     pub feeds: TokensWithFeed,
     swap: SwapActionParams,
     should_unwrap_native_token: bool,
+    /// The collateral token to pay the execution fee, if the order pays the execution fee
+    /// from the position's collateral.
+    execution_fee_token: Option<Pubkey>,
     callback: Option<Callback>,
     virtual_inventories: BTreeSet<Pubkey>,
 }
@@ -907,6 +922,11 @@ where
                 .header
                 .flags
                 .get_flag(ActionFlag::ShouldUnwrapNativeToken),
+            execution_fee_token: order
+                .header
+                .flags
+                .get_flag(ActionFlag::PayExecutionFeeFromCollateral)
+                .then_some(params.collateral_token),
             callback: Callback::from_header(&order.header)?,
             virtual_inventories,
         });
@@ -1021,6 +1041,9 @@ where
 
         let kind = hint.kind;
         let is_swap = matches!(kind, OrderKind::LimitSwap | OrderKind::MarketSwap);
+        let execution_fee_token_account = hint
+            .execution_fee_token
+            .map(|token| get_associated_token_address(&authority, &token));
         let mut require_claimable_accounts = false;
 
         let CallbackParams {
@@ -1101,6 +1124,7 @@ where
                             callback_program,
                             callback_shared_data_account,
                             callback_partitioned_data_account,
+                            execution_fee_token_account,
                         },
                         &ID,
                         self.client.store_program_id(),
@@ -1164,6 +1188,7 @@ where
                         callback_program,
                         callback_shared_data_account,
                         callback_partitioned_data_account,
+                        execution_fee_token_account,
                     },
                     &ID,
                     self.client.store_program_id(),
@@ -1202,6 +1227,13 @@ where
             execute_order = prepare_event_buffer.merge(execute_order);
         }
 
+        if let Some(token) = hint.execution_fee_token.as_ref() {
+            let prepare_execution_fee_token_account =
+                self.client
+                    .prepare_associated_token_account(token, &anchor_spl::token::ID, None);
+            execute_order = prepare_execution_fee_token_account.merge(execute_order);
+        }
+
         if self.close {
             let close = self
                 .client
@@ -1220,6 +1252,9 @@ where
                     rent_receiver: hint.rent_receiver,
                     should_unwrap_native_token: hint.should_unwrap_native_token,
                     callback: hint.callback,
+                    market: None,
+                    // The order is no longer recorded as a resting order after the execution.
+                    position: None,
                 })
                 .build()
                 .await?;
@@ -1325,6 +1360,8 @@ pub struct CloseOrderHint {
     pub(super) rent_receiver: Pubkey,
     pub(super) should_unwrap_native_token: bool,
     pub(super) callback: Option<Callback>,
+    pub(super) market: Option<Pubkey>,
+    pub(super) position: Option<Pubkey>,
 }
 
 impl CloseOrderHint {
@@ -1356,6 +1393,14 @@ impl CloseOrderHint {
                 .flags
                 .get_flag(ActionFlag::ShouldUnwrapNativeToken),
             callback: Callback::from_header(&order.header)?,
+            // The market is required to close an order queued for fair execution.
+            market: (order.market_order_seq != 0).then_some(order.header.market),
+            // The position is required to close a recorded resting order.
+            position: order
+                .header
+                .flags
+                .get_flag(ActionFlag::RestingOrderRecorded)
+                .then_some(order.params.position),
         })
     }
 }
@@ -1501,12 +1546,16 @@ where
                     callback_program,
                     callback_shared_data_account,
                     callback_partitioned_data_account,
+                    position: hint.position,
+                    rent_recipient: None,
+                    market: hint.market,
                 },
                 &ID,
                 self.client.store_program_id(),
             ))
             .anchor_args(args::CloseOrderV2 {
                 reason: self.reason.clone(),
+                close_reason: CloseReason::Unspecified,
             }))
     }
 }
@@ -1810,6 +1859,9 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> PositionCutBuilder<'a, C> {
     }
 
     /// Set whether to close the order after the execution.
+    ///
+    /// The order of a liquidation is not closed if partial liquidation is enabled,
+    /// since its rent receiver is unknown before the execution.
     pub fn close(&mut self, close: bool) -> &mut Self {
         self.close = close;
         self
@@ -2002,12 +2054,16 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> PositionCutBuilder<'a, C> {
             .compute_budget(ComputeBudget::default().with_limit(POSITION_CUT_COMPUTE_BUDGET))
             .lookup_tables(self.alts.clone());
 
+        // Whether the position cut is a full close, which is unknown before execution
+        // if partial liquidation is enabled.
         let is_full_close = match self.kind {
-            PositionCutKind::Liquidate => true,
-            PositionCutKind::AutoDeleverage(size) => size >= hint.position_size,
+            PositionCutKind::Liquidate => {
+                (hint.store.factor.partial_liquidation_buffer_factor == 0).then_some(true)
+            }
+            PositionCutKind::AutoDeleverage(size) => Some(size >= hint.position_size),
         };
 
-        if self.close {
+        if let Some(is_full_close) = is_full_close.filter(|_| self.close) {
             let close = self
                 .client
                 .close_order(&order)?
@@ -2027,6 +2083,8 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> PositionCutBuilder<'a, C> {
                     rent_receiver: if is_full_close { owner } else { payer },
                     should_unwrap_native_token: true,
                     callback: None,
+                    market: None,
+                    position: None,
                 })
                 .reason("position cut")
                 .build()
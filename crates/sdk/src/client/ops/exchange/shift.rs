@@ -5,7 +5,7 @@ use anchor_spl::associated_token::get_associated_token_address;
 use gmsol_programs::gmsol_store::{
     accounts::{Shift, Store},
     client::{accounts, args},
-    types::CreateShiftParams,
+    types::{CloseReason, CreateShiftParams},
     ID,
 };
 use gmsol_solana_utils::{
@@ -15,6 +15,7 @@ use gmsol_solana_utils::{
     transaction_builder::TransactionBuilder,
 };
 use gmsol_utils::{
+    action::ActionFlag,
     market::{ordered_tokens, HasMarketMeta, MarketMeta},
     oracle::PriceProviderKind,
     pubkey::optional_address,
@@ -26,7 +27,7 @@ use crate::{
     builders::utils::generate_nonce,
     client::{
         feeds_parser::{FeedAddressMap, FeedsParser},
-        ops::token_account::TokenAccountOps,
+        ops::{token_account::TokenAccountOps, user::UserOps},
         pull_oracle::{FeedIds, PullOraclePriceConsumer},
     },
     pda::NonceBytes,
@@ -188,13 +189,20 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> CreateShiftBuilder<'a, C> {
                 system_program: system_program::ID,
                 token_program: token_program_id,
                 associated_token_program: anchor_spl::associated_token::ID,
+                user: self.client.find_user_address(&self.store, &owner),
             })
             .anchor_args(args::CreateShift {
                 nonce,
                 params: self.get_create_shift_params(),
             });
 
-        Ok((prepare_escrow.merge(prepare_ata).merge(rpc), shift))
+        Ok((
+            prepare_escrow
+                .merge(self.client.prepare_user(&self.store)?)
+                .merge(prepare_ata)
+                .merge(rpc),
+            shift,
+        ))
     }
 }
 
@@ -216,6 +224,7 @@ pub struct CloseShiftHint {
     to_market_token: Pubkey,
     from_market_token_escrow: Pubkey,
     to_market_token_escrow: Pubkey,
+    is_pending_action_recorded: bool,
 }
 
 impl CloseShiftHint {
@@ -230,6 +239,10 @@ impl CloseShiftHint {
             from_market_token_escrow: tokens.from_market_token.account,
             to_market_token: tokens.to_market_token.token,
             to_market_token_escrow: tokens.to_market_token.account,
+            is_pending_action_recorded: shift
+                .header
+                .flags
+                .get_flag(ActionFlag::PendingActionRecorded),
         })
     }
 
@@ -291,27 +304,36 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> CloseShiftBuilder<'a, C> {
         let rpc = self
             .client
             .store_transaction()
-            .anchor_accounts(accounts::CloseShift {
-                executor,
-                store: hint.store,
-                store_wallet: self.client.find_store_wallet_address(&hint.store),
-                owner: hint.owner,
-                receiver: hint.receiver,
-                shift: self.shift,
-                from_market_token: hint.from_market_token,
-                to_market_token: hint.to_market_token,
-                from_market_token_escrow: hint.from_market_token_escrow,
-                to_market_token_escrow: hint.to_market_token_escrow,
-                from_market_token_ata: hint.from_market_token_ata(),
-                to_market_token_ata: hint.to_market_token_ata(),
-                system_program: system_program::ID,
-                token_program: anchor_spl::token::ID,
-                associated_token_program: anchor_spl::associated_token::ID,
-                event_authority: self.client.store_event_authority(),
-                program: *self.client.store_program_id(),
-            })
+            .accounts(fix_optional_account_metas(
+                accounts::CloseShift {
+                    executor,
+                    store: hint.store,
+                    store_wallet: self.client.find_store_wallet_address(&hint.store),
+                    owner: hint.owner,
+                    receiver: hint.receiver,
+                    shift: self.shift,
+                    from_market_token: hint.from_market_token,
+                    to_market_token: hint.to_market_token,
+                    from_market_token_escrow: hint.from_market_token_escrow,
+                    to_market_token_escrow: hint.to_market_token_escrow,
+                    from_market_token_ata: hint.from_market_token_ata(),
+                    to_market_token_ata: hint.to_market_token_ata(),
+                    system_program: system_program::ID,
+                    token_program: anchor_spl::token::ID,
+                    associated_token_program: anchor_spl::associated_token::ID,
+                    event_authority: self.client.store_event_authority(),
+                    program: *self.client.store_program_id(),
+                    rent_recipient: None,
+                    user: hint
+                        .is_pending_action_recorded
+                        .then(|| self.client.find_user_address(&hint.store, &hint.owner)),
+                },
+                &ID,
+                self.client.store_program_id(),
+            ))
             .anchor_args(args::CloseShift {
                 reason: self.reason.clone(),
+                close_reason: CloseReason::Unspecified,
             });
 
         Ok(rpc)
@@ -341,6 +363,7 @@ pub struct ExecuteShiftHint {
     to_market_token: Pubkey,
     from_market_token_escrow: Pubkey,
     to_market_token_escrow: Pubkey,
+    is_pending_action_recorded: bool,
     /// Feeds.
     pub feeds: TokensWithFeed,
     virtual_inventories: BTreeSet<Pubkey>,
@@ -369,6 +392,7 @@ impl ExecuteShiftHint {
             to_market_token,
             from_market_token_escrow,
             to_market_token_escrow,
+            is_pending_action_recorded,
         } = CloseShiftHint::new(shift)?;
 
         Ok(Self {
@@ -379,6 +403,7 @@ impl ExecuteShiftHint {
             from_market_token_escrow,
             to_market_token,
             to_market_token_escrow,
+            is_pending_action_recorded,
             token_map: *optional_address(&store.token_map).ok_or(crate::Error::NotFound)?,
             feeds,
             virtual_inventories,
@@ -535,6 +560,7 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> ExecuteShiftBuilder<'a, C> {
                     to_market_token: hint.to_market_token,
                     from_market_token_escrow: hint.from_market_token_escrow,
                     to_market_token_escrow: hint.to_market_token_escrow,
+                    is_pending_action_recorded: hint.is_pending_action_recorded,
                 })
                 .reason("executed")
                 .build()
@@ -4,7 +4,7 @@ use anchor_spl::associated_token::get_associated_token_address;
 use gmsol_programs::gmsol_store::{
     accounts::Withdrawal,
     client::{accounts, args},
-    types::CreateWithdrawalParams,
+    types::{CloseReason, CreateWithdrawalParams},
     ID,
 };
 use gmsol_solana_utils::{
@@ -25,7 +25,7 @@ use crate::{
     builders::utils::{generate_nonce, get_ata_or_owner},
     client::{
         feeds_parser::{FeedAddressMap, FeedsParser},
-        ops::token_account::TokenAccountOps,
+        ops::{token_account::TokenAccountOps, user::UserOps},
         pull_oracle::{FeedIds, PullOraclePriceConsumer},
     },
     pda::NonceBytes,
@@ -280,6 +280,7 @@ where
                 final_long_token_escrow,
                 final_short_token_escrow,
                 market_token_source: self.get_or_find_associated_market_token_account(),
+                user: self.client.find_user_address(&self.store, &owner),
             })
             .anchor_args(args::CreateWithdrawal {
                 nonce,
@@ -315,6 +316,7 @@ where
 
         Ok((
             prepare_escrows
+                .merge(self.client.prepare_user(&self.store)?)
                 .merge(prepare_final_long_token_ata)
                 .merge(prepare_final_short_token_ata)
                 .merge(create),
@@ -343,6 +345,7 @@ pub struct CloseWithdrawalHint {
     final_long_token_account: Pubkey,
     final_short_token_account: Pubkey,
     should_unwrap_native_token: bool,
+    is_pending_action_recorded: bool,
 }
 
 impl<'a> From<&'a Withdrawal> for CloseWithdrawalHint {
@@ -361,6 +364,10 @@ impl<'a> From<&'a Withdrawal> for CloseWithdrawalHint {
                 .header
                 .flags
                 .get_flag(ActionFlag::ShouldUnwrapNativeToken),
+            is_pending_action_recorded: withdrawal
+                .header
+                .flags
+                .get_flag(ActionFlag::PendingActionRecorded),
         }
     }
 }
@@ -424,30 +431,39 @@ where
         Ok(self
             .client
             .store_transaction()
-            .anchor_accounts(accounts::CloseWithdrawal {
-                store: self.store,
-                store_wallet: self.client.find_store_wallet_address(&self.store),
-                withdrawal: self.withdrawal,
-                market_token: hint.market_token,
-                token_program: anchor_spl::token::ID,
-                system_program: system_program::ID,
-                event_authority: self.client.store_event_authority(),
-                executor: payer,
-                owner: hint.owner,
-                receiver: hint.receiver,
-                final_long_token: hint.final_long_token,
-                final_short_token: hint.final_short_token,
-                market_token_escrow: hint.market_token_account,
-                final_long_token_escrow: hint.final_long_token_account,
-                final_short_token_escrow: hint.final_short_token_account,
-                market_token_ata,
-                final_long_token_ata,
-                final_short_token_ata,
-                associated_token_program: anchor_spl::associated_token::ID,
-                program: *self.client.store_program_id(),
-            })
+            .accounts(fix_optional_account_metas(
+                accounts::CloseWithdrawal {
+                    store: self.store,
+                    store_wallet: self.client.find_store_wallet_address(&self.store),
+                    withdrawal: self.withdrawal,
+                    market_token: hint.market_token,
+                    token_program: anchor_spl::token::ID,
+                    system_program: system_program::ID,
+                    event_authority: self.client.store_event_authority(),
+                    executor: payer,
+                    owner: hint.owner,
+                    receiver: hint.receiver,
+                    final_long_token: hint.final_long_token,
+                    final_short_token: hint.final_short_token,
+                    market_token_escrow: hint.market_token_account,
+                    final_long_token_escrow: hint.final_long_token_account,
+                    final_short_token_escrow: hint.final_short_token_account,
+                    market_token_ata,
+                    final_long_token_ata,
+                    final_short_token_ata,
+                    associated_token_program: anchor_spl::associated_token::ID,
+                    program: *self.client.store_program_id(),
+                    rent_recipient: None,
+                    user: hint
+                        .is_pending_action_recorded
+                        .then(|| self.client.find_user_address(&self.store, &hint.owner)),
+                },
+                &ID,
+                self.client.store_program_id(),
+            ))
             .anchor_args(args::CloseWithdrawal {
                 reason: self.reason.clone(),
+                close_reason: CloseReason::Unspecified,
             }))
     }
 }
@@ -481,6 +497,7 @@ pub struct ExecuteWithdrawalHint {
     pub feeds: TokensWithFeed,
     swap: SwapActionParams,
     should_unwrap_native_token: bool,
+    is_pending_action_recorded: bool,
     virtual_inventories: BTreeSet<Pubkey>,
 }
 
@@ -501,6 +518,7 @@ impl ExecuteWithdrawalHint {
             final_long_token_account,
             final_short_token_account,
             should_unwrap_native_token,
+            is_pending_action_recorded,
         } = CloseWithdrawalHint::from(withdrawal);
         let swap = SwapActionParams::from(withdrawal.swap);
         Ok(Self {
@@ -515,6 +533,7 @@ impl ExecuteWithdrawalHint {
             feeds: swap.to_feeds(map).map_err(crate::Error::custom)?,
             swap,
             should_unwrap_native_token,
+            is_pending_action_recorded,
             virtual_inventories,
         })
     }
@@ -689,6 +708,7 @@ where
                     final_long_token_account: hint.final_long_token_escrow,
                     final_short_token_account: hint.final_short_token_escrow,
                     should_unwrap_native_token: hint.should_unwrap_native_token,
+                    is_pending_action_recorded: hint.is_pending_action_recorded,
                 })
                 .reason("executed")
                 .build()
@@ -451,7 +451,7 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> GetGlvTokenValueBuilder<'a, C>
             .anchor_args(args::GetGlvTokenValue {
                 amount: *amount,
                 maximize: *maximize,
-                max_age: *max_age,
+                max_age: Some(*max_age),
                 emit_event: *emit_event,
             })
             .anchor_accounts(accounts::GetGlvTokenValue {
@@ -727,7 +727,7 @@ impl<'a, C: Deref<Target = impl Signer> + Clone> GetMarketTokenValueBuilder<'a,
                 amount: *amount,
                 pnl_factor: pnl_factor.to_string(),
                 maximize: *maximize,
-                max_age: *max_age,
+                max_age: Some(*max_age),
                 emit_event: *emit_event,
             })
             .anchor_accounts(accounts::GetMarketTokenValue {
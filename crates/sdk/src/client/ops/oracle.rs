@@ -98,6 +98,7 @@ impl<C: Deref<Target = impl Signer> + Clone> OracleOps<C> for crate::Client<C> {
                 authority,
                 store: *store,
                 price_feed,
+                price_feed_counter: self.find_price_feed_counter_address(store, token),
                 system_program: system_program::ID,
             })
             .anchor_args(args::InitializePriceFeed {
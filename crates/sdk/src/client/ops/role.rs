@@ -53,6 +53,7 @@ impl<C: Deref<Target = impl Signer> + Clone> RoleOps<C> for crate::Client<C> {
             .anchor_args(args::GrantRole {
                 user: *user,
                 role: role.to_string(),
+                expires_at: None,
             })
     }
 
@@ -177,6 +177,8 @@ pub trait TreasuryOps<C> {
     ) -> impl Future<Output = crate::Result<TransactionBuilder<C, Pubkey>>>;
 
     /// Cancel a swap.
+    ///
+    /// The market account required by queued orders is only provided when `hint` is `None`.
     fn cancel_treasury_swap(
         &self,
         store: &Pubkey,
@@ -818,8 +820,8 @@ impl<C: Deref<Target = impl Signer> + Clone> TreasuryOps<C> for crate::Client<C>
         let receiver = self.find_treasury_receiver_address(&config);
         let user = self.find_user_address(store, &receiver);
 
-        let (swap_in_token, swap_out_token) = match hint {
-            Some((swap_in_token, swap_out_token)) => (*swap_in_token, *swap_out_token),
+        let (swap_in_token, swap_out_token, market) = match hint {
+            Some((swap_in_token, swap_out_token)) => (*swap_in_token, *swap_out_token, None),
             None => {
                 let order = self.order(order).await?;
                 let swap_in_token = order.tokens.initial_collateral.token().ok_or_else(|| {
@@ -829,7 +831,9 @@ impl<C: Deref<Target = impl Signer> + Clone> TreasuryOps<C> for crate::Client<C>
                 let swap_out_token = order.tokens.final_output_token.token().ok_or_else(|| {
                     crate::Error::custom("invalid swap order: missing swap out token")
                 })?;
-                (swap_in_token, swap_out_token)
+                // The market is only required if the order is queued for fair execution.
+                let market = (order.market_order_seq != 0).then_some(order.header.market);
+                (swap_in_token, swap_out_token, market)
             }
         };
 
@@ -860,26 +864,31 @@ impl<C: Deref<Target = impl Signer> + Clone> TreasuryOps<C> for crate::Client<C>
         let cancel = self
             .treasury_transaction()
             .anchor_args(args::CancelSwap {})
-            .anchor_accounts(accounts::CancelSwap {
-                authority: self.payer(),
-                store: *store,
-                store_wallet: self.find_store_wallet_address(store),
-                config,
-                receiver,
-                user,
-                swap_in_token,
-                swap_out_token,
-                swap_in_token_receiver_vault,
-                swap_out_token_receiver_vault,
-                swap_in_token_escrow,
-                swap_out_token_escrow,
-                order: *order,
-                event_authority: self.store_event_authority(),
-                store_program: *self.store_program_id(),
-                token_program: token_program_id,
-                associated_token_program: anchor_spl::associated_token::ID,
-                system_program: system_program::ID,
-            });
+            .accounts(fix_optional_account_metas(
+                accounts::CancelSwap {
+                    authority: self.payer(),
+                    store: *store,
+                    store_wallet: self.find_store_wallet_address(store),
+                    config,
+                    receiver,
+                    user,
+                    swap_in_token,
+                    swap_out_token,
+                    swap_in_token_receiver_vault,
+                    swap_out_token_receiver_vault,
+                    swap_in_token_escrow,
+                    swap_out_token_escrow,
+                    order: *order,
+                    event_authority: self.store_event_authority(),
+                    store_program: *self.store_program_id(),
+                    token_program: token_program_id,
+                    associated_token_program: anchor_spl::associated_token::ID,
+                    system_program: system_program::ID,
+                    market,
+                },
+                &ID,
+                self.treasury_program_id(),
+            ));
 
         Ok(prepare.merge(cancel))
     }
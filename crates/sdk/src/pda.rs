@@ -91,6 +91,9 @@ pub const GT_EXCHANGE_SEED: &[u8] = b"gt_exchange";
 /// Seed for [`PriceFeed`](store_accounts::PriceFeed).
 pub const PRICE_FEED_SEED: &[u8] = b"price_feed";
 
+/// Seed for [`PriceFeedCounter`](store_accounts::PriceFeedCounter).
+pub const PRICE_FEED_COUNTER_SEED: &[u8] = b"price_feed_counter";
+
 /// Seed for [`Config`](treasury_accounts::Config).
 #[cfg(treasury)]
 pub const TREASURY_CONFIG_SEED: &[u8] = b"config";
@@ -433,6 +436,18 @@ pub fn find_price_feed_address(
     )
 }
 
+/// Find PDA for the custom price feed counter account of a token.
+pub fn find_price_feed_counter_address(
+    store: &Pubkey,
+    token: &Pubkey,
+    store_program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PRICE_FEED_COUNTER_SEED, store.as_ref(), token.as_ref()],
+        store_program_id,
+    )
+}
+
 /// Find PDA for virtual inventory for swaps account.
 pub fn find_virtual_inventory_for_swaps_address(
     store: &Pubkey,
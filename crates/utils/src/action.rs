@@ -20,6 +20,12 @@ pub const MAX_ACTION_FLAGS: usize = 8;
 pub enum ActionFlag {
     /// Should unwrap native token.
     ShouldUnwrapNativeToken,
+    /// Pay execution fee from the position's collateral.
+    PayExecutionFeeFromCollateral,
+    /// Recorded as a resting order of the position.
+    RestingOrderRecorded,
+    /// Recorded as a pending action of the owner.
+    PendingActionRecorded,
     // CHECK: should have no more than `MAX_ACTION_FLAGS` of flags.
 }
 
@@ -90,6 +96,45 @@ impl ActionState {
     }
 }
 
+/// Structured reason for closing an action.
+#[non_exhaustive]
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Default,
+    num_enum::IntoPrimitive,
+    num_enum::TryFromPrimitive,
+    PartialEq,
+    Eq,
+    strum::EnumString,
+    strum::Display,
+    AnchorSerialize,
+    AnchorDeserialize,
+    InitSpace,
+)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CloseReason {
+    /// Unspecified.
+    #[default]
+    Unspecified,
+    /// Cancelled by the owner.
+    UserCancel,
+    /// Closed after a successful execution.
+    Completed,
+    /// The action has expired.
+    Expired,
+    /// The execution of the action failed.
+    ExecutionFailed,
+    /// The market of the action is disabled.
+    MarketDisabled,
+    /// Other reasons, see the reason string for details.
+    Other,
+}
+
 /// Callback kind for action.
 #[non_exhaustive]
 #[repr(u8)]
@@ -127,6 +127,54 @@ pub enum AmountKey {
     OracleMaxFutureTimestampExcess,
     /// Max ADL prices staleness (seconds).
     AdlPricesMaxStaleness,
+    /// Min amount of native tokens to be unwrapped automatically.
+    MinAutoUnwrapAmount,
+    /// Max age of actions to be executed (seconds).
+    MaxActionAge,
+    /// Min timestamp adjustment in seconds allowed for feed configs.
+    MinTimestampAdjustment,
+    /// Max timestamp adjustment in seconds allowed for feed configs. `0` means no upper bound.
+    MaxTimestampAdjustment,
+    /// Max number of tokens allowed to be priced in a single `set_prices_from_price_feed` call. `0` means using the hard max.
+    MaxTokensPerPriceSet,
+    /// Whether self-trade prevention for orders is enabled. Non-zero means enabled.
+    SelfTradePrevention,
+    /// Min GT balance required to initialize a referral code. `0` means no requirement.
+    MinGtForReferralCode,
+    /// Default oracle staleness (in seconds) used by read instructions when none is passed. `0` means not set.
+    DefaultOracleStaleness,
+    /// Whether GT exchange vaults can be confirmed by anyone after the deposit window. Non-zero means enabled.
+    GtVaultAutoConfirm,
+    /// Max number of pending GLV shifts allowed for each GLV. `0` means unlimited.
+    MaxConcurrentGlvShifts,
+    /// Lockup period (in seconds) of newly minted GT before it can be spent.
+    /// The lockup is disabled if zero.
+    GtMintLockupSeconds,
+    /// Max length of encoded referral code strings, excluding the leading `1`s.
+    /// No limit if zero.
+    MaxReferralCodeLength,
+    /// Charset of encoded referral code strings (the representation of `ReferralCodeCharset`).
+    /// Any characters are allowed if zero.
+    ReferralCodeCharset,
+    /// Max total length of the swap paths of a deposit. `0` means the global max is used.
+    MaxDepositSwapPathLength,
+    /// Max total length of the swap paths of a withdrawal. `0` means the global max is used.
+    MaxWithdrawalSwapPathLength,
+    /// Max total length of the swap paths of an order. `0` means the global max is used.
+    MaxOrderSwapPathLength,
+    /// Max number of custom price feeds per token. `0` means unlimited.
+    MaxCustomPriceFeedsPerToken,
+    /// Min expiration duration (in seconds) of market config buffers.
+    MinMarketConfigBufferExpireAfter,
+    /// Max expiration duration (in seconds) of market config buffers. `0` means unlimited.
+    MaxMarketConfigBufferExpireAfter,
+    /// Max size (in bytes) of the compressed Chainlink Data Streams reports. `0` means the default bound is used.
+    MaxChainlinkReportSize,
+    /// Max number of pending actions (orders, deposits, withdrawals, shifts, GLV deposits and
+    /// GLV withdrawals) of a user. `0` means unlimited.
+    MaxPendingActionsPerUser,
+    /// Whether newly created market orders are queued to be executed in the order of creation in each market. Non-zero means enabled.
+    FairMarketOrderExecution,
 }
 
 /// Factor keys.
@@ -142,6 +190,17 @@ pub enum FactorKey {
     OracleRefPriceDeviation,
     /// Order fee discount for referred user.
     OrderFeeDiscountForReferredUser,
+    /// Buffer factor above the min collateral factor to restore by partial liquidations.
+    /// Partial liquidation is disabled if zero.
+    PartialLiquidationBufferFactor,
+    /// Tolerance (factor) beyond the acceptable price after which a triggered limit order is cancelled on execution.
+    /// Auto-cancel is disabled if zero.
+    LimitOrderAutoCancelTolerance,
+    /// Order fee to GT stakers factor. The fraction of paid order fee value recorded as staker rewards of GT holders.
+    /// Only the value is accounted; the fee tokens stay in the claimable fee pools of the markets.
+    OrderFeeToGtStakersFactor,
+    /// Max ratio of the confidence interval to the price accepted from Pyth price updates. `0` means unlimited.
+    MaxPythConfidenceRatio,
 }
 
 /// Address keys.
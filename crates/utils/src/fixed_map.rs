@@ -153,7 +153,7 @@ macro_rules! impl_fixed_map {
                     self.binary_search(&key).ok().map(|index| {
                         let value = std::mem::take(&mut self.data[index].value);
                         let len = self.len();
-                        for i in index..len {
+                        for i in index..(len - 1) {
                             self.data[i] = self.data[i + 1];
                         }
                         self.data[len - 1] = [<$map Entry>]::default();
@@ -172,6 +172,11 @@ macro_rules! impl_fixed_map {
                     self.count == 0
                 }
 
+                /// Get the max number of entries.
+                pub const fn capacity(&self) -> usize {
+                    $len
+                }
+
                 /// Entries.
                 pub fn entries(&self) -> impl Iterator<Item = (&[u8; $key_len], &$value)> {
                     self.data.iter().take(self.len()).map(|entry| {
@@ -243,6 +248,26 @@ mod tests {
         assert_eq!(map.len(), 2);
     }
 
+    #[test]
+    fn test_len_and_capacity() {
+        let mut map = FixedFactorMap::default();
+
+        assert!(map.is_empty());
+        assert_eq!(map.len(), 0);
+        assert_eq!(map.capacity(), 32);
+
+        for i in 0..32 {
+            assert_eq!(map.insert(&format!("key{i}"), i), None);
+        }
+        assert!(!map.is_empty());
+        assert_eq!(map.len(), 32);
+        assert_eq!(map.capacity(), 32);
+
+        assert_eq!(map.remove("key0"), Some(0));
+        assert_eq!(map.len(), 31);
+        assert_eq!(map.capacity(), 32);
+    }
+
     fn to_bytes(key: &Pubkey) -> [u8; 32] {
         key.to_bytes()
     }
@@ -155,6 +155,18 @@ pub enum MarketConfigFlag {
     SkipBorrowingFeeForSmallerSide,
     /// Ignore open interest for usage factor.
     IgnoreOpenInterestForUsageFactor,
+    /// Enforce reserves. Enabled by default for new markets.
+    ///
+    /// It can be disabled to relax reserve validation while bootstrapping a new market with
+    /// initial liquidity, and should be re-enabled once the market is seeded. Increasing
+    /// positions is not allowed while it is disabled. Note that markets initialized before
+    /// this flag was introduced have it disabled, and must enable it to allow increases.
+    EnforceReserves,
+    /// Enable the insurance fund as a backstop for the bad debt of insolvent closes.
+    ///
+    /// When enabled, the bad debt of insolvent closes is covered by the insurance fund of the
+    /// collateral token as much as possible, and the rest is left as bad debt.
+    EnableInsuranceFund,
     // CHECK: cannot have more than `MAX_CONFIG_FLAGS` flags.
 }
 
@@ -306,6 +318,24 @@ pub enum MarketConfigKey {
     MaxOpenInterestForShort,
     /// Min tokens for first deposit.
     MinTokensForFirstDeposit,
+    /// Min market token price for deposits.
+    MinMarketTokenPrice,
+    /// Cooldown between increases of the same position (seconds).
+    IncreaseCooldownSeconds,
+    /// Min deposit fee in USD. `0` means no floor.
+    MinDepositFeeUsd,
+    /// Min withdrawal fee in USD. `0` means no floor.
+    MinWithdrawalFeeUsd,
+    /// Max negative swap price impact factor (relative to the swap-in value) per hop. `0` means no limit.
+    MaxSwapImpactFactor,
+    /// Max size in USD of a single increase or swap order. `0` means no limit.
+    MaxOrderSizeUsd,
+    /// Max number of open positions. `0` means unlimited.
+    ///
+    /// Only the positions opened after the open position counter was introduced are counted.
+    MaxOpenPositions,
+    /// Max total negative position impact factor charged to positions when executing liquidations. `0` means no cap.
+    MaxPositionImpactFactorForLiquidationExecution,
 }
 
 /// Market Flags.
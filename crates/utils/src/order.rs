@@ -78,6 +78,28 @@ impl OrderKind {
     pub fn is_market_decrease(&self) -> bool {
         matches!(self, Self::MarketDecrease)
     }
+
+    /// Is resting position order, i.e., a limit or stop-loss position order.
+    pub fn is_resting_position(&self) -> bool {
+        matches!(
+            self,
+            Self::LimitIncrease | Self::LimitDecrease | Self::StopLossDecrease
+        )
+    }
+
+    /// Returns whether an order of this kind would be a self-trade when filled against
+    /// a resting order of the `resting` kind on the same position.
+    ///
+    /// Liquidation and auto-deleveraging orders are never self-trades.
+    pub fn is_self_trade_against(&self, resting: &Self) -> bool {
+        if matches!(self, Self::Liquidation | Self::AutoDeleveraging)
+            || !resting.is_resting_position()
+        {
+            return false;
+        }
+        (self.is_increase_position() && resting.is_decrease_position())
+            || (self.is_decrease_position() && resting.is_increase_position())
+    }
 }
 
 /// Order side.
@@ -107,6 +129,19 @@ impl OrderSide {
     }
 }
 
+/// Max number of position flags.
+pub const MAX_POSITION_FLAGS: usize = 8;
+
+/// Position flags.
+#[derive(num_enum::IntoPrimitive)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum PositionFlag {
+    /// Whether the position is counted in the open positions of its market.
+    OpenRecorded,
+    // CHECK: should have no more than `MAX_POSITION_FLAGS` of flags.
+}
+
 /// Position Kind.
 #[non_exhaustive]
 #[repr(u8)]
@@ -176,3 +211,29 @@ pub enum TradeFlag {
 }
 
 crate::flags!(TradeFlag, 8, u8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn self_trade_against_resting_orders() {
+        use OrderKind::*;
+
+        for kind in [MarketIncrease, LimitIncrease] {
+            assert!(kind.is_self_trade_against(&LimitDecrease));
+            assert!(kind.is_self_trade_against(&StopLossDecrease));
+            assert!(!kind.is_self_trade_against(&LimitIncrease));
+        }
+        for kind in [MarketDecrease, LimitDecrease, StopLossDecrease] {
+            assert!(kind.is_self_trade_against(&LimitIncrease));
+            assert!(!kind.is_self_trade_against(&LimitDecrease));
+            assert!(!kind.is_self_trade_against(&StopLossDecrease));
+        }
+        for kind in [Liquidation, AutoDeleveraging] {
+            assert!(!kind.is_self_trade_against(&LimitIncrease));
+        }
+        assert!(!MarketIncrease.is_self_trade_against(&MarketDecrease));
+        assert!(!MarketSwap.is_self_trade_against(&LimitDecrease));
+    }
+}
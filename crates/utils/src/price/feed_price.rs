@@ -95,6 +95,11 @@ impl PriceFeedPrice {
         }
     }
 
+    /// Get the number of decimals of the prices.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
     /// Get min price.
     pub fn min_price(&self) -> &u128 {
         &self.min_price
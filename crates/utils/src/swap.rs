@@ -114,6 +114,24 @@ impl SwapActionParams {
         &self.tokens[0..end]
     }
 
+    /// Insert an extra token whose price is required by the action,
+    /// keeping the tokens sorted.
+    ///
+    /// Returns `false` if the token is already included.
+    pub fn insert_token(&mut self, token: &Pubkey) -> SwapActionParamsResult<bool> {
+        let num_tokens = self.num_tokens();
+        let Err(idx) = self.tokens().binary_search(token) else {
+            return Ok(false);
+        };
+        if num_tokens >= MAX_TOKENS {
+            return Err(SwapActionParamsError::InvalidSwapPath("too many tokens"));
+        }
+        self.tokens.copy_within(idx..num_tokens, idx + 1);
+        self.tokens[idx] = *token;
+        self.num_tokens += 1;
+        Ok(true)
+    }
+
     /// Convert to token records.
     pub fn to_token_records<'a>(
         &'a self,
@@ -179,3 +197,26 @@ impl HasSwapParams for SwapActionParams {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_token_keeps_tokens_sorted() {
+        let mut params = SwapActionParams::default();
+        let mut tokens = (0..3).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+        for token in tokens.iter() {
+            assert!(params.insert_token(token).unwrap());
+        }
+        assert!(!params.insert_token(&tokens[1]).unwrap());
+        tokens.sort();
+        assert_eq!(params.tokens(), tokens.as_slice());
+
+        while params.num_tokens() < SwapActionParams::MAX_TOKENS {
+            params.insert_token(&Pubkey::new_unique()).unwrap();
+        }
+        assert!(params.insert_token(&Pubkey::new_unique()).is_err());
+        assert!(params.tokens().windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -7,6 +7,7 @@ use crate::{
     fixed_str::{bytes_to_fixed_str, FixedStrError},
     market::HasMarketMeta,
     oracle::PriceProviderKind,
+    price::Decimal,
     pubkey::DEFAULT_PUBKEY,
     swap::HasSwapParams,
 };
@@ -49,6 +50,9 @@ pub enum TokenConfigError {
     /// Max deviation factor too small.
     #[error("max deviation factor too small")]
     MaxDeviationFactorTooSmall,
+    /// Invalid max price.
+    #[error("invalid max price")]
+    InvalidMaxPrice,
 }
 
 pub(crate) type TokenConfigResult<T> = std::result::Result<T, TokenConfigError>;
@@ -66,6 +70,8 @@ pub enum TokenConfigFlag {
     Synthetic,
     /// Indicates whether price adjustment is allowed.
     AllowPriceAdjustment,
+    /// Indicates whether a fallback provider is set.
+    HasFallbackProvider,
     // CHECK: Cannot have more than `MAX_FLAGS` flags.
 }
 
@@ -90,8 +96,21 @@ pub struct TokenConfig {
     pub feeds: [FeedConfig; MAX_FEEDS],
     /// Heartbeat duration.
     pub heartbeat_duration: u32,
+    /// Allowed providers (a bitset indexed by [`PriceProviderKind`]).
+    /// A value of `0` means no restriction is applied.
+    pub allowed_providers: u8,
+    /// The decimal multiplier of the max price bound.
+    pub max_price_decimal_multiplier: u8,
+    #[cfg_attr(feature = "debug", debug(skip))]
+    padding_0: [u8; 2],
+    /// The value of the max price bound.
+    /// A value of `0` means no bound is applied.
+    pub max_price_value: u32,
+    /// Fallback provider.
+    /// Only effective if the [`HasFallbackProvider`](TokenConfigFlag::HasFallbackProvider) flag is set.
+    pub fallback_provider: u8,
     #[cfg_attr(feature = "debug", debug(skip))]
-    reserved: [u8; 32],
+    reserved: [u8; 23],
 }
 
 #[cfg(feature = "display")]
@@ -175,6 +194,71 @@ impl TokenConfig {
         Ok(kind)
     }
 
+    /// Returns whether the given provider is allowed for this token.
+    pub fn is_provider_allowed(&self, provider: &PriceProviderKind) -> bool {
+        self.allowed_providers == 0 || self.allowed_providers & (1 << (*provider as u8)) != 0
+    }
+
+    /// Set allowed providers.
+    ///
+    /// Returns error if the bitset contains an invalid provider index.
+    pub fn set_allowed_providers(&mut self, allowed_providers: u8) -> TokenConfigResult<()> {
+        for index in 0..u8::BITS as u8 {
+            if allowed_providers & (1 << index) != 0 {
+                PriceProviderKind::try_from(index)
+                    .map_err(|_| TokenConfigError::InvalidProviderIndex)?;
+            }
+        }
+        self.allowed_providers = allowed_providers;
+        Ok(())
+    }
+
+    /// Get the max price bound.
+    ///
+    /// Returns `None` if no bound is applied.
+    pub fn max_price(&self) -> Option<Decimal> {
+        (self.max_price_value != 0).then_some(Decimal {
+            value: self.max_price_value,
+            decimal_multiplier: self.max_price_decimal_multiplier,
+        })
+    }
+
+    /// Set the max price bound. Pass `None` to remove the bound.
+    pub fn set_max_price(&mut self, max_price: Option<Decimal>) -> TokenConfigResult<()> {
+        match max_price {
+            Some(price) => {
+                if price.value == 0 || price.decimal_multiplier > Decimal::MAX_DECIMAL_MULTIPLIER {
+                    return Err(TokenConfigError::InvalidMaxPrice);
+                }
+                self.max_price_value = price.value;
+                self.max_price_decimal_multiplier = price.decimal_multiplier;
+            }
+            None => {
+                self.max_price_value = 0;
+                self.max_price_decimal_multiplier = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the fallback provider.
+    ///
+    /// Returns `None` if no fallback provider is set.
+    pub fn fallback_provider(&self) -> TokenConfigResult<Option<PriceProviderKind>> {
+        if !self.flag(TokenConfigFlag::HasFallbackProvider) {
+            return Ok(None);
+        }
+        let kind = PriceProviderKind::try_from(self.fallback_provider)
+            .map_err(|_| TokenConfigError::InvalidProviderIndex)?;
+        Ok(Some(kind))
+    }
+
+    /// Set the fallback provider. Pass `None` to remove it.
+    pub fn set_fallback_provider(&mut self, provider: Option<PriceProviderKind>) {
+        self.fallback_provider = provider.map(|kind| kind as u8).unwrap_or_default();
+        self.set_flag(TokenConfigFlag::HasFallbackProvider, provider.is_some());
+    }
+
     /// Get price feed address for the expected provider.
     pub fn get_expected_feed(&self) -> TokenConfigResult<Pubkey> {
         self.get_feed(&self.expected_provider()?)
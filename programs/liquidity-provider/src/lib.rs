@@ -1,5 +1,6 @@
 use anchor_lang::prelude::AccountsClose;
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::token_interface as token_if;
 use anchor_spl::token_interface::{
     CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
@@ -14,6 +15,8 @@ pub const POSITION_SEED: &'static [u8] = b"position";
 pub const GLOBAL_STATE_SEED: &'static [u8] = b"global_state";
 #[constant]
 pub const VAULT_SEED: &'static [u8] = b"vault";
+#[constant]
+pub const DEPOSIT_RECEIVER_SEED: &'static [u8] = b"deposit_receiver";
 // IDL-safe constants (u8) exposed via #[constant]
 #[constant]
 pub const APY_BUCKETS_U8: u8 = 53;
@@ -23,8 +26,18 @@ pub const APY_LAST_INDEX_U8: u8 = APY_BUCKETS_U8 - 1; // 52
 // Internal mirrors as usize for array lengths and indexing
 pub const APY_BUCKETS: usize = APY_BUCKETS_U8 as usize;
 pub const APY_LAST_INDEX: usize = APY_LAST_INDEX_U8 as usize;
+/// Max number of APY buckets that can be updated in a single instruction.
+#[constant]
+pub const MAX_APY_BUCKETS_PER_UPDATE_U8: u8 = 32;
+pub const MAX_APY_BUCKETS_PER_UPDATE: usize = MAX_APY_BUCKETS_PER_UPDATE_U8 as usize;
 #[constant]
 pub const APY_MAX: u128 = 200_000_000_000_000_000_000u128; // 200% at 1e20 scale
+/// Current layout version of `GlobalState`; older accounts must be migrated with `migrate_global_state`.
+#[constant]
+pub const GLOBAL_STATE_VERSION: u8 = 2;
+/// Current layout version of `Position`; older accounts must be migrated with `migrate_position`.
+#[constant]
+pub const POSITION_VERSION: u8 = 2;
 
 use gmsol_programs::gmsol_store::{
     accounts::{Store, UserHeader},
@@ -61,6 +74,9 @@ pub mod gmsol_liquidity_provider {
         global_state.lp_token_price = MARKET_USD_UNIT; // $1.00 in 1e20 units
         global_state.min_stake_value = min_stake_value;
         global_state.claim_enabled = false;
+        global_state.total_staked_value_usd = 0;
+        global_state.max_reward_window_seconds = 0;
+        global_state.version = GLOBAL_STATE_VERSION;
         global_state.bump = ctx.bumps.global_state;
         msg!(
             "LP staking program initialized, min_stake_value(1e20)={}, initial_apy(1e20)={}",
@@ -79,6 +95,7 @@ pub mod gmsol_liquidity_provider {
     }
 
     /// Update APY gradient with a sparse table (only non-zero buckets)
+    /// At most `MAX_APY_BUCKETS_PER_UPDATE` buckets can be updated per call.
     pub fn update_apy_gradient_sparse(
         ctx: Context<UpdateApyGradient>,
         bucket_indices: Vec<u8>, // indices of buckets to update
@@ -91,6 +108,10 @@ pub mod gmsol_liquidity_provider {
             bucket_indices.len() == apy_values.len(),
             ErrorCode::InvalidArgument
         );
+        require!(
+            bucket_indices.len() <= MAX_APY_BUCKETS_PER_UPDATE,
+            ErrorCode::TooManyApyBuckets
+        );
 
         // Apply sparse updates
         for (idx, val) in bucket_indices.into_iter().zip(apy_values.into_iter()) {
@@ -107,6 +128,7 @@ pub mod gmsol_liquidity_provider {
     }
 
     /// Update APY gradient for a contiguous range of buckets
+    /// At most `MAX_APY_BUCKETS_PER_UPDATE` buckets can be updated per call.
     pub fn update_apy_gradient_range(
         ctx: Context<UpdateApyGradient>,
         start_bucket: u8,
@@ -126,6 +148,10 @@ pub mod gmsol_liquidity_provider {
             apy_values.len() == expected_size,
             ErrorCode::InvalidArgument
         );
+        require!(
+            expected_size <= MAX_APY_BUCKETS_PER_UPDATE,
+            ErrorCode::TooManyApyBuckets
+        );
 
         // Apply range updates
         for (i, apy_value) in apy_values.into_iter().enumerate() {
@@ -150,30 +176,6 @@ pub mod gmsol_liquidity_provider {
         lp_staked_amount: u64,
         lp_staked_value: u128, // scaled USD at stake time
     ) -> Result<()> {
-        let now = Clock::get()?.unix_timestamp;
-
-        // Enforce minimum stake value (scaled 1e20)
-        require!(
-            lp_staked_value >= ctx.accounts.global_state.min_stake_value,
-            ErrorCode::InvalidArgument
-        );
-
-        // Use GlobalState PDA as controller for GT CPI
-        let gs_seeds: &[&[u8]] = &[GLOBAL_STATE_SEED, &[ctx.accounts.global_state.bump]];
-        let signer_seeds: &[&[&[u8]]] = &[gs_seeds];
-
-        let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.gt_program.to_account_info(),
-            GtUpdateCtx {
-                authority: ctx.accounts.global_state.to_account_info(),
-                store: ctx.accounts.gt_store.to_account_info(),
-            },
-            signer_seeds,
-        );
-        // Snapshot C(start) at stake time
-        let r: GtReturn<u128> = gt_cpi::update_gt_cumulative_inv_cost_factor(cpi_ctx)?;
-        let c_start: u128 = r.get();
-
         // Transfer LP tokens from user to the position vault
         if lp_staked_amount > 0 {
             let cpi_accounts = TransferChecked {
@@ -187,29 +189,80 @@ pub mod gmsol_liquidity_provider {
             token_if::transfer_checked(cpi_ctx, lp_staked_amount, ctx.accounts.lp_mint.decimals)?;
         }
 
-        // Init position fields
-        let position = &mut ctx.accounts.position;
-        position.owner = ctx.accounts.owner.key();
-        position.global_state = ctx.accounts.global_state.key();
-        position.lp_mint = ctx.accounts.lp_mint.key();
-        position.vault = ctx.accounts.position_vault.key();
-        position.position_id = position_id;
-        position.staked_amount = lp_staked_amount;
-        position.staked_value_usd = lp_staked_value;
-        position.stake_start_time = now;
-        position.cum_inv_cost = c_start;
-        position.bump = ctx.bumps.position;
-
-        msg!(
-            "Stake created: owner={}, amount={}, value(1e20)={}, start_ts={}, C_start={}, pos_id={}",
-            position.owner,
+        let accounts = ctx.accounts;
+        open_position(
+            &mut accounts.global_state,
+            &accounts.gt_store,
+            &accounts.gt_program,
+            &mut accounts.position,
+            ctx.bumps.position,
+            &accounts.owner,
+            &accounts.lp_mint,
+            &accounts.position_vault,
+            position_id,
             lp_staked_amount,
             lp_staked_value,
-            now,
-            c_start,
-            position_id
+        )
+    }
+
+    /// Stake the GM tokens received from an executed deposit into a new LP position.
+    ///
+    /// Deposits are executed asynchronously by keepers, so the stake is done in two steps:
+    /// the user creates a deposit with the `deposit_receiver` PDA of (global_state, owner)
+    /// as its receiver, and once the deposit has been executed, this instruction moves the
+    /// whole balance of the receiver's token account into the position vault.
+    ///
+    /// Fails if the received amount is below `min_lp_amount` (at least 1), which is the
+    /// case if the deposit has not been executed yet.
+    pub fn stake_deposited_lp(
+        ctx: Context<StakeDepositedLp>,
+        position_id: u64,
+        min_lp_amount: u64,
+        lp_staked_value: u128, // scaled USD at stake time
+    ) -> Result<()> {
+        let lp_staked_amount = ctx.accounts.deposit_receiver_lp_token.amount;
+        require!(
+            lp_staked_amount > 0 && lp_staked_amount >= min_lp_amount,
+            ErrorCode::DepositNotExecuted
         );
-        Ok(())
+
+        // Transfer the received LP tokens from the deposit receiver to the position vault
+        let global_state_key = ctx.accounts.global_state.key();
+        let owner_key = ctx.accounts.owner.key();
+        let receiver_seeds: &[&[u8]] = &[
+            DEPOSIT_RECEIVER_SEED,
+            global_state_key.as_ref(),
+            owner_key.as_ref(),
+            &[ctx.bumps.deposit_receiver],
+        ];
+        let signer_seeds: &[&[&[u8]]] = &[receiver_seeds];
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.deposit_receiver_lp_token.to_account_info(),
+            mint: ctx.accounts.lp_mint.to_account_info(),
+            to: ctx.accounts.position_vault.to_account_info(),
+            authority: ctx.accounts.deposit_receiver.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_if::transfer_checked(cpi_ctx, lp_staked_amount, ctx.accounts.lp_mint.decimals)?;
+
+        let accounts = ctx.accounts;
+        open_position(
+            &mut accounts.global_state,
+            &accounts.gt_store,
+            &accounts.gt_program,
+            &mut accounts.position,
+            ctx.bumps.position,
+            &accounts.owner,
+            &accounts.lp_mint,
+            &accounts.position_vault,
+            position_id,
+            lp_staked_amount,
+            lp_staked_value,
+        )
     }
 
     /// Calculate GT rewards for LP based on stored Position data (no mint)
@@ -288,6 +341,7 @@ pub mod gmsol_liquidity_provider {
         {
             let position = &mut ctx.accounts.position;
             position.cum_inv_cost = cum_now;
+            position.last_checkpoint_time = out.current_time;
         }
 
         msg!(
@@ -347,6 +401,7 @@ pub mod gmsol_liquidity_provider {
         {
             let position = &mut ctx.accounts.position;
             position.cum_inv_cost = cum_now;
+            position.last_checkpoint_time = out.current_time;
         }
 
         // 2) Apply unstake amount
@@ -445,6 +500,114 @@ pub mod gmsol_liquidity_provider {
             );
         }
 
+        // Release the unstaked value from the aggregate staked value
+        let released_value = if full_exit {
+            old_value
+        } else {
+            old_value
+                .checked_sub(new_value)
+                .ok_or(ErrorCode::MathOverflow)?
+        };
+        let gs = &mut ctx.accounts.global_state;
+        gs.total_staked_value_usd = gs
+            .total_staked_value_usd
+            .checked_sub(released_value)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Get the aggregate staked value (USD, 1e20 scaled) across all positions.
+    /// Positions created before the aggregate was tracked are only included once migrated.
+    pub fn get_total_staked_value(ctx: Context<ReadGlobalState>) -> Result<u128> {
+        Ok(ctx.accounts.global_state.total_staked_value_usd)
+    }
+
+    /// Migrate the global state to `GLOBAL_STATE_VERSION`, reallocating it if needed.
+    /// Must be called before any other instruction once the program is upgraded.
+    pub fn migrate_global_state(ctx: Context<MigrateGlobalState>) -> Result<()> {
+        let info = ctx.accounts.global_state.to_account_info();
+        realloc_legacy_account(
+            &info,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+            8 + GlobalState::INIT_SPACE,
+        )?;
+        let mut gs = GlobalState::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require_keys_eq!(
+            gs.authority,
+            ctx.accounts.authority.key(),
+            ErrorCode::Unauthorized
+        );
+        require!(
+            gs.version < GLOBAL_STATE_VERSION,
+            ErrorCode::AlreadyMigrated
+        );
+
+        // v0 -> v1: the aggregate staked value starts from zero and is backfilled by `migrate_position`
+        if gs.version < 1 {
+            gs.total_staked_value_usd = 0;
+        }
+        // v1 -> v2: the reward accrual window is unbounded until configured
+        if gs.version < 2 {
+            gs.max_reward_window_seconds = 0;
+        }
+
+        let from_version = gs.version;
+        gs.version = GLOBAL_STATE_VERSION;
+        gs.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        msg!(
+            "Global state migrated: version {} -> {}",
+            from_version,
+            GLOBAL_STATE_VERSION
+        );
+        Ok(())
+    }
+
+    /// Migrate a position to `POSITION_VERSION`, reallocating it if needed.
+    /// Permissionless; the payer only funds the extra rent. Legacy positions are counted into
+    /// the aggregate staked value exactly once.
+    pub fn migrate_position(ctx: Context<MigratePosition>) -> Result<()> {
+        let info = ctx.accounts.position.to_account_info();
+        realloc_legacy_account(
+            &info,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            8 + Position::INIT_SPACE,
+        )?;
+        let mut position = Position::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require_keys_eq!(
+            position.global_state,
+            ctx.accounts.global_state.key(),
+            ErrorCode::InvalidArgument
+        );
+        require!(
+            position.version < POSITION_VERSION,
+            ErrorCode::AlreadyMigrated
+        );
+
+        // v0 -> v1: count the position into the aggregate staked value
+        if position.version < 1 {
+            let gs = &mut ctx.accounts.global_state;
+            gs.total_staked_value_usd = gs
+                .total_staked_value_usd
+                .checked_add(position.staked_value_usd)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+        // v1 -> v2: the last checkpoint is not recorded and falls back to the stake start time
+        if position.version < 2 {
+            position.last_checkpoint_time = 0;
+        }
+
+        let from_version = position.version;
+        position.version = POSITION_VERSION;
+        position.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        msg!(
+            "Position migrated: pos_id={}, version {} -> {}",
+            position.position_id,
+            from_version,
+            POSITION_VERSION
+        );
         Ok(())
     }
 
@@ -459,6 +622,20 @@ pub mod gmsol_liquidity_provider {
         Ok(())
     }
 
+    /// Update the max reward accrual window in seconds (0 = unbounded)
+    pub fn update_max_reward_window_seconds(
+        ctx: Context<UpdateMaxRewardWindow>,
+        new_max_reward_window_seconds: u64,
+    ) -> Result<()> {
+        let gs = &mut ctx.accounts.global_state;
+        gs.max_reward_window_seconds = new_max_reward_window_seconds;
+        msg!(
+            "max_reward_window_seconds updated to: {}",
+            new_max_reward_window_seconds
+        );
+        Ok(())
+    }
+
     /// Propose transferring program authority to `new_authority` (two-step handover).
     pub fn transfer_authority(
         ctx: Context<TransferAuthority>,
@@ -490,6 +667,107 @@ pub mod gmsol_liquidity_provider {
     }
 }
 
+/// Initialize a new position and snapshot stake-time values.
+/// The staked LP tokens must have been transferred to the position vault by the caller.
+#[allow(clippy::too_many_arguments)]
+fn open_position<'info>(
+    global_state: &mut Account<'info, GlobalState>,
+    gt_store: &AccountLoader<'info, Store>,
+    gt_program: &Program<'info, GmsolStore>,
+    position: &mut Account<'info, Position>,
+    position_bump: u8,
+    owner: &Signer<'info>,
+    lp_mint: &InterfaceAccount<'info, Mint>,
+    position_vault: &InterfaceAccount<'info, TokenAccount>,
+    position_id: u64,
+    lp_staked_amount: u64,
+    lp_staked_value: u128,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    // Enforce minimum stake value (scaled 1e20)
+    require!(
+        lp_staked_value >= global_state.min_stake_value,
+        ErrorCode::InvalidArgument
+    );
+
+    // Use GlobalState PDA as controller for GT CPI
+    let gs_seeds: &[&[u8]] = &[GLOBAL_STATE_SEED, &[global_state.bump]];
+    let signer_seeds: &[&[&[u8]]] = &[gs_seeds];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        gt_program.to_account_info(),
+        GtUpdateCtx {
+            authority: global_state.to_account_info(),
+            store: gt_store.to_account_info(),
+        },
+        signer_seeds,
+    );
+    // Snapshot C(start) at stake time
+    let r: GtReturn<u128> = gt_cpi::update_gt_cumulative_inv_cost_factor(cpi_ctx)?;
+    let c_start: u128 = r.get();
+
+    // Init position fields
+    position.owner = owner.key();
+    position.global_state = global_state.key();
+    position.lp_mint = lp_mint.key();
+    position.vault = position_vault.key();
+    position.position_id = position_id;
+    position.staked_amount = lp_staked_amount;
+    position.staked_value_usd = lp_staked_value;
+    position.stake_start_time = now;
+    position.cum_inv_cost = c_start;
+    position.last_checkpoint_time = now;
+    position.version = POSITION_VERSION;
+    position.bump = position_bump;
+
+    // Track the aggregate staked value
+    global_state.total_staked_value_usd = global_state
+        .total_staked_value_usd
+        .checked_add(lp_staked_value)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    msg!(
+        "Stake created: owner={}, amount={}, value(1e20)={}, start_ts={}, C_start={}, pos_id={}",
+        position.owner,
+        lp_staked_amount,
+        lp_staked_value,
+        now,
+        c_start,
+        position_id
+    );
+    Ok(())
+}
+
+/// Reallocate an account of this program created with a legacy layout to `space` bytes.
+/// The new bytes are zero-filled and the extra rent is paid by `payer`.
+fn realloc_legacy_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+) -> Result<()> {
+    require_keys_eq!(*account.owner, crate::ID, ErrorCode::InvalidArgument);
+    if account.data_len() >= space {
+        return Ok(());
+    }
+
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+    if required_lamports > lamports {
+        let cpi_ctx = CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: payer.to_account_info(),
+                to: account.clone(),
+            },
+        );
+        system_program::transfer(cpi_ctx, required_lamports - lamports)?;
+    }
+    account.realloc(space, true)?;
+    Ok(())
+}
+
 /// Calculate GT reward amount (returns raw amount in base units, respecting token decimals)
 /// Expects the integral over the window: inv_cost_integral = C(now) - C(start), where
 /// C(t) accumulates (MARKET_USD_UNIT / price(t)) dt. No extra multiply by `duration_seconds`
@@ -520,6 +798,7 @@ struct ComputeRewardOut {
     prev_cum: u128,
     inv_cost_integral: u128,
     duration_seconds: i64,
+    current_time: i64,
 }
 
 /// Compute reward by (a) refreshing C(t) via GT CPI and (b) applying APY-per-sec and integral.
@@ -549,10 +828,15 @@ fn compute_reward_with_cpi<'info>(
 
     // 2) Compute integral over [last_snapshot, now]
     require!(cum_now >= prev_cum, ErrorCode::InvalidArgument);
-    let inv_cost_integral = cum_now - prev_cum;
+    let current_time = Clock::get()?.unix_timestamp;
+    let inv_cost_integral = cap_inv_cost_integral(
+        cum_now - prev_cum,
+        position.last_checkpoint(),
+        current_time,
+        global_state.max_reward_window_seconds,
+    )?;
 
     // 3) Duration and time-weighted APY
-    let current_time = Clock::get()?.unix_timestamp;
     let duration_seconds = current_time.saturating_sub(position.stake_start_time);
     let avg_apy = compute_time_weighted_apy(
         position.stake_start_time,
@@ -579,9 +863,31 @@ fn compute_reward_with_cpi<'info>(
         prev_cum,
         inv_cost_integral,
         duration_seconds,
+        current_time,
     })
 }
 
+/// Cap the integral to the max reward window (0 = unbounded).
+/// Rewards stop accruing past the window until the position is touched again; the capped
+/// integral is the uncapped one scaled by `max_window / elapsed`.
+fn cap_inv_cost_integral(
+    inv_cost_integral: u128,
+    last_checkpoint: i64,
+    now: i64,
+    max_reward_window_seconds: u64,
+) -> Result<u128> {
+    let elapsed = now.saturating_sub(last_checkpoint).max(0) as u64;
+    if max_reward_window_seconds == 0 || elapsed <= max_reward_window_seconds {
+        return Ok(inv_cost_integral);
+    }
+    MulDiv::checked_mul_div(
+        &inv_cost_integral,
+        &(max_reward_window_seconds as u128),
+        &(elapsed as u128),
+    )
+    .ok_or_else(|| error!(ErrorCode::MathOverflow))
+}
+
 /// Compute time-weighted average APR over [start, now] using APY_BUCKETS-bucket weekly gradient (1e20-scaled).
 fn compute_time_weighted_apy(
     stake_start_time: i64,
@@ -646,7 +952,7 @@ pub struct Initialize<'info> {
 #[instruction(position_id: u64)]
 pub struct StakeLp<'info> {
     /// Global config (PDA)
-    #[account(seeds = [GLOBAL_STATE_SEED], bump = global_state.bump)]
+    #[account(mut, seeds = [GLOBAL_STATE_SEED], bump = global_state.bump)]
     pub global_state: Account<'info, GlobalState>,
 
     /// LP token mint to be staked
@@ -707,6 +1013,79 @@ pub struct StakeLp<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
+/// Accounts context for staking the LP tokens received from an executed deposit
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct StakeDepositedLp<'info> {
+    /// Global config (PDA)
+    #[account(mut, seeds = [GLOBAL_STATE_SEED], bump = global_state.bump)]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// LP token mint to be staked
+    pub lp_mint: InterfaceAccount<'info, Mint>,
+
+    /// Position PDA to initialize for (global_state, owner, position_id)
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Position::INIT_SPACE,
+        seeds = [
+            POSITION_SEED,
+            global_state.key().as_ref(),
+            owner.key().as_ref(),
+            &position_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub position: Account<'info, Position>,
+
+    /// Vault token account (PDA) to hold staked LP tokens for this position
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            POSITION_SEED,
+            global_state.key().as_ref(),
+            owner.key().as_ref(),
+            &position_id.to_le_bytes(),
+            VAULT_SEED,
+        ],
+        bump,
+        token::mint = lp_mint,
+        token::authority = global_state,
+    )]
+    pub position_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The GT Store account (mutated by CPI)
+    #[account(mut)]
+    pub gt_store: AccountLoader<'info, Store>,
+
+    /// GT program
+    pub gt_program: Program<'info, GmsolStore>,
+
+    /// Owner paying rent and recorded as position owner
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: PDA used as the receiver of the owner's deposits; only used as a signer
+    #[account(
+        seeds = [DEPOSIT_RECEIVER_SEED, global_state.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub deposit_receiver: UncheckedAccount<'info>,
+
+    /// Deposit receiver's LP token account holding the minted LP tokens
+    #[account(
+        mut,
+        token::mint = lp_mint,
+        token::authority = deposit_receiver,
+    )]
+    pub deposit_receiver_lp_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
 /// Accounts context for calculating GT reward from a Position
 #[derive(Accounts)]
 #[instruction(position_id: u64)]
@@ -787,7 +1166,7 @@ pub struct ClaimGt<'info> {
 #[instruction(position_id: u64)]
 pub struct UnstakeLp<'info> {
     /// Global config (PDA)
-    #[account(seeds = [GLOBAL_STATE_SEED], bump = global_state.bump)]
+    #[account(mut, seeds = [GLOBAL_STATE_SEED], bump = global_state.bump)]
     pub global_state: Account<'info, GlobalState>,
 
     /// LP token mint for this position (must match position.lp_mint)
@@ -856,6 +1235,41 @@ pub struct UnstakeLp<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
+/// Accounts for read-only queries of the global state
+#[derive(Accounts)]
+pub struct ReadGlobalState<'info> {
+    /// Global config (PDA)
+    #[account(seeds = [GLOBAL_STATE_SEED], bump = global_state.bump)]
+    pub global_state: Account<'info, GlobalState>,
+}
+
+/// Accounts for migrating the global state to the current version
+#[derive(Accounts)]
+pub struct MigrateGlobalState<'info> {
+    /// CHECK: Global config (PDA); deserialized after being reallocated to the current layout
+    #[account(mut, seeds = [GLOBAL_STATE_SEED], bump)]
+    pub global_state: UncheckedAccount<'info>,
+    /// Current authority, paying for the extra rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for migrating a position to the current version
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    /// Global config (PDA), must have been migrated
+    #[account(mut, seeds = [GLOBAL_STATE_SEED], bump = global_state.bump)]
+    pub global_state: Account<'info, GlobalState>,
+    /// CHECK: Position to migrate; deserialized after being reallocated to the current layout
+    #[account(mut)]
+    pub position: UncheckedAccount<'info>,
+    /// Payer of the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct SetClaimEnabled<'info> {
     /// Global config (PDA). The `authority` signer must match `global_state.authority`.
@@ -874,6 +1288,15 @@ pub struct UpdateMinStakeValue<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct UpdateMaxRewardWindow<'info> {
+    /// Global config (PDA). The `authority` signer must match `global_state.authority`.
+    #[account(mut, seeds = [GLOBAL_STATE_SEED], bump = global_state.bump, has_one = authority)]
+    pub global_state: Account<'info, GlobalState>,
+    /// Current authority
+    pub authority: Signer<'info>,
+}
+
 /// Accounts for APY gradient updates (used by sparse and range initializers)
 #[derive(Accounts)]
 pub struct UpdateApyGradient<'info> {
@@ -921,6 +1344,12 @@ pub struct GlobalState {
     pub claim_enabled: bool,
     /// PDA bump for this GlobalState (derived from seed [GLOBAL_STATE_SEED])
     pub bump: u8,
+    /// Aggregate staked value in USD (scaled by 1e20) across all positions
+    pub total_staked_value_usd: u128,
+    /// Layout version, see `GLOBAL_STATE_VERSION` (0 for legacy accounts)
+    pub version: u8,
+    /// Max reward accrual window in seconds since the last checkpoint (0 = unbounded)
+    pub max_reward_window_seconds: u64,
 }
 
 /// Position account to persist LP stake data and snapshot stake-time values
@@ -947,6 +1376,21 @@ pub struct Position {
     pub cum_inv_cost: u128,
     /// PDA bump
     pub bump: u8,
+    /// Layout version, see `POSITION_VERSION` (0 for legacy accounts)
+    pub version: u8,
+    /// Unix timestamp of the last claim/stake checkpoint (0 if not recorded)
+    pub last_checkpoint_time: i64,
+}
+
+impl Position {
+    /// Last checkpoint time, falling back to the stake start time if not recorded.
+    fn last_checkpoint(&self) -> i64 {
+        if self.last_checkpoint_time == 0 {
+            self.stake_start_time
+        } else {
+            self.last_checkpoint_time
+        }
+    }
 }
 
 #[error_code]
@@ -961,4 +1405,62 @@ pub enum ErrorCode {
     ApyTooLarge,
     #[msg("Claim is disabled by protocol policy")]
     ClaimDisabled,
+    #[msg("The deposit has not been executed or the received amount is too small")]
+    DepositNotExecuted,
+    #[msg("Too many APY buckets updated in a single instruction")]
+    TooManyApyBuckets,
+    #[msg("The account has already been migrated to the current version")]
+    AlreadyMigrated,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cap_inv_cost_integral_within_window() {
+        // Unbounded window.
+        assert_eq!(
+            cap_inv_cost_integral(1_000, 0, 1_000_000, 0).unwrap(),
+            1_000
+        );
+        // Elapsed time within the window.
+        assert_eq!(cap_inv_cost_integral(1_000, 100, 200, 100).unwrap(), 1_000);
+        assert_eq!(cap_inv_cost_integral(1_000, 100, 150, 100).unwrap(), 1_000);
+        // The last checkpoint is in the future.
+        assert_eq!(cap_inv_cost_integral(1_000, 200, 100, 10).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn cap_inv_cost_integral_beyond_window() {
+        assert_eq!(cap_inv_cost_integral(1_000, 0, 100, 25).unwrap(), 250);
+        // Rounded down.
+        assert_eq!(cap_inv_cost_integral(1_000, 0, 3, 1).unwrap(), 333);
+        assert_eq!(
+            cap_inv_cost_integral(u128::MAX, 0, 3, 2).unwrap(),
+            u128::MAX / 3 * 2
+        );
+        assert_eq!(cap_inv_cost_integral(0, 0, 100, 25).unwrap(), 0);
+    }
+
+    #[test]
+    fn last_checkpoint_falls_back_to_stake_start_time() {
+        let mut position = Position {
+            owner: Pubkey::default(),
+            global_state: Pubkey::default(),
+            lp_mint: Pubkey::default(),
+            vault: Pubkey::default(),
+            position_id: 0,
+            staked_amount: 0,
+            staked_value_usd: 0,
+            stake_start_time: 100,
+            cum_inv_cost: 0,
+            bump: 0,
+            version: 1,
+            last_checkpoint_time: 0,
+        };
+        assert_eq!(position.last_checkpoint(), 100);
+        position.last_checkpoint_time = 200;
+        assert_eq!(position.last_checkpoint(), 200);
+    }
 }
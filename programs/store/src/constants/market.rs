@@ -142,8 +142,38 @@ pub const DEFAULT_MAX_OPEN_INTEREST_FOR_SHORT: Factor = 450_000 * super::MARKET_
 /// Default min tokens for first deposit.
 pub const DEFAULT_MIN_TOKENS_FOR_FIRST_DEPOSIT: Factor = 0;
 
+/// Default min market token price for deposits.
+pub const DEFAULT_MIN_MARKET_TOKEN_PRICE: Factor = 0;
+
+/// Default cooldown between increases of the same position (disabled).
+pub const DEFAULT_INCREASE_COOLDOWN_SECONDS: Factor = 0;
+
+/// Default min deposit fee in USD.
+pub const DEFAULT_MIN_DEPOSIT_FEE_USD: Factor = 0;
+
+/// Default min withdrawal fee in USD.
+pub const DEFAULT_MIN_WITHDRAWAL_FEE_USD: Factor = 0;
+
+/// Default max negative swap price impact factor per hop (no limit).
+pub const DEFAULT_MAX_SWAP_IMPACT_FACTOR: Factor = 0;
+
+/// Default max order size in USD (no limit).
+pub const DEFAULT_MAX_ORDER_SIZE_USD: Factor = 0;
+
+/// Default max number of open positions.
+pub const DEFAULT_MAX_OPEN_POSITIONS: Factor = 0;
+
+/// Default max position impact factor for liquidation execution (no cap).
+pub const DEFAULT_MAX_POSITION_IMPACT_FACTOR_FOR_LIQUIDATION_EXECUTION: Factor = 0;
+
 /// Default skip borrowing fee for smaller side.
 pub const DEFAULT_SKIP_BORROWING_FEE_FOR_SMALLER_SIDE: bool = true;
 
 /// Default ignore open interest for usage factor.
 pub const DEFAULT_IGNORE_OPEN_INTEREST_FOR_USAGE_FACTOR: bool = false;
+
+/// Default enforce reserves.
+pub const DEFAULT_ENFORCE_RESERVES: bool = true;
+
+/// Default enable insurance fund.
+pub const DEFAULT_ENABLE_INSURANCE_FUND: bool = false;
@@ -52,6 +52,9 @@ pub const MARKET_USD_TO_AMOUNT_DIVISOR: u128 =
 #[constant]
 pub const MARKET_DECIMALS: u8 = Decimal::MAX_DECIMALS;
 
+/// Max number of entries in a config batch.
+pub const MAX_CONFIG_BATCH_LEN: usize = 32;
+
 /// Default claimable time window.
 pub const DEFAULT_CLAIMABLE_TIME_WINDOW: Amount = 3600;
 
@@ -73,8 +76,86 @@ pub const DEFAULT_ORACLE_MAX_FUTURE_TIMESTAMP_EXCESS: Amount = 0;
 /// Default max ADL prices staleness (in seconds).
 pub const DEFAULT_ADL_PRICES_MAX_STALENESS: Amount = 0;
 
+/// Default min amount of native tokens to be unwrapped automatically.
+pub const DEFAULT_MIN_AUTO_UNWRAP_AMOUNT: Amount = 0;
+
+/// Default max age of actions to be executed (in seconds).
+pub const DEFAULT_MAX_ACTION_AGE: Amount = 0;
+
+/// Default min timestamp adjustment.
+pub const DEFAULT_MIN_TIMESTAMP_ADJUSTMENT: Amount = 0;
+
+/// Default max timestamp adjustment.
+pub const DEFAULT_MAX_TIMESTAMP_ADJUSTMENT: Amount = 0;
+
+/// Default max number of tokens per price set.
+pub const DEFAULT_MAX_TOKENS_PER_PRICE_SET: Amount = 0;
+
+/// Default self-trade prevention toggle (disabled).
+pub const DEFAULT_SELF_TRADE_PREVENTION: Amount = 0;
+
+/// Default min GT balance required to initialize a referral code.
+pub const DEFAULT_MIN_GT_FOR_REFERRAL_CODE: Amount = 0;
+
+/// Default oracle staleness for read instructions (not set).
+pub const DEFAULT_DEFAULT_ORACLE_STALENESS: Amount = 0;
+
+/// Default GT exchange vault auto-confirm toggle (disabled).
+pub const DEFAULT_GT_VAULT_AUTO_CONFIRM: Amount = 0;
+
+/// Default max number of concurrent GLV shifts (unlimited).
+pub const DEFAULT_MAX_CONCURRENT_GLV_SHIFTS: Amount = 0;
+
+/// Default GT mint lockup period (disabled).
+pub const DEFAULT_GT_MINT_LOCKUP_SECONDS: Amount = 0;
+
+/// Default max referral code length (no limit).
+pub const DEFAULT_MAX_REFERRAL_CODE_LENGTH: Amount = 0;
+
+/// Default referral code charset (any bytes).
+pub const DEFAULT_REFERRAL_CODE_CHARSET: Amount = 0;
+
+/// Default max total length of the swap paths of a deposit.
+pub const DEFAULT_MAX_DEPOSIT_SWAP_PATH_LENGTH: Amount = 0;
+
+/// Default max total length of the swap paths of a withdrawal.
+pub const DEFAULT_MAX_WITHDRAWAL_SWAP_PATH_LENGTH: Amount = 0;
+
+/// Default max total length of the swap paths of an order.
+pub const DEFAULT_MAX_ORDER_SWAP_PATH_LENGTH: Amount = 0;
+
+/// Default max number of custom price feeds per token (unlimited).
+pub const DEFAULT_MAX_CUSTOM_PRICE_FEEDS_PER_TOKEN: Amount = 0;
+
+/// Default min expiration duration of market config buffers.
+pub const DEFAULT_MIN_MARKET_CONFIG_BUFFER_EXPIRE_AFTER: Amount = 0;
+
+/// Default max expiration duration of market config buffers (unlimited).
+pub const DEFAULT_MAX_MARKET_CONFIG_BUFFER_EXPIRE_AFTER: Amount = 0;
+
+/// Default max size of the compressed Chainlink Data Streams reports, which is the max size of a transaction.
+pub const DEFAULT_MAX_CHAINLINK_REPORT_SIZE: Amount = 1232;
+
+/// Default max number of pending actions of a user (unlimited).
+pub const DEFAULT_MAX_PENDING_ACTIONS_PER_USER: Amount = 0;
+
+/// Default fair market order execution (disabled).
+pub const DEFAULT_FAIR_MARKET_ORDER_EXECUTION: Amount = 0;
+
 /// Default oracle ref price deviation.
 pub const DEFAULT_ORACLE_REF_PRICE_DEVIATION: Factor = 1_000_000_000_000_000;
 
+/// Default partial liquidation buffer factor (disabled).
+pub const DEFAULT_PARTIAL_LIQUIDATION_BUFFER_FACTOR: Factor = 0;
+
+/// Default limit order auto-cancel tolerance (disabled).
+pub const DEFAULT_LIMIT_ORDER_AUTO_CANCEL_TOLERANCE: Factor = 0;
+
+/// Default order fee to GT stakers factor.
+pub const DEFAULT_ORDER_FEE_TO_GT_STAKERS_FACTOR: Factor = 0;
+
+/// Default max Pyth confidence ratio (unlimited).
+pub const DEFAULT_MAX_PYTH_CONFIDENCE_RATIO: Factor = 0;
+
 /// Default GT vault time window size.
 pub const DEFAULT_GT_VAULT_TIME_WINDOW: u32 = 24 * 60 * 60;
@@ -3,7 +3,7 @@ use borsh::BorshSerialize;
 use gmsol_model::action::deposit::DepositReport;
 use gmsol_utils::InitSpace;
 
-use crate::states::common::action::ActionState;
+use crate::states::common::action::{ActionState, CloseReason};
 
 use super::Event;
 
@@ -85,9 +85,12 @@ pub struct DepositRemoved {
     /// Reason.
     #[max_len(32)]
     pub reason: String,
+    /// Structured close reason.
+    pub close_reason: CloseReason,
 }
 
 impl DepositRemoved {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         id: u64,
         store: Pubkey,
@@ -96,6 +99,7 @@ impl DepositRemoved {
         owner: Pubkey,
         state: ActionState,
         reason: impl ToString,
+        close_reason: CloseReason,
     ) -> Result<Self> {
         let clock = Clock::get()?;
         Ok(Self {
@@ -108,6 +112,7 @@ impl DepositRemoved {
             owner,
             state,
             reason: reason.to_string(),
+            close_reason,
         })
     }
 }
@@ -3,7 +3,7 @@ use borsh::BorshSerialize;
 
 use gmsol_utils::InitSpace;
 
-use crate::states::common::action::ActionState;
+use crate::states::common::action::{ActionState, CloseReason};
 
 use super::Event;
 
@@ -33,9 +33,12 @@ pub struct GlvDepositRemoved {
     /// Reason.
     #[max_len(32)]
     pub reason: String,
+    /// Structured close reason.
+    pub close_reason: CloseReason,
 }
 
 impl GlvDepositRemoved {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         id: u64,
         store: Pubkey,
@@ -45,6 +48,7 @@ impl GlvDepositRemoved {
         owner: Pubkey,
         state: ActionState,
         reason: impl ToString,
+        close_reason: CloseReason,
     ) -> Result<Self> {
         let clock = Clock::get()?;
         Ok(Self {
@@ -58,6 +62,7 @@ impl GlvDepositRemoved {
             owner,
             state,
             reason: reason.to_string(),
+            close_reason,
         })
     }
 }
@@ -94,9 +99,12 @@ pub struct GlvWithdrawalRemoved {
     /// Reason.
     #[max_len(32)]
     pub reason: String,
+    /// Structured close reason.
+    pub close_reason: CloseReason,
 }
 
 impl GlvWithdrawalRemoved {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         id: u64,
         store: Pubkey,
@@ -106,6 +114,7 @@ impl GlvWithdrawalRemoved {
         owner: Pubkey,
         state: ActionState,
         reason: impl ToString,
+        close_reason: CloseReason,
     ) -> Result<Self> {
         let clock = Clock::get()?;
         Ok(Self {
@@ -119,6 +128,7 @@ impl GlvWithdrawalRemoved {
             owner,
             state,
             reason: reason.to_string(),
+            close_reason,
         })
     }
 }
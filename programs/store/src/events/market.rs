@@ -156,9 +156,15 @@ pub struct EventOtherState {
     pub short_token_balance: u64,
     /// Funding factor per second.
     pub funding_factor_per_second: i128,
+    /// Number of open positions.
+    pub open_positions: u64,
+    /// Insurance fund of long token.
+    pub long_token_insurance_fund: u64,
+    /// Insurance fund of short token.
+    pub short_token_insurance_fund: u64,
     #[cfg_attr(feature = "debug", debug(skip))]
     #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
-    pub(crate) reserved: [u8; 256],
+    pub(crate) reserved: [u8; 232],
 }
 
 static_assertions::const_assert_eq!(EventOtherState::INIT_SPACE, OtherState::INIT_SPACE);
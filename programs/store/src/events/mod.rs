@@ -25,11 +25,15 @@ mod market;
 /// GT events.
 mod gt;
 
+/// Role events.
+mod roles;
+
 pub use deposit::*;
 pub use glv::*;
 pub use gt::*;
 pub use market::*;
 pub use order::*;
+pub use roles::*;
 pub use shift::*;
 pub use swap::*;
 pub use trade::*;
@@ -8,7 +8,7 @@ use gmsol_model::action::{
 use gmsol_utils::InitSpace;
 
 use crate::states::{
-    common::action::{Action, ActionState},
+    common::action::{Action, ActionState, CloseReason},
     order::OrderKind,
     Order,
 };
@@ -130,9 +130,12 @@ pub struct OrderRemoved {
     /// Reason.
     #[max_len(32)]
     pub reason: String,
+    /// Structured close reason.
+    pub close_reason: CloseReason,
 }
 
 impl OrderRemoved {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         id: u64,
         store: Pubkey,
@@ -142,6 +145,7 @@ impl OrderRemoved {
         owner: Pubkey,
         state: ActionState,
         reason: impl ToString,
+        close_reason: CloseReason,
     ) -> Result<Self> {
         let clock = Clock::get()?;
         Ok(Self {
@@ -155,6 +159,7 @@ impl OrderRemoved {
             owner,
             state,
             reason: reason.to_string(),
+            close_reason,
         })
     }
 }
@@ -217,6 +222,98 @@ impl InitSpace for InsufficientFundingFeePayment {
 
 impl Event for InsufficientFundingFeePayment {}
 
+/// An event indicating that the insurance fund of a market has been drawn
+/// to cover the bad debt of an insolvent close.
+#[event]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, InitSpace)]
+pub struct InsuranceFundDrawn {
+    /// Timestamp.
+    pub ts: i64,
+    /// Slot.
+    pub slot: u64,
+    /// Store.
+    pub store: Pubkey,
+    /// Market token.
+    pub market_token: Pubkey,
+    /// Unpaid collateral token amount (the deficit).
+    pub unpaid_amount: u128,
+    /// Collateral token amount drawn from the insurance fund.
+    pub drawn_amount: u64,
+    /// Remaining insurance fund of the collateral token.
+    pub remaining_amount: u64,
+    /// Whether the collateral token is long token.
+    pub is_collateral_token_long: bool,
+}
+
+impl InsuranceFundDrawn {
+    pub(crate) fn new(
+        store: &Pubkey,
+        market_token: &Pubkey,
+        unpaid_amount: u128,
+        drawn_amount: u64,
+        remaining_amount: u64,
+        is_collateral_token_long: bool,
+    ) -> Result<Self> {
+        let clock = Clock::get()?;
+        Ok(Self {
+            ts: clock.unix_timestamp,
+            slot: clock.slot,
+            store: *store,
+            market_token: *market_token,
+            unpaid_amount,
+            drawn_amount,
+            remaining_amount,
+            is_collateral_token_long,
+        })
+    }
+}
+
+impl InitSpace for InsuranceFundDrawn {
+    const INIT_SPACE: usize = <Self as Space>::INIT_SPACE;
+}
+
+impl Event for InsuranceFundDrawn {}
+
+/// An event indicating that a position has become liquidatable.
+///
+/// It is only emitted when the position transitions from non-liquidatable
+/// to liquidatable during the execution of an order.
+#[event]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, InitSpace)]
+pub struct PositionLiquidatable {
+    /// Timestamp.
+    pub ts: i64,
+    /// Slot.
+    pub slot: u64,
+    /// Store.
+    pub store: Pubkey,
+    /// Position.
+    pub position: Pubkey,
+    /// Market token.
+    pub market_token: Pubkey,
+}
+
+impl PositionLiquidatable {
+    pub(crate) fn new(store: &Pubkey, position: &Pubkey, market_token: &Pubkey) -> Result<Self> {
+        let clock = Clock::get()?;
+        Ok(Self {
+            ts: clock.unix_timestamp,
+            slot: clock.slot,
+            store: *store,
+            position: *position,
+            market_token: *market_token,
+        })
+    }
+}
+
+impl InitSpace for PositionLiquidatable {
+    const INIT_SPACE: usize = <Self as Space>::INIT_SPACE;
+}
+
+impl Event for PositionLiquidatable {}
+
 /// Order parameters for event.
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// All roles revoked event.
+#[event]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct AllRolesRevoked {
+    /// Event time.
+    pub ts: i64,
+    /// Store account.
+    pub store: Pubkey,
+    /// The user whose roles were revoked.
+    pub user: Pubkey,
+    /// The names of the revoked roles.
+    pub roles: Vec<String>,
+}
+
+impl AllRolesRevoked {
+    pub(crate) fn new(store: Pubkey, user: Pubkey, roles: Vec<String>) -> Result<Self> {
+        Ok(Self {
+            ts: Clock::get()?.unix_timestamp,
+            store,
+            user,
+            roles,
+        })
+    }
+}
@@ -3,7 +3,7 @@ use borsh::BorshSerialize;
 
 use gmsol_utils::InitSpace;
 
-use crate::states::common::action::ActionState;
+use crate::states::common::action::{ActionState, CloseReason};
 
 use super::Event;
 
@@ -31,9 +31,12 @@ pub struct ShiftRemoved {
     /// Reason.
     #[max_len(32)]
     pub reason: String,
+    /// Structured close reason.
+    pub close_reason: CloseReason,
 }
 
 impl ShiftRemoved {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         id: u64,
         store: Pubkey,
@@ -42,6 +45,7 @@ impl ShiftRemoved {
         owner: Pubkey,
         state: ActionState,
         reason: impl ToString,
+        close_reason: CloseReason,
     ) -> Result<Self> {
         let clock = Clock::get()?;
         Ok(Self {
@@ -54,6 +58,7 @@ impl ShiftRemoved {
             owner,
             state,
             reason: reason.to_string(),
+            close_reason,
         })
     }
 }
@@ -4,7 +4,7 @@ use borsh::BorshSerialize;
 use gmsol_model::action::withdraw::WithdrawReport;
 use gmsol_utils::InitSpace;
 
-use crate::states::common::action::ActionState;
+use crate::states::common::action::{ActionState, CloseReason};
 
 use super::Event;
 
@@ -86,6 +86,8 @@ pub struct WithdrawalRemoved {
     /// Reason.
     #[max_len(32)]
     pub reason: String,
+    /// Structured close reason.
+    pub close_reason: CloseReason,
 }
 
 impl InitSpace for WithdrawalRemoved {
@@ -95,6 +97,7 @@ impl InitSpace for WithdrawalRemoved {
 impl Event for WithdrawalRemoved {}
 
 impl WithdrawalRemoved {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         id: u64,
         store: Pubkey,
@@ -103,6 +106,7 @@ impl WithdrawalRemoved {
         owner: Pubkey,
         state: ActionState,
         reason: impl ToString,
+        close_reason: CloseReason,
     ) -> Result<Self> {
         let clock = Clock::get()?;
         Ok(Self {
@@ -115,6 +119,7 @@ impl WithdrawalRemoved {
             owner,
             state,
             reason: reason.to_string(),
+            close_reason,
         })
     }
 }
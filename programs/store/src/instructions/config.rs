@@ -1,7 +1,9 @@
 use crate::states::{Amount, Factor};
 use anchor_lang::prelude::*;
 
-use crate::{states::Store, utils::internal};
+use crate::{constants, states::Store, utils::internal, CoreError};
+
+use super::ReadStore;
 
 /// The accounts definition of instructions for updating configs.
 #[derive(Accounts)]
@@ -26,11 +28,11 @@ impl<'info> internal::Authentication<'info> for InsertConfig<'info> {
 
 /// CHECK: only CONFIG_KEEPER is allowed to invoke.
 pub(crate) fn unchecked_insert_amount(
-    ctx: Context<InsertConfig>,
+    accounts: &InsertConfig,
     key: &str,
     amount: Amount,
 ) -> Result<()> {
-    *ctx.accounts.store.load_mut()?.get_amount_mut(key)? = amount;
+    *accounts.store.load_mut()?.get_amount_mut(key)? = amount;
     Ok(())
 }
 
@@ -40,20 +42,128 @@ pub(crate) fn unchecked_insert_amount(
 ///   which can also be updated using
 ///   [`insert_order_fee_discount_for_referred_user`](crate::gmsol_store::insert_order_fee_discount_for_referred_user)
 pub(crate) fn unchecked_insert_factor(
-    ctx: Context<InsertConfig>,
+    accounts: &InsertConfig,
     key: &str,
     factor: Factor,
 ) -> Result<()> {
-    *ctx.accounts.store.load_mut()?.get_factor_mut(key)? = factor;
+    *accounts.store.load_mut()?.get_factor_mut(key)? = factor;
     Ok(())
 }
 
 /// CHECK: only CONFIG_KEEPER is allowed to invoke.
 pub(crate) fn unchecked_insert_address(
-    ctx: Context<InsertConfig>,
+    accounts: &InsertConfig,
     key: &str,
     address: Pubkey,
 ) -> Result<()> {
-    *ctx.accounts.store.load_mut()?.get_address_mut(key)? = address;
+    *accounts.store.load_mut()?.get_address_mut(key)? = address;
+    Ok(())
+}
+
+/// A typed config entry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConfigEntry {
+    /// Amount config, see [`AmountKey`](crate::states::AmountKey).
+    Amount(String, Amount),
+    /// Factor config, see [`FactorKey`](crate::states::FactorKey).
+    Factor(String, Factor),
+    /// Address config, see [`AddressKey`](crate::states::AddressKey).
+    Address(String, Pubkey),
+}
+
+/// CHECK: only CONFIG_KEEPER is allowed to invoke.
+pub(crate) fn unchecked_insert_config_batch(
+    ctx: Context<InsertConfig>,
+    entries: &[ConfigEntry],
+) -> Result<()> {
+    require_gte!(
+        constants::MAX_CONFIG_BATCH_LEN,
+        entries.len(),
+        CoreError::ExceedMaxLengthLimit
+    );
+    for entry in entries {
+        match entry {
+            ConfigEntry::Amount(key, amount) => {
+                unchecked_insert_amount(ctx.accounts, key, *amount)?
+            }
+            ConfigEntry::Factor(key, factor) => {
+                unchecked_insert_factor(ctx.accounts, key, *factor)?
+            }
+            ConfigEntry::Address(key, address) => {
+                unchecked_insert_address(ctx.accounts, key, *address)?
+            }
+        }
+    }
     Ok(())
 }
+
+/// Get the amount value of the given key.
+pub(crate) fn get_amount(ctx: Context<ReadStore>, key: &str) -> Result<Amount> {
+    Ok(*ctx.accounts.store.load()?.get_amount(key)?)
+}
+
+/// Get the factor value of the given key.
+pub(crate) fn get_factor(ctx: Context<ReadStore>, key: &str) -> Result<Factor> {
+    Ok(*ctx.accounts.store.load()?.get_factor(key)?)
+}
+
+/// Get the address value of the given key.
+pub(crate) fn get_address(ctx: Context<ReadStore>, key: &str) -> Result<Pubkey> {
+    Ok(*ctx.accounts.store.load()?.get_address(key)?)
+}
+
+/// The accounts definition for [`set_rent_recipient_allowed`](crate::gmsol_store::set_rent_recipient_allowed)
+/// instruction.
+#[derive(Accounts)]
+pub struct SetRentRecipientAllowed<'info> {
+    /// Authority.
+    pub authority: Signer<'info>,
+    /// Store.
+    #[account(mut)]
+    pub store: AccountLoader<'info, Store>,
+}
+
+/// CHECK: only CONFIG_KEEPER is allowed to invoke.
+pub(crate) fn unchecked_set_rent_recipient_allowed(
+    ctx: Context<SetRentRecipientAllowed>,
+    recipient: &Pubkey,
+    allowed: bool,
+) -> Result<()> {
+    let changed = ctx
+        .accounts
+        .store
+        .load_mut()?
+        .rent_recipient_allowlist_mut()
+        .set_allowed(recipient, allowed)?;
+    if changed {
+        msg!(
+            "[Config] rent recipient `{}` is now {}",
+            recipient,
+            if allowed { "allowed" } else { "disallowed" },
+        );
+    }
+    Ok(())
+}
+
+impl<'info> internal::Authentication<'info> for SetRentRecipientAllowed<'info> {
+    fn authority(&self) -> &Signer<'info> {
+        &self.authority
+    }
+
+    fn store(&self) -> &AccountLoader<'info, Store> {
+        &self.store
+    }
+}
+
+/// Get the allowed rent recipients of the store.
+pub(crate) fn get_allowed_rent_recipients(ctx: Context<ReadStore>) -> Result<Vec<Pubkey>> {
+    Ok(ctx
+        .accounts
+        .store
+        .load()?
+        .rent_recipient_allowlist()
+        .recipients()
+        .to_vec())
+}
@@ -0,0 +1,730 @@
+use anchor_lang::{prelude::*, Discriminator, ZeroCopy};
+use anchor_spl::{token::Mint, token_interface::TokenAccount};
+use gmsol_callback::interface::ActionKind;
+use gmsol_model::{
+    price::Prices, LiquidityMarketExt, LiquidityMarketMutExt, MarketAction, PnlFactorKind,
+};
+
+use crate::{
+    instructions::ReadStore,
+    states::{
+        common::{
+            action::{Action, ActionHeader},
+            swap::{HasSwapParams, SwapActionParams},
+            token::TokenAndAccount,
+        },
+        feature::{ActionDisabledFlag, DomainDisabledFlag},
+        market::SimulatedLiquidityMarket,
+        Deposit, GlvDeposit, GlvShift, GlvWithdrawal, Market, MarketConfigKey, Order, Seed, Shift,
+        Store, Withdrawal,
+    },
+    CoreError, ModelError,
+};
+
+/// The accounts definition for read-only instructions for actions.
+///
+/// Remaining accounts expected by the instructions:
+/// - `[]` action accounts of any kinds.
+#[derive(Accounts)]
+pub struct ReadActions<'info> {
+    /// The store that owns the actions.
+    pub store: AccountLoader<'info, Store>,
+}
+
+/// Execution fee recorded in an action.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionExecutionFee {
+    /// The address of the action.
+    pub action: Pubkey,
+    /// The kind of the action.
+    pub kind: ActionKind,
+    /// Whether the action is pending.
+    pub is_pending: bool,
+    /// The max execution fee in lamports.
+    pub max_execution_lamports: u64,
+}
+
+/// Get the execution fees recorded in the actions provided in the remaining accounts.
+pub(crate) fn get_pending_execution_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadActions<'info>>,
+) -> Result<Vec<ActionExecutionFee>> {
+    let store = ctx.accounts.store.key();
+    ctx.remaining_accounts
+        .iter()
+        .map(|info| {
+            let (kind, header) = load_action_header(info)?;
+            require_keys_eq!(header.store, store, CoreError::StoreMismatched);
+            Ok(ActionExecutionFee {
+                action: info.key(),
+                kind,
+                is_pending: header.action_state()?.is_pending(),
+                max_execution_lamports: header.max_execution_lamports(),
+            })
+        })
+        .collect()
+}
+
+/// Escrow account recorded in an action.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionEscrow {
+    /// The mint of the escrowed token.
+    pub mint: Pubkey,
+    /// The address of the escrow account.
+    pub escrow: Pubkey,
+}
+
+/// Escrow accounts recorded in an action.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionEscrows {
+    /// The address of the action.
+    pub action: Pubkey,
+    /// The kind of the action.
+    pub kind: ActionKind,
+    /// The escrow accounts.
+    pub escrows: Vec<ActionEscrow>,
+}
+
+/// Get the escrow accounts recorded in the actions provided in the remaining accounts.
+pub(crate) fn get_action_escrows<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadActions<'info>>,
+) -> Result<Vec<ActionEscrows>> {
+    let store = ctx.accounts.store.key();
+    ctx.remaining_accounts
+        .iter()
+        .map(|info| {
+            let (kind, header) = load_action_header(info)?;
+            require_keys_eq!(header.store, store, CoreError::StoreMismatched);
+            Ok(ActionEscrows {
+                action: info.key(),
+                kind,
+                escrows: load_action_escrows(kind, info)?,
+            })
+        })
+        .collect()
+}
+
+/// Balance of an escrow account recorded in an action.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionEscrowBalance {
+    /// The mint of the escrowed token.
+    pub mint: Pubkey,
+    /// The address of the escrow account.
+    pub escrow: Pubkey,
+    /// The current balance of the escrow account, or `None` if the
+    /// account does not exist.
+    pub balance: Option<u64>,
+}
+
+/// Balances of the escrow accounts recorded in an action.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionEscrowBalances {
+    /// The address of the action.
+    pub action: Pubkey,
+    /// The kind of the action.
+    pub kind: ActionKind,
+    /// The balances of the escrow accounts.
+    pub escrows: Vec<ActionEscrowBalance>,
+}
+
+/// Get the balances of the escrow accounts recorded in the actions provided in the remaining accounts.
+///
+/// Each action account must be followed by its escrow accounts, in the same order as
+/// returned by [`get_action_escrows`].
+pub(crate) fn get_action_escrow_balances<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadActions<'info>>,
+) -> Result<Vec<ActionEscrowBalances>> {
+    let store = ctx.accounts.store.key();
+    let mut accounts = ctx.remaining_accounts.iter();
+    let mut results = Vec::new();
+    while let Some(info) = accounts.next() {
+        let (kind, header) = load_action_header(info)?;
+        require_keys_eq!(header.store, store, CoreError::StoreMismatched);
+        let escrows = load_action_escrows(kind, info)?
+            .into_iter()
+            .map(|ActionEscrow { mint, escrow }| {
+                let account = accounts
+                    .next()
+                    .ok_or_else(|| error!(ErrorCode::AccountNotEnoughKeys))?;
+                require_keys_eq!(account.key(), escrow, CoreError::InvalidArgument);
+                let balance = if account.data_is_empty() {
+                    None
+                } else {
+                    let account = InterfaceAccount::<TokenAccount>::try_from(account)?;
+                    require_keys_eq!(account.mint, mint, CoreError::TokenMintMismatched);
+                    Some(account.amount)
+                };
+                Ok(ActionEscrowBalance {
+                    mint,
+                    escrow,
+                    balance,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        results.push(ActionEscrowBalances {
+            action: info.key(),
+            kind,
+            escrows,
+        });
+    }
+    Ok(results)
+}
+
+/// Estimated compute requirement class of an execution.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExecutionComputeClass {
+    /// No swaps and only a few tokens to price.
+    Light,
+    /// A few swap hops or a position update.
+    Medium,
+    /// Long swap paths, many tokens to price or GLV valuation.
+    Heavy,
+}
+
+impl ExecutionComputeClass {
+    fn from_weight(weight: u16) -> Self {
+        match weight {
+            0..=4 => Self::Light,
+            5..=10 => Self::Medium,
+            _ => Self::Heavy,
+        }
+    }
+}
+
+/// Estimated compute requirement of the execution of an action.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutionComputeEstimate {
+    /// The address of the action.
+    pub action: Pubkey,
+    /// The kind of the action.
+    pub kind: ActionKind,
+    /// The total number of swap hops of both swap paths.
+    pub swap_hops: u8,
+    /// The number of tokens to be priced by the oracle.
+    ///
+    /// For shifts, the tokens are not recorded in the action, so an upper
+    /// bound is returned instead.
+    pub num_tokens_to_price: u8,
+    /// Whether a position will be updated.
+    pub updates_position: bool,
+    /// Whether the GLV token value will be calculated, which requires
+    /// the valuation of all markets in the GLV.
+    pub requires_glv_valuation: bool,
+    /// The estimated compute requirement class.
+    pub class: ExecutionComputeClass,
+}
+
+/// Get the estimated compute requirements for executing the actions provided in the remaining accounts.
+pub(crate) fn estimate_execution_compute<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadActions<'info>>,
+) -> Result<Vec<ExecutionComputeEstimate>> {
+    // Long token, short token and the index tokens of both markets.
+    const MAX_SHIFT_TOKENS: u8 = 4;
+
+    let store = ctx.accounts.store.key();
+    ctx.remaining_accounts
+        .iter()
+        .map(|info| {
+            let (kind, header) = load_action_header(info)?;
+            require_keys_eq!(header.store, store, CoreError::StoreMismatched);
+            let swap_stats = |swap: &SwapActionParams| {
+                (swap.primary_length + swap.secondary_length, swap.num_tokens)
+            };
+            let (stats, updates_position, requires_glv_valuation) = match kind {
+                ActionKind::Deposit => (
+                    Some(swap_stats(
+                        AccountLoader::<Deposit>::try_from(info)?.load()?.swap(),
+                    )),
+                    false,
+                    false,
+                ),
+                ActionKind::Withdrawal => (
+                    Some(swap_stats(
+                        AccountLoader::<Withdrawal>::try_from(info)?.load()?.swap(),
+                    )),
+                    false,
+                    false,
+                ),
+                ActionKind::Order => {
+                    let order = AccountLoader::<Order>::try_from(info)?;
+                    let order = order.load()?;
+                    let kind = order.params().kind()?;
+                    (
+                        Some(swap_stats(order.swap())),
+                        kind.is_increase_position() || kind.is_decrease_position(),
+                        false,
+                    )
+                }
+                ActionKind::GlvDeposit => (
+                    Some(swap_stats(
+                        AccountLoader::<GlvDeposit>::try_from(info)?.load()?.swap(),
+                    )),
+                    false,
+                    true,
+                ),
+                ActionKind::GlvWithdrawal => (
+                    Some(swap_stats(
+                        AccountLoader::<GlvWithdrawal>::try_from(info)?
+                            .load()?
+                            .swap(),
+                    )),
+                    false,
+                    true,
+                ),
+                ActionKind::Shift => (None, false, false),
+                ActionKind::GlvShift => (None, false, true),
+                _ => return err!(CoreError::InvalidArgument),
+            };
+            let (swap_hops, num_tokens_to_price) = stats.unwrap_or((0, MAX_SHIFT_TOKENS));
+            let weight = u16::from(swap_hops)
+                + u16::from(num_tokens_to_price)
+                + if updates_position { 3 } else { 0 }
+                + if requires_glv_valuation { 6 } else { 0 };
+            Ok(ExecutionComputeEstimate {
+                action: info.key(),
+                kind,
+                swap_hops,
+                num_tokens_to_price,
+                updates_position,
+                requires_glv_valuation,
+                class: ExecutionComputeClass::from_weight(weight),
+            })
+        })
+        .collect()
+}
+
+/// Load the escrow accounts recorded in the given action account.
+fn load_action_escrows<'info>(
+    kind: ActionKind,
+    info: &'info AccountInfo<'info>,
+) -> Result<Vec<ActionEscrow>> {
+    let tokens = match kind {
+        ActionKind::Deposit => {
+            let tokens = AccountLoader::<Deposit>::try_from(info)?.load()?.tokens;
+            vec![
+                tokens.initial_long_token,
+                tokens.initial_short_token,
+                tokens.market_token,
+            ]
+        }
+        ActionKind::Withdrawal => {
+            let tokens = AccountLoader::<Withdrawal>::try_from(info)?.load()?.tokens;
+            vec![
+                tokens.market_token,
+                tokens.final_long_token,
+                tokens.final_short_token,
+            ]
+        }
+        ActionKind::Shift => {
+            let tokens = AccountLoader::<Shift>::try_from(info)?.load()?.tokens;
+            vec![tokens.from_market_token, tokens.to_market_token]
+        }
+        ActionKind::Order => {
+            let tokens = AccountLoader::<Order>::try_from(info)?.load()?.tokens;
+            vec![
+                tokens.initial_collateral,
+                tokens.final_output_token,
+                tokens.long_token,
+                tokens.short_token,
+            ]
+        }
+        ActionKind::GlvDeposit => {
+            let tokens = AccountLoader::<GlvDeposit>::try_from(info)?.load()?.tokens;
+            vec![
+                tokens.initial_long_token,
+                tokens.initial_short_token,
+                tokens.market_token,
+                tokens.glv_token,
+            ]
+        }
+        ActionKind::GlvWithdrawal => {
+            let tokens = AccountLoader::<GlvWithdrawal>::try_from(info)?
+                .load()?
+                .tokens;
+            vec![
+                tokens.glv_token,
+                tokens.market_token,
+                tokens.final_long_token,
+                tokens.final_short_token,
+            ]
+        }
+        // GLV shifts do not use escrow accounts.
+        ActionKind::GlvShift => vec![],
+        _ => return err!(CoreError::InvalidArgument),
+    };
+    Ok(collect_escrows(&tokens))
+}
+
+fn collect_escrows(tokens: &[TokenAndAccount]) -> Vec<ActionEscrow> {
+    let mut escrows = Vec::<ActionEscrow>::with_capacity(tokens.len());
+    for (mint, escrow) in tokens.iter().filter_map(TokenAndAccount::token_and_account) {
+        // Escrow accounts may be shared between tokens of the same mint.
+        if escrows.iter().all(|recorded| recorded.escrow != escrow) {
+            escrows.push(ActionEscrow { mint, escrow });
+        }
+    }
+    escrows
+}
+
+/// The accounts definition for [`is_action_executable`](crate::gmsol_store::is_action_executable).
+///
+/// Remaining accounts expected by this instruction:
+/// - `[]` the deposit or withdrawal account to check.
+#[derive(Accounts)]
+pub struct ReadLiquidityAction<'info> {
+    /// The store that owns the action.
+    pub store: AccountLoader<'info, Store>,
+    /// The market of the action.
+    #[account(
+        has_one = store,
+        constraint = market.load()?.meta.market_token_mint == market_token.key() @ CoreError::InvalidArgument,
+    )]
+    pub market: AccountLoader<'info, Market>,
+    /// The market token of the market.
+    pub market_token: Account<'info, Mint>,
+}
+
+/// The reason why an action is (not) executable.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ActionExecutableReason {
+    /// The action is executable.
+    Executable,
+    /// The action is not pending.
+    NotPending,
+    /// The execution of this kind of action is disabled.
+    FeatureDisabled,
+    /// The market is disabled.
+    MarketDisabled,
+    /// The action is too old to be executed.
+    ActionTooOld,
+    /// The action has a swap path, whose execution cannot be checked by this instruction.
+    SwapNotSupported,
+    /// The requirements for the first deposit are not met.
+    FirstDepositRequirementsNotMet,
+    /// The market token price is below the configured min price.
+    MarketTokenPriceTooLow,
+    /// The simulated execution failed, e.g. the pool caps or reserves would be exceeded.
+    SimulationFailed,
+    /// The output amount would be less than the min output of the action.
+    InsufficientOutput,
+}
+
+/// Executability of an action.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionExecutability {
+    /// Whether the action is executable.
+    pub is_executable: bool,
+    /// The reason code.
+    pub reason: ActionExecutableReason,
+}
+
+/// Check whether the given deposit or withdrawal is currently executable with the given prices.
+pub(crate) fn is_action_executable<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadLiquidityAction<'info>>,
+    prices: &Prices<u128>,
+) -> Result<ActionExecutability> {
+    prices.validate().map_err(ModelError::from)?;
+
+    let accounts = &ctx.accounts;
+    let info = ctx
+        .remaining_accounts
+        .first()
+        .ok_or_else(|| error!(ErrorCode::AccountNotEnoughKeys))?;
+    let is_deposit = {
+        let data = info.try_borrow_data()?;
+        if data.starts_with(Deposit::DISCRIMINATOR) {
+            true
+        } else if data.starts_with(Withdrawal::DISCRIMINATOR) {
+            false
+        } else {
+            msg!("[Action] only deposits and withdrawals are supported");
+            return err!(CoreError::InvalidArgument);
+        }
+    };
+
+    let store = accounts.store.load()?;
+    let market = accounts.market.load()?;
+    let reason = if is_deposit {
+        let loader = AccountLoader::<Deposit>::try_from(info)?;
+        let deposit = loader.load()?;
+        let header = deposit.header();
+        validate_action_header(&accounts.store.key(), &accounts.market.key(), header)?;
+        match check_execution_preconditions(&store, &market, DomainDisabledFlag::Deposit, header)? {
+            Some(reason) => reason,
+            None => check_deposit(&market, &accounts.market_token, &deposit, prices)?,
+        }
+    } else {
+        let loader = AccountLoader::<Withdrawal>::try_from(info)?;
+        let withdrawal = loader.load()?;
+        let header = withdrawal.header();
+        validate_action_header(&accounts.store.key(), &accounts.market.key(), header)?;
+        match check_execution_preconditions(
+            &store,
+            &market,
+            DomainDisabledFlag::Withdrawal,
+            header,
+        )? {
+            Some(reason) => reason,
+            None => check_withdrawal(&market, &accounts.market_token, &withdrawal, prices)?,
+        }
+    };
+
+    Ok(ActionExecutability {
+        is_executable: reason == ActionExecutableReason::Executable,
+        reason,
+    })
+}
+
+fn validate_action_header(store: &Pubkey, market: &Pubkey, header: &ActionHeader) -> Result<()> {
+    require_keys_eq!(header.store, *store, CoreError::StoreMismatched);
+    require_keys_eq!(header.market, *market, CoreError::MarketMismatched);
+    Ok(())
+}
+
+fn check_execution_preconditions(
+    store: &Store,
+    market: &Market,
+    domain: DomainDisabledFlag,
+    header: &ActionHeader,
+) -> Result<Option<ActionExecutableReason>> {
+    if !header.action_state()?.is_pending() {
+        return Ok(Some(ActionExecutableReason::NotPending));
+    }
+    if store
+        .validate_feature_enabled(domain, ActionDisabledFlag::Execute)
+        .is_err()
+    {
+        return Ok(Some(ActionExecutableReason::FeatureDisabled));
+    }
+    if !market.is_enabled() {
+        return Ok(Some(ActionExecutableReason::MarketDisabled));
+    }
+    if store.validate_action_age(header.updated_at).is_err() {
+        return Ok(Some(ActionExecutableReason::ActionTooOld));
+    }
+    Ok(None)
+}
+
+fn check_deposit(
+    market: &Market,
+    market_token: &Mint,
+    deposit: &Deposit,
+    prices: &Prices<u128>,
+) -> Result<ActionExecutableReason> {
+    if deposit.swap().primary_length() != 0 || deposit.swap().secondary_length() != 0 {
+        return Ok(ActionExecutableReason::SwapNotSupported);
+    }
+
+    let params = &deposit.params;
+    if market_token.supply == 0
+        && Deposit::validate_first_deposit(
+            &deposit.header().receiver(),
+            params.min_market_token_amount,
+            market,
+        )
+        .is_err()
+    {
+        return Ok(ActionExecutableReason::FirstDepositRequirementsNotMet);
+    }
+
+    let mut simulated =
+        SimulatedLiquidityMarket::try_new(market, market_token).map_err(ModelError::from)?;
+
+    let min_market_token_price = *market
+        .get_config_by_key(MarketConfigKey::MinMarketTokenPrice)
+        .ok_or_else(|| error!(CoreError::Unimplemented))?;
+    if min_market_token_price != 0 {
+        let market_token_price = simulated
+            .market_token_price(prices, PnlFactorKind::MaxAfterDeposit, false)
+            .map_err(ModelError::from)?;
+        if market_token_price < min_market_token_price {
+            return Ok(ActionExecutableReason::MarketTokenPriceTooLow);
+        }
+    }
+
+    let min_fee_value = *market
+        .get_config_by_key(MarketConfigKey::MinDepositFeeUsd)
+        .ok_or_else(|| error!(CoreError::Unimplemented))?;
+    let Ok(report) = simulated
+        .deposit(
+            params.initial_long_token_amount.into(),
+            params.initial_short_token_amount.into(),
+            *prices,
+        )
+        .and_then(|d| d.with_min_fee_value(min_fee_value).execute())
+    else {
+        return Ok(ActionExecutableReason::SimulationFailed);
+    };
+
+    let is_sufficient = u64::try_from(*report.minted())
+        .is_ok_and(|minted| params.validate_market_token_amount(minted).is_ok());
+    if !is_sufficient {
+        return Ok(ActionExecutableReason::InsufficientOutput);
+    }
+
+    Ok(ActionExecutableReason::Executable)
+}
+
+fn check_withdrawal(
+    market: &Market,
+    market_token: &Mint,
+    withdrawal: &Withdrawal,
+    prices: &Prices<u128>,
+) -> Result<ActionExecutableReason> {
+    if withdrawal.swap().primary_length() != 0 || withdrawal.swap().secondary_length() != 0 {
+        return Ok(ActionExecutableReason::SwapNotSupported);
+    }
+
+    let min_fee_value = *market
+        .get_config_by_key(MarketConfigKey::MinWithdrawalFeeUsd)
+        .ok_or_else(|| error!(CoreError::Unimplemented))?;
+    let mut simulated =
+        SimulatedLiquidityMarket::try_new(market, market_token).map_err(ModelError::from)?;
+    let Ok(report) = simulated
+        .withdraw(withdrawal.params.market_token_amount.into(), *prices)
+        .and_then(|w| w.with_min_fee_value(min_fee_value).execute())
+    else {
+        return Ok(ActionExecutableReason::SimulationFailed);
+    };
+
+    let outputs = u64::try_from(*report.long_token_output())
+        .ok()
+        .zip(u64::try_from(*report.short_token_output()).ok());
+    if !outputs.is_some_and(|(long_amount, short_amount)| {
+        withdrawal
+            .params
+            .validate_output_amounts(long_amount, short_amount)
+            .is_ok()
+    }) {
+        return Ok(ActionExecutableReason::InsufficientOutput);
+    }
+
+    Ok(ActionExecutableReason::Executable)
+}
+
+/// Derive the address of the action account of the given kind.
+pub(crate) fn derive_action_address(
+    ctx: Context<ReadStore>,
+    owner: &Pubkey,
+    kind: ActionKind,
+    nonce: &[u8; 32],
+) -> Result<Pubkey> {
+    let seed = match kind {
+        ActionKind::Deposit => Deposit::SEED,
+        ActionKind::Withdrawal => Withdrawal::SEED,
+        ActionKind::Shift => Shift::SEED,
+        ActionKind::Order => Order::SEED,
+        ActionKind::GlvDeposit => GlvDeposit::SEED,
+        ActionKind::GlvWithdrawal => GlvWithdrawal::SEED,
+        ActionKind::GlvShift => GlvShift::SEED,
+        _ => return err!(CoreError::InvalidArgument),
+    };
+    let store = ctx.accounts.store.key();
+    let (address, _) =
+        Pubkey::find_program_address(&[seed, store.as_ref(), owner.as_ref(), nonce], &crate::ID);
+    Ok(address)
+}
+
+/// Swap parameters recorded in a deposit or withdrawal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ActionSwapParams {
+    /// The address of the action.
+    pub action: Pubkey,
+    /// The kind of the action.
+    pub kind: ActionKind,
+    /// The market tokens of the swap path for the long token.
+    pub long_token_swap_path: Vec<Pubkey>,
+    /// The market tokens of the swap path for the short token.
+    pub short_token_swap_path: Vec<Pubkey>,
+    /// The tokens that must be priced to execute the action, sorted by address.
+    pub tokens: Vec<Pubkey>,
+}
+
+/// Get the swap parameters recorded in the deposits and withdrawals provided in the remaining accounts.
+pub(crate) fn get_action_swap_params<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadActions<'info>>,
+) -> Result<Vec<ActionSwapParams>> {
+    let store = ctx.accounts.store.key();
+    ctx.remaining_accounts
+        .iter()
+        .map(|info| {
+            let (kind, header) = load_action_header(info)?;
+            require_keys_eq!(header.store, store, CoreError::StoreMismatched);
+            let to_params = |swap: &SwapActionParams| ActionSwapParams {
+                action: info.key(),
+                kind,
+                long_token_swap_path: swap.primary_swap_path().to_vec(),
+                short_token_swap_path: swap.secondary_swap_path().to_vec(),
+                tokens: swap.tokens().to_vec(),
+            };
+            let params = match kind {
+                ActionKind::Deposit => {
+                    to_params(AccountLoader::<Deposit>::try_from(info)?.load()?.swap())
+                }
+                ActionKind::Withdrawal => {
+                    to_params(AccountLoader::<Withdrawal>::try_from(info)?.load()?.swap())
+                }
+                _ => {
+                    msg!("[Action] not a deposit or withdrawal: {}", info.key);
+                    return err!(CoreError::InvalidArgument);
+                }
+            };
+            Ok(params)
+        })
+        .collect()
+}
+
+/// Load the header of an action account of any kind.
+pub(crate) fn load_action_header<'info>(
+    info: &'info AccountInfo<'info>,
+) -> Result<(ActionKind, ActionHeader)> {
+    fn load<'info, T: Action + ZeroCopy + Owner>(
+        info: &'info AccountInfo<'info>,
+    ) -> Result<ActionHeader> {
+        Ok(*AccountLoader::<T>::try_from(info)?.load()?.header())
+    }
+
+    let discriminator: [u8; 8] = {
+        let data = info.try_borrow_data()?;
+        data.get(..8)
+            .and_then(|d| d.try_into().ok())
+            .ok_or_else(|| error!(ErrorCode::AccountDiscriminatorNotFound))?
+    };
+
+    let loaded = match discriminator.as_slice() {
+        d if d == Deposit::DISCRIMINATOR => (ActionKind::Deposit, load::<Deposit>(info)?),
+        d if d == Withdrawal::DISCRIMINATOR => (ActionKind::Withdrawal, load::<Withdrawal>(info)?),
+        d if d == Shift::DISCRIMINATOR => (ActionKind::Shift, load::<Shift>(info)?),
+        d if d == Order::DISCRIMINATOR => (ActionKind::Order, load::<Order>(info)?),
+        d if d == GlvDeposit::DISCRIMINATOR => (ActionKind::GlvDeposit, load::<GlvDeposit>(info)?),
+        d if d == GlvWithdrawal::DISCRIMINATOR => {
+            (ActionKind::GlvWithdrawal, load::<GlvWithdrawal>(info)?)
+        }
+        d if d == GlvShift::DISCRIMINATOR => (ActionKind::GlvShift, load::<GlvShift>(info)?),
+        _ => {
+            msg!("[Action] unknown action account: {}", info.key);
+            return err!(CoreError::InvalidArgument);
+        }
+    };
+
+    Ok(loaded)
+}
@@ -11,7 +11,7 @@ use crate::{
     states::{
         common::action::{Action, ActionExt},
         feature::{ActionDisabledFlag, DomainDisabledFlag},
-        Deposit, Market, NonceBytes, RoleKey, Seed, Store, StoreWalletSigner,
+        Deposit, Market, NonceBytes, RoleKey, Seed, Store, StoreWalletSigner, UserHeader,
     },
     utils::{
         internal,
@@ -100,6 +100,16 @@ pub struct CreateDeposit<'info> {
     pub token_program: Program<'info, Token>,
     /// The associated token program.
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// User Account.
+    #[account(
+        mut,
+        constraint = user.load()?.is_initialized() @ CoreError::InvalidUserAccount,
+        has_one = owner,
+        has_one = store,
+        seeds = [UserHeader::SEED, store.key().as_ref(), owner.key().as_ref()],
+        bump = user.load()?.bump,
+    )]
+    pub user: AccountLoader<'info, UserHeader>,
 }
 
 impl<'info> internal::Create<'info, Deposit> for CreateDeposit<'info> {
@@ -154,6 +164,13 @@ impl<'info> internal::Create<'info, Deposit> for CreateDeposit<'info> {
             .swap_paths(remaining_accounts)
             .build()
             .execute()?;
+        // Make sure the discriminator is written to the account data.
+        self.deposit.exit(&crate::ID)?;
+        internal::record_pending_action(
+            &self.store,
+            &self.user,
+            &mut self.deposit.load_mut()?.header,
+        )?;
         emit!(DepositCreated::new(self.store.key(), self.deposit.key())?);
         Ok(())
     }
@@ -326,6 +343,24 @@ pub struct CloseDeposit<'info> {
     pub token_program: Program<'info, Token>,
     /// The associated token program.
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The optional recipient of the rent of the action account.
+    ///
+    /// Only used when the caller is not the owner, in which case it must be
+    /// in the rent recipient allowlist of the store.
+    /// CHECK: only used to receive the rent and is validated during the close.
+    #[account(mut)]
+    pub rent_recipient: Option<UncheckedAccount<'info>>,
+    /// The user account of the owner.
+    ///
+    /// Must be provided if the deposit has been recorded as a pending action of the owner.
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = store,
+        seeds = [UserHeader::SEED, store.key().as_ref(), owner.key().as_ref()],
+        bump = user.load()?.bump,
+    )]
+    pub user: Option<AccountLoader<'info, UserHeader>>,
 }
 
 impl<'info> internal::Authentication<'info> for CloseDeposit<'info> {
@@ -343,6 +378,10 @@ impl<'info> internal::Close<'info, Deposit> for CloseDeposit<'info> {
         RoleKey::ORDER_KEEPER
     }
 
+    fn user(&self) -> Option<&AccountLoader<'info, UserHeader>> {
+        self.user.as_ref()
+    }
+
     fn rent_receiver(&self) -> AccountInfo<'info> {
         debug_assert!(
             self.deposit.load().unwrap().header.rent_receiver() == self.owner.key,
@@ -351,6 +390,10 @@ impl<'info> internal::Close<'info, Deposit> for CloseDeposit<'info> {
         self.owner.to_account_info()
     }
 
+    fn rent_recipient(&self) -> Option<AccountInfo<'info>> {
+        self.rent_recipient.as_ref().map(|a| a.to_account_info())
+    }
+
     fn store_wallet_bump(&self, bumps: &Self::Bumps) -> u8 {
         bumps.store_wallet
     }
@@ -392,6 +435,7 @@ impl<'info> internal::Close<'info, Deposit> for CloseDeposit<'info> {
             .escrow_authority_seeds(&seeds)
             .init_if_needed(init_if_needed)
             .rent_receiver(self.rent_receiver())
+            .min_unwrap_amount(self.store.load()?.min_auto_unwrap_amount())
             .should_unwrap_native(self.deposit.load()?.header().should_unwrap_native_token());
 
         // Transfer market tokens.
@@ -137,6 +137,9 @@ pub(crate) fn validated_recent_timestamp(config: &Store, timestamp: i64) -> Resu
 ///     markets excluding the current market in the swap params.
 ///   - M+N..M+N+V. `[writable]` V virtual inventory accounts, where V represents the total
 ///     number of unique virtual inventories required by the markets.
+///   - M+N+V..M+N+V+L. `[]` L linked order accounts, all the unclosed resting orders of the
+///     position except the `order`, required if self-trade prevention is enabled and the
+///     `order` is a position order other than liquidation and auto-deleveraging orders.
 #[event_cpi]
 #[derive(Accounts)]
 #[instruction(recent_timestamp: i64)]
@@ -308,6 +311,10 @@ pub struct ExecuteIncreaseOrSwapOrderV2<'info> {
     /// CHECK: expected to be checked by the callback program.
     #[account(mut)]
     pub callback_partitioned_data_account: Option<UncheckedAccount<'info>>,
+    /// The collateral token account of the executor to receive the execution fee.
+    /// Required only if the order pays the execution fee from the position's collateral.
+    #[account(mut)]
+    pub execution_fee_token_account: Option<Box<Account<'info, TokenAccount>>>,
 }
 
 impl<'info> internal::Authentication<'info> for ExecuteIncreaseOrSwapOrderV2<'info> {
@@ -355,7 +362,12 @@ impl<'info> ExecuteIncreaseOrSwapOrderV2<'info> {
         accounts.transfer_tokens_in(&signer, remaining_accounts, &event_emitter)?;
 
         let (is_position_removed, transfer_out, should_send_trade_event) = accounts
-            .perform_execution(remaining_accounts, throw_on_execution_error, &event_emitter)?;
+            .perform_execution(
+                remaining_accounts,
+                throw_on_execution_error,
+                execution_fee,
+                &event_emitter,
+            )?;
 
         let executed = transfer_out.executed();
         if executed {
@@ -381,7 +393,8 @@ impl<'info> ExecuteIncreaseOrSwapOrderV2<'info> {
         }
 
         // It must be placed at the end to be executed correctly.
-        ctx.accounts.pay_execution_fee(execution_fee)?;
+        ctx.accounts
+            .pay_execution_fee(execution_fee, executed, &event_emitter)?;
 
         Ok(())
     }
@@ -466,6 +479,7 @@ impl<'info> ExecuteIncreaseOrSwapOrderV2<'info> {
         &mut self,
         remaining_accounts: &'info [AccountInfo<'info>],
         throw_on_execution_error: bool,
+        execution_fee: u64,
         event_emitter: &EventEmitter<'_, 'info>,
     ) -> Result<(RemovePosition, Box<TransferOut>, ShouldSendTradeEvent)> {
         // Note: We only need the tokens here, the feeds are not necessary.
@@ -484,6 +498,7 @@ impl<'info> ExecuteIncreaseOrSwapOrderV2<'info> {
             .position(self.position.as_ref())
             .event(self.event.as_ref())
             .throw_on_execution_error(throw_on_execution_error)
+            .execution_fee(execution_fee)
             .executor(self.authority.to_account_info())
             .event_emitter(*event_emitter)
             .callback_authority(self.callback_authority.as_ref())
@@ -553,7 +568,43 @@ impl<'info> ExecuteIncreaseOrSwapOrderV2<'info> {
     }
 
     #[inline(never)]
-    fn pay_execution_fee(&self, execution_fee: u64) -> Result<()> {
+    fn pay_execution_fee(
+        &self,
+        execution_fee: u64,
+        executed: bool,
+        event_emitter: &EventEmitter<'_, 'info>,
+    ) -> Result<()> {
+        if self
+            .order
+            .load()?
+            .header
+            .should_pay_execution_fee_from_collateral()
+        {
+            if !executed {
+                msg!("[Order] the order is not executed, no execution fee is paid from collateral");
+                return Ok(());
+            }
+            let (token, vault) = if self.market.load()?.meta().long_token_mint
+                == self.order.load()?.params.collateral_token
+            {
+                (self.long_token.as_deref(), self.long_token_vault.as_deref())
+            } else {
+                (
+                    self.short_token.as_deref(),
+                    self.short_token_vault.as_deref(),
+                )
+            };
+            return transfer_execution_fee_from_collateral(
+                &self.store,
+                &self.market,
+                &self.order,
+                token.ok_or_else(|| error!(CoreError::TokenMintNotProvided))?,
+                vault.ok_or_else(|| error!(CoreError::TokenAccountNotProvided))?,
+                self.execution_fee_token_account.as_deref(),
+                &self.token_program,
+                event_emitter,
+            );
+        }
         let execution_lamports = self.order.load()?.execution_lamports(execution_fee);
         PayExecutionFeeOperation::builder()
             .payer(self.order.to_account_info())
@@ -575,6 +626,9 @@ impl<'info> ExecuteIncreaseOrSwapOrderV2<'info> {
 ///     markets excluding the current market in the swap params.
 ///   - M+N..M+N+V. `[writable]` V virtual inventory accounts, where V represents the total
 ///     number of unique virtual inventories required by the markets.
+///   - M+N+V..M+N+V+L. `[]` L linked order accounts, all the unclosed resting orders of the
+///     position except the `order`, required if self-trade prevention is enabled and the
+///     `order` is a position order other than liquidation and auto-deleveraging orders.
 #[event_cpi]
 #[derive(Accounts)]
 #[instruction(recent_timestamp: i64)]
@@ -768,6 +822,10 @@ pub struct ExecuteDecreaseOrderV2<'info> {
     /// CHECK: expected to be checked by the callback program.
     #[account(mut)]
     pub callback_partitioned_data_account: Option<UncheckedAccount<'info>>,
+    /// The collateral token account of the executor to receive the execution fee.
+    /// Required only if the order pays the execution fee from the position's collateral.
+    #[account(mut)]
+    pub execution_fee_token_account: Option<Box<Account<'info, TokenAccount>>>,
 }
 
 impl<'info> internal::Authentication<'info> for ExecuteDecreaseOrderV2<'info> {
@@ -807,7 +865,12 @@ impl<'info> ExecuteDecreaseOrderV2<'info> {
         let event_authority = accounts.event_authority.clone();
         let event_emitter = EventEmitter::new(&event_authority, ctx.bumps.event_authority);
         let (is_position_removed, transfer_out, should_send_trade_event) = accounts
-            .perform_execution(remaining_accounts, throw_on_execution_error, &event_emitter)?;
+            .perform_execution(
+                remaining_accounts,
+                throw_on_execution_error,
+                execution_fee,
+                &event_emitter,
+            )?;
 
         let executed = transfer_out.executed();
         if executed {
@@ -829,7 +892,8 @@ impl<'info> ExecuteDecreaseOrderV2<'info> {
         }
 
         // It must be placed at the end to be executed correctly.
-        ctx.accounts.pay_execution_fee(execution_fee)?;
+        ctx.accounts
+            .pay_execution_fee(execution_fee, executed, &event_emitter)?;
 
         Ok(())
     }
@@ -839,6 +903,7 @@ impl<'info> ExecuteDecreaseOrderV2<'info> {
         &mut self,
         remaining_accounts: &'info [AccountInfo<'info>],
         throw_on_execution_error: bool,
+        execution_fee: u64,
         event_emitter: &EventEmitter<'_, 'info>,
     ) -> Result<(RemovePosition, Box<TransferOut>, ShouldSendTradeEvent)> {
         // Note: We only need the tokens here, the feeds are not necessary.
@@ -857,6 +922,7 @@ impl<'info> ExecuteDecreaseOrderV2<'info> {
             .position(Some(&self.position))
             .event(Some(&self.event))
             .throw_on_execution_error(throw_on_execution_error)
+            .execution_fee(execution_fee)
             .executor(self.authority.to_account_info())
             .event_emitter(*event_emitter)
             .callback_authority(self.callback_authority.as_ref())
@@ -927,7 +993,40 @@ impl<'info> ExecuteDecreaseOrderV2<'info> {
     }
 
     #[inline(never)]
-    fn pay_execution_fee(&self, execution_fee: u64) -> Result<()> {
+    fn pay_execution_fee(
+        &self,
+        execution_fee: u64,
+        executed: bool,
+        event_emitter: &EventEmitter<'_, 'info>,
+    ) -> Result<()> {
+        if self
+            .order
+            .load()?
+            .header
+            .should_pay_execution_fee_from_collateral()
+        {
+            if !executed {
+                msg!("[Order] the order is not executed, no execution fee is paid from collateral");
+                return Ok(());
+            }
+            let (token, vault) = if self.market.load()?.meta().long_token_mint
+                == self.order.load()?.params.collateral_token
+            {
+                (&self.long_token, &self.long_token_vault)
+            } else {
+                (&self.short_token, &self.short_token_vault)
+            };
+            return transfer_execution_fee_from_collateral(
+                &self.store,
+                &self.market,
+                &self.order,
+                token,
+                vault,
+                self.execution_fee_token_account.as_deref(),
+                &self.token_program,
+                event_emitter,
+            );
+        }
         let execution_lamports = self.order.load()?.execution_lamports(execution_fee);
         PayExecutionFeeOperation::builder()
             .payer(self.order.to_account_info())
@@ -939,6 +1038,45 @@ impl<'info> ExecuteDecreaseOrderV2<'info> {
     }
 }
 
+/// Transfer the execution fee that has been paid from the position's collateral
+/// (in collateral tokens) to the executor.
+#[allow(clippy::too_many_arguments)]
+fn transfer_execution_fee_from_collateral<'info>(
+    store: &AccountLoader<'info, Store>,
+    market: &AccountLoader<'info, Market>,
+    order: &AccountLoader<'info, Order>,
+    token: &Account<'info, Mint>,
+    vault: &Account<'info, TokenAccount>,
+    execution_fee_token_account: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    event_emitter: &EventEmitter<'_, 'info>,
+) -> Result<()> {
+    let to =
+        execution_fee_token_account.ok_or_else(|| error!(CoreError::TokenAccountNotProvided))?;
+    let amount = {
+        let order = order.load()?;
+        require_keys_eq!(
+            token.key(),
+            order.params.collateral_token,
+            CoreError::InvalidArgument
+        );
+        order.execution_fee_from_collateral
+    };
+    require_keys_eq!(to.mint, token.key(), CoreError::InvalidArgument);
+    MarketTransferOutOperation::builder()
+        .store(store)
+        .token_program(token_program.to_account_info())
+        .market(market)
+        .to(to.to_account_info())
+        .vault(vault.to_account_info())
+        .amount(amount)
+        .decimals(token.decimals)
+        .token_mint(token.to_account_info())
+        .event_emitter(*event_emitter)
+        .build()
+        .execute()
+}
+
 #[deprecated(since = "0.6.0", note = "use v2 instructions instead.")]
 mod deprecated {
     use super::*;
@@ -952,6 +1090,9 @@ mod deprecated {
     ///     markets excluding the current market in the swap params.
     ///   - M+N..M+N+V. `[writable]` V virtual inventory accounts, where V represents the total
     ///     number of unique virtual inventories required by the markets.
+    ///   - M+N+V..M+N+V+L. `[]` L linked order accounts, all the unclosed resting orders of the
+    ///     position except the `order`, required if self-trade prevention is enabled and the
+    ///     `order` is a position order other than liquidation and auto-deleveraging orders.
     #[event_cpi]
     #[derive(Accounts)]
     #[instruction(recent_timestamp: i64)]
@@ -1145,7 +1286,12 @@ mod deprecated {
         accounts.transfer_tokens_in(&signer, remaining_accounts, &event_emitter)?;
 
         let (is_position_removed, transfer_out, should_send_trade_event) = accounts
-            .perform_execution(remaining_accounts, throw_on_execution_error, &event_emitter)?;
+            .perform_execution(
+                remaining_accounts,
+                throw_on_execution_error,
+                execution_fee,
+                &event_emitter,
+            )?;
 
         if transfer_out.executed() {
             accounts.order.load_mut()?.header.completed()?;
@@ -1266,6 +1412,7 @@ mod deprecated {
             &mut self,
             remaining_accounts: &'info [AccountInfo<'info>],
             throw_on_execution_error: bool,
+            execution_fee: u64,
             event_emitter: &EventEmitter<'_, 'info>,
         ) -> Result<(RemovePosition, Box<TransferOut>, ShouldSendTradeEvent)> {
             // Note: We only need the tokens here, the feeds are not necessary.
@@ -1284,6 +1431,7 @@ mod deprecated {
                 .position(self.position.as_ref())
                 .event(self.event.as_ref())
                 .throw_on_execution_error(throw_on_execution_error)
+                .execution_fee(execution_fee)
                 .executor(self.authority.to_account_info())
                 .event_emitter(*event_emitter)
                 .callback_authority(None)
@@ -1354,6 +1502,15 @@ mod deprecated {
 
         #[inline(never)]
         fn pay_execution_fee(&self, execution_fee: u64) -> Result<()> {
+            // Paying execution fee from collateral is only supported by v2 instructions.
+            require!(
+                !self
+                    .order
+                    .load()?
+                    .header
+                    .should_pay_execution_fee_from_collateral(),
+                CoreError::InvalidArgument
+            );
             let execution_lamports = self.order.load()?.execution_lamports(execution_fee);
             PayExecutionFeeOperation::builder()
                 .payer(self.order.to_account_info())
@@ -1375,6 +1532,9 @@ mod deprecated {
     ///     markets excluding the current market in the swap params.
     ///   - M+N..M+N+V. `[writable]` V virtual inventory accounts, where V represents the total
     ///     number of unique virtual inventories required by the markets.
+    ///   - M+N+V..M+N+V+L. `[]` L linked order accounts, all the unclosed resting orders of the
+    ///     position except the `order`, required if self-trade prevention is enabled and the
+    ///     `order` is a position order other than liquidation and auto-deleveraging orders.
     #[event_cpi]
     #[derive(Accounts)]
     #[instruction(recent_timestamp: i64)]
@@ -1581,7 +1741,12 @@ mod deprecated {
         let event_authority = accounts.event_authority.clone();
         let event_emitter = EventEmitter::new(&event_authority, ctx.bumps.event_authority);
         let (is_position_removed, transfer_out, should_send_trade_event) = accounts
-            .perform_execution(remaining_accounts, throw_on_execution_error, &event_emitter)?;
+            .perform_execution(
+                remaining_accounts,
+                throw_on_execution_error,
+                execution_fee,
+                &event_emitter,
+            )?;
 
         if transfer_out.executed() {
             accounts.order.load_mut()?.header.completed()?;
@@ -1623,6 +1788,7 @@ mod deprecated {
             &mut self,
             remaining_accounts: &'info [AccountInfo<'info>],
             throw_on_execution_error: bool,
+            execution_fee: u64,
             event_emitter: &EventEmitter<'_, 'info>,
         ) -> Result<(RemovePosition, Box<TransferOut>, ShouldSendTradeEvent)> {
             // Note: We only need the tokens here, the feeds are not necessary.
@@ -1641,6 +1807,7 @@ mod deprecated {
                 .position(Some(&self.position))
                 .event(Some(&self.event))
                 .throw_on_execution_error(throw_on_execution_error)
+                .execution_fee(execution_fee)
                 .executor(self.authority.to_account_info())
                 .event_emitter(*event_emitter)
                 .callback_authority(None)
@@ -1712,6 +1879,15 @@ mod deprecated {
 
         #[inline(never)]
         fn pay_execution_fee(&self, execution_fee: u64) -> Result<()> {
+            // Paying execution fee from collateral is only supported by v2 instructions.
+            require!(
+                !self
+                    .order
+                    .load()?
+                    .header
+                    .should_pay_execution_fee_from_collateral(),
+                CoreError::InvalidArgument
+            );
             let execution_lamports = self.order.load()?.execution_lamports(execution_fee);
             PayExecutionFeeOperation::builder()
                 .payer(self.order.to_account_info())
@@ -1,3 +1,6 @@
+/// Read-only instructions for actions.
+pub mod action;
+
 /// Deposit creation and cancellation.
 pub mod deposit;
 
@@ -28,6 +31,7 @@ pub mod shift;
 /// Execute shift.
 pub mod execute_shift;
 
+pub use action::*;
 pub use deposit::*;
 pub use execute_deposit::*;
 pub use execute_order::*;
@@ -9,7 +9,10 @@ use gmsol_callback::{
     interface::{ActionKind, CallbackInterface},
     CALLBACK_AUTHORITY_SEED,
 };
-use gmsol_model::utils::apply_factor;
+use gmsol_model::{
+    action::decrease_position::DecreasePositionSwapType, num::MulDiv, utils::apply_factor,
+    PositionStateExt,
+};
 use gmsol_utils::{action::ActionCallbackKind, InitSpace};
 
 use crate::{
@@ -27,8 +30,8 @@ use crate::{
         order::{Order, OrderKind},
         position::PositionKind,
         user::UserHeader,
-        HasMarketMeta, Market, NonceBytes, Position, RoleKey, Seed, Store, StoreWalletSigner,
-        UpdateOrderParams,
+        HasMarketMeta, Market, NonceBytes, Oracle, Position, RoleKey, Seed, Store,
+        StoreWalletSigner, TokenMapHeader, UpdateOrderParams,
     },
     utils::{internal, token::is_associated_token_account_or_owner},
     CoreError,
@@ -448,6 +451,8 @@ impl<'info> internal::Create<'info, Order> for CreateOrderV2<'info> {
                 return err!(CoreError::OrderKindNotAllowed);
             }
         }
+        self.record_pending_action()?;
+        self.record_resting_order()?;
         emit!(OrderCreated::new(
             self.store.key(),
             self.order.key(),
@@ -459,6 +464,24 @@ impl<'info> internal::Create<'info, Order> for CreateOrderV2<'info> {
 }
 
 impl CreateOrderV2<'_> {
+    fn record_pending_action(&self) -> Result<()> {
+        let mut order = self.order.load_mut()?;
+        internal::record_pending_action(&self.store, &self.user, &mut order.header)
+    }
+
+    fn record_resting_order(&self) -> Result<()> {
+        let mut order = self.order.load_mut()?;
+        if order.params().kind()?.is_resting_position() {
+            let position = self
+                .position
+                .as_ref()
+                .ok_or_else(|| error!(CoreError::PositionIsRequired))?;
+            order.header.set_resting_order_recorded(true);
+            position.load_mut()?.add_resting_order()?;
+        }
+        Ok(())
+    }
+
     fn transfer_tokens(&mut self, params: &CreateOrderParams) -> Result<()> {
         let kind = params.kind;
         if !matches!(
@@ -649,6 +672,35 @@ pub struct CloseOrderV2<'info> {
     /// CHECK: expected to be checked by the callback program.
     #[account(mut)]
     pub callback_partitioned_data_account: Option<UncheckedAccount<'info>>,
+    /// The position of the order to close together with the order.
+    ///
+    /// Required if the order is recorded as a resting order of the position, in which case
+    /// it is removed from the resting orders of the position. Otherwise, it must be empty
+    /// if provided. The position is closed and its rent is returned to the owner if it is
+    /// empty and has no unclosed resting orders.
+    #[account(
+        mut,
+        has_one = store,
+        has_one = owner,
+        constraint = order.load()?.params().position() == Some(&position.key()) @ CoreError::PositionMismatched,
+    )]
+    pub position: Option<AccountLoader<'info, Position>>,
+    /// The optional recipient of the rent of the action account.
+    ///
+    /// Only used when the caller is not the owner, in which case it must be
+    /// in the rent recipient allowlist of the store.
+    /// CHECK: only used to receive the rent and is validated during the close.
+    #[account(mut)]
+    pub rent_recipient: Option<UncheckedAccount<'info>>,
+    /// The market of the order.
+    ///
+    /// Required only if the order is pending and queued for fair execution, in which
+    /// case it is removed from the queue.
+    #[account(
+        mut,
+        constraint = order.load()?.header.market == market.key() @ CoreError::MarketMismatched,
+    )]
+    pub market: Option<AccountLoader<'info, Market>>,
 }
 
 impl<'info> internal::Authentication<'info> for CloseOrderV2<'info> {
@@ -666,10 +718,18 @@ impl<'info> internal::Close<'info, Order> for CloseOrderV2<'info> {
         RoleKey::ORDER_KEEPER
     }
 
+    fn user(&self) -> Option<&AccountLoader<'info, UserHeader>> {
+        Some(&self.user)
+    }
+
     fn rent_receiver(&self) -> AccountInfo<'info> {
         self.rent_receiver.to_account_info()
     }
 
+    fn rent_recipient(&self) -> Option<AccountInfo<'info>> {
+        self.rent_recipient.as_ref().map(|a| a.to_account_info())
+    }
+
     #[inline(never)]
     fn validate(&self) -> Result<()> {
         let order = self.order.load()?;
@@ -687,6 +747,21 @@ impl<'info> internal::Close<'info, Order> for CloseOrderV2<'info> {
                     ActionDisabledFlag::Cancel,
                 )?;
         }
+        match self.position.as_ref() {
+            Some(position) if !order.header.is_resting_order_recorded() => {
+                require!(
+                    position.load()?.state.is_empty(),
+                    CoreError::PreconditionsAreNotMet
+                );
+            }
+            Some(_) => {}
+            None => {
+                require!(
+                    !order.header.is_resting_order_recorded(),
+                    CoreError::PositionIsRequired
+                );
+            }
+        }
         Ok(())
     }
 
@@ -707,6 +782,9 @@ impl<'info> internal::Close<'info, Order> for CloseOrderV2<'info> {
 
         if success {
             self.handle_closed(is_caller_owner)?;
+            self.remove_resting_order()?;
+            self.close_empty_position()?;
+            self.finish_fair_execution()?;
         }
 
         Ok(success)
@@ -749,6 +827,7 @@ impl<'info> CloseOrderV2<'info> {
             .escrow_authority_seeds(&seeds)
             .rent_receiver(self.rent_receiver())
             .init_if_needed(init_if_needed)
+            .min_unwrap_amount(self.store.load()?.min_auto_unwrap_amount())
             .should_unwrap_native(self.order.load()?.header().should_unwrap_native_token());
 
         let state = self.order.load()?.header().action_state()?;
@@ -906,7 +985,10 @@ impl<'info> CloseOrderV2<'info> {
             let mut store = self.store.load_mut()?;
             let mut referrer_user = referrer_user.load_mut()?;
 
-            store.gt_mut().mint_to(&mut referrer_user, reward)?;
+            let lockup_seconds = store.gt_mint_lockup_seconds();
+            store
+                .gt_mut()
+                .mint_to(&mut referrer_user, reward, lockup_seconds)?;
 
             event_emitter.emit_cpi(&GtUpdated::rewarded(
                 reward,
@@ -918,6 +1000,60 @@ impl<'info> CloseOrderV2<'info> {
         Ok(())
     }
 
+    /// Remove the order from the resting orders of its position if it is recorded.
+    #[inline(never)]
+    fn remove_resting_order(&self) -> Result<()> {
+        if !self
+            .order
+            .load_mut()?
+            .header
+            .set_resting_order_recorded(false)
+        {
+            return Ok(());
+        }
+        let position = self
+            .position
+            .as_ref()
+            .ok_or_else(|| error!(CoreError::PositionIsRequired))?;
+        position.load_mut()?.remove_resting_order()
+    }
+
+    /// Close the position if provided, unless it is not empty or still has unclosed
+    /// resting orders.
+    #[inline(never)]
+    fn close_empty_position(&self) -> Result<()> {
+        let Some(position) = self.position.as_ref() else {
+            return Ok(());
+        };
+        {
+            let position = position.load()?;
+            if !position.state.is_empty() || position.num_resting_orders() != 0 {
+                return Ok(());
+            }
+        }
+        position.close(self.owner.to_account_info())?;
+        Ok(())
+    }
+
+    /// Remove the order from the fair execution queue of the market if it is
+    /// still pending.
+    #[inline(never)]
+    fn finish_fair_execution(&self) -> Result<()> {
+        let seq = {
+            let order = self.order.load()?;
+            match order.market_order_seq() {
+                Some(seq) if order.header.action_state()?.is_pending() => seq,
+                _ => return Ok(()),
+            }
+        };
+        self.market
+            .as_ref()
+            .ok_or_else(|| error!(CoreError::MarketAccountIsNotProvided))?
+            .load_mut()?
+            .indexer_mut()
+            .finish_market_order_seq(seq)
+    }
+
     #[inline(never)]
     fn handle_closed(&self, is_caller_owner: bool) -> Result<()> {
         match self.order.load()?.header.callback_kind()? {
@@ -1079,6 +1215,146 @@ impl UpdateOrderV2<'_> {
     }
 }
 
+/// The accounts definition for the [`update_order_reprice`](crate::gmsol_store::update_order_reprice)
+/// instruction.
+///
+/// Remaining accounts expected by this instruction:
+///   - 0..N. `[]` N feed accounts, where N represents the total number of unique tokens
+///     associated with the market, sorted by token address.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateOrderReprice<'info> {
+    /// Owner.
+    pub owner: Signer<'info>,
+    /// Store.
+    #[account(has_one = token_map)]
+    pub store: AccountLoader<'info, Store>,
+    /// Token Map.
+    #[account(has_one = store)]
+    pub token_map: AccountLoader<'info, TokenMapHeader>,
+    /// Oracle buffer to use, whose authority must be the `owner`.
+    #[account(mut, has_one = store, constraint = oracle.load()?.authority == owner.key() @ CoreError::PermissionDenied)]
+    pub oracle: AccountLoader<'info, Oracle>,
+    /// Market.
+    #[account(mut, has_one = store)]
+    pub market: AccountLoader<'info, Market>,
+    /// Order to update.
+    #[account(
+        mut,
+        constraint = order.load()?.header.store == store.key() @ CoreError::StoreMismatched,
+        constraint = order.load()?.header.market == market.key() @ CoreError::MarketMismatched,
+        constraint = order.load()?.header.owner== owner.key() @ CoreError::OwnerMismatched,
+    )]
+    pub order: AccountLoader<'info, Order>,
+}
+
+impl<'info> UpdateOrderReprice<'info> {
+    const MAX_BPS: u128 = 10_000;
+
+    pub(crate) fn invoke(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        trigger_price: u128,
+        slippage_bps: u16,
+    ) -> Result<()> {
+        let accounts = ctx.accounts;
+        let (kind, is_long) = {
+            let order = accounts.order.load()?;
+            let kind = order.params().kind()?;
+            accounts
+                .store
+                .load()?
+                .validate_not_restarted()?
+                .validate_feature_enabled(
+                    kind.try_into()
+                        .map_err(CoreError::from)
+                        .map_err(|err| error!(err))?,
+                    ActionDisabledFlag::Update,
+                )?;
+            // Orders with callbacks must be updated with `update_order_v2`.
+            require!(
+                matches!(order.header.callback_kind()?, ActionCallbackKind::Disabled),
+                CoreError::InvalidArgument
+            );
+            (kind, order.params().side()?.is_long())
+        };
+
+        // Only trigger orders of positions have both trigger and acceptable prices.
+        require!(
+            matches!(
+                kind,
+                OrderKind::LimitIncrease | OrderKind::LimitDecrease | OrderKind::StopLossDecrease
+            ),
+            CoreError::InvalidArgument
+        );
+        require!(trigger_price != 0, CoreError::InvalidTriggerPrice);
+        require_gte!(
+            Self::MAX_BPS,
+            u128::from(slippage_bps),
+            CoreError::InvalidArgument
+        );
+
+        let acceptable_price =
+            accounts.acceptable_price(kind, is_long, slippage_bps, ctx.remaining_accounts)?;
+
+        let params = UpdateOrderParams {
+            size_delta_value: None,
+            acceptable_price: Some(acceptable_price),
+            trigger_price: Some(trigger_price),
+            min_output: None,
+            valid_from_ts: None,
+        };
+        let id = accounts.market.load_mut()?.indexer_mut().next_order_id()?;
+        accounts.order.load_mut()?.update(id, &params)?;
+
+        let event_emitter = EventEmitter::new(&accounts.event_authority, ctx.bumps.event_authority);
+        let order_address = accounts.order.key();
+        event_emitter.emit_cpi(&OrderUpdated::new(
+            false,
+            &order_address,
+            &*accounts.order.load()?,
+        )?)?;
+        Ok(())
+    }
+
+    /// Derive the acceptable price from the current index token price with the given slippage.
+    fn acceptable_price(
+        &self,
+        kind: OrderKind,
+        is_long: bool,
+        slippage_bps: u16,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<u128> {
+        let market = self.market.load()?;
+        market.validate(&self.store.key())?;
+        let tokens = market
+            .meta()
+            .ordered_tokens()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let index_token_price = self.oracle.load_mut()?.with_prices(
+            &self.store,
+            &self.token_map,
+            &tokens,
+            remaining_accounts,
+            |oracle, _| Ok(oracle.market_prices(&*market)?.index_token_price),
+        )?;
+
+        // Buying the index token (increasing a long or decreasing a short) accepts higher prices.
+        let is_buy = kind.is_increase_position() == is_long;
+        let slippage_bps = u128::from(slippage_bps);
+        let price = if is_buy {
+            index_token_price
+                .max
+                .checked_mul_div_ceil(&(Self::MAX_BPS + slippage_bps), &Self::MAX_BPS)
+        } else {
+            index_token_price
+                .min
+                .checked_mul_div(&(Self::MAX_BPS - slippage_bps), &Self::MAX_BPS)
+        };
+        price.ok_or_else(|| error!(CoreError::ValueOverflow))
+    }
+}
+
 /// The accounts definition for the [`cancel_order_if_no_position`](crate::gmsol_store::cancel_order_if_no_position)
 /// instruction.
 #[derive(Accounts)]
@@ -1118,6 +1394,96 @@ impl<'info> internal::Authentication<'info> for CancelOrderIfNoPosition<'info> {
     }
 }
 
+/// The accounts definition for read-only instructions for order.
+#[derive(Accounts)]
+pub struct ReadOrder<'info> {
+    /// Store.
+    pub store: AccountLoader<'info, Store>,
+    /// Order.
+    #[account(
+        constraint = order.load()?.header.store == store.key() @ CoreError::StoreMismatched,
+    )]
+    pub order: AccountLoader<'info, Order>,
+}
+
+/// Decoded order parameters.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct OrderParamsInfo {
+    /// Order kind.
+    pub kind: OrderKind,
+    /// Whether the position is long. `None` for swap orders.
+    pub is_long: Option<bool>,
+    /// Market token.
+    pub market_token: Pubkey,
+    /// Position address. `None` for swap orders.
+    pub position: Option<Pubkey>,
+    /// Collateral token or swap out token.
+    pub collateral_token: Pubkey,
+    /// Initial collateral token, only available for increase and swap orders.
+    pub initial_collateral_token: Option<Pubkey>,
+    /// Final output token, only available for decrease and swap orders.
+    pub final_output_token: Option<Pubkey>,
+    /// Initial collateral delta amount or swap in amount.
+    pub initial_collateral_delta_amount: u64,
+    /// Size delta value.
+    pub size_delta_value: u128,
+    /// Min output amount or value.
+    pub min_output: u128,
+    /// Trigger price (in unit price).
+    pub trigger_price: u128,
+    /// Acceptable price (in unit price).
+    pub acceptable_price: u128,
+    /// Decrease position swap type. `None` for non-decrease orders.
+    pub decrease_position_swap_type: Option<DecreasePositionSwapType>,
+    /// The length of the swap path.
+    pub swap_path_length: u8,
+    /// The timestamp from which the order is valid.
+    pub valid_from_ts: i64,
+    /// The timestamp after which the order is too old to be executed.
+    /// `None` if the max action age is not set.
+    pub deadline: Option<i64>,
+}
+
+/// Get the decoded parameters of the order.
+pub(crate) fn get_order_params(ctx: Context<ReadOrder>) -> Result<OrderParamsInfo> {
+    let order = ctx.accounts.order.load()?;
+    let params = order.params();
+    let kind = params.kind()?;
+    let tokens = order.tokens();
+
+    let deadline = ctx
+        .accounts
+        .store
+        .load()?
+        .action_expires_at(order.header().updated_at)
+        .map_err(|err| error!(err))?;
+
+    Ok(OrderParamsInfo {
+        kind,
+        is_long: (!kind.is_swap())
+            .then(|| params.side().map(|side| side.is_long()))
+            .transpose()?,
+        market_token: *order.market_token(),
+        position: params.position().copied(),
+        collateral_token: params.collateral_token,
+        initial_collateral_token: tokens.initial_collateral().token(),
+        final_output_token: tokens.final_output_token().token(),
+        initial_collateral_delta_amount: params.amount(),
+        size_delta_value: params.size(),
+        min_output: params.min_output(),
+        trigger_price: params.trigger_price(),
+        acceptable_price: params.acceptable_price(),
+        decrease_position_swap_type: kind
+            .is_decrease_position()
+            .then(|| params.decrease_position_swap_type())
+            .transpose()?,
+        swap_path_length: order.swap().primary_length() as u8,
+        valid_from_ts: params.valid_from_ts(),
+        deadline,
+    })
+}
+
 #[deprecated(since = "0.6.0", note = "use v2 instructions instead.")]
 mod deprecated {
     use super::*;
@@ -1379,6 +1745,8 @@ mod deprecated {
                     return err!(CoreError::OrderKindNotAllowed);
                 }
             }
+            self.record_pending_action()?;
+            self.record_resting_order()?;
             emit!(OrderCreated::new(
                 self.store.key(),
                 self.order.key(),
@@ -1389,6 +1757,24 @@ mod deprecated {
     }
 
     impl CreateOrder<'_> {
+        fn record_pending_action(&self) -> Result<()> {
+            let mut order = self.order.load_mut()?;
+            internal::record_pending_action(&self.store, &self.user, &mut order.header)
+        }
+
+        fn record_resting_order(&self) -> Result<()> {
+            let mut order = self.order.load_mut()?;
+            if order.params().kind()?.is_resting_position() {
+                let position = self
+                    .position
+                    .as_ref()
+                    .ok_or_else(|| error!(CoreError::PositionIsRequired))?;
+                order.header.set_resting_order_recorded(true);
+                position.load_mut()?.add_resting_order()?;
+            }
+            Ok(())
+        }
+
         fn transfer_tokens(&mut self, params: &CreateOrderParams) -> Result<()> {
             let kind = params.kind;
             if !matches!(
@@ -1580,6 +1966,10 @@ mod deprecated {
             RoleKey::ORDER_KEEPER
         }
 
+        fn user(&self) -> Option<&AccountLoader<'info, UserHeader>> {
+            Some(&self.user)
+        }
+
         fn rent_receiver(&self) -> AccountInfo<'info> {
             self.rent_receiver.to_account_info()
         }
@@ -1625,7 +2015,23 @@ mod deprecated {
         ) -> Result<internal::Success> {
             let transfer_success = self.transfer_to_atas(init_if_needed, store_wallet_signer)?;
             let process_success = self.process_gt_reward(event_emitter)?;
-            Ok(transfer_success && process_success)
+            let success = transfer_success && process_success;
+            if success {
+                {
+                    let order = self.order.load()?;
+                    if order.market_order_seq().is_some()
+                        && order.header.action_state()?.is_pending()
+                    {
+                        msg!("[Order] use `close_order_v2` to close a pending queued market order");
+                        return err!(CoreError::InvalidArgument);
+                    }
+                }
+                if self.order.load()?.header.is_resting_order_recorded() {
+                    msg!("[Order] use `close_order_v2` to close a recorded resting order");
+                    return err!(CoreError::InvalidArgument);
+                }
+            }
+            Ok(success)
         }
 
         fn event_authority(&self, bumps: &Self::Bumps) -> (AccountInfo<'info>, u8) {
@@ -1665,6 +2071,7 @@ mod deprecated {
                 .escrow_authority_seeds(&seeds)
                 .rent_receiver(self.rent_receiver())
                 .init_if_needed(init_if_needed)
+                .min_unwrap_amount(self.store.load()?.min_auto_unwrap_amount())
                 .should_unwrap_native(self.order.load()?.header().should_unwrap_native_token());
 
             let state = self.order.load()?.header().action_state()?;
@@ -1822,7 +2229,10 @@ mod deprecated {
                 let mut store = self.store.load_mut()?;
                 let mut referrer_user = referrer_user.load_mut()?;
 
-                store.gt_mut().mint_to(&mut referrer_user, reward)?;
+                let lockup_seconds = store.gt_mint_lockup_seconds();
+                store
+                    .gt_mut()
+                    .mint_to(&mut referrer_user, reward, lockup_seconds)?;
 
                 event_emitter.emit_cpi(&GtUpdated::rewarded(
                     reward,
@@ -1899,3 +2309,165 @@ mod deprecated {
         Ok(())
     }
 }
+
+/// The accounts definition for
+/// [`check_order_prerequisites`](crate::gmsol_store::check_order_prerequisites).
+///
+/// The escrow accounts are optional. A required escrow account that is not provided
+/// is reported as missing.
+#[derive(Accounts)]
+#[instruction(nonce: [u8; 32], params: CreateOrderParams)]
+pub struct CheckOrderPrerequisites<'info> {
+    /// The owner of the order to be created.
+    /// CHECK: only the address is used.
+    pub owner: UncheckedAccount<'info>,
+    /// Store.
+    pub store: AccountLoader<'info, Store>,
+    /// Market.
+    #[account(has_one = store)]
+    pub market: AccountLoader<'info, Market>,
+    /// User Account.
+    /// CHECK: only the address and the initialization state are checked.
+    #[account(
+        seeds = [UserHeader::SEED, store.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub user: UncheckedAccount<'info>,
+    /// The related position.
+    /// Only required by increase and decrease orders.
+    /// CHECK: only the address and the initialization state are checked.
+    #[account(
+        seeds = [
+            Position::SEED,
+            store.key().as_ref(),
+            owner.key().as_ref(),
+            market.load()?.meta().market_token_mint.as_ref(),
+            params.collateral_token(market.load()?.meta()).as_ref(),
+            &[params.to_position_kind()? as u8],
+        ],
+        bump,
+    )]
+    pub position: Option<UncheckedAccount<'info>>,
+    /// Initial collateral token / swap in token.
+    /// Only required by increase and swap orders.
+    /// CHECK: only the address is used.
+    pub initial_collateral_token: Option<UncheckedAccount<'info>>,
+    /// Final output token.
+    /// CHECK: only the address is used.
+    pub final_output_token: UncheckedAccount<'info>,
+    /// Initial collateral token escrow account.
+    /// CHECK: the address is checked in the instruction.
+    pub initial_collateral_token_escrow: Option<UncheckedAccount<'info>>,
+    /// Final output token escrow account.
+    /// CHECK: the address is checked in the instruction.
+    pub final_output_token_escrow: Option<UncheckedAccount<'info>>,
+    /// Long token escrow.
+    /// CHECK: the address is checked in the instruction.
+    pub long_token_escrow: Option<UncheckedAccount<'info>>,
+    /// Short token escrow.
+    /// CHECK: the address is checked in the instruction.
+    pub short_token_escrow: Option<UncheckedAccount<'info>>,
+}
+
+/// The prerequisites of an order creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderPrerequisites {
+    /// Whether all the prerequisites are met.
+    pub is_ready: bool,
+    /// Whether the user account has not been prepared.
+    pub user_not_prepared: bool,
+    /// Whether the position account required by the order has not been prepared.
+    pub position_missing: bool,
+    /// The addresses of the required escrow accounts that have not been prepared.
+    pub missing_escrows: Vec<Pubkey>,
+}
+
+/// Check which prerequisites of creating an order with the given params are missing.
+pub(crate) fn check_order_prerequisites(
+    ctx: Context<CheckOrderPrerequisites>,
+    nonce: &NonceBytes,
+    params: &CreateOrderParams,
+) -> Result<OrderPrerequisites> {
+    use anchor_spl::associated_token::get_associated_token_address;
+
+    let accounts = ctx.accounts;
+    let store = accounts.store.key();
+    let owner = accounts.owner.key();
+
+    let user_not_prepared = {
+        let user = &accounts.user;
+        if user.owner != &crate::ID || user.data_is_empty() {
+            true
+        } else {
+            let data = user.try_borrow_data()?;
+            let len = 8 + std::mem::size_of::<UserHeader>();
+            let data = data
+                .get(..len)
+                .ok_or_else(|| error!(ErrorCode::AccountDidNotDeserialize))?;
+            !UserHeader::try_deserialize(&mut &data[..])?.is_initialized()
+        }
+    };
+
+    let kind = params.kind;
+    let requires_position = kind.is_increase_position() || kind.is_decrease_position();
+    let position_missing = requires_position
+        && accounts
+            .position
+            .as_ref()
+            .map(|position| position.owner != &crate::ID || position.data_is_empty())
+            .unwrap_or(true);
+
+    let order = Pubkey::find_program_address(
+        &[Order::SEED, store.as_ref(), owner.as_ref(), nonce],
+        &crate::ID,
+    )
+    .0;
+    let (long_token, short_token) = {
+        let market = accounts.market.load()?;
+        let meta = market.meta();
+        (meta.long_token_mint, meta.short_token_mint)
+    };
+
+    let mut required = Vec::with_capacity(4);
+    if kind.is_increase_position() || kind.is_swap() {
+        let token = accounts
+            .initial_collateral_token
+            .as_ref()
+            .ok_or_else(|| error!(CoreError::MissingInitialCollateralToken))?;
+        required.push((token.key(), &accounts.initial_collateral_token_escrow));
+    }
+    if kind.is_decrease_position() || kind.is_swap() {
+        required.push((
+            accounts.final_output_token.key(),
+            &accounts.final_output_token_escrow,
+        ));
+    }
+    if requires_position {
+        required.push((long_token, &accounts.long_token_escrow));
+        required.push((short_token, &accounts.short_token_escrow));
+    }
+
+    let mut missing_escrows = Vec::new();
+    for (mint, escrow) in required {
+        let expected = get_associated_token_address(&order, &mint);
+        let is_prepared = match escrow {
+            Some(escrow) => {
+                require_keys_eq!(escrow.key(), expected, CoreError::InvalidArgument);
+                escrow.owner == &anchor_spl::token::ID && !escrow.data_is_empty()
+            }
+            None => false,
+        };
+        if !is_prepared && !missing_escrows.contains(&expected) {
+            missing_escrows.push(expected);
+        }
+    }
+
+    Ok(OrderPrerequisites {
+        is_ready: !user_not_prepared && !position_missing && missing_escrows.is_empty(),
+        user_not_prepared,
+        position_missing,
+        missing_escrows,
+    })
+}
@@ -11,7 +11,7 @@ use crate::{
     states::{
         common::action::{Action, ActionExt},
         feature::{ActionDisabledFlag, DomainDisabledFlag},
-        Market, NonceBytes, RoleKey, Seed, Shift, Store, StoreWalletSigner,
+        Market, NonceBytes, RoleKey, Seed, Shift, Store, StoreWalletSigner, UserHeader,
     },
     utils::{internal, token::is_associated_token_account},
     CoreError,
@@ -85,6 +85,16 @@ pub struct CreateShift<'info> {
     pub token_program: Program<'info, Token>,
     /// The associated token program.
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// User Account.
+    #[account(
+        mut,
+        constraint = user.load()?.is_initialized() @ CoreError::InvalidUserAccount,
+        has_one = owner,
+        has_one = store,
+        seeds = [UserHeader::SEED, store.key().as_ref(), owner.key().as_ref()],
+        bump = user.load()?.bump,
+    )]
+    pub user: AccountLoader<'info, UserHeader>,
 }
 
 impl<'info> internal::Create<'info, Shift> for CreateShift<'info> {
@@ -137,6 +147,13 @@ impl<'info> internal::Create<'info, Shift> for CreateShift<'info> {
             .params(params)
             .build()
             .execute()?;
+        // Make sure the discriminator is written to the account data.
+        self.shift.exit(&crate::ID)?;
+        internal::record_pending_action(
+            &self.store,
+            &self.user,
+            &mut self.shift.load_mut()?.header,
+        )?;
         Ok(())
     }
 }
@@ -238,6 +255,24 @@ pub struct CloseShift<'info> {
     pub token_program: Program<'info, Token>,
     /// The associated token program.
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The optional recipient of the rent of the action account.
+    ///
+    /// Only used when the caller is not the owner, in which case it must be
+    /// in the rent recipient allowlist of the store.
+    /// CHECK: only used to receive the rent and is validated during the close.
+    #[account(mut)]
+    pub rent_recipient: Option<UncheckedAccount<'info>>,
+    /// The user account of the owner.
+    ///
+    /// Must be provided if the shift has been recorded as a pending action of the owner.
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = store,
+        seeds = [UserHeader::SEED, store.key().as_ref(), owner.key().as_ref()],
+        bump = user.load()?.bump,
+    )]
+    pub user: Option<AccountLoader<'info, UserHeader>>,
 }
 
 impl<'info> internal::Authentication<'info> for CloseShift<'info> {
@@ -255,6 +290,10 @@ impl<'info> internal::Close<'info, Shift> for CloseShift<'info> {
         RoleKey::ORDER_KEEPER
     }
 
+    fn user(&self) -> Option<&AccountLoader<'info, UserHeader>> {
+        self.user.as_ref()
+    }
+
     fn rent_receiver(&self) -> AccountInfo<'info> {
         debug_assert!(
             self.shift.load().unwrap().header.rent_receiver() == self.owner.key,
@@ -263,6 +302,10 @@ impl<'info> internal::Close<'info, Shift> for CloseShift<'info> {
         self.owner.to_account_info()
     }
 
+    fn rent_recipient(&self) -> Option<AccountInfo<'info>> {
+        self.rent_recipient.as_ref().map(|a| a.to_account_info())
+    }
+
     fn store_wallet_bump(&self, bumps: &Self::Bumps) -> u8 {
         bumps.store_wallet
     }
@@ -300,6 +343,7 @@ impl<'info> internal::Close<'info, Shift> for CloseShift<'info> {
             .escrow_authority_seeds(&seeds)
             .init_if_needed(init_if_needed)
             .rent_receiver(self.rent_receiver())
+            .min_unwrap_amount(self.store.load()?.min_auto_unwrap_amount())
             .should_unwrap_native(self.shift.load()?.header().should_unwrap_native_token());
 
         // Transfer from_market tokens.
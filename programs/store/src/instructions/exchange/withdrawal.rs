@@ -12,7 +12,7 @@ use crate::{
         common::action::{Action, ActionExt},
         feature::{ActionDisabledFlag, DomainDisabledFlag},
         withdrawal::Withdrawal,
-        Market, NonceBytes, RoleKey, Seed, Store, StoreWalletSigner,
+        Market, NonceBytes, RoleKey, Seed, Store, StoreWalletSigner, UserHeader,
     },
     utils::{
         internal,
@@ -93,6 +93,16 @@ pub struct CreateWithdrawal<'info> {
     pub token_program: Program<'info, Token>,
     /// The associated token program.
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// User Account.
+    #[account(
+        mut,
+        constraint = user.load()?.is_initialized() @ CoreError::InvalidUserAccount,
+        has_one = owner,
+        has_one = store,
+        seeds = [UserHeader::SEED, store.key().as_ref(), owner.key().as_ref()],
+        bump = user.load()?.bump,
+    )]
+    pub user: AccountLoader<'info, UserHeader>,
 }
 
 impl<'info> internal::Create<'info, Withdrawal> for CreateWithdrawal<'info> {
@@ -146,6 +156,13 @@ impl<'info> internal::Create<'info, Withdrawal> for CreateWithdrawal<'info> {
             .swap_paths(remaining_accounts)
             .build()
             .execute()?;
+        // Make sure the discriminator is written to the account data.
+        self.withdrawal.exit(&crate::ID)?;
+        internal::record_pending_action(
+            &self.store,
+            &self.user,
+            &mut self.withdrawal.load_mut()?.header,
+        )?;
         emit!(WithdrawalCreated::new(
             self.store.key(),
             self.withdrawal.key(),
@@ -272,6 +289,24 @@ pub struct CloseWithdrawal<'info> {
     pub token_program: Program<'info, Token>,
     /// The associated token program.
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The optional recipient of the rent of the action account.
+    ///
+    /// Only used when the caller is not the owner, in which case it must be
+    /// in the rent recipient allowlist of the store.
+    /// CHECK: only used to receive the rent and is validated during the close.
+    #[account(mut)]
+    pub rent_recipient: Option<UncheckedAccount<'info>>,
+    /// The user account of the owner.
+    ///
+    /// Must be provided if the withdrawal has been recorded as a pending action of the owner.
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = store,
+        seeds = [UserHeader::SEED, store.key().as_ref(), owner.key().as_ref()],
+        bump = user.load()?.bump,
+    )]
+    pub user: Option<AccountLoader<'info, UserHeader>>,
 }
 
 impl<'info> internal::Authentication<'info> for CloseWithdrawal<'info> {
@@ -289,6 +324,10 @@ impl<'info> internal::Close<'info, Withdrawal> for CloseWithdrawal<'info> {
         RoleKey::ORDER_KEEPER
     }
 
+    fn user(&self) -> Option<&AccountLoader<'info, UserHeader>> {
+        self.user.as_ref()
+    }
+
     fn rent_receiver(&self) -> AccountInfo<'info> {
         debug_assert!(
             self.withdrawal.load().unwrap().header.rent_receiver() == self.owner.key,
@@ -297,6 +336,10 @@ impl<'info> internal::Close<'info, Withdrawal> for CloseWithdrawal<'info> {
         self.owner.to_account_info()
     }
 
+    fn rent_recipient(&self) -> Option<AccountInfo<'info>> {
+        self.rent_recipient.as_ref().map(|a| a.to_account_info())
+    }
+
     fn store_wallet_bump(&self, bumps: &Self::Bumps) -> u8 {
         bumps.store_wallet
     }
@@ -337,6 +380,7 @@ impl<'info> internal::Close<'info, Withdrawal> for CloseWithdrawal<'info> {
             .escrow_authority_seeds(&seeds)
             .init_if_needed(init_if_needed)
             .rent_receiver(self.rent_receiver())
+            .min_unwrap_amount(self.store.load()?.min_auto_unwrap_amount())
             .should_unwrap_native(
                 self.withdrawal
                     .load()?
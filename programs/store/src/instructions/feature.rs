@@ -1,11 +1,14 @@
 use anchor_lang::prelude::*;
 
 use crate::{
+    instructions::ReadStore,
     states::{
         feature::{ActionDisabledFlag, DomainDisabledFlag},
+        order::OrderKind,
         Store,
     },
     utils::internal,
+    CoreError,
 };
 
 /// The accounts definition for [`toggle_feature`](crate::gmsol_store::toggle_feature).
@@ -42,3 +45,141 @@ impl<'info> internal::Authentication<'info> for ToggleFeature<'info> {
         &self.store
     }
 }
+
+/// A feature flag that gates an instruction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RequiredFeature {
+    /// The domain part of the feature flag, in the format accepted by
+    /// [`toggle_feature`](crate::gmsol_store::toggle_feature).
+    pub domain: String,
+    /// The action part of the feature flag, in the format accepted by
+    /// [`toggle_feature`](crate::gmsol_store::toggle_feature).
+    pub action: String,
+    /// Whether the feature is currently enabled.
+    pub is_enabled: bool,
+}
+
+const ORDER_KINDS: [OrderKind; 9] = [
+    OrderKind::Liquidation,
+    OrderKind::AutoDeleveraging,
+    OrderKind::MarketSwap,
+    OrderKind::MarketIncrease,
+    OrderKind::MarketDecrease,
+    OrderKind::LimitSwap,
+    OrderKind::LimitIncrease,
+    OrderKind::LimitDecrease,
+    OrderKind::StopLossDecrease,
+];
+
+fn order_domains(
+    order_kind: Option<OrderKind>,
+    is_accepted: impl Fn(&OrderKind) -> bool,
+) -> Result<Vec<DomainDisabledFlag>> {
+    let to_domain = |kind: OrderKind| {
+        DomainDisabledFlag::try_from(kind)
+            .map_err(CoreError::from)
+            .map_err(|err| error!(err))
+    };
+    match order_kind {
+        Some(kind) => {
+            require!(is_accepted(&kind), CoreError::InvalidArgument);
+            Ok(vec![to_domain(kind)?])
+        }
+        None => ORDER_KINDS
+            .into_iter()
+            .filter(is_accepted)
+            .map(to_domain)
+            .collect(),
+    }
+}
+
+fn is_user_order(kind: &OrderKind) -> bool {
+    !matches!(kind, OrderKind::Liquidation | OrderKind::AutoDeleveraging)
+}
+
+/// Returns the feature flags that gate the given instruction.
+fn required_features(
+    instruction: &str,
+    order_kind: Option<OrderKind>,
+) -> Result<Vec<(DomainDisabledFlag, ActionDisabledFlag)>> {
+    use ActionDisabledFlag as Action;
+    use DomainDisabledFlag as Domain;
+
+    let with_action = |domains: Vec<DomainDisabledFlag>, action: ActionDisabledFlag| {
+        domains
+            .into_iter()
+            .map(|domain| (domain, action))
+            .collect::<Vec<_>>()
+    };
+
+    let features = match instruction {
+        "create_order" | "create_order_v2" => {
+            with_action(order_domains(order_kind, is_user_order)?, Action::Create)
+        }
+        "update_order" | "update_order_v2" | "update_order_reprice" => {
+            with_action(order_domains(order_kind, is_user_order)?, Action::Update)
+        }
+        "close_order" | "close_order_v2" => {
+            with_action(order_domains(order_kind, is_user_order)?, Action::Cancel)
+        }
+        "execute_increase_or_swap_order" | "execute_increase_or_swap_order_v2" => with_action(
+            order_domains(order_kind, |kind| {
+                kind.is_increase_position() || kind.is_swap()
+            })?,
+            Action::Execute,
+        ),
+        "execute_decrease_order" | "execute_decrease_order_v2" => with_action(
+            order_domains(order_kind, OrderKind::is_decrease_position)?,
+            Action::Execute,
+        ),
+        "liquidate" => vec![
+            (Domain::Liquidation, Action::Create),
+            (Domain::Liquidation, Action::Execute),
+        ],
+        "auto_deleverage" => vec![
+            (Domain::AutoDeleveraging, Action::Create),
+            (Domain::AutoDeleveraging, Action::Execute),
+        ],
+        "create_deposit" => vec![(Domain::Deposit, Action::Create)],
+        "close_deposit" => vec![(Domain::Deposit, Action::Cancel)],
+        "execute_deposit" => vec![(Domain::Deposit, Action::Execute)],
+        "create_withdrawal" => vec![(Domain::Withdrawal, Action::Create)],
+        "close_withdrawal" => vec![(Domain::Withdrawal, Action::Cancel)],
+        "execute_withdrawal" => vec![(Domain::Withdrawal, Action::Execute)],
+        "create_shift" => vec![(Domain::Shift, Action::Create)],
+        "close_shift" => vec![(Domain::Shift, Action::Cancel)],
+        "execute_shift" => vec![(Domain::Shift, Action::Execute)],
+        "create_glv_deposit" => vec![(Domain::GlvDeposit, Action::Create)],
+        "close_glv_deposit" => vec![(Domain::GlvDeposit, Action::Cancel)],
+        "execute_glv_deposit" => vec![(Domain::GlvDeposit, Action::Execute)],
+        "create_glv_withdrawal" => vec![(Domain::GlvWithdrawal, Action::Create)],
+        "close_glv_withdrawal" => vec![(Domain::GlvWithdrawal, Action::Cancel)],
+        "execute_glv_withdrawal" => vec![(Domain::GlvWithdrawal, Action::Execute)],
+        "create_glv_shift" => vec![(Domain::GlvShift, Action::Create)],
+        "close_glv_shift" => vec![(Domain::GlvShift, Action::Cancel)],
+        "execute_glv_shift" => vec![(Domain::GlvShift, Action::Execute)],
+        _ => vec![],
+    };
+
+    Ok(features)
+}
+
+/// Get the feature flags that gate the given instruction.
+pub(crate) fn get_required_features(
+    ctx: Context<ReadStore>,
+    instruction: &str,
+    order_kind: Option<OrderKind>,
+) -> Result<Vec<RequiredFeature>> {
+    let store = ctx.accounts.store.load()?;
+    let features = required_features(instruction, order_kind)?
+        .into_iter()
+        .map(|(domain, action)| RequiredFeature {
+            domain: domain.to_string(),
+            action: action.to_string(),
+            is_enabled: !store.is_feature_disabled(domain, action),
+        })
+        .collect();
+    Ok(features)
+}
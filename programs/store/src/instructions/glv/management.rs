@@ -475,3 +475,15 @@ impl<'info> internal::Authentication<'info> for RemoveGlvMarket<'info> {
         &self.store
     }
 }
+
+/// The accounts definition for read-only instructions for GLV.
+#[derive(Accounts)]
+pub struct ReadGlv<'info> {
+    /// GLV.
+    pub glv: AccountLoader<'info, Glv>,
+}
+
+/// Get the minimum GLV token amount required for the first deposit.
+pub(crate) fn get_glv_first_deposit_minimum(ctx: Context<ReadGlv>) -> Result<u64> {
+    Ok(ctx.accounts.glv.load()?.min_tokens_for_first_deposit)
+}
@@ -1,11 +1,18 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{token::Mint, token_interface};
-use gmsol_model::utils::market_token_amount_to_usd;
+use gmsol_model::{
+    price::Prices,
+    utils::{div_to_factor, market_token_amount_to_usd},
+};
 use gmsol_utils::swap::SwapActionParams;
 
 use crate::{
+    constants,
     events::{EventEmitter, GlvTokenValue},
-    ops::glv::get_glv_value_for_market_with_new_index_price,
+    ops::glv::{
+        get_glv_value_for_market_with_new_index_price, get_market_pool_value_for_glv,
+        GlvValueForMarket,
+    },
     states::{Glv, Market, MaxAgeValidator, Oracle, Store, TokenMapHeader, TokenMapLoader},
     CoreError,
 };
@@ -46,10 +53,11 @@ impl<'info> GetGlvTokenValue<'info> {
         ctx: Context<'_, '_, 'info, 'info, Self>,
         amount: u64,
         maximize: bool,
-        max_age: u32,
+        max_age: Option<u32>,
         emit_event: bool,
     ) -> Result<u128> {
         let accounts = ctx.accounts;
+        let max_age = accounts.store.load()?.oracle_staleness(max_age)?;
 
         accounts.evaluate(
             amount,
@@ -141,3 +149,224 @@ impl<'info> GetGlvTokenValue<'info> {
         )
     }
 }
+
+/// The accounts definition for [`get_glv_nav`](crate::gmsol_store::get_glv_nav).
+///
+/// Remaining accounts expected by this instruction:
+///
+///   - 0..N. `[]` N market accounts, where N represents the total number of markets managed
+///     by the given GLV, in the same order as the market tokens stored in the GLV.
+///   - N..2N. `[]` N market token accounts (see above for the definition of N).
+#[derive(Accounts)]
+pub struct ReadGlvWithMarkets<'info> {
+    /// GLV.
+    pub glv: AccountLoader<'info, Glv>,
+}
+
+/// Value of the market tokens held by a GLV.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlvMarketNav {
+    /// Market token.
+    pub market_token: Pubkey,
+    /// The market token balance of the GLV.
+    pub balance: u64,
+    /// The pool value of the market.
+    pub pool_value: i128,
+    /// The value of the market tokens held by the GLV.
+    ///
+    /// It is zero if the pool value of the market is negative.
+    pub value: u128,
+    /// The weight of the market in the GLV, as a factor of the total NAV.
+    ///
+    /// It is zero if the total NAV is zero.
+    pub weight: u128,
+    /// Whether the pool value of the market is negative.
+    pub is_pool_value_negative: bool,
+}
+
+/// NAV and composition of a GLV.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlvNav {
+    /// GLV token.
+    pub glv_token: Pubkey,
+    /// The total NAV of the GLV.
+    pub total_value: u128,
+    /// The per-market breakdown.
+    pub markets: Vec<GlvMarketNav>,
+}
+
+/// Get the NAV and the per-market composition of the GLV.
+pub(crate) fn get_glv_nav<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadGlvWithMarkets<'info>>,
+    prices: &[Prices<u128>],
+    maximize: bool,
+) -> Result<GlvNav> {
+    let glv = ctx.accounts.glv.load()?;
+    let len = glv.num_markets();
+
+    require_eq!(prices.len(), len, CoreError::InvalidArgument);
+    require_gte!(
+        ctx.remaining_accounts.len(),
+        len * 2,
+        CoreError::InvalidArgument
+    );
+
+    let (markets, market_tokens) = ctx.remaining_accounts[..len * 2].split_at(len);
+
+    let mut total_value = 0u128;
+    let mut navs = Vec::with_capacity(len);
+    for (((expected_market_token, market), market_token), prices) in glv
+        .market_tokens()
+        .zip(markets)
+        .zip(market_tokens)
+        .zip(prices)
+    {
+        require_keys_eq!(
+            market_token.key(),
+            expected_market_token,
+            CoreError::MarketTokenMintMismatched
+        );
+        let balance = glv
+            .market_config(&expected_market_token)
+            .ok_or_else(|| error!(CoreError::NotFound))?
+            .balance();
+
+        let market = AccountLoader::<Market>::try_from(market)?;
+        let mint = Account::<Mint>::try_from(market_token)?;
+        let market = market.load()?;
+        let meta = market.validated_meta(glv.store())?;
+        require_keys_eq!(
+            meta.market_token_mint,
+            expected_market_token,
+            CoreError::MarketTokenMintMismatched
+        );
+        let market = market.as_liquidity_market(&mint);
+
+        let pool_value = get_market_pool_value_for_glv(prices, &market, maximize)?;
+        let is_pool_value_negative = balance != 0 && pool_value.is_negative();
+        let value = if is_pool_value_negative {
+            0
+        } else {
+            GlvValueForMarket::from_pool_value(u128::from(balance), pool_value, mint.supply.into())?
+                .market_token_value_in_glv
+        };
+
+        total_value = total_value
+            .checked_add(value)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        navs.push(GlvMarketNav {
+            market_token: expected_market_token,
+            balance,
+            pool_value,
+            value,
+            weight: 0,
+            is_pool_value_negative,
+        });
+    }
+
+    // All weights are zero if the GLV holds no value, e.g. it is empty.
+    if total_value != 0 {
+        for nav in navs.iter_mut() {
+            nav.weight =
+                div_to_factor::<_, { constants::MARKET_DECIMALS }>(&nav.value, &total_value, false)
+                    .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        }
+    }
+
+    Ok(GlvNav {
+        glv_token: *glv.glv_token(),
+        total_value,
+        markets: navs,
+    })
+}
+
+/// Utilization of the caps of a market in a GLV.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlvMarketUtilization {
+    /// Market token.
+    pub market_token: Pubkey,
+    /// The market token balance of the GLV.
+    pub balance: u64,
+    /// The maximized value of the market tokens held by the GLV.
+    pub value: u128,
+    /// Max balance amount. Zero means unlimited.
+    pub max_amount: u64,
+    /// Max value. Zero means unlimited.
+    pub max_value: u128,
+    /// The fraction of the max amount used, as a factor.
+    ///
+    /// It is zero if the max amount is unlimited.
+    pub amount_utilization: u128,
+    /// The fraction of the max value used, as a factor.
+    ///
+    /// It is zero if the max value is unlimited.
+    pub value_utilization: u128,
+    /// Whether the pool value of the market is negative.
+    pub is_pool_value_negative: bool,
+}
+
+/// Per-market cap utilization of a GLV.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlvUtilization {
+    /// GLV token.
+    pub glv_token: Pubkey,
+    /// The per-market utilization.
+    pub markets: Vec<GlvMarketUtilization>,
+}
+
+/// Get the utilization of the max amount and max value caps of each market in the GLV.
+pub(crate) fn get_glv_utilization<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadGlvWithMarkets<'info>>,
+    prices: &[Prices<u128>],
+) -> Result<GlvUtilization> {
+    let glv_loader = ctx.accounts.glv.clone();
+    // Values are maximized to match the cap validation on deposits.
+    let nav = get_glv_nav(ctx, prices, true)?;
+    let glv = glv_loader.load()?;
+
+    let markets = nav
+        .markets
+        .into_iter()
+        .map(|market| {
+            let config = glv
+                .market_config(&market.market_token)
+                .ok_or_else(|| error!(CoreError::NotFound))?;
+            let max_amount = config.max_amount();
+            let max_value = config.max_value();
+            let amount_utilization =
+                utilization(&u128::from(market.balance), &u128::from(max_amount))?;
+            let value_utilization = utilization(&market.value, &max_value)?;
+            Ok(GlvMarketUtilization {
+                market_token: market.market_token,
+                balance: market.balance,
+                value: market.value,
+                max_amount,
+                max_value,
+                amount_utilization,
+                value_utilization,
+                is_pool_value_negative: market.is_pool_value_negative,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(GlvUtilization {
+        glv_token: nav.glv_token,
+        markets,
+    })
+}
+
+fn utilization(used: &u128, cap: &u128) -> Result<u128> {
+    if *cap == 0 {
+        return Ok(0);
+    }
+    div_to_factor::<_, { constants::MARKET_DECIMALS }>(used, cap, false)
+        .ok_or_else(|| error!(CoreError::ValueOverflow))
+}
@@ -18,7 +18,7 @@ use crate::{
         feature::{ActionDisabledFlag, DomainDisabledFlag},
         glv::{GlvMarketFlag, GlvShift},
         Chainlink, Glv, Market, NonceBytes, Oracle, RoleKey, Seed, Store, StoreWalletSigner,
-        TokenMapHeader,
+        TokenMapHeader, UserHeader,
     },
     utils::internal,
     CoreError,
@@ -152,6 +152,11 @@ impl<'info> internal::Create<'info, GlvShift> for CreateGlvShift<'info> {
             .build()
             .execute()?;
 
+        {
+            let max_active_shifts = self.store.load()?.max_concurrent_glv_shifts();
+            self.glv.load_mut()?.on_shift_created(max_active_shifts)?;
+        }
+
         // Set the funder of the GLV shift.
         {
             self.glv_shift.exit(&crate::ID)?;
@@ -193,6 +198,7 @@ pub struct CloseGlvShift<'info> {
     pub store_wallet: SystemAccount<'info>,
     /// GLV.
     #[account(
+        mut,
         has_one = store,
         constraint = glv.load()?.contains(&from_market_token.key()) @ CoreError::InvalidArgument,
         constraint = glv.load()?.contains(&to_market_token.key()) @ CoreError::InvalidArgument,
@@ -223,6 +229,13 @@ pub struct CloseGlvShift<'info> {
     pub token_program: Program<'info, Token>,
     /// The associated token program.
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The optional recipient of the rent of the action account.
+    ///
+    /// Only used when the caller is not the owner, in which case it must be
+    /// in the rent recipient allowlist of the store.
+    /// CHECK: only used to receive the rent and is validated during the close.
+    #[account(mut)]
+    pub rent_recipient: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> internal::Close<'info, GlvShift> for CloseGlvShift<'info> {
@@ -230,6 +243,11 @@ impl<'info> internal::Close<'info, GlvShift> for CloseGlvShift<'info> {
         RoleKey::ORDER_KEEPER
     }
 
+    fn user(&self) -> Option<&AccountLoader<'info, UserHeader>> {
+        // GLV shifts are created by keepers and are not recorded as pending actions.
+        None
+    }
+
     fn rent_receiver(&self) -> AccountInfo<'info> {
         debug_assert!(
             self.glv_shift.load().unwrap().header().rent_receiver() == self.funder.key,
@@ -238,6 +256,10 @@ impl<'info> internal::Close<'info, GlvShift> for CloseGlvShift<'info> {
         self.funder.to_account_info()
     }
 
+    fn rent_recipient(&self) -> Option<AccountInfo<'info>> {
+        self.rent_recipient.as_ref().map(|a| a.to_account_info())
+    }
+
     fn skip_completion_check_for_keeper(&self) -> Result<bool> {
         // Allow the funder to close the GLV shift even if it has not reached a final state.
         Ok(*self.glv_shift.load()?.funder() == self.authority.key())
@@ -267,6 +289,9 @@ impl<'info> internal::Close<'info, GlvShift> for CloseGlvShift<'info> {
         _store_wallet_signer: &StoreWalletSigner,
         _event_emitter: &EventEmitter<'_, 'info>,
     ) -> Result<internal::Success> {
+        if self.glv_shift.load()?.header().action_state()?.is_pending() {
+            self.glv.load_mut()?.on_shift_finished();
+        }
         Ok(true)
     }
 
@@ -415,6 +440,7 @@ pub fn unchecked_execute_glv_shift<'info>(
     } else {
         accounts.glv_shift.load_mut()?.header_mut().cancelled()?;
     }
+    accounts.glv.load_mut()?.on_shift_finished();
 
     // It must be placed at the end to be executed correctly.
     accounts.pay_execution_fee(execution_lamports)?;
@@ -491,3 +517,63 @@ impl<'info> ExecuteGlvShift<'info> {
         )
     }
 }
+
+/// The accounts definition for read-only instructions for GLV shift.
+#[derive(Accounts)]
+pub struct ReadGlvShift<'info> {
+    /// GLV shift.
+    pub glv_shift: AccountLoader<'info, GlvShift>,
+}
+
+/// The markets and amounts recorded in a GLV shift.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GlvShiftInfo {
+    /// The GLV.
+    pub glv: Pubkey,
+    /// The market to shift from.
+    pub from_market: Pubkey,
+    /// The market token to shift from.
+    pub from_market_token: Pubkey,
+    /// The market to shift to.
+    pub to_market: Pubkey,
+    /// The market token to shift to.
+    pub to_market_token: Pubkey,
+    /// The long token of the markets.
+    pub long_token: Pubkey,
+    /// The short token of the markets.
+    pub short_token: Pubkey,
+    /// The amount of from market tokens to shift.
+    pub from_market_token_amount: u64,
+    /// The min amount of to market tokens to receive.
+    pub min_to_market_token_amount: u64,
+    /// Whether the GLV shift is pending.
+    pub is_pending: bool,
+}
+
+/// Get the markets and amounts recorded in the GLV shift.
+pub(crate) fn get_glv_shift_info(ctx: Context<ReadGlvShift>) -> Result<GlvShiftInfo> {
+    let glv_shift = ctx.accounts.glv_shift.load()?;
+    let header = glv_shift.header();
+    let tokens = glv_shift.tokens();
+    let params = &glv_shift.shift.params;
+    let from_market_token = tokens.from_market_token();
+    let to_market_token = tokens.to_market_token();
+    let (from_market, _) =
+        Market::find_market_address(&header.store, &from_market_token, ctx.program_id);
+    let (to_market, _) =
+        Market::find_market_address(&header.store, &to_market_token, ctx.program_id);
+    Ok(GlvShiftInfo {
+        glv: *glv_shift.glv(),
+        from_market,
+        from_market_token,
+        to_market,
+        to_market_token,
+        long_token: *tokens.long_token(),
+        short_token: *tokens.short_token(),
+        from_market_token_amount: params.from_market_token_amount(),
+        min_to_market_token_amount: params.min_to_market_token_amount(),
+        is_pending: header.action_state()?.is_pending(),
+    })
+}
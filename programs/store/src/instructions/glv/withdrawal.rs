@@ -25,7 +25,7 @@ use crate::{
         feature::{ActionDisabledFlag, DomainDisabledFlag},
         glv::{GlvWithdrawal, SplitAccountsForGlv},
         Chainlink, Glv, Market, NonceBytes, Oracle, RoleKey, Seed, Store, StoreWalletSigner,
-        TokenMapHeader, TokenMapLoader,
+        TokenMapHeader, TokenMapLoader, UserHeader,
     },
     utils::{
         internal,
@@ -120,6 +120,16 @@ pub struct CreateGlvWithdrawal<'info> {
     pub glv_token_program: Program<'info, Token2022>,
     /// The associated token program.
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// User Account.
+    #[account(
+        mut,
+        constraint = user.load()?.is_initialized() @ CoreError::InvalidUserAccount,
+        has_one = owner,
+        has_one = store,
+        seeds = [UserHeader::SEED, store.key().as_ref(), owner.key().as_ref()],
+        bump = user.load()?.bump,
+    )]
+    pub user: AccountLoader<'info, UserHeader>,
 }
 
 impl<'info> internal::Create<'info, GlvWithdrawal> for CreateGlvWithdrawal<'info> {
@@ -177,6 +187,13 @@ impl<'info> internal::Create<'info, GlvWithdrawal> for CreateGlvWithdrawal<'info
             .swap_paths(remaining_accounts)
             .build()
             .unchecked_execute()?;
+        // Make sure the discriminator is written to the account data.
+        self.glv_withdrawal.exit(&crate::ID)?;
+        internal::record_pending_action(
+            &self.store,
+            &self.user,
+            &mut self.glv_withdrawal.load_mut()?.header,
+        )?;
         Ok(())
     }
 }
@@ -332,6 +349,24 @@ pub struct CloseGlvWithdrawal<'info> {
     pub glv_token_program: Program<'info, Token2022>,
     /// The associated token program.
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// The optional recipient of the rent of the action account.
+    ///
+    /// Only used when the caller is not the owner, in which case it must be
+    /// in the rent recipient allowlist of the store.
+    /// CHECK: only used to receive the rent and is validated during the close.
+    #[account(mut)]
+    pub rent_recipient: Option<UncheckedAccount<'info>>,
+    /// The user account of the owner.
+    ///
+    /// Must be provided if the GLV withdrawal has been recorded as a pending action of the owner.
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = store,
+        seeds = [UserHeader::SEED, store.key().as_ref(), owner.key().as_ref()],
+        bump = user.load()?.bump,
+    )]
+    pub user: Option<AccountLoader<'info, UserHeader>>,
 }
 
 impl<'info> internal::Close<'info, GlvWithdrawal> for CloseGlvWithdrawal<'info> {
@@ -339,6 +374,10 @@ impl<'info> internal::Close<'info, GlvWithdrawal> for CloseGlvWithdrawal<'info>
         RoleKey::ORDER_KEEPER
     }
 
+    fn user(&self) -> Option<&AccountLoader<'info, UserHeader>> {
+        self.user.as_ref()
+    }
+
     fn rent_receiver(&self) -> AccountInfo<'info> {
         debug_assert!(
             self.glv_withdrawal.load().unwrap().header.rent_receiver() == self.owner.key,
@@ -347,6 +386,10 @@ impl<'info> internal::Close<'info, GlvWithdrawal> for CloseGlvWithdrawal<'info>
         self.owner.to_account_info()
     }
 
+    fn rent_recipient(&self) -> Option<AccountInfo<'info>> {
+        self.rent_recipient.as_ref().map(|a| a.to_account_info())
+    }
+
     fn store_wallet_bump(&self, bumps: &Self::Bumps) -> u8 {
         bumps.store_wallet
     }
@@ -387,6 +430,7 @@ impl<'info> internal::Close<'info, GlvWithdrawal> for CloseGlvWithdrawal<'info>
             .escrow_authority_seeds(&seeds)
             .init_if_needed(init_if_needed)
             .rent_receiver(self.rent_receiver())
+            .min_unwrap_amount(self.store.load()?.min_auto_unwrap_amount())
             .should_unwrap_native(
                 self.glv_withdrawal
                     .load()?
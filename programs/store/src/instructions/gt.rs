@@ -3,6 +3,7 @@ use gmsol_utils::InitSpace;
 
 use crate::{
     events::{EventEmitter, GtBuyback, GtUpdated},
+    instructions::ReadStore,
     states::{
         gt::{GtExchange, GtExchangeVault},
         user::UserHeader,
@@ -121,6 +122,19 @@ pub(crate) fn unchecked_gt_set_referral_reward_factors(
         .set_referral_reward_factors(factors)
 }
 
+/// CHECK: only GT_CONTROLLER is authorized to use this instruction.
+pub(crate) fn unchecked_gt_set_minting_cost_grow_round_up(
+    ctx: Context<ConfigureGt>,
+    round_up: bool,
+) -> Result<()> {
+    ctx.accounts
+        .store
+        .load_mut()?
+        .gt_mut()
+        .set_minting_cost_grow_round_up(round_up);
+    Ok(())
+}
+
 /// CHECK: only GT_CONTROLLER is authorized to use this instruction.
 #[cfg(feature = "test-only")]
 pub(crate) fn unchecked_gt_set_exchange_time_window(
@@ -363,6 +377,21 @@ pub(crate) fn unchecked_confirm_gt_exchange_vault(
     Ok(())
 }
 
+/// Confirm the GT exchange vault without the GT_CONTROLLER role.
+///
+/// The store must have enabled auto-confirm of GT exchange vaults. The deposit
+/// window check is still enforced when confirming the vault.
+pub(crate) fn auto_confirm_gt_exchange_vault(ctx: Context<ConfirmGtExchangeVault>) -> Result<()> {
+    require!(
+        ctx.accounts
+            .store
+            .load()?
+            .is_gt_vault_auto_confirm_enabled(),
+        CoreError::FeatureDisabled
+    );
+    unchecked_confirm_gt_exchange_vault(ctx, None, None)
+}
+
 impl<'info> internal::Authentication<'info> for ConfirmGtExchangeVault<'info> {
     fn authority(&self) -> &Signer<'info> {
         &self.authority
@@ -501,8 +530,9 @@ impl MintGtReward<'_> {
         let mut user = ctx.accounts.user.load_mut()?;
         let event_emitter =
             EventEmitter::new(&ctx.accounts.event_authority, ctx.bumps.event_authority);
+        let lockup_seconds = store.gt_mint_lockup_seconds();
         let gt = store.gt_mut();
-        gt.mint_to(&mut user, amount)?;
+        gt.mint_to(&mut user, amount, lockup_seconds)?;
         event_emitter.emit_cpi(&GtUpdated::rewarded(amount, gt, Some(&user)))?;
         Ok(())
     }
@@ -517,3 +547,161 @@ impl<'info> internal::Authentication<'info> for MintGtReward<'info> {
         &self.store
     }
 }
+
+/// The accounts definition for read-only instructions for GT of a user.
+#[derive(Accounts)]
+pub struct ReadUserGt<'info> {
+    /// Store.
+    #[account(
+        constraint = store.load()?.gt().is_initialized() @ CoreError::PreconditionsAreNotMet,
+    )]
+    pub store: AccountLoader<'info, Store>,
+    /// User Account.
+    #[account(
+        constraint = user.load()?.is_initialized() @ CoreError::InvalidUserAccount,
+        has_one = store,
+    )]
+    pub user: AccountLoader<'info, UserHeader>,
+}
+
+/// Get the amount of GT required for the user to reach the next rank.
+pub(crate) fn get_gt_to_next_rank(ctx: Context<ReadUserGt>) -> Result<Option<u64>> {
+    let store = ctx.accounts.store.load()?;
+    let user = ctx.accounts.user.load()?;
+    Ok(store.gt().amount_to_next_rank(user.gt().amount()))
+}
+
+/// Rank of a GT user.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GtUserRank {
+    /// GT balance of the user.
+    pub amount: u64,
+    /// Current rank.
+    pub rank: u8,
+    /// The lower threshold of the current rank.
+    pub rank_threshold: u64,
+    /// The threshold of the next rank. `None` if the user is already at the top rank.
+    pub next_rank_threshold: Option<u64>,
+}
+
+/// Get the current rank and the rank thresholds of the user.
+pub(crate) fn get_user_rank(ctx: Context<ReadUserGt>) -> Result<GtUserRank> {
+    let store = ctx.accounts.store.load()?;
+    let user = ctx.accounts.user.load()?;
+    let gt = store.gt();
+    let amount = user.gt().amount();
+    let rank = gt.rank_of(amount);
+    Ok(GtUserRank {
+        amount,
+        rank,
+        rank_threshold: gt
+            .rank_threshold(rank)
+            .ok_or_else(|| error!(CoreError::Internal))?,
+        next_rank_threshold: gt.rank_threshold(rank.saturating_add(1)),
+    })
+}
+
+/// GT state summary.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GtStateSummary {
+    /// GT decimals.
+    pub decimals: u8,
+    /// Current minting cost.
+    pub minting_cost: u128,
+    /// Minting cost grow factor.
+    pub minting_cost_grow_factor: u128,
+    /// Grow step amount.
+    pub grow_step_amount: u64,
+    /// Number of grow steps passed.
+    pub grow_steps: u64,
+    /// Whether the minting cost is rounded up when it grows.
+    pub is_minting_cost_grow_round_up: bool,
+    /// Rank thresholds.
+    pub ranks: Vec<u64>,
+    /// Time window for GT exchange.
+    pub exchange_time_window: u32,
+    /// Total minted GT.
+    pub total_minted: u64,
+    /// Supply of buybackable GT.
+    pub supply: u64,
+}
+
+/// Get the GT state summary of the store.
+pub(crate) fn get_gt_state(ctx: Context<ReadStore>) -> Result<GtStateSummary> {
+    let store = ctx.accounts.store.load()?;
+    let gt = store.gt();
+    require!(gt.is_initialized(), CoreError::PreconditionsAreNotMet);
+    Ok(GtStateSummary {
+        decimals: gt.decimals(),
+        minting_cost: gt.minting_cost(),
+        minting_cost_grow_factor: gt.minting_cost_grow_factor(),
+        grow_step_amount: gt.grow_step_amount(),
+        grow_steps: gt.grow_steps(),
+        is_minting_cost_grow_round_up: gt.is_minting_cost_grow_round_up(),
+        ranks: gt.ranks().to_vec(),
+        exchange_time_window: gt.exchange_time_window(),
+        total_minted: gt.total_minted(),
+        supply: gt.supply(),
+    })
+}
+
+/// GT staker rewards of a user.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GtStakerRewards {
+    /// Total staker reward value recorded for GT holders.
+    pub total_distributed_value: u128,
+    /// Cumulative staker reward value per unit GT.
+    pub value_per_gt: u128,
+    /// The staker reward value accumulated by the user, including the unsettled part.
+    pub user_accumulated_value: u128,
+}
+
+/// Get the accumulated GT staker rewards of the user.
+pub(crate) fn get_gt_staker_rewards(ctx: Context<ReadUserGt>) -> Result<GtStakerRewards> {
+    let store = ctx.accounts.store.load()?;
+    let user = ctx.accounts.user.load()?;
+    let gt = store.gt();
+    let user_accumulated_value = user
+        .gt()
+        .accrued_staker_reward_value()
+        .checked_add(gt.pending_staker_reward(&user)?)
+        .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+    Ok(GtStakerRewards {
+        total_distributed_value: gt.total_staker_reward_value(),
+        value_per_gt: gt.staker_reward_value_per_gt(),
+        user_accumulated_value,
+    })
+}
+
+/// The accounts definition for read-only instructions for GT exchange vaults.
+#[derive(Accounts)]
+pub struct ReadGtExchangeVault<'info> {
+    /// Store.
+    #[account(
+        constraint = store.load()?.gt().is_initialized() @ CoreError::PreconditionsAreNotMet,
+    )]
+    pub store: AccountLoader<'info, Store>,
+    /// GT exchange vault.
+    #[account(
+        constraint = vault.load()?.is_initialized() @ CoreError::InvalidArgument,
+        has_one = store,
+    )]
+    pub vault: AccountLoader<'info, GtExchangeVault>,
+}
+
+/// Get the number of seconds until the GT exchange vault becomes confirmable.
+pub(crate) fn get_gt_vault_confirm_eta(ctx: Context<ReadGtExchangeVault>) -> Result<i64> {
+    let vault = ctx.accounts.vault.load()?;
+    require!(!vault.is_confirmed(), CoreError::PreconditionsAreNotMet);
+    let now = Clock::get()?.unix_timestamp;
+    vault
+        .confirmable_at()?
+        .checked_sub(now)
+        .ok_or_else(|| error!(CoreError::ValueOverflow))
+}
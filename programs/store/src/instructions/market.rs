@@ -3,11 +3,14 @@ use crate::{
     ops::market::MarketTransferOutOperation,
     states::{
         market::{
+            config::{MarketFeeConfig, MarketRiskConfig},
             revertible::{Revertible, RevertibleMarket},
             status::MarketStatus,
             utils::ValidateMarketBalances,
+            MarketAddresses, SimulatedLiquidityMarket,
         },
-        Factor, HasMarketMeta, MaxAgeValidator, Oracle,
+        position::PositionAccruedFees,
+        Factor, HasMarketMeta, MaxAgeValidator, Oracle, Position,
     },
     ModelError,
 };
@@ -15,16 +18,18 @@ use crate::{
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use gmsol_model::{
-    num::Unsigned, price::Prices, BalanceExt, Bank, BaseMarketMut, LiquidityMarketExt,
-    PnlFactorKind, PoolExt,
+    num::Unsigned, price::Prices, Balance, BalanceExt, Bank, BaseMarket, BaseMarketExt,
+    BaseMarketMut, LiquidityMarketExt, LiquidityMarketMutExt, PnlFactorKind, PoolExt,
 };
 use gmsol_utils::InitSpace;
 
 use crate::{
     constants,
+    instructions::ReadStore,
     states::{
-        market::config::{EntryArgs, MarketConfigBuffer},
-        Market, Seed, Store, TokenMapAccess, TokenMapHeader, TokenMapLoader,
+        market::config::{EntryArgs, MarketConfigBuffer, MarketConfigFlag},
+        AmountKey, Market, MarketConfigKey, Seed, Store, TokenMapAccess, TokenMapHeader,
+        TokenMapLoader,
     },
     utils::internal,
     CoreError,
@@ -266,6 +271,25 @@ pub struct MarketTransferIn<'info> {
 pub(crate) fn unchecked_market_transfer_in(
     ctx: Context<MarketTransferIn>,
     amount: u64,
+) -> Result<()> {
+    transfer_in_to_market(ctx, amount, false)
+}
+
+/// Transfer some tokens into the insurance fund of the market.
+///
+/// ## CHECK
+/// - Only MARKET_KEEPER can fund the insurance fund with this method.
+pub(crate) fn unchecked_fund_market_insurance(
+    ctx: Context<MarketTransferIn>,
+    amount: u64,
+) -> Result<()> {
+    transfer_in_to_market(ctx, amount, true)
+}
+
+fn transfer_in_to_market(
+    ctx: Context<MarketTransferIn>,
+    amount: u64,
+    to_insurance_fund: bool,
 ) -> Result<()> {
     use anchor_spl::token;
 
@@ -303,6 +327,13 @@ pub(crate) fn unchecked_market_transfer_in(
         market
             .record_transferred_in_by_token(token, &amount)
             .map_err(ModelError::from)?;
+        if to_insurance_fund {
+            let is_long_token = market
+                .market_meta()
+                .to_token_side(token)
+                .map_err(CoreError::from)?;
+            market.record_insurance_fund_deposit(is_long_token, amount)?;
+        }
         market.commit();
     }
 
@@ -457,6 +488,541 @@ pub(crate) fn get_market_status(
     Ok(status)
 }
 
+/// Check whether ADL is required for the given side of the market.
+pub(crate) fn is_adl_required(
+    ctx: Context<ReadMarket>,
+    prices: &Prices<u128>,
+    is_long: bool,
+) -> Result<bool> {
+    prices.validate().map_err(ModelError::from)?;
+    let market = ctx.accounts.market.load()?;
+    let is_required = market
+        .pnl_factor_exceeded(prices, PnlFactorKind::ForAdl, is_long)
+        .map_err(ModelError::from)?
+        .is_some();
+    Ok(is_required)
+}
+
+/// Get market fee config.
+pub(crate) fn get_market_fee_config(ctx: Context<ReadMarket>) -> Result<MarketFeeConfig> {
+    let market = ctx.accounts.market.load()?;
+    Ok(market.fee_config())
+}
+
+/// Get the tokens the market requires prices for, sorted by address.
+pub(crate) fn get_market_price_tokens(ctx: Context<ReadMarket>) -> Result<Vec<Pubkey>> {
+    let market = ctx.accounts.market.load()?;
+    Ok(market.meta().ordered_tokens().into_iter().collect())
+}
+
+/// Get market liquidation and ADL related config.
+pub(crate) fn get_market_risk_params(ctx: Context<ReadMarket>) -> Result<MarketRiskConfig> {
+    let market = ctx.accounts.market.load()?;
+    Ok(market.risk_config())
+}
+
+/// Insurance fund balances of a market.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarketInsuranceFund {
+    /// Whether the insurance fund is enabled for the market.
+    pub is_enabled: bool,
+    /// Insurance fund amount of long token.
+    pub long_token_amount: u64,
+    /// Insurance fund amount of short token.
+    pub short_token_amount: u64,
+}
+
+/// Get the insurance fund balances of the market.
+pub(crate) fn get_market_insurance_fund(ctx: Context<ReadMarket>) -> Result<MarketInsuranceFund> {
+    let market = ctx.accounts.market.load()?;
+    let state = market.state();
+    Ok(MarketInsuranceFund {
+        is_enabled: market.get_config_flag_by_key(MarketConfigFlag::EnableInsuranceFund),
+        long_token_amount: state.insurance_fund(true),
+        short_token_amount: state.insurance_fund(false),
+    })
+}
+
+/// Borrowing and funding accrual state of a market.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarketAccrualState {
+    /// The timestamp of the last borrowing state update.
+    pub last_borrowing_ts: i64,
+    /// The timestamp of the last funding state update.
+    pub last_funding_ts: i64,
+    /// Seconds passed since the last borrowing state update.
+    pub borrowing_duration_in_seconds: u64,
+    /// Seconds passed since the last funding state update.
+    pub funding_duration_in_seconds: u64,
+    /// Cumulative borrowing factor for long positions if refreshed now.
+    pub next_cumulative_borrowing_factor_for_long: u128,
+    /// Cumulative borrowing factor for short positions if refreshed now.
+    pub next_cumulative_borrowing_factor_for_short: u128,
+    /// Pending borrowing fee value of long positions if refreshed now.
+    pub pending_borrowing_fee_value_for_long: u128,
+    /// Pending borrowing fee value of short positions if refreshed now.
+    pub pending_borrowing_fee_value_for_short: u128,
+    /// Current funding factor per second.
+    pub funding_factor_per_second: i128,
+}
+
+/// Get the borrowing and funding accrual state of the market.
+pub(crate) fn get_market_accrual_state(
+    ctx: Context<ReadMarket>,
+    prices: &Prices<u128>,
+) -> Result<MarketAccrualState> {
+    use gmsol_model::{BorrowingFeeMarket, BorrowingFeeMarketExt, ClockKind, PerpMarket};
+
+    prices.validate().map_err(ModelError::from)?;
+
+    let market = ctx.accounts.market.load()?;
+    let clock = |kind: ClockKind| {
+        market
+            .clock(kind)
+            .ok_or_else(|| error!(CoreError::NotFound))
+    };
+    let last_borrowing_ts = clock(ClockKind::Borrowing)?;
+    let last_funding_ts = clock(ClockKind::Funding)?;
+
+    let borrowing_duration_in_seconds = market
+        .passed_in_seconds_for_borrowing()
+        .map_err(ModelError::from)?;
+    let funding_duration_in_seconds = Clock::get()?
+        .unix_timestamp
+        .saturating_sub(last_funding_ts)
+        .max(0) as u64;
+
+    let next_cumulative_borrowing_factor = |is_long: bool| {
+        market
+            .next_cumulative_borrowing_factor(is_long, prices, borrowing_duration_in_seconds)
+            .map(|(factor, _)| factor)
+            .map_err(ModelError::from)
+    };
+    let pending_borrowing_fee_value = |is_long: bool| {
+        market
+            .total_pending_borrowing_fees(prices, is_long)
+            .map_err(ModelError::from)
+    };
+
+    Ok(MarketAccrualState {
+        last_borrowing_ts,
+        last_funding_ts,
+        borrowing_duration_in_seconds,
+        funding_duration_in_seconds,
+        next_cumulative_borrowing_factor_for_long: next_cumulative_borrowing_factor(true)?,
+        next_cumulative_borrowing_factor_for_short: next_cumulative_borrowing_factor(false)?,
+        pending_borrowing_fee_value_for_long: pending_borrowing_fee_value(true)?,
+        pending_borrowing_fee_value_for_short: pending_borrowing_fee_value(false)?,
+        funding_factor_per_second: *market.funding_factor_per_second(),
+    })
+}
+
+/// Derive the canonical market and market token addresses.
+pub(crate) fn derive_market_address(
+    ctx: Context<ReadStore>,
+    index_token: &Pubkey,
+    long_token: &Pubkey,
+    short_token: &Pubkey,
+) -> Result<MarketAddresses> {
+    let store = ctx.accounts.store.key();
+    let (market_token, _) = Market::find_market_token_address(
+        &store,
+        index_token,
+        long_token,
+        short_token,
+        ctx.program_id,
+    );
+    let (market, _) = Market::find_market_address(&store, &market_token, ctx.program_id);
+    Ok(MarketAddresses {
+        market,
+        market_token,
+    })
+}
+
+/// Claimable fees of a market.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarketClaimableFees {
+    /// Market token.
+    pub market_token: Pubkey,
+    /// Long token.
+    pub long_token: Pubkey,
+    /// Short token.
+    pub short_token: Pubkey,
+    /// Claimable long token amount.
+    pub long_token_amount: u128,
+    /// Claimable short token amount.
+    pub short_token_amount: u128,
+}
+
+/// The fee receiver and claimable fees status of the store.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeReceiverStatus {
+    /// The receiver address.
+    pub receiver: Pubkey,
+    /// The next receiver address.
+    pub next_receiver: Pubkey,
+    /// Claimable fees of the provided markets.
+    pub markets: Vec<MarketClaimableFees>,
+}
+
+/// Get the fee receiver of the store and the claimable fees of the markets
+/// provided in the remaining accounts.
+pub(crate) fn get_fee_receiver_status<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadStore<'info>>,
+) -> Result<FeeReceiverStatus> {
+    let store_address = ctx.accounts.store.key();
+    let (receiver, next_receiver) = {
+        let store = ctx.accounts.store.load()?;
+        (store.receiver(), store.next_receiver())
+    };
+
+    let markets = ctx
+        .remaining_accounts
+        .iter()
+        .map(|info| {
+            let market = AccountLoader::<Market>::try_from(info)?;
+            let market = market.load()?;
+            let meta = market.validated_meta(&store_address)?;
+            let pool = market.claimable_fee_pool().map_err(ModelError::from)?;
+            Ok(MarketClaimableFees {
+                market_token: meta.market_token_mint,
+                long_token: meta.long_token_mint,
+                short_token: meta.short_token_mint,
+                long_token_amount: pool.long_amount().map_err(ModelError::from)?,
+                short_token_amount: pool.short_amount().map_err(ModelError::from)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(FeeReceiverStatus {
+        receiver,
+        next_receiver,
+        markets,
+    })
+}
+
+/// A market that accepts the given token as collateral.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarketForCollateral {
+    /// The address of the market.
+    pub market: Pubkey,
+    /// Market token.
+    pub market_token: Pubkey,
+    /// Whether the token is the long token of the market.
+    pub is_long_token: bool,
+    /// Whether the token is the short token of the market.
+    pub is_short_token: bool,
+}
+
+/// Get the markets provided in the remaining accounts that accept the given token
+/// as collateral.
+///
+/// Disabled markets are skipped.
+pub(crate) fn get_markets_for_collateral<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadStore<'info>>,
+    token: &Pubkey,
+) -> Result<Vec<MarketForCollateral>> {
+    let store_address = ctx.accounts.store.key();
+    let mut markets = Vec::new();
+    for info in ctx.remaining_accounts {
+        let market = AccountLoader::<Market>::try_from(info)?;
+        let market = market.load()?;
+        require_keys_eq!(market.store, store_address, CoreError::StoreMismatched);
+        if !market.is_enabled() {
+            continue;
+        }
+        let meta = market.meta();
+        let is_long_token = meta.long_token_mint == *token;
+        let is_short_token = meta.short_token_mint == *token;
+        if is_long_token || is_short_token {
+            markets.push(MarketForCollateral {
+                market: info.key(),
+                market_token: meta.market_token_mint,
+                is_long_token,
+                is_short_token,
+            });
+        }
+    }
+    Ok(markets)
+}
+
+/// The accounts definition for [`is_market_shiftable`](crate::gmsol_store::is_market_shiftable).
+#[derive(Accounts)]
+pub struct ReadShiftMarkets<'info> {
+    /// Store.
+    pub store: AccountLoader<'info, Store>,
+    /// The market to shift from.
+    #[account(constraint = from_market.load()?.store == store.key() @ CoreError::StoreMismatched)]
+    pub from_market: AccountLoader<'info, Market>,
+    /// The market to shift to.
+    #[account(constraint = to_market.load()?.store == store.key() @ CoreError::StoreMismatched)]
+    pub to_market: AccountLoader<'info, Market>,
+}
+
+/// The reason why a shift between two markets is (not) allowed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MarketShiftableReason {
+    /// The shift is allowed.
+    Shiftable,
+    /// The two markets are the same market.
+    ///
+    /// Corresponds to [`CoreError::InvalidShiftMarkets`].
+    SameMarket,
+    /// The market to shift from is disabled.
+    FromMarketDisabled,
+    /// The market to shift to is disabled.
+    ToMarketDisabled,
+    /// The long tokens or the short tokens of the two markets do not match.
+    ///
+    /// Corresponds to [`CoreError::TokenMintMismatched`].
+    TokenMintMismatched,
+}
+
+/// Shiftability between two markets.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarketShiftability {
+    /// Whether a shift from `from_market` to `to_market` is allowed.
+    pub is_shiftable: bool,
+    /// The reason code.
+    pub reason: MarketShiftableReason,
+}
+
+/// Check whether a shift from `from_market` to `to_market` is allowed.
+///
+/// The checks are the same as those performed when creating a shift.
+pub(crate) fn is_market_shiftable(ctx: Context<ReadShiftMarkets>) -> Result<MarketShiftability> {
+    let accounts = ctx.accounts;
+    let reason = if accounts.from_market.key() == accounts.to_market.key() {
+        MarketShiftableReason::SameMarket
+    } else {
+        let from_market = accounts.from_market.load()?;
+        let to_market = accounts.to_market.load()?;
+        if !from_market.is_enabled() {
+            MarketShiftableReason::FromMarketDisabled
+        } else if !to_market.is_enabled() {
+            MarketShiftableReason::ToMarketDisabled
+        } else if from_market.validate_shiftable(&to_market).is_err() {
+            MarketShiftableReason::TokenMintMismatched
+        } else {
+            MarketShiftableReason::Shiftable
+        }
+    };
+    Ok(MarketShiftability {
+        is_shiftable: reason == MarketShiftableReason::Shiftable,
+        reason,
+    })
+}
+
+/// The accounts definition for read-only instructions for position.
+#[derive(Accounts)]
+pub struct ReadPosition<'info> {
+    /// Market.
+    pub market: AccountLoader<'info, Market>,
+    /// Position.
+    #[account(
+        constraint = position.load()?.market_token == market.load()?.meta.market_token_mint @ CoreError::MarketTokenMintMismatched,
+    )]
+    pub position: AccountLoader<'info, Position>,
+}
+
+/// Get position accrued fees.
+pub(crate) fn get_position_accrued_fees(
+    ctx: Context<ReadPosition>,
+    prices: &Prices<u128>,
+) -> Result<PositionAccruedFees> {
+    let market = ctx.accounts.market.load()?;
+    let position = ctx.accounts.position.load()?;
+    let fees = PositionAccruedFees::from_position(&position.as_position(&market)?, prices)
+        .map_err(ModelError::from)?;
+    Ok(fees)
+}
+
+/// Cost basis of a position.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositionCostBasis {
+    /// Size in USD.
+    pub size_in_usd: u128,
+    /// Size in tokens.
+    pub size_in_tokens: u128,
+    /// The volume-weighted average entry price (unit price).
+    pub average_entry_price: u128,
+    /// The cost basis value of the current size, including the fees paid on increases.
+    pub cost_basis_value: u128,
+}
+
+/// Get the entry price and cost basis of a position.
+pub(crate) fn get_position_cost_basis(ctx: Context<ReadPosition>) -> Result<PositionCostBasis> {
+    let position = ctx.accounts.position.load()?;
+    Ok(PositionCostBasis {
+        size_in_usd: position.state.size_in_usd,
+        size_in_tokens: position.state.size_in_tokens,
+        average_entry_price: position.average_entry_price(),
+        cost_basis_value: position.cost_basis_value,
+    })
+}
+
+/// Max safe decrease of a position.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxSafeDecrease {
+    /// The current size of the position in USD, which is the size delta to fully close it.
+    pub size_in_usd: u128,
+    /// The largest size delta in USD that keeps the position open and valid.
+    ///
+    /// `0` means that no partial decrease is safe, and the position can only be fully closed.
+    pub max_partial_size_delta_usd: u128,
+}
+
+/// Get the max size delta that can be decreased without closing the position.
+///
+/// The same checks as the partial close check of the decrease model are applied,
+/// assuming that no collateral is withdrawn.
+pub(crate) fn get_max_safe_decrease(
+    ctx: Context<ReadPosition>,
+    prices: &Prices<u128>,
+) -> Result<MaxSafeDecrease> {
+    use gmsol_model::{num::MulDiv, PerpMarket, PositionExt, PositionState};
+
+    prices.validate().map_err(ModelError::from)?;
+
+    let market = ctx.accounts.market.load()?;
+    let position = ctx.accounts.position.load()?;
+    let position = position.as_position(&market)?;
+
+    let size_in_usd = *position.size_in_usd();
+    let params = market.position_params().map_err(ModelError::from)?;
+    let min_collateral_value = i128::try_from(*params.min_collateral_value())
+        .map_err(|_| error!(CoreError::ValueOverflow))?;
+    let collateral_value = i128::try_from(
+        position
+            .collateral_value(prices)
+            .map_err(ModelError::from)?,
+    )
+    .map_err(|_| error!(CoreError::ValueOverflow))?;
+    let (estimated_pnl, _, _) = position
+        .pnl_value(prices, &size_in_usd)
+        .map_err(ModelError::from)?;
+
+    // Returns whether the position remains valid after decreasing by `size_delta_usd`.
+    let is_safe = |size_delta_usd: u128| -> Result<bool> {
+        let estimated_realized_pnl = size_delta_usd
+            .checked_mul_div_with_signed_numerator(&estimated_pnl, &size_in_usd)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        let estimated_remaining_pnl = estimated_pnl
+            .checked_sub(estimated_realized_pnl)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        let remaining_collateral_value = collateral_value
+            .checked_add(estimated_realized_pnl.min(0))
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        let remaining_value = remaining_collateral_value
+            .checked_add(estimated_remaining_pnl)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        Ok(remaining_value >= min_collateral_value)
+    };
+
+    // The remaining value is non-increasing in the size delta, so binary search can be used.
+    let mut max_partial_size_delta_usd = 0;
+    if let Some(upper) = size_in_usd.checked_sub(*params.min_position_size_usd()) {
+        if upper != 0 && is_safe(1)? {
+            let (mut low, mut high) = (1, upper);
+            while low < high {
+                let mid = low + (high - low).div_ceil(2);
+                if is_safe(mid)? {
+                    low = mid;
+                } else {
+                    high = mid - 1;
+                }
+            }
+            max_partial_size_delta_usd = low;
+        }
+    }
+
+    Ok(MaxSafeDecrease {
+        size_in_usd,
+        max_partial_size_delta_usd,
+    })
+}
+
+/// Margin metrics of a position.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositionMargin {
+    /// Size in USD.
+    pub size_in_usd: u128,
+    /// The remaining collateral value after closing the whole position, which is the value
+    /// used to determine whether the position is liquidatable. Liquidation fees are not included.
+    pub remaining_collateral_value: i128,
+    /// The current margin ratio, i.e., the remaining collateral value divided by the size
+    /// in USD, as a factor. `0` if the position is empty.
+    pub margin_ratio: i128,
+    /// The maintenance margin ratio (min collateral factor), as a factor.
+    pub maintenance_margin_ratio: u128,
+    /// The min collateral value required.
+    pub min_collateral_value: u128,
+    /// Whether the position is liquidatable at the given prices.
+    pub is_liquidatable: bool,
+}
+
+/// Get the current margin ratio and the maintenance margin ratio of a position.
+///
+/// The same maintenance margin model as the liquidation check is used.
+pub(crate) fn get_position_margin(
+    ctx: Context<ReadPosition>,
+    prices: &Prices<u128>,
+) -> Result<PositionMargin> {
+    use gmsol_model::{num::MulDiv, PerpMarket, PositionExt, PositionState};
+
+    prices.validate().map_err(ModelError::from)?;
+
+    let market = ctx.accounts.market.load()?;
+    let position = ctx.accounts.position.load()?;
+    let position = position.as_position(&market)?;
+
+    let size_in_usd = *position.size_in_usd();
+    let params = market.position_params().map_err(ModelError::from)?;
+    let remaining_collateral_value = position
+        .remaining_collateral_value_for_liquidation(prices)
+        .map_err(ModelError::from)?;
+    let margin_ratio = if size_in_usd == 0 {
+        0
+    } else {
+        constants::MARKET_USD_UNIT
+            .checked_mul_div_with_signed_numerator(&remaining_collateral_value, &size_in_usd)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?
+    };
+    let is_liquidatable = position
+        .check_liquidatable(prices, true)
+        .map_err(ModelError::from)?
+        .is_some();
+
+    Ok(PositionMargin {
+        size_in_usd,
+        remaining_collateral_value,
+        margin_ratio,
+        maintenance_margin_ratio: *params.min_collateral_factor(),
+        min_collateral_value: *params.min_collateral_value(),
+        is_liquidatable,
+    })
+}
+
 /// The accounts definition for read-only instructions for market.
 #[derive(Accounts)]
 pub struct ReadMarketWithToken<'info> {
@@ -484,6 +1050,141 @@ pub(crate) fn get_market_token_price(
     Ok(price)
 }
 
+/// Deposit preview.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DepositPreview {
+    /// The long token amount required.
+    pub long_token_amount: u64,
+    /// The short token amount required.
+    pub short_token_amount: u64,
+    /// The expected market token amount to mint.
+    pub market_token_amount: u64,
+}
+
+/// Get the deposit preview for the given USD values.
+pub(crate) fn get_deposit_preview(
+    ctx: Context<ReadMarketWithToken>,
+    prices: &Prices<u128>,
+    long_token_value: u128,
+    short_token_value: u128,
+) -> Result<DepositPreview> {
+    use gmsol_model::MarketAction;
+
+    require!(
+        long_token_value != 0 || short_token_value != 0,
+        CoreError::EmptyDeposit
+    );
+    prices.validate().map_err(ModelError::from)?;
+
+    let market = ctx.accounts.market.load()?;
+    let min_fee_value = *market
+        .get_config_by_key(MarketConfigKey::MinDepositFeeUsd)
+        .ok_or_else(|| error!(CoreError::Unimplemented))?;
+
+    // Round up so that the deposited tokens are worth at least the given values.
+    let long_token_amount = long_token_value.div_ceil(*prices.long_token_price.pick_price(false));
+    let short_token_amount =
+        short_token_value.div_ceil(*prices.short_token_price.pick_price(false));
+
+    let mut simulated = SimulatedLiquidityMarket::try_new(&market, &ctx.accounts.market_token)
+        .map_err(ModelError::from)?;
+    let report = simulated
+        .deposit(long_token_amount, short_token_amount, *prices)
+        .and_then(|d| d.with_min_fee_value(min_fee_value).execute())
+        .map_err(ModelError::from)?;
+
+    Ok(DepositPreview {
+        long_token_amount: long_token_amount
+            .try_into()
+            .map_err(|_| error!(CoreError::TokenAmountOverflow))?,
+        short_token_amount: short_token_amount
+            .try_into()
+            .map_err(|_| error!(CoreError::TokenAmountOverflow))?,
+        market_token_amount: (*report.minted())
+            .try_into()
+            .map_err(|_| error!(CoreError::TokenAmountOverflow))?,
+    })
+}
+
+/// Market token price change.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarketTokenPriceChange {
+    /// The market token price before the mint or burn.
+    pub price_before: u128,
+    /// The market token price after the mint or burn.
+    pub price_after: u128,
+    /// The amount of market tokens minted or burned.
+    pub market_token_amount: u64,
+}
+
+/// Preview the market token price change caused by a mint (deposit) or a burn (withdrawal).
+///
+/// A deposit of the given long and short token amounts is simulated if any of them
+/// is non-zero, otherwise a withdrawal of `burn_amount` market tokens is simulated.
+pub(crate) fn preview_market_token_price_change(
+    ctx: Context<ReadMarketWithToken>,
+    prices: &Prices<u128>,
+    long_token_amount: u64,
+    short_token_amount: u64,
+    burn_amount: u64,
+    maximize: bool,
+) -> Result<MarketTokenPriceChange> {
+    use gmsol_model::MarketAction;
+
+    let is_mint = long_token_amount != 0 || short_token_amount != 0;
+    require!(is_mint != (burn_amount != 0), CoreError::InvalidArgument);
+    prices.validate().map_err(ModelError::from)?;
+
+    let market = ctx.accounts.market.load()?;
+    let pnl_factor = if is_mint {
+        PnlFactorKind::MaxAfterDeposit
+    } else {
+        PnlFactorKind::MaxAfterWithdrawal
+    };
+
+    let mut simulated = SimulatedLiquidityMarket::try_new(&market, &ctx.accounts.market_token)
+        .map_err(ModelError::from)?;
+    let price_before = simulated
+        .market_token_price(prices, pnl_factor, maximize)
+        .map_err(ModelError::from)?;
+
+    let market_token_amount = if is_mint {
+        let min_fee_value = *market
+            .get_config_by_key(MarketConfigKey::MinDepositFeeUsd)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?;
+        let report = simulated
+            .deposit(long_token_amount.into(), short_token_amount.into(), *prices)
+            .and_then(|d| d.with_min_fee_value(min_fee_value).execute())
+            .map_err(ModelError::from)?;
+        *report.minted()
+    } else {
+        let min_fee_value = *market
+            .get_config_by_key(MarketConfigKey::MinWithdrawalFeeUsd)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?;
+        let report = simulated
+            .withdraw(burn_amount.into(), *prices)
+            .and_then(|w| w.with_min_fee_value(min_fee_value).execute())
+            .map_err(ModelError::from)?;
+        *report.params().market_token_amount()
+    };
+
+    let price_after = simulated
+        .market_token_price(prices, pnl_factor, maximize)
+        .map_err(ModelError::from)?;
+
+    Ok(MarketTokenPriceChange {
+        price_before,
+        price_after,
+        market_token_amount: market_token_amount
+            .try_into()
+            .map_err(|_| error!(CoreError::TokenAmountOverflow))?,
+    })
+}
+
 /// The accounts definition for [`initialize_market_config_buffer`](crate::gmsol_store::initialize_market_config_buffer).
 ///
 /// *[See also the documentation for the instruction.](crate::gmsol_store::initialize_market_config_buffer)*
@@ -506,6 +1207,28 @@ pub(crate) fn initialize_market_config_buffer(
     ctx: Context<InitializeMarketConfigBuffer>,
     expire_after_secs: u32,
 ) -> Result<()> {
+    {
+        let store = ctx.accounts.store.load()?;
+        let expire_after_secs = u64::from(expire_after_secs);
+        let min = *store
+            .get_amount_by_key(AmountKey::MinMarketConfigBufferExpireAfter)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?;
+        let max = *store
+            .get_amount_by_key(AmountKey::MaxMarketConfigBufferExpireAfter)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?;
+        require_gte!(
+            expire_after_secs,
+            min,
+            CoreError::MarketConfigBufferExpiryOutOfRange
+        );
+        if max != 0 {
+            require_gte!(
+                max,
+                expire_after_secs,
+                CoreError::MarketConfigBufferExpiryOutOfRange
+            );
+        }
+    }
     let buffer = &mut ctx.accounts.buffer;
     buffer.authority = ctx.accounts.authority.key();
     buffer.store = ctx.accounts.store.key();
@@ -800,12 +1523,13 @@ impl<'info> GetMarketTokenValue<'info> {
         amount: u64,
         pnl_factor: PnlFactorKind,
         maximize: bool,
-        max_age: u32,
+        max_age: Option<u32>,
         emit_event: bool,
     ) -> Result<u128> {
         let accounts = ctx.accounts;
         let event_authority_bump = ctx.bumps.event_authority;
         accounts.validate()?;
+        let max_age = accounts.store.load()?.oracle_staleness(max_age)?;
         accounts.evaluate(
             amount,
             pnl_factor,
@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
 use gmsol_chainlink_datastreams::interface::ChainlinkDataStreamsInterface;
 use gmsol_utils::InitSpace;
+use pyth_solana_receiver_sdk::price_update::{PriceUpdateV2, VerificationLevel};
 
 use crate::{
-    states::{AmountKey, PriceFeed, PriceFeedPrice, PriceProviderKind, Seed, Store},
+    constants,
+    states::{
+        oracle::pyth::Pyth, AmountKey, FactorKey, PriceFeed, PriceFeedCounter, PriceFeedPrice,
+        PriceProviderKind, Seed, Store,
+    },
     utils::internal,
     CoreError,
 };
@@ -33,6 +38,15 @@ pub struct InitializePriceFeed<'info> {
         bump,
     )]
     pub price_feed: AccountLoader<'info, PriceFeed>,
+    /// The counter of the custom price feeds of the token.
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PriceFeedCounter::INIT_SPACE,
+        seeds = [PriceFeedCounter::SEED, store.key().as_ref(), token.as_ref()],
+        bump,
+    )]
+    pub price_feed_counter: AccountLoader<'info, PriceFeedCounter>,
     /// The system program.
     pub system_program: Program<'info, System>,
 }
@@ -46,9 +60,40 @@ pub(crate) fn unchecked_initialize_price_feed(
     feed_id: &Pubkey,
 ) -> Result<()> {
     require!(
-        matches!(provider, PriceProviderKind::ChainlinkDataStreams),
+        matches!(
+            provider,
+            PriceProviderKind::ChainlinkDataStreams | PriceProviderKind::Pyth
+        ),
         CoreError::NotSupportedCustomPriceProvider
     );
+    {
+        let store = ctx.accounts.store.key();
+        match ctx.accounts.price_feed_counter.load_init() {
+            Ok(mut counter) => {
+                counter.init(ctx.bumps.price_feed_counter, &store, token);
+            }
+            Err(Error::AnchorError(err)) => {
+                if err.error_code_number != ErrorCode::AccountDiscriminatorAlreadySet as u32 {
+                    return Err(Error::AnchorError(err));
+                }
+            }
+            Err(err) => {
+                return Err(err);
+            }
+        }
+        ctx.accounts.price_feed_counter.exit(&crate::ID)?;
+
+        let max_feeds = *ctx
+            .accounts
+            .store
+            .load()?
+            .get_amount_by_key(AmountKey::MaxCustomPriceFeedsPerToken)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?;
+        let mut counter = ctx.accounts.price_feed_counter.load_mut()?;
+        require_keys_eq!(counter.store, store, CoreError::InvalidArgument);
+        require_keys_eq!(counter.token, *token, CoreError::InvalidArgument);
+        counter.record_new_feed(max_feeds)?;
+    }
     let mut feed = ctx.accounts.price_feed.load_init()?;
     feed.init(
         ctx.bumps.price_feed,
@@ -108,6 +153,23 @@ pub(crate) fn unchecked_update_price_feed_with_chainlink(
         CoreError::InvalidArgument
     );
 
+    {
+        let max_size = match *accounts
+            .store
+            .load()?
+            .get_amount_by_key(AmountKey::MaxChainlinkReportSize)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?
+        {
+            0 => constants::DEFAULT_MAX_CHAINLINK_REPORT_SIZE,
+            size => size,
+        };
+        require_gte!(
+            max_size,
+            compressed_report.len() as u64,
+            CoreError::InvalidPriceReport
+        );
+    }
+
     let price = accounts.decode_and_validate_report(&compressed_report)?;
     accounts.verify_report(compressed_report)?;
 
@@ -191,3 +253,116 @@ impl UpdatePriceFeedWithChainlink<'_> {
         Ok(())
     }
 }
+
+/// The accounts definition for [`update_price_feed_with_pyth`](crate::update_price_feed_with_pyth) instruction.
+#[derive(Accounts)]
+pub struct UpdatePriceFeedWithPyth<'info> {
+    /// Authority.
+    pub authority: Signer<'info>,
+    /// Store.
+    pub store: AccountLoader<'info, Store>,
+    /// Price update account posted by the Pyth receiver program.
+    /// The ownership check of [`Account`] ensures that it has been verified by the receiver program.
+    pub price_update: Account<'info, PriceUpdateV2>,
+    /// Price Feed Account.
+    #[account(mut, has_one = store, has_one = authority)]
+    pub price_feed: AccountLoader<'info, PriceFeed>,
+}
+
+/// CHECK: only PRICE_KEEPER can update custom price feed.
+pub(crate) fn unchecked_update_price_feed_with_pyth(
+    ctx: Context<UpdatePriceFeedWithPyth>,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    let feed_id = {
+        let feed = accounts.price_feed.load()?;
+        require_eq!(
+            feed.provider()?,
+            PriceProviderKind::Pyth,
+            CoreError::InvalidArgument
+        );
+        *feed.feed_id()
+    };
+
+    require!(
+        matches!(
+            accounts.price_update.verification_level,
+            VerificationLevel::Full
+        ),
+        CoreError::InvalidPriceReport
+    );
+
+    let price = accounts
+        .price_update
+        .get_price_unchecked(&feed_id.to_bytes())
+        .map_err(|err| {
+            msg!("[Pyth] get price error: {}", err);
+            error!(CoreError::InvalidPriceReport)
+        })?;
+
+    let (max_confidence_ratio, max_future_excess) = {
+        let store = accounts.store.load()?;
+        (
+            *store
+                .get_factor_by_key(FactorKey::MaxPythConfidenceRatio)
+                .ok_or_else(|| error!(CoreError::Unimplemented))?,
+            *store
+                .get_amount_by_key(AmountKey::OracleMaxFutureTimestampExcess)
+                .ok_or_else(|| error!(CoreError::Unimplemented))?,
+        )
+    };
+
+    let price = Pyth::to_price_feed_price(&price, max_confidence_ratio)?;
+
+    accounts
+        .price_feed
+        .load_mut()?
+        .update(&price, max_future_excess)?;
+
+    Ok(())
+}
+
+impl<'info> internal::Authentication<'info> for UpdatePriceFeedWithPyth<'info> {
+    fn authority(&self) -> &Signer<'info> {
+        &self.authority
+    }
+
+    fn store(&self) -> &AccountLoader<'info, Store> {
+        &self.store
+    }
+}
+
+/// The accounts definition for [`set_price_feed_max_report_age`](crate::set_price_feed_max_report_age) instruction.
+#[derive(Accounts)]
+pub struct SetPriceFeedMaxReportAge<'info> {
+    /// Authority.
+    pub authority: Signer<'info>,
+    /// Store.
+    pub store: AccountLoader<'info, Store>,
+    /// Price Feed Account.
+    #[account(mut, has_one = store)]
+    pub price_feed: AccountLoader<'info, PriceFeed>,
+}
+
+/// CHECK: only MARKET_KEEPER can set the max report age.
+pub(crate) fn unchecked_set_price_feed_max_report_age(
+    ctx: Context<SetPriceFeedMaxReportAge>,
+    max_report_age: u32,
+) -> Result<()> {
+    ctx.accounts
+        .price_feed
+        .load_mut()?
+        .set_max_report_age(max_report_age);
+    Ok(())
+}
+
+impl<'info> internal::Authentication<'info> for SetPriceFeedMaxReportAge<'info> {
+    fn authority(&self) -> &Signer<'info> {
+        &self.authority
+    }
+
+    fn store(&self) -> &AccountLoader<'info, Store> {
+        &self.store
+    }
+}
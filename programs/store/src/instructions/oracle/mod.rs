@@ -4,10 +4,15 @@ pub mod custom;
 use std::ops::Deref;
 
 use anchor_lang::prelude::*;
+use gmsol_utils::price::Decimal;
 
 use crate::{
-    states::{Chainlink, Oracle, PriceValidator, Store, TokenMapHeader, TokenMapLoader},
+    states::{
+        get_validated_price, Chainlink, Oracle, PriceValidator, Store, TokenMapAccess,
+        TokenMapHeader, TokenMapLoader,
+    },
     utils::internal,
+    CoreError,
 };
 
 pub use self::custom::*;
@@ -104,12 +109,26 @@ pub(crate) fn unchecked_set_prices_from_price_feed<'info>(
     ctx: Context<'_, '_, 'info, 'info, SetPricesFromPriceFeed<'info>>,
     tokens: Vec<Pubkey>,
 ) -> Result<()> {
-    let validator = PriceValidator::try_from(ctx.accounts.store.load()?.deref())?;
+    let validator = {
+        let store = ctx.accounts.store.load()?;
+        require_gte!(
+            store.max_tokens_per_price_set(),
+            tokens.len(),
+            CoreError::ExceedMaxLengthLimit
+        );
+        PriceValidator::try_from(store.deref())?
+    };
     let token_map = ctx.accounts.token_map.load_token_map()?;
     ctx.accounts
         .oracle
         .load_mut()?
-        .set_prices_from_remaining_accounts(validator, &token_map, &tokens, ctx.remaining_accounts)
+        .set_prices_from_remaining_accounts(
+            validator,
+            &token_map,
+            &tokens,
+            ctx.remaining_accounts,
+        )?;
+    Ok(())
 }
 
 impl<'info> internal::Authentication<'info> for SetPricesFromPriceFeed<'info> {
@@ -121,3 +140,101 @@ impl<'info> internal::Authentication<'info> for SetPricesFromPriceFeed<'info> {
         &self.store
     }
 }
+
+/// The accounts definition for [`get_effective_token_price`](crate::gmsol_store::get_effective_token_price)
+/// and [`get_display_price`](crate::gmsol_store::get_display_price).
+///
+/// Remaining accounts expected by this instruction:
+///
+///   - 0. `[]` The feed account of the expected provider (or the fallback provider) of the token.
+///   - 1. `[]` (optional) The stale feed account of the expected provider, if the feed above is of the fallback provider.
+#[derive(Accounts)]
+pub struct ReadTokenPrice<'info> {
+    /// Store.
+    #[account(has_one = token_map)]
+    pub store: AccountLoader<'info, Store>,
+    /// Token map.
+    #[account(has_one = store)]
+    pub token_map: AccountLoader<'info, TokenMapHeader>,
+}
+
+/// The price of a token that would be used by the oracle.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EffectiveTokenPrice {
+    /// The index of the provider supplying the price, see [`PriceProviderKind`](crate::states::PriceProviderKind).
+    pub provider: u8,
+    /// Min unit price.
+    pub min_price: u128,
+    /// Max unit price.
+    pub max_price: u128,
+    /// Oracle timestamp of the price.
+    pub oracle_ts: i64,
+    /// Oracle slot of the price.
+    pub oracle_slot: u64,
+}
+
+/// Get the price of the token that would be used by the oracle.
+pub(crate) fn get_effective_token_price<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadTokenPrice<'info>>,
+    token: &Pubkey,
+) -> Result<EffectiveTokenPrice> {
+    let store = ctx.accounts.store.load()?;
+    let token_map = ctx.accounts.token_map.load_token_map()?;
+    let token_config = token_map
+        .get(token)
+        .ok_or_else(|| error!(CoreError::NotFound))?;
+    let price = get_validated_price(&store, token_config, ctx.remaining_accounts)?;
+    Ok(EffectiveTokenPrice {
+        provider: price.provider as u8,
+        min_price: price.price.min.to_unit_price(),
+        max_price: price.price.max.to_unit_price(),
+        oracle_ts: price.oracle_ts,
+        oracle_slot: price.oracle_slot,
+    })
+}
+
+/// The price of a token scaled for display.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayPrice {
+    /// Min price of one whole token.
+    pub min_price: u128,
+    /// Max price of one whole token.
+    pub max_price: u128,
+    /// The number of decimals of the prices, i.e. the precision of the token.
+    pub decimals: u8,
+}
+
+/// Get the price of one whole token, with the decimals to be the precision of the token.
+pub(crate) fn get_display_price<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadTokenPrice<'info>>,
+    token: &Pubkey,
+) -> Result<DisplayPrice> {
+    let store = ctx.accounts.store.load()?;
+    let token_map = ctx.accounts.token_map.load_token_map()?;
+    let token_config = token_map
+        .get(token)
+        .ok_or_else(|| error!(CoreError::NotFound))?;
+    let price = get_validated_price(&store, token_config, ctx.remaining_accounts)?;
+
+    let token_decimals = token_config.token_decimals();
+    let precision = token_config.precision();
+    require_gte!(
+        Decimal::MAX_DECIMALS,
+        token_decimals.saturating_add(precision),
+        CoreError::InvalidArgument
+    );
+    // The unit price is the price of the smallest unit of the token with `MAX_DECIMALS` decimals,
+    // so the price of one whole token with `precision` decimals is given by
+    // `unit_price * 10^token_decimals / 10^(MAX_DECIMALS - precision)`.
+    let divisor = 10u128.pow((Decimal::MAX_DECIMALS - token_decimals - precision) as u32);
+
+    Ok(DisplayPrice {
+        min_price: price.price.min.to_unit_price() / divisor,
+        max_price: price.price.max.to_unit_price() / divisor,
+        decimals: precision,
+    })
+}
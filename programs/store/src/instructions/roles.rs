@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{states::Store, utils::internal};
+use crate::{events::AllRolesRevoked, states::Store, utils::internal};
 
 /// The accounts definition for [`check_admin`](crate::gmsol_store::check_admin)
 /// and [`check_role`](crate::gmsol_store::check_role).
@@ -28,8 +28,9 @@ pub(crate) fn check_role(ctx: Context<CheckRole>, role: String) -> Result<bool>
         .has_role(ctx.accounts.authority.key, &role)
 }
 
-/// The accounts definition for [`has_admin`](crate::gmsol_store::has_admin)
-/// and [`has_role`](crate::gmsol_store::has_role).
+/// The accounts definition for [`has_admin`](crate::gmsol_store::has_admin),
+/// [`has_role`](crate::gmsol_store::has_role), [`list_roles`](crate::gmsol_store::list_roles)
+/// and [`list_role_members`](crate::gmsol_store::list_role_members).
 #[derive(Accounts)]
 pub struct HasRole<'info> {
     /// The store account in which the role is defined.
@@ -46,6 +47,22 @@ pub fn has_role(ctx: Context<HasRole>, authority: Pubkey, role: String) -> Resul
     ctx.accounts.store.load()?.has_role(&authority, &role)
 }
 
+/// Get the names of all enabled roles in the given `store`.
+pub(crate) fn list_roles(ctx: Context<HasRole>) -> Result<Vec<String>> {
+    ctx.accounts
+        .store
+        .load()?
+        .role()
+        .enabled_roles()
+        .map(|role| role.map(ToString::to_string))
+        .collect()
+}
+
+/// Get the members that have the given role in the given `store`.
+pub(crate) fn list_role_members(ctx: Context<HasRole>, role: String) -> Result<Vec<Pubkey>> {
+    ctx.accounts.store.load()?.role().role_members(&role)
+}
+
 /// The accounts definition for [`enable_role`](crate::gmsol_store::enable_role).
 ///
 /// *[See also the documentation for the instruction.](crate::gmsol_store::enable_role).*
@@ -126,8 +143,12 @@ pub(crate) fn unchecked_grant_role(
     ctx: Context<GrantRole>,
     user: Pubkey,
     role: String,
+    expires_at: Option<i64>,
 ) -> Result<()> {
-    ctx.accounts.store.load_mut()?.grant(&user, &role)
+    ctx.accounts
+        .store
+        .load_mut()?
+        .grant_with_expiry(&user, &role, expires_at)
 }
 
 impl<'info> internal::Authentication<'info> for GrantRole<'info> {
@@ -173,3 +194,52 @@ impl<'info> internal::Authentication<'info> for RevokeRole<'info> {
         &self.store
     }
 }
+
+/// The accounts definition for [`revoke_all_roles`](crate::gmsol_store::revoke_all_roles).
+///
+/// *[See also the documentation for the instruction.](crate::gmsol_store::revoke_all_roles).*
+#[derive(Accounts)]
+pub struct RevokeAllRoles<'info> {
+    /// The caller of this instruction.
+    pub authority: Signer<'info>,
+    /// The store account from which the roles are to be revoked.
+    #[account(mut)]
+    pub store: AccountLoader<'info, Store>,
+}
+
+/// Revoke all roles from the user.
+///
+/// # CHECK
+/// - This instruction can only be called by the `ADMIN`.
+pub(crate) fn unchecked_revoke_all_roles(ctx: Context<RevokeAllRoles>, user: Pubkey) -> Result<()> {
+    let roles = ctx.accounts.store.load_mut()?.revoke_all(&user)?;
+    emit!(AllRolesRevoked::new(ctx.accounts.store.key(), user, roles)?);
+    Ok(())
+}
+
+impl<'info> internal::Authentication<'info> for RevokeAllRoles<'info> {
+    fn authority(&self) -> &Signer<'info> {
+        &self.authority
+    }
+
+    fn store(&self) -> &AccountLoader<'info, Store> {
+        &self.store
+    }
+}
+
+/// The accounts definition for [`prune_expired_roles`](crate::gmsol_store::prune_expired_roles).
+///
+/// *[See also the documentation for the instruction.](crate::gmsol_store::prune_expired_roles).*
+#[derive(Accounts)]
+pub struct PruneExpiredRoles<'info> {
+    /// The store account from which the expired roles are to be pruned.
+    #[account(mut)]
+    pub store: AccountLoader<'info, Store>,
+}
+
+/// Revoke all expired time-limited role grants.
+pub(crate) fn prune_expired_roles(ctx: Context<PruneExpiredRoles>) -> Result<()> {
+    let pruned = ctx.accounts.store.load_mut()?.prune_expired_roles()?;
+    msg!("[Role] pruned {} expired role grants", pruned);
+    Ok(())
+}
@@ -151,6 +151,39 @@ impl<'info> internal::Authentication<'info> for TransferStoreAuthority<'info> {
     }
 }
 
+/// The accounts definition for
+/// [`cancel_store_authority_transfer`](crate::gmsol_store::cancel_store_authority_transfer).
+#[derive(Accounts)]
+pub struct CancelStoreAuthorityTransfer<'info> {
+    /// The caller of this instruction.
+    pub authority: Signer<'info>,
+    /// The store account whose pending authority transfer is to be cancelled.
+    #[account(mut)]
+    pub store: AccountLoader<'info, Store>,
+}
+
+/// Cancel the pending transfer of the authority of the store.
+///
+/// ## CHECK
+/// - Only ADMIN can execute this instruction.
+pub(crate) fn unchecked_cancel_store_authority_transfer(
+    ctx: Context<CancelStoreAuthorityTransfer>,
+) -> Result<()> {
+    ctx.accounts.store.load_mut()?.cancel_next_authority()?;
+    msg!("[Store] the pending authority transfer has been cancelled");
+    Ok(())
+}
+
+impl<'info> internal::Authentication<'info> for CancelStoreAuthorityTransfer<'info> {
+    fn authority(&self) -> &Signer<'info> {
+        &self.authority
+    }
+
+    fn store(&self) -> &AccountLoader<'info, Store> {
+        &self.store
+    }
+}
+
 /// The accounts definition for
 /// [`accept_store_authority`](crate::gmsol_store::accept_store_authority).
 #[derive(Accounts)]
@@ -273,3 +306,29 @@ pub(crate) fn _get_token_map(ctx: Context<ReadStore>) -> Result<Option<Pubkey>>
         .token_map()
         .copied())
 }
+
+/// Metadata of a store.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StoreInfo {
+    /// The admin (authority) of the store.
+    pub admin: Pubkey,
+    /// The key used to derive the store address.
+    pub key: String,
+    /// The claimable fee receiver.
+    pub receiver: Pubkey,
+    /// The token map address, if set.
+    pub token_map: Option<Pubkey>,
+}
+
+/// Get the metadata of the store.
+pub(crate) fn get_store_info(ctx: Context<ReadStore>) -> Result<StoreInfo> {
+    let store = ctx.accounts.store.load()?;
+    Ok(StoreInfo {
+        admin: store.authority,
+        key: store.key()?.to_string(),
+        receiver: store.receiver(),
+        token_map: store.token_map().copied(),
+    })
+}
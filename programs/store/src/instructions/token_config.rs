@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
-use gmsol_utils::token_config::TokenConfigFlag;
+use gmsol_utils::{price::Decimal, token_config::TokenConfigFlag};
 
 use crate::{
     states::{
@@ -194,6 +194,34 @@ impl ToggleTokenConfig<'_> {
             .set_flag(flag, enable);
         Ok(())
     }
+
+    /// Toggle the configs for the given tokens.
+    ///
+    /// ## CHECK
+    /// - Only [`MARKET_KEEPER`](crate::states::RoleKey::MARKET_KEEPER) can perform this action.
+    pub(crate) fn invoke_batch_unchecked(
+        ctx: Context<Self>,
+        toggles: &[TokenConfigToggle],
+    ) -> Result<()> {
+        require!(!toggles.is_empty(), CoreError::InvalidArgument);
+        let mut map = ctx.accounts.token_map.load_token_map_mut()?;
+        for toggle in toggles {
+            map.get_mut(&toggle.token)
+                .ok_or_else(|| error!(CoreError::NotFound))?
+                .set_flag(TokenConfigFlag::Enabled, toggle.enable);
+        }
+        Ok(())
+    }
+}
+
+/// An entry of [`toggle_token_configs_batch`](crate::gmsol_store::toggle_token_configs_batch).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct TokenConfigToggle {
+    /// The token whose config will be updated.
+    pub token: Pubkey,
+    /// Enable or disable the config.
+    pub enable: bool,
 }
 
 impl<'info> internal::Authentication<'info> for ToggleTokenConfig<'info> {
@@ -240,6 +268,10 @@ pub(crate) fn unchecked_set_expected_provider(
         provider,
         CoreError::PreconditionsAreNotMet
     );
+    require!(
+        config.is_provider_allowed(&provider),
+        CoreError::PriceProviderNotAllowed
+    );
 
     config.set_expected_provider(provider);
     Ok(())
@@ -292,9 +324,16 @@ impl SetFeedConfig<'_> {
         );
 
         let mut map = ctx.accounts.token_map.load_token_map_mut()?;
-        let feed_config = map
+        let config = map
             .get_mut(&token)
-            .ok_or_else(|| error!(CoreError::NotFound))?
+            .ok_or_else(|| error!(CoreError::NotFound))?;
+
+        require!(
+            config.is_provider_allowed(provider),
+            CoreError::PriceProviderNotAllowed
+        );
+
+        let feed_config = config
             .get_feed_config_mut(provider)
             .map_err(CoreError::from)
             .map_err(|err| error!(err))?;
@@ -306,6 +345,10 @@ impl SetFeedConfig<'_> {
         }
 
         if let Some(timestamp_adjustment) = timestamp_adjustment {
+            ctx.accounts
+                .store
+                .load()?
+                .validate_timestamp_adjustment(timestamp_adjustment)?;
             new_config = new_config.with_timestamp_adjustment(timestamp_adjustment);
         }
 
@@ -336,6 +379,181 @@ impl<'info> internal::Authentication<'info> for SetFeedConfig<'info> {
     }
 }
 
+/// The accounts definition for [`set_allowed_providers`](crate::gmsol_store::set_allowed_providers).
+///
+/// [*See also the documentation for the instruction.*](crate::gmsol_store::set_allowed_providers)
+#[derive(Accounts)]
+pub struct SetAllowedProviders<'info> {
+    /// The authority of the instruction.
+    pub authority: Signer<'info>,
+    /// The store that owns the token map.
+    pub store: AccountLoader<'info, Store>,
+    /// The token map to update.
+    #[account(mut, has_one = store)]
+    pub token_map: AccountLoader<'info, TokenMapHeader>,
+}
+
+impl SetAllowedProviders<'_> {
+    /// Set allowed providers for the given token.
+    ///
+    /// ## CHECK
+    /// - Only [`MARKET_KEEPER`](crate::states::RoleKey::MARKET_KEEPER) can perform this action.
+    pub(crate) fn invoke_unchecked(
+        ctx: Context<Self>,
+        token: Pubkey,
+        allowed_providers: u8,
+    ) -> Result<()> {
+        let mut map = ctx.accounts.token_map.load_token_map_mut()?;
+        let config = map
+            .get_mut(&token)
+            .ok_or_else(|| error!(CoreError::NotFound))?;
+
+        config
+            .set_allowed_providers(allowed_providers)
+            .map_err(CoreError::from)
+            .map_err(|err| error!(err))?;
+
+        // The expected provider must remain allowed.
+        let expected_provider = config.expected_provider().map_err(CoreError::from)?;
+        require!(
+            config.is_provider_allowed(&expected_provider),
+            CoreError::PriceProviderNotAllowed
+        );
+
+        // So must the fallback provider.
+        if let Some(fallback_provider) = config.fallback_provider().map_err(CoreError::from)? {
+            require!(
+                config.is_provider_allowed(&fallback_provider),
+                CoreError::PriceProviderNotAllowed
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info> internal::Authentication<'info> for SetAllowedProviders<'info> {
+    fn authority(&self) -> &Signer<'info> {
+        &self.authority
+    }
+
+    fn store(&self) -> &AccountLoader<'info, Store> {
+        &self.store
+    }
+}
+
+/// The accounts definition for [`set_max_price`](crate::gmsol_store::set_max_price).
+///
+/// [*See also the documentation for the instruction.*](crate::gmsol_store::set_max_price)
+#[derive(Accounts)]
+pub struct SetMaxPrice<'info> {
+    /// The authority of the instruction.
+    pub authority: Signer<'info>,
+    /// The store that owns the token map.
+    pub store: AccountLoader<'info, Store>,
+    /// The token map to update.
+    #[account(mut, has_one = store)]
+    pub token_map: AccountLoader<'info, TokenMapHeader>,
+}
+
+impl SetMaxPrice<'_> {
+    /// Set the max price bound for the given token.
+    ///
+    /// ## CHECK
+    /// - Only [`MARKET_KEEPER`](crate::states::RoleKey::MARKET_KEEPER) can perform this action.
+    pub(crate) fn invoke_unchecked(
+        ctx: Context<Self>,
+        token: Pubkey,
+        value: u32,
+        decimal_multiplier: u8,
+    ) -> Result<()> {
+        let mut map = ctx.accounts.token_map.load_token_map_mut()?;
+        let config = map
+            .get_mut(&token)
+            .ok_or_else(|| error!(CoreError::NotFound))?;
+
+        let max_price = (value != 0).then_some(Decimal {
+            value,
+            decimal_multiplier,
+        });
+        config
+            .set_max_price(max_price)
+            .map_err(CoreError::from)
+            .map_err(|err| error!(err))?;
+
+        Ok(())
+    }
+}
+
+impl<'info> internal::Authentication<'info> for SetMaxPrice<'info> {
+    fn authority(&self) -> &Signer<'info> {
+        &self.authority
+    }
+
+    fn store(&self) -> &AccountLoader<'info, Store> {
+        &self.store
+    }
+}
+
+/// The accounts definition for [`set_fallback_provider`](crate::gmsol_store::set_fallback_provider).
+///
+/// [*See also the documentation for the instruction.*](crate::gmsol_store::set_fallback_provider)
+#[derive(Accounts)]
+pub struct SetFallbackProvider<'info> {
+    /// The authority of the instruction.
+    pub authority: Signer<'info>,
+    /// The store that owns the token map.
+    pub store: AccountLoader<'info, Store>,
+    /// The token map to update.
+    #[account(mut, has_one = store)]
+    pub token_map: AccountLoader<'info, TokenMapHeader>,
+}
+
+impl SetFallbackProvider<'_> {
+    /// Set the fallback provider for the given token.
+    ///
+    /// ## CHECK
+    /// - Only [`MARKET_KEEPER`](crate::states::RoleKey::MARKET_KEEPER) can perform this action.
+    pub(crate) fn invoke_unchecked(
+        ctx: Context<Self>,
+        token: Pubkey,
+        provider: Option<PriceProviderKind>,
+    ) -> Result<()> {
+        let mut map = ctx.accounts.token_map.load_token_map_mut()?;
+        let config = map
+            .get_mut(&token)
+            .ok_or_else(|| error!(CoreError::NotFound))?;
+
+        if let Some(provider) = provider.as_ref() {
+            let expected_provider = config.expected_provider().map_err(CoreError::from)?;
+            require_neq!(expected_provider, *provider, CoreError::InvalidArgument);
+            require!(
+                config.is_provider_allowed(provider),
+                CoreError::PriceProviderNotAllowed
+            );
+            // The feed of the fallback provider must have been configured.
+            config
+                .get_feed_config(provider)
+                .map_err(CoreError::from)
+                .map_err(|err| error!(err))?;
+        }
+
+        config.set_fallback_provider(provider);
+
+        Ok(())
+    }
+}
+
+impl<'info> internal::Authentication<'info> for SetFallbackProvider<'info> {
+    fn authority(&self) -> &Signer<'info> {
+        &self.authority
+    }
+
+    fn store(&self) -> &AccountLoader<'info, Store> {
+        &self.store
+    }
+}
+
 /// The accounts definition of the instructions to read token map.
 #[derive(Accounts)]
 pub struct ReadTokenMap<'info> {
@@ -353,6 +571,46 @@ pub(crate) fn is_token_config_enabled(ctx: Context<ReadTokenMap>, token: &Pubkey
         .ok_or_else(|| error!(CoreError::NotFound))
 }
 
+/// Get the tokens whose configs are disabled.
+pub(crate) fn get_disabled_tokens(ctx: Context<ReadTokenMap>) -> Result<Vec<Pubkey>> {
+    let token_map = ctx.accounts.token_map.load_token_map()?;
+    let tokens = token_map
+        .tokens()
+        .filter(|token| {
+            token_map
+                .get(token)
+                .is_some_and(|config| !config.is_enabled())
+        })
+        .collect();
+    Ok(tokens)
+}
+
+/// The size of a token map.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenMapSize {
+    /// The number of tokens in the token map.
+    pub len: u32,
+    /// The max number of tokens of the token map.
+    pub capacity: u32,
+}
+
+/// Get the number of tokens and the capacity of the token map.
+pub(crate) fn get_token_map_size(ctx: Context<ReadTokenMap>) -> Result<TokenMapSize> {
+    let token_map = ctx.accounts.token_map.load()?;
+    Ok(TokenMapSize {
+        len: token_map
+            .len()
+            .try_into()
+            .map_err(|_| error!(CoreError::ValueOverflow))?,
+        capacity: token_map
+            .capacity()
+            .try_into()
+            .map_err(|_| error!(CoreError::ValueOverflow))?,
+    })
+}
+
 /// Get expected provider for the given token.
 pub(crate) fn token_expected_provider(
     ctx: Context<ReadTokenMap>,
@@ -400,6 +658,49 @@ pub(crate) fn token_timestamp_adjustment(
         .map_err(|err| error!(err))
 }
 
+/// The configured feed of a price provider.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenFeedInfo {
+    /// The index of the price provider, see [`PriceProviderKind`].
+    pub provider: u8,
+    /// The configured feed address (or feed id) for the provider.
+    pub feed: Pubkey,
+    /// The configured timestamp adjustment for the provider.
+    pub timestamp_adjustment: u32,
+    /// Whether the provider is the expected provider of the token.
+    pub is_expected: bool,
+    /// Whether the provider is allowed for the token.
+    pub is_allowed: bool,
+}
+
+/// Get the configured feeds of all providers for the given token.
+pub(crate) fn get_token_feeds(
+    ctx: Context<ReadTokenMap>,
+    token: &Pubkey,
+) -> Result<Vec<TokenFeedInfo>> {
+    let token_map = ctx.accounts.token_map.load_token_map()?;
+    let config = token_map
+        .get(token)
+        .ok_or_else(|| error!(CoreError::NotFound))?;
+    let expected_provider = config.expected_provider().ok();
+    let feeds = (0..config.feeds.len())
+        .filter_map(|index| PriceProviderKind::try_from(index as u8).ok())
+        .filter_map(|provider| {
+            let feed_config = config.get_feed_config(&provider).ok()?;
+            Some(TokenFeedInfo {
+                provider: provider as u8,
+                feed: *feed_config.feed(),
+                timestamp_adjustment: feed_config.timestamp_adjustment(),
+                is_expected: expected_provider == Some(provider),
+                is_allowed: config.is_provider_allowed(&provider),
+            })
+        })
+        .collect();
+    Ok(feeds)
+}
+
 /// Get the name of the given token.
 pub(crate) fn token_name(ctx: Context<ReadTokenMap>, token: &Pubkey) -> Result<String> {
     ctx.accounts
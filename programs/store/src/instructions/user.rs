@@ -2,10 +2,12 @@ use anchor_lang::prelude::*;
 use gmsol_utils::InitSpace;
 
 use crate::{
+    instructions::ReadStore,
     states::{
         user::{ReferralCodeBytes, ReferralCodeV2, UserHeader},
         Seed, Store,
     },
+    utils::internal,
     CoreError,
 };
 
@@ -98,6 +100,36 @@ pub(crate) fn initialize_referral_code(
         code != ReferralCodeBytes::default(),
         CoreError::InvalidArgument
     );
+    require!(
+        !ctx.accounts
+            .store
+            .load()?
+            .referral_code_blocklist()
+            .is_blocked(&code),
+        CoreError::ReferralCodeBlocked
+    );
+
+    {
+        let store = ctx.accounts.store.load()?;
+        ReferralCodeV2::validate_code(
+            &code,
+            store.max_referral_code_length(),
+            store.referral_code_charset()?,
+        )?;
+    }
+
+    {
+        let min_gt = ctx.accounts.store.load()?.min_gt_for_referral_code();
+        let balance = ctx.accounts.user.load()?.gt.amount();
+        if balance < min_gt {
+            msg!(
+                "[Referral] GT balance {} is below the min {} required to initialize a referral code",
+                balance,
+                min_gt
+            );
+            return err!(CoreError::NotEnoughGtForReferralCode);
+        }
+    }
 
     // Initialize Referral Code Account.
     ctx.accounts.referral_code.load_init()?.init(
@@ -155,7 +187,15 @@ pub struct SetReferrer<'info> {
     pub referrer_user: AccountLoader<'info, UserHeader>,
 }
 
-pub(crate) fn set_referrer(ctx: Context<SetReferrer>, _code: ReferralCodeBytes) -> Result<()> {
+pub(crate) fn set_referrer(ctx: Context<SetReferrer>, code: ReferralCodeBytes) -> Result<()> {
+    require!(
+        !ctx.accounts
+            .store
+            .load()?
+            .referral_code_blocklist()
+            .is_blocked(&code),
+        CoreError::ReferralCodeBlocked
+    );
     require!(
         ctx.accounts.referrer_user.load()?.referral.referrer != ctx.accounts.user.load()?.owner,
         CoreError::MutualReferral
@@ -308,3 +348,130 @@ pub(crate) fn cancel_referral_code_transfer(
 
     Ok(())
 }
+
+/// The accounts definitions for [`set_referral_code_blocked`](crate::gmsol_store::set_referral_code_blocked)
+/// instruction.
+#[derive(Accounts)]
+pub struct SetReferralCodeBlocked<'info> {
+    /// Authority.
+    pub authority: Signer<'info>,
+    /// Store.
+    #[account(mut)]
+    pub store: AccountLoader<'info, Store>,
+}
+
+/// CHECK: only CONFIG_KEEPER is allowed to use this instruction.
+pub(crate) fn unchecked_set_referral_code_blocked(
+    ctx: Context<SetReferralCodeBlocked>,
+    code: ReferralCodeBytes,
+    blocked: bool,
+) -> Result<()> {
+    let changed = ctx
+        .accounts
+        .store
+        .load_mut()?
+        .referral_code_blocklist_mut()
+        .set_blocked(&code, blocked)?;
+    if changed {
+        msg!(
+            "[Referral] referral code `{:?}` is now {}",
+            code,
+            if blocked { "blocked" } else { "unblocked" },
+        );
+    }
+    Ok(())
+}
+
+impl<'info> internal::Authentication<'info> for SetReferralCodeBlocked<'info> {
+    fn authority(&self) -> &Signer<'info> {
+        &self.authority
+    }
+
+    fn store(&self) -> &AccountLoader<'info, Store> {
+        &self.store
+    }
+}
+
+/// Get the blocked referral codes of the store.
+pub(crate) fn get_blocked_referral_codes(
+    ctx: Context<ReadStore>,
+) -> Result<Vec<ReferralCodeBytes>> {
+    Ok(ctx
+        .accounts
+        .store
+        .load()?
+        .referral_code_blocklist()
+        .codes()
+        .to_vec())
+}
+/// The accounts definitions for [`get_referral_summary`](crate::gmsol_store::get_referral_summary)
+/// instruction.
+///
+/// Remaining accounts expected by this instruction:
+///
+///   - 0..N. `[]` N user accounts of the direct referees of the `user`, whose paid
+///     order fee values will be summed up.
+#[derive(Accounts)]
+pub struct ReadReferral<'info> {
+    /// Store.
+    pub store: AccountLoader<'info, Store>,
+    /// User Account.
+    #[account(
+        has_one = store,
+        constraint = user.load()?.is_initialized() @ CoreError::InvalidUserAccount,
+    )]
+    pub user: AccountLoader<'info, UserHeader>,
+}
+
+/// Referral summary of a user.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferralSummary {
+    /// The (owner) address of the referrer of the user, if any.
+    pub referrer: Option<Pubkey>,
+    /// The referral code account owned by the user, if any.
+    pub code: Option<Pubkey>,
+    /// The number of direct referees.
+    pub referee_count: u128,
+    /// The number of referee accounts provided.
+    pub provided_referee_count: u32,
+    /// The sum of the paid order fee values of the provided referees.
+    ///
+    /// Referred fees are not tracked by the referrer's account, so this value
+    /// only covers the referees passed in as remaining accounts.
+    pub provided_referees_paid_fee_value: u128,
+}
+
+/// Get the referral summary of the given user.
+pub(crate) fn get_referral_summary<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReadReferral<'info>>,
+) -> Result<ReferralSummary> {
+    let store = ctx.accounts.store.key();
+    let user = ctx.accounts.user.load()?;
+    let referral = user.referral();
+
+    let mut paid_fee_value = 0u128;
+    let mut seen = std::collections::BTreeSet::default();
+    for account in ctx.remaining_accounts {
+        require!(seen.insert(account.key), CoreError::InvalidArgument);
+        let referee = AccountLoader::<UserHeader>::try_from(account)?;
+        let referee = referee.load()?;
+        require_keys_eq!(referee.store, store, CoreError::StoreMismatched);
+        require!(referee.is_initialized(), CoreError::InvalidUserAccount);
+        require_keys_eq!(
+            referee.referral().referrer().copied().unwrap_or_default(),
+            user.owner,
+            CoreError::InvalidArgument
+        );
+        paid_fee_value = paid_fee_value.saturating_add(referee.gt().paid_fee_value());
+    }
+
+    Ok(ReferralSummary {
+        referrer: referral.referrer().copied(),
+        code: referral.code().copied(),
+        referee_count: referral.referee_count(),
+        provided_referee_count: seen.len() as u32,
+        provided_referees_paid_fee_value: paid_fee_value,
+    })
+}
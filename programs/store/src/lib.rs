@@ -36,19 +36,29 @@
 //!
 //! #### Instructions for Store Accounts
 //! - [`initialize`](gmsol_store::initialize): Create a new [`Store`](states::Store) account.
-//! - [`transfer_store_authority`]: Transfer the authority of the given store to a new authority.
+//! - [`transfer_store_authority`]: Request to transfer the authority of the given store to a new authority.
+//! - [`accept_store_authority`]: Accept the pending transfer of the authority of the given store.
+//! - [`cancel_store_authority_transfer`]: Cancel the pending transfer of the authority of the given store.
 //! - [`transfer_receiver`](gmsol_store::transfer_receiver): Set the claimable fee receiver address.
 //! - [`set_token_map`]: Set the token map account to use.
+//! - [`get_store_info`](gmsol_store::get_store_info): Get the admin, key, receiver and token map of the store.
 //!
 //! #### Instructions for Config Management
 //! - [`insert_amount`]: Insert an amount to the global config.
 //! - [`insert_factor`]: Insert a factor to the global config.
 //! - [`insert_address`]: Insert an address to the global config.
+//! - [`insert_config_batch`]: Insert a batch of typed config values to the global config.
+//! - [`get_amount`]: Get an amount from the global config.
+//! - [`get_factor`]: Get a factor from the global config.
+//! - [`get_address`]: Get an address from the global config.
 //! - [`insert_order_fee_discount_for_referred_user`]:
 //!   Insert order fee discount for referred user factor to the global config.
+//! - [`set_rent_recipient_allowed`]: Allow or disallow a recipient of the rent of actions closed by keepers.
+//! - [`get_allowed_rent_recipients`](gmsol_store::get_allowed_rent_recipients): Get the allowed rent recipients.
 //!
 //! #### Instructions for Feature Management
 //! - [`toggle_feature`]: Enable or disable the given feature.
+//! - [`get_required_features`]: Get the feature flags that gate the given instruction.
 //!
 //! ## Role-based Permission Management
 //!
@@ -68,10 +78,14 @@
 //! - [`check_role`](gmsol_store::check_role): Check whether *the signer* has the given role in the given store.
 //! - [`has_admin`](gmsol_store::has_admin): Return whether the given address is the admin of the given store.
 //! - [`has_role`](gmsol_store::has_role): Return whether the given address has the given role in the given store.
+//! - [`list_roles`](gmsol_store::list_roles): Return the names of all enabled roles in the given store.
+//! - [`list_role_members`](gmsol_store::list_role_members): Return the addresses that have the given role in the given store.
 //! - [`enable_role`]: Insert or enable a role for the given store.
 //! - [`disable_role`]: Disable an existing role for the given store.
-//! - [`grant_role`]: Grant a role to the given user in the given store.
+//! - [`grant_role`]: Grant a role, optionally time-limited, to the given user in the given store.
 //! - [`revoke_role`]: Revoke a role from the given user in the given store.
+//! - [`revoke_all_roles`]: Revoke all roles from the given user in the given store.
+//! - [`prune_expired_roles`](gmsol_store::prune_expired_roles): Revoke all expired time-limited role grants in the given store.
 //!
 //! ## Token Config and Oracle Management
 //!
@@ -82,13 +96,20 @@
 //! - [`push_to_token_map_synthetic`]: Push a new token config for a "synthetic"
 //!   token to the given token map.
 //! - [`toggle_token_config`]: Enable or disable a token config of the given token map.
+//! - [`toggle_token_configs_batch`]: Enable or disable multiple token configs of the given token map.
 //! - [`set_expected_provider`]: Set the expected provider for the given token.
 //! - [`set_feed_config`]: Set the feed config of the given provider for the given token.
+//! - [`set_allowed_providers`]: Set the allowed providers for the given token.
+//! - [`set_max_price`]: Set the max price bound for the given token.
+//! - [`set_fallback_provider`]: Set the fallback provider for the given token.
 //! - [`is_token_config_enabled`](gmsol_store::is_token_config_enabled): Check if the config for the given token is enabled.
+//! - [`get_disabled_tokens`](gmsol_store::get_disabled_tokens): Get the tokens whose configs are disabled.
+//! - [`get_token_map_size`](gmsol_store::get_token_map_size): Get the number of tokens and the capacity of the token map.
 //! - [`token_expected_provider`](gmsol_store::token_expected_provider): Get the expected provider set for the given token.
 //! - [`token_feed`](gmsol_store::token_feed): Get the feed address of the given provider set for the given token.
 //! - [`token_timestamp_adjustment`](gmsol_store::token_timestamp_adjustment): Get the timestamp adjustment of the given
 //!   provider for the give token.
+//! - [`get_token_feeds`](gmsol_store::get_token_feeds): Get the configured feeds of all providers for the given token.
 //! - [`token_name`](gmsol_store::token_name): Get the name of the given token.
 //! - [`token_decimals`](gmsol_store::token_decimals): Get the token decimals of the given token.
 //! - [`token_precision`](gmsol_store::token_precision): Get the price precision of the given token.
@@ -98,8 +119,12 @@
 //! - [`clear_all_prices`]: Clear the prices of the given oracle account.
 //! - [`set_prices_from_price_feed`]: Validate and set prices parsed from the
 //!   provided price feed accounts.
+//! - [`get_effective_token_price`]: Get the validated price of a token that the oracle would use.
+//! - [`get_display_price`]: Get the price of one whole token scaled for display.
 //! - [`initialize_price_feed`]: Initialize a custom price feed.
 //! - [`update_price_feed_with_chainlink`]: Update a custom Chainlink price feed with Chainlink Data Streams report.
+//! - [`update_price_feed_with_pyth`]: Update a custom Pyth price feed with a verified Pyth price update.
+//! - [`set_price_feed_max_report_age`]: Set the max age of price reports accepted by a custom price feed.
 //!
 //! ## Market Management
 //!
@@ -109,11 +134,28 @@
 //! - [`initialize_market`]: Initialize a [`Market`](states::Market) account.
 //! - [`toggle_market`]: Enable or disable the given market.
 //! - [`market_transfer_in`]: Transfer tokens into the market and record the amount in its balance.
+//! - [`fund_market_insurance`]: Transfer tokens into the insurance fund of the market.
 //! - [`update_market_config`]: Update an item in the market config.
 //! - [`update_market_config_with_buffer`]: Update the market config with the given
 //!   [`MarketConfigBuffer`](states::market::config::MarketConfigBuffer) account.
 //! - [`get_market_status`](gmsol_store::get_market_status): Calculate the market status with the given prices.
+//! - [`is_adl_required`](gmsol_store::is_adl_required): Check whether ADL is required for the given side of the market.
+//! - [`get_market_fee_config`](gmsol_store::get_market_fee_config): Get the fee related parameters of the market config.
+//! - [`get_market_risk_params`](gmsol_store::get_market_risk_params): Get the liquidation and ADL related parameters of the market config.
+//! - [`get_market_price_tokens`](gmsol_store::get_market_price_tokens): Get the tokens the market requires prices for.
+//! - [`get_market_accrual_state`](gmsol_store::get_market_accrual_state): Get the borrowing and funding accrual state of the market.
+//! - [`get_market_insurance_fund`](gmsol_store::get_market_insurance_fund): Get the insurance fund balances of the market.
+//! - [`derive_market_address`](gmsol_store::derive_market_address): Derive the canonical market and market token addresses.
+//! - [`get_position_accrued_fees`](gmsol_store::get_position_accrued_fees): Get the accrued borrowing and funding fees of a position.
+//! - [`get_position_cost_basis`](gmsol_store::get_position_cost_basis): Get the average entry price and cost basis of a position.
+//! - [`get_max_safe_decrease`](gmsol_store::get_max_safe_decrease): Get the max size delta a position can be decreased by without being fully closed.
+//! - [`get_position_margin`](gmsol_store::get_position_margin): Get the current margin ratio and the maintenance margin ratio of a position.
 //! - [`get_market_token_price`](gmsol_store::get_market_token_price): Calculate the market token price the given prices.
+//! - [`get_deposit_preview`](gmsol_store::get_deposit_preview): Preview a deposit of the given USD values.
+//! - [`preview_market_token_price_change`](gmsol_store::preview_market_token_price_change): Preview the market token price change caused by a mint or burn.
+//! - [`get_fee_receiver_status`](gmsol_store::get_fee_receiver_status): Get the fee receiver and the claimable fees of markets.
+//! - [`get_markets_for_collateral`](gmsol_store::get_markets_for_collateral): Get the markets that accept the given token as collateral.
+//! - [`is_market_shiftable`](gmsol_store::is_market_shiftable): Check whether a shift between the given markets is allowed.
 //! - [`toggle_gt_minting`]: Enable or disable GT minting for the given market.
 //!
 //! #### Instructions for [`MarketConfigBuffer`](states::market::config::MarketConfigBuffer) accounts
@@ -133,6 +175,22 @@
 //! ## Exchange
 //! The instructions for providing functionalities as an exchange are as follows:
 //!
+//! #### Instructions for actions
+//! - [`get_pending_execution_fees`](gmsol_store::get_pending_execution_fees): Get the execution fees
+//!   recorded in the given actions.
+//! - [`get_action_escrows`](gmsol_store::get_action_escrows): Get the escrow accounts recorded
+//!   in the given actions.
+//! - [`get_action_escrow_balances`](gmsol_store::get_action_escrow_balances): Get the balances
+//!   of the escrow accounts recorded in the given actions.
+//! - [`estimate_execution_compute`](gmsol_store::estimate_execution_compute): Get the estimated
+//!   compute requirements for executing the given actions.
+//! - [`get_action_swap_params`](gmsol_store::get_action_swap_params): Get the swap parameters
+//!   recorded in the given deposits and withdrawals.
+//! - [`derive_action_address`](gmsol_store::derive_action_address): Derive the address of the
+//!   action account of the given kind.
+//! - [`is_action_executable`](gmsol_store::is_action_executable): Check whether a deposit or
+//!   withdrawal is currently executable with the given prices.
+//!
 //! #### Instructions for [`Deposit`](states::Deposit)
 //! - [`create_deposit`]: Create a deposit by the owner.
 //! - [`execute_deposit`](gmsol_store::execute_deposit()): Execute a deposit by keepers.
@@ -152,11 +210,15 @@
 //! - [`prepare_position`](gmsol_store::prepare_position): Prepare the position account for orders.
 //! - [`prepare_trade_event_buffer`](gmsol_store::prepare_trade_event_buffer): Prepare trade event buffer.
 //! - [`create_order`]: Create an order by the owner.
+//! - [`check_order_prerequisites`](gmsol_store::check_order_prerequisites): Check which prerequisites of creating an order are missing.
 //! - [`update_order`](gmsol_store::update_order): Update an order by the owner.
+//! - [`update_order_reprice`](gmsol_store::update_order_reprice): Update the trigger price of an
+//!   order and reprice its acceptable price with the current oracle prices.
 //! - [`execute_increase_or_swap_order`](gmsol_store::execute_increase_or_swap_order()): Execute an order by keepers.
 //! - [`execute_decrease_order`]: Execute a decrease order by keepers.
 //! - [`close_order`]: Close an order, either by the owner or by keepers.
 //! - [`cancel_order_if_no_position`]: Cancel an order if the position does not exist.
+//! - [`get_order_params`](gmsol_store::get_order_params): Get the decoded parameters of an order.
 //! - [`liquidate`]: Perform a liquidation by keepers.
 //! - [`auto_deleverage`]: Perform an ADL by keepers.
 //! - [`update_adl_state`]: Update the ADL state of the market.
@@ -171,6 +233,9 @@
 //! - [`update_glv_config`]: Update GLV global config.
 //! - [`insert_glv_market`]: Insert a new market to the GLV.
 //! - [`remove_glv_market`]: Remove a market from the GLV.
+//! - [`get_glv_first_deposit_minimum`]: Get the minimum GLV token amount for the first deposit.
+//! - [`get_glv_nav`]: Get the NAV and the per-market composition of the GLV.
+//! - [`get_glv_utilization`]: Get the per-market cap utilization of the GLV.
 //!
 //! #### Instructions for [`GlvDeposit`](states::GlvDeposit)
 //! - [`create_glv_deposit`]: Create a GLV deposit by the owner.
@@ -186,6 +251,7 @@
 //! - [`create_glv_shift`]: Create a GLV shift by keepers.
 //! - [`execute_glv_shift`]: Execute a GLV shift by keepers.
 //! - [`close_glv_shift`]: Close a shift by keepers.
+//! - [`get_glv_shift_info`]: Get the markets and amounts recorded in a GLV shift.
 //!
 //! ## User Accounts and Referrals
 //! The instructions for user accounts and referrals are as follows:
@@ -195,6 +261,9 @@
 //! - [`transfer_referral_code`](gmsol_store::transfer_referral_code): Transfer the referral code to others.
 //! - [`cancel_referral_code_transfer`](gmsol_store::cancel_referral_code_transfer): Cancel the referral code transfer.
 //! - [`accept_referral_code`](gmsol_store::accept_referral_code): Complete the referral code transfer.
+//! - [`set_referral_code_blocked`]: Block or unblock a referral code.
+//! - [`get_blocked_referral_codes`](gmsol_store::get_blocked_referral_codes): Get the blocked referral codes.
+//! - [`get_referral_summary`](gmsol_store::get_referral_summary): Get the referral summary of a user.
 //!
 //! ## GT Model
 //!
@@ -205,10 +274,17 @@
 //! - [`gt_set_order_fee_discount_factors`]: Set order fee discount factors.
 //! - [`gt_set_referral_reward_factors`]: Set referral reward factors.
 //! - [`gt_set_exchange_time_window`]: Set GT exchange time window.
+//! - [`gt_set_minting_cost_grow_round_up`]: Set the rounding direction of the GT minting cost growth.
 //! - [`prepare_gt_exchange_vault`](gmsol_store::prepare_gt_exchange_vault): Prepare current GT exchange vault.
 //! - [`confirm_gt_exchange_vault`]: Confirm GT exchange vault.
+//! - [`auto_confirm_gt_exchange_vault`](gmsol_store::auto_confirm_gt_exchange_vault): Confirm GT exchange vault permissionlessly after the deposit window if enabled.
 //! - [`request_gt_exchange`](gmsol_store::request_gt_exchange): Request a GT exchange.
 //! - [`close_gt_exchange`]: Close a confirmed GT exchange.
+//! - [`get_gt_to_next_rank`](gmsol_store::get_gt_to_next_rank): Get the amount of GT required to reach the next rank.
+//! - [`get_user_rank`](gmsol_store::get_user_rank): Get the current rank and the rank thresholds of a GT user.
+//! - [`get_gt_state`](gmsol_store::get_gt_state): Get the GT state summary of the store.
+//! - [`get_gt_staker_rewards`](gmsol_store::get_gt_staker_rewards): Get the order fee value recorded for GT holders.
+//! - [`get_gt_vault_confirm_eta`](gmsol_store::get_gt_vault_confirm_eta): Get the number of seconds until a GT exchange vault becomes confirmable.
 
 /// Instructions.
 pub mod instructions;
@@ -238,15 +314,22 @@ use self::{
         withdrawal::CreateWithdrawalParams,
     },
     states::{
+        common::action::CloseReason,
         glv::UpdateGlvParams,
-        market::{config::EntryArgs, status::MarketStatus},
-        order::UpdateOrderParams,
+        market::{
+            config::{EntryArgs, MarketFeeConfig, MarketRiskConfig},
+            status::MarketStatus,
+            MarketAddresses,
+        },
+        order::{OrderKind, UpdateOrderParams},
+        position::PositionAccruedFees,
         token_config::UpdateTokenConfigParams,
         FactorKey, PriceProviderKind,
     },
     utils::internal,
 };
 use anchor_lang::prelude::*;
+use gmsol_callback::interface::ActionKind;
 use gmsol_model::price::Prices;
 
 #[cfg_attr(test, macro_use)]
@@ -336,6 +419,27 @@ pub mod gmsol_store {
         instructions::accept_store_authority(ctx)
     }
 
+    /// Cancel the pending transfer of the authority (admin) of the given store.
+    ///
+    /// This resets `next_authority` to the current authority, so that the previously
+    /// requested address can no longer accept the transfer.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](CancelStoreAuthorityTransfer).*
+    ///
+    /// # Errors
+    /// - The [`authority`](CancelStoreAuthorityTransfer::authority) must be a signer and the current
+    ///   admin of the store.
+    /// - The [`store`](CancelStoreAuthorityTransfer::store) must be an initialized store account
+    ///   owned by the store program.
+    /// - There must be a pending authority transfer.
+    #[access_control(internal::Authenticate::only_admin(&ctx))]
+    pub fn cancel_store_authority_transfer(
+        ctx: Context<CancelStoreAuthorityTransfer>,
+    ) -> Result<()> {
+        instructions::unchecked_cancel_store_authority_transfer(ctx)
+    }
+
     /// Request to transfer the receiver address to a new one.
     /// # Note
     /// This instruction only sets `next_receiver`. Use [`accept_receiver`] to
@@ -393,6 +497,21 @@ pub mod gmsol_store {
         instructions::unchecked_set_token_map(ctx)
     }
 
+    /// Get the metadata of the store.
+    ///
+    /// Returns the admin, the key used to derive the store address, the claimable fee receiver
+    /// and the token map address of the store.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadStore).*
+    ///
+    /// # Errors
+    /// - The [`store`](ReadStore::store) must be an initialized store account owned by the
+    ///   store program.
+    pub fn get_store_info(ctx: Context<ReadStore>) -> Result<StoreInfo> {
+        instructions::get_store_info(ctx)
+    }
+
     // ===========================================
     //      Role-based Permission Management
     // ===========================================
@@ -483,6 +602,34 @@ pub mod gmsol_store {
         instructions::has_role(ctx, authority, role)
     }
 
+    /// Return the names of all enabled roles in the given store.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](HasRole).*
+    ///
+    /// # Errors
+    /// - The [`store`](HasRole::store) must be an initialized store account owned by
+    ///   the store program.
+    pub fn list_roles(ctx: Context<HasRole>) -> Result<Vec<String>> {
+        instructions::list_roles(ctx)
+    }
+
+    /// Return the addresses that have the given role in the given store.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](HasRole).*
+    ///
+    /// # Arguments
+    /// - `role`: The name of the role.
+    ///
+    /// # Errors
+    /// - The [`store`](HasRole::store) must be an initialized store account owned by
+    ///   the store program.
+    /// - The `role` must exist and be enabled in the store's role configuration.
+    pub fn list_role_members(ctx: Context<HasRole>, role: String) -> Result<Vec<Pubkey>> {
+        instructions::list_role_members(ctx, role)
+    }
+
     /// Insert or enable a role for the given store.
     ///
     /// This instruction adds a new role or enables an existing disabled role in the store's role configuration.
@@ -534,14 +681,25 @@ pub mod gmsol_store {
     /// # Arguments
     /// - `user`: The address of the user to whom the role should be granted.
     /// - `role`: The name of the role to be granted. Must be an enabled role in the store.
+    /// - `expires_at`: The optional timestamp at which the grant expires. An expired grant is
+    ///   treated as absent, and can be cleared with [`prune_expired_roles`](gmsol_store::prune_expired_roles).
     ///
     /// # Errors
     /// - The [`authority`](GrantRole::authority) must be a signer and be the `ADMIN` of the store.
     /// - The [`store`](GrantRole::store) must be an initialized store account owned by the store program.
     /// - The `role` must exist and be enabled in the store's role table.
+    /// - The `expires_at` must be in the future if provided, otherwise returns
+    ///   [`CoreError::InvalidArgument`].
+    /// - The number of time-limited grants must not exceed
+    ///   [`MAX_EXPIRING_ROLE_GRANTS`](states::roles::MAX_EXPIRING_ROLE_GRANTS).
     #[access_control(internal::Authenticate::only_admin(&ctx))]
-    pub fn grant_role(ctx: Context<GrantRole>, user: Pubkey, role: String) -> Result<()> {
-        instructions::unchecked_grant_role(ctx, user, role)
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        user: Pubkey,
+        role: String,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::unchecked_grant_role(ctx, user, role, expires_at)
     }
 
     /// Revoke a role from the given user in the given store.
@@ -566,6 +724,41 @@ pub mod gmsol_store {
         instructions::unchecked_revoke_role(ctx, user, role)
     }
 
+    /// Revoke all roles from the given user in the given store.
+    ///
+    /// This instruction clears every role of the user and removes the user from the store's
+    /// member table, emitting an [`AllRolesRevoked`](events::AllRolesRevoked) event listing
+    /// the revoked roles.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](RevokeAllRoles).*
+    ///
+    /// # Arguments
+    /// - `user`: The address of the user from whom all roles should be revoked.
+    ///
+    /// # Errors
+    /// - The [`authority`](RevokeAllRoles::authority) must be a signer and be the `ADMIN` of the store.
+    /// - The [`store`](RevokeAllRoles::store) must be an initialized store account owned by the store program.
+    /// - The `user` must exist in the store's member table, otherwise returns
+    ///   [`CoreError::NotFound`].
+    #[access_control(internal::Authenticate::only_admin(&ctx))]
+    pub fn revoke_all_roles(ctx: Context<RevokeAllRoles>, user: Pubkey) -> Result<()> {
+        instructions::unchecked_revoke_all_roles(ctx, user)
+    }
+
+    /// Revoke all expired time-limited role grants in the given store.
+    ///
+    /// This instruction is permissionless.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](PruneExpiredRoles).*
+    ///
+    /// # Errors
+    /// - The [`store`](PruneExpiredRoles::store) must be an initialized store account owned by the store program.
+    pub fn prune_expired_roles(ctx: Context<PruneExpiredRoles>) -> Result<()> {
+        instructions::prune_expired_roles(ctx)
+    }
+
     // ===========================================
     //              Config Management
     // ===========================================
@@ -590,7 +783,7 @@ pub mod gmsol_store {
     /// - The store must be initialized and owned by this program.
     #[access_control(internal::Authenticate::only_config_keeper(&ctx))]
     pub fn insert_amount(ctx: Context<InsertConfig>, key: String, amount: u64) -> Result<()> {
-        instructions::unchecked_insert_amount(ctx, &key, amount)
+        instructions::unchecked_insert_amount(ctx.accounts, &key, amount)
     }
 
     /// Insert a factor value into the store's global configuration.
@@ -612,7 +805,7 @@ pub mod gmsol_store {
     /// - The store must be initialized and owned by this program.
     #[access_control(internal::Authenticate::only_config_keeper(&ctx))]
     pub fn insert_factor(ctx: Context<InsertConfig>, key: String, factor: u128) -> Result<()> {
-        instructions::unchecked_insert_factor(ctx, &key, factor)
+        instructions::unchecked_insert_factor(ctx.accounts, &key, factor)
     }
 
     /// Insert an address value into the store's global configuration.
@@ -635,7 +828,129 @@ pub mod gmsol_store {
     /// - The store must be initialized and owned by this program.
     #[access_control(internal::Authenticate::only_config_keeper(&ctx))]
     pub fn insert_address(ctx: Context<InsertConfig>, key: String, address: Pubkey) -> Result<()> {
-        instructions::unchecked_insert_address(ctx, &key, address)
+        instructions::unchecked_insert_address(ctx.accounts, &key, address)
+    }
+
+    /// Insert a batch of config values into the store's global configuration.
+    ///
+    /// The entries are applied in order, and the whole batch is aborted if any of them
+    /// is invalid.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](InsertConfig).*
+    ///
+    /// # Arguments
+    /// - `entries`: The typed config entries to insert, see [`ConfigEntry`].
+    ///
+    /// # Errors
+    /// - The [`authority`](InsertConfig::authority) must be a signer and have the CONFIG_KEEPER role
+    ///   in the store.
+    /// - The number of `entries` must not exceed [`MAX_CONFIG_BATCH_LEN`](constants::MAX_CONFIG_BATCH_LEN).
+    /// - The key of each entry must be defined in [`AmountKey`](crate::states::AmountKey),
+    ///   [`FactorKey`](crate::states::FactorKey) or [`AddressKey`](crate::states::AddressKey)
+    ///   according to its type.
+    /// - The store must be initialized and owned by this program.
+    #[access_control(internal::Authenticate::only_config_keeper(&ctx))]
+    pub fn insert_config_batch(
+        ctx: Context<InsertConfig>,
+        entries: Vec<ConfigEntry>,
+    ) -> Result<()> {
+        instructions::unchecked_insert_config_batch(ctx, &entries)
+    }
+
+    /// Get an amount value from the store's global configuration.
+    ///
+    /// Returns the default value if the config has never been set.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadStore).*
+    ///
+    /// # Arguments
+    /// - `key`: The configuration key to read. Must be a valid amount key defined in
+    ///   [`AmountKey`](crate::states::AmountKey).
+    ///
+    /// # Errors
+    /// - The [`store`](ReadStore::store) must be an initialized store account owned by the
+    ///   store program.
+    /// - The provided `key` must be defined in [`AmountKey`](crate::states::AmountKey).
+    pub fn get_amount(ctx: Context<ReadStore>, key: String) -> Result<u64> {
+        instructions::get_amount(ctx, &key)
+    }
+
+    /// Get a factor value from the store's global configuration.
+    ///
+    /// Returns the default value if the config has never been set.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadStore).*
+    ///
+    /// # Arguments
+    /// - `key`: The configuration key to read. Must be a valid factor key defined in
+    ///   [`FactorKey`](crate::states::FactorKey).
+    ///
+    /// # Errors
+    /// - The [`store`](ReadStore::store) must be an initialized store account owned by the
+    ///   store program.
+    /// - The provided `key` must be defined in [`FactorKey`](crate::states::FactorKey).
+    pub fn get_factor(ctx: Context<ReadStore>, key: String) -> Result<u128> {
+        instructions::get_factor(ctx, &key)
+    }
+
+    /// Get an address value from the store's global configuration.
+    ///
+    /// Returns the default value if the config has never been set.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadStore).*
+    ///
+    /// # Arguments
+    /// - `key`: The configuration key to read. Must be a valid address key defined in
+    ///   [`AddressKey`](crate::states::AddressKey).
+    ///
+    /// # Errors
+    /// - The [`store`](ReadStore::store) must be an initialized store account owned by the
+    ///   store program.
+    /// - The provided `key` must be defined in [`AddressKey`](crate::states::AddressKey).
+    pub fn get_address(ctx: Context<ReadStore>, key: String) -> Result<Pubkey> {
+        instructions::get_address(ctx, &key)
+    }
+
+    /// Allow or disallow the given rent recipient.
+    ///
+    /// Keepers closing actions on behalf of their owners can only route the rent of the
+    /// action accounts to allowed recipients.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](SetRentRecipientAllowed)*
+    ///
+    /// # Arguments
+    /// - `recipient`: The rent recipient to allow or disallow.
+    /// - `allowed`: Whether to allow the recipient.
+    ///
+    /// # Errors
+    /// - The [`authority`](SetRentRecipientAllowed::authority) must be a signer and have the
+    ///   CONFIG_KEEPER role in the store.
+    /// - The [`store`](SetRentRecipientAllowed::store) must be properly initialized.
+    /// - The `recipient` must not be the default pubkey.
+    /// - The allowlist must not be full when allowing a new recipient.
+    #[access_control(internal::Authenticate::only_config_keeper(&ctx))]
+    pub fn set_rent_recipient_allowed(
+        ctx: Context<SetRentRecipientAllowed>,
+        recipient: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        instructions::unchecked_set_rent_recipient_allowed(ctx, &recipient, allowed)
+    }
+
+    /// Get the allowed rent recipients of the store.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadStore)*
+    ///
+    /// # Errors
+    /// - The [`store`](ReadStore::store) must be properly initialized.
+    pub fn get_allowed_rent_recipients(ctx: Context<ReadStore>) -> Result<Vec<Pubkey>> {
+        instructions::get_allowed_rent_recipients(ctx)
     }
 
     /// Insert order fee discount for referred user factor to the global config.
@@ -665,7 +980,7 @@ pub mod gmsol_store {
         factor: u128,
     ) -> Result<()> {
         let key = FactorKey::OrderFeeDiscountForReferredUser;
-        instructions::unchecked_insert_factor(ctx, &key.to_string(), factor)
+        instructions::unchecked_insert_factor(ctx.accounts, &key.to_string(), factor)
     }
 
     // ===========================================
@@ -722,6 +1037,32 @@ pub mod gmsol_store {
         instructions::unchecked_toggle_feature(ctx, domain, action, enable)
     }
 
+    /// Get the feature flags that gate the given instruction.
+    ///
+    /// Returns the `(domain, action)` pairs, in the format accepted by
+    /// [`toggle_feature`](crate::gmsol_store::toggle_feature), that must be enabled
+    /// for the given instruction to succeed. Instructions that are not gated by any
+    /// feature flag return an empty list.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadStore).*
+    ///
+    /// # Arguments
+    /// - `instruction`: The name of the instruction, e.g. `create_deposit`.
+    /// - `order_kind`: The kind of the order for order instructions. If not provided,
+    ///   the flags for all order kinds accepted by the instruction are returned.
+    ///
+    /// # Errors
+    /// - The [`store`](ReadStore::store) must be an initialized store account.
+    /// - The `order_kind` must be accepted by the given order instruction if provided.
+    pub fn get_required_features(
+        ctx: Context<ReadStore>,
+        instruction: String,
+        order_kind: Option<OrderKind>,
+    ) -> Result<Vec<RequiredFeature>> {
+        instructions::get_required_features(ctx, &instruction, order_kind)
+    }
+
     // ===========================================
     //           Token Config Management
     // ===========================================
@@ -848,6 +1189,32 @@ pub mod gmsol_store {
         ToggleTokenConfig::invoke_unchecked(ctx, token, TokenConfigFlag::Enabled, enable)
     }
 
+    /// Enable or disable the configs for the given tokens atomically.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts*](ToggleTokenConfig).
+    ///
+    /// # Arguments
+    /// - `toggles`: The tokens whose configs will be updated, with whether to enable
+    ///   or disable each of them.
+    ///
+    /// # Errors
+    /// - The [`authority`](ToggleTokenConfig::authority) must be a signer
+    ///   and a MARKET_KEEPER in the given store.
+    /// - The [`store`](ToggleTokenConfig::store) must be an initialized [`Store`](states::Store)
+    ///   account owned by the store program .
+    /// - The [`token_map`](ToggleTokenConfig::token_map) must be an initialized token map account
+    ///   owned by the `store`.
+    /// - The `toggles` must not be empty.
+    /// - Every token in `toggles` must exist in the token map.
+    #[access_control(internal::Authenticate::only_market_keeper(&ctx))]
+    pub fn toggle_token_configs_batch(
+        ctx: Context<ToggleTokenConfig>,
+        toggles: Vec<TokenConfigToggle>,
+    ) -> Result<()> {
+        ToggleTokenConfig::invoke_batch_unchecked(ctx, &toggles)
+    }
+
     /// Enable or disable price adjustment for the token.
     ///
     /// # Accounts
@@ -898,6 +1265,7 @@ pub mod gmsol_store {
     ///   owned by the `store`.
     /// - The given `token` must exist in the token map.
     /// - The `provider` index must correspond to a valid [`PriceProviderKind`].
+    /// - The `provider` must be allowed for the token.
     #[access_control(internal::Authenticate::only_market_keeper(&ctx))]
     pub fn set_expected_provider(
         ctx: Context<SetExpectedProvider>,
@@ -933,6 +1301,7 @@ pub mod gmsol_store {
     ///   owned by the `store`.
     /// - The given `token` must exist in the token map.
     /// - The `provider` index must correspond to a valid [`PriceProviderKind`].
+    /// - The `timestamp_adjustment` must be within the bounds configured in the store.
     #[deprecated(since = "0.6.0", note = "use `set_feed_config_v2` instead")]
     #[access_control(internal::Authenticate::only_market_keeper(&ctx))]
     pub fn set_feed_config(
@@ -974,6 +1343,8 @@ pub mod gmsol_store {
     ///   owned by the `store`.
     /// - The given `token` must exist in the token map.
     /// - The `provider` index must correspond to a valid [`PriceProviderKind`].
+    /// - The `provider` must be allowed for the token.
+    /// - The `timestamp_adjustment` must be within the bounds configured in the store.
     #[access_control(internal::Authenticate::only_market_keeper(&ctx))]
     pub fn set_feed_config_v2(
         ctx: Context<SetFeedConfig>,
@@ -994,6 +1365,113 @@ pub mod gmsol_store {
         )
     }
 
+    /// Set the allowed providers for the given token.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts*](SetAllowedProviders).
+    ///
+    /// # Arguments
+    /// - `token`: The token whose config will be updated.
+    /// - `allowed_providers`: The bitset of allowed providers, where the bit at
+    ///   each [`PriceProviderKind`] index indicates whether the provider is allowed.
+    ///   `0` means no restriction is applied.
+    ///
+    /// # Errors
+    /// - The [`authority`](SetAllowedProviders::authority) must be a signer
+    ///   and a MARKET_KEEPER in the given store.
+    /// - The [`store`](SetAllowedProviders::store) must be an initialized [`Store`](states::Store)
+    ///   account owned by the store program.
+    /// - The [`token_map`](SetAllowedProviders::token_map) must be an initialized token map account
+    ///   owned by the `store`.
+    /// - The given `token` must exist in the token map.
+    /// - Each bit set in `allowed_providers` must correspond to a valid [`PriceProviderKind`].
+    /// - The current expected provider of the token must remain allowed.
+    #[access_control(internal::Authenticate::only_market_keeper(&ctx))]
+    pub fn set_allowed_providers(
+        ctx: Context<SetAllowedProviders>,
+        token: Pubkey,
+        allowed_providers: u8,
+    ) -> Result<()> {
+        SetAllowedProviders::invoke_unchecked(ctx, token, allowed_providers)
+    }
+
+    /// Set the max price bound for the given token.
+    ///
+    /// Oracle prices of the token whose max price exceeds the bound are rejected. Since the bound
+    /// is validated when the prices are set to the oracle, it applies uniformly across all the
+    /// valuation paths using the oracle prices, including the GLV valuation of
+    /// [`get_glv_token_value`](crate::gmsol_store::get_glv_token_value).
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts*](SetMaxPrice).
+    ///
+    /// # Arguments
+    /// - `token`: The token whose config will be updated.
+    /// - `value`: The value of the max price bound, in the same representation as the
+    ///   [`Decimal`](gmsol_utils::price::Decimal) prices of the token. `0` means no bound.
+    /// - `decimal_multiplier`: The decimal multiplier of the max price bound.
+    ///
+    /// # Errors
+    /// - The [`authority`](SetMaxPrice::authority) must be a signer
+    ///   and a MARKET_KEEPER in the given store.
+    /// - The [`store`](SetMaxPrice::store) must be an initialized [`Store`](states::Store)
+    ///   account owned by the store program.
+    /// - The [`token_map`](SetMaxPrice::token_map) must be an initialized token map account
+    ///   owned by the `store`.
+    /// - The given `token` must exist in the token map.
+    /// - The `decimal_multiplier` must not exceed
+    ///   [`Decimal::MAX_DECIMAL_MULTIPLIER`](gmsol_utils::price::Decimal::MAX_DECIMAL_MULTIPLIER).
+    #[access_control(internal::Authenticate::only_market_keeper(&ctx))]
+    pub fn set_max_price(
+        ctx: Context<SetMaxPrice>,
+        token: Pubkey,
+        value: u32,
+        decimal_multiplier: u8,
+    ) -> Result<()> {
+        SetMaxPrice::invoke_unchecked(ctx, token, value, decimal_multiplier)
+    }
+
+    /// Set the fallback provider for the given token.
+    ///
+    /// When the feed of the expected provider is stale, the price of the token can be supplied
+    /// by the feed of the fallback provider instead, which can also be a custom price feed.
+    /// The stale feed of the expected provider must be provided to prove its staleness. The
+    /// fallback price must still pass all the validations of the oracle, and the provider that
+    /// supplied the price is recorded in the oracle.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts*](SetFallbackProvider).
+    ///
+    /// # Arguments
+    /// - `token`: The token whose config will be updated.
+    /// - `provider`: The index of the fallback provider. Must be a valid [`PriceProviderKind`]
+    ///   value. `None` to remove the fallback provider.
+    ///
+    /// # Errors
+    /// - The [`authority`](SetFallbackProvider::authority) must be a signer
+    ///   and a MARKET_KEEPER in the given store.
+    /// - The [`store`](SetFallbackProvider::store) must be an initialized [`Store`](states::Store)
+    ///   account owned by the store program.
+    /// - The [`token_map`](SetFallbackProvider::token_map) must be an initialized token map account
+    ///   owned by the `store`.
+    /// - The given `token` must exist in the token map.
+    /// - The fallback provider must differ from the expected provider, be allowed for the token
+    ///   and have its feed configured.
+    #[access_control(internal::Authenticate::only_market_keeper(&ctx))]
+    pub fn set_fallback_provider(
+        ctx: Context<SetFallbackProvider>,
+        token: Pubkey,
+        provider: Option<u8>,
+    ) -> Result<()> {
+        let provider = provider
+            .map(|provider| {
+                PriceProviderKind::try_from(provider)
+                    .map_err(|_| error!(CoreError::InvalidProviderKindIndex))
+            })
+            .transpose()?;
+        SetFallbackProvider::invoke_unchecked(ctx, token, provider)
+    }
+
     /// Return whether the token config is enabled.
     ///
     /// # Accounts
@@ -1013,6 +1491,30 @@ pub mod gmsol_store {
         instructions::is_token_config_enabled(ctx, &token)
     }
 
+    /// Get the tokens whose configs are disabled in the token map.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts*](ReadTokenMap).
+    ///
+    /// # Errors
+    /// - The [`token_map`](ReadTokenMap::token_map) must be an initialized token map account
+    ///   owned by the `store`.
+    pub fn get_disabled_tokens(ctx: Context<ReadTokenMap>) -> Result<Vec<Pubkey>> {
+        instructions::get_disabled_tokens(ctx)
+    }
+
+    /// Get the number of tokens and the capacity of the token map.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts*](ReadTokenMap).
+    ///
+    /// # Errors
+    /// - The [`token_map`](ReadTokenMap::token_map) must be an initialized token map account
+    ///   owned by the `store`.
+    pub fn get_token_map_size(ctx: Context<ReadTokenMap>) -> Result<TokenMapSize> {
+        instructions::get_token_map_size(ctx)
+    }
+
     /// Get the expected provider of the given token.
     ///
     /// # Accounts
@@ -1092,6 +1594,32 @@ pub mod gmsol_store {
         )
     }
 
+    /// Get the configured feeds of all price providers for the given token.
+    ///
+    /// Providers without a configured feed are skipped. Note that for the custom price feeds
+    /// (e.g. Chainlink Data Streams), the returned feed is the feed id rather than the address
+    /// of the [`PriceFeed`](states::PriceFeed) account to pass.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts*](ReadTokenMap).
+    ///
+    /// # Arguments
+    /// - `token`: The address of the token to query for.
+    ///
+    /// # Errors
+    /// - The [`token_map`](ReadTokenMap::token_map) must be an initialized token map account
+    ///   owned by the store program.
+    /// - The given `token` must exist in the token map.
+    ///
+    /// # Returns
+    /// Returns the provider index, feed address and timestamp adjustment of each configured provider.
+    pub fn get_token_feeds(
+        ctx: Context<ReadTokenMap>,
+        token: Pubkey,
+    ) -> Result<Vec<TokenFeedInfo>> {
+        instructions::get_token_feeds(ctx, &token)
+    }
+
     /// Get the name of the token.
     ///
     /// # Accounts
@@ -1212,9 +1740,15 @@ pub mod gmsol_store {
     ///   by the given store. It must not have any prices set and be in the cleared state.
     /// - The [`token_map`](SetPricesFromPriceFeed::token_map) must be an initialized token map account
     ///   that is owned and authorized by the store.
-    /// - The number of tokens provided cannot exceed [`MAX_TOKENS`](crate::states::oracle::price_map::PriceMap::MAX_TOKENS).
+    /// - The number of tokens provided cannot exceed [`MAX_TOKENS`](crate::states::oracle::price_map::PriceMap::MAX_TOKENS)
+    ///   or the [`max_tokens_per_price_set`](states::Store::max_tokens_per_price_set) configured in the store.
     /// - Each token in `tokens` must be configured and enabled in the token map.
     /// - For each token, there must be a valid corresponding price feed account included in the remaining accounts.
+    ///   It must be the feed of the expected provider of the token, or of its fallback provider
+    ///   (see [`set_fallback_provider`]) if the feed of the expected provider is stale.
+    /// - For each token whose price is supplied by its fallback provider, the feed of its expected
+    ///   provider must be provided in order right after the price feed accounts, and it must not
+    ///   have been updated within the heartbeat duration of the token.
     #[access_control(internal::Authenticate::only_oracle_controller(&ctx))]
     pub fn set_prices_from_price_feed<'info>(
         ctx: Context<'_, '_, 'info, 'info, SetPricesFromPriceFeed<'info>>,
@@ -1223,6 +1757,61 @@ pub mod gmsol_store {
         instructions::unchecked_set_prices_from_price_feed(ctx, tokens)
     }
 
+    /// Get the price of the given token that would be used when setting prices to an oracle,
+    /// together with the provider supplying it.
+    ///
+    /// The price is read from the feed of the expected provider of the token, or of its fallback
+    /// provider if set, and goes through the same validations as in [`set_prices_from_price_feed`].
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadTokenPrice)*
+    ///
+    /// # Arguments
+    /// - `token`: The address of the token to query for.
+    ///
+    /// # Errors
+    /// - The [`store`](ReadTokenPrice::store) must be an initialized store account.
+    /// - The [`token_map`](ReadTokenPrice::token_map) must be the token map of the `store`.
+    /// - The `token` must exist in the token map and be enabled.
+    /// - The feed account must be provided in the remaining accounts and be a valid feed of
+    ///   the expected provider or the fallback provider of the token.
+    /// - If the feed account is of the fallback provider, the stale feed of the expected provider
+    ///   must be provided right after it.
+    /// - The price must pass the validations of the oracle, including the max age check.
+    pub fn get_effective_token_price<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadTokenPrice<'info>>,
+        token: Pubkey,
+    ) -> Result<EffectiveTokenPrice> {
+        instructions::get_effective_token_price(ctx, &token)
+    }
+
+    /// Get the price of one whole token for display, i.e. already scaled by the token decimals
+    /// and with the decimals to be the precision of the token.
+    ///
+    /// The price is obtained in the same way as in [`get_effective_token_price`].
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadTokenPrice)*
+    ///
+    /// # Arguments
+    /// - `token`: The address of the token to query for.
+    ///
+    /// # Errors
+    /// - The [`store`](ReadTokenPrice::store) must be an initialized store account.
+    /// - The [`token_map`](ReadTokenPrice::token_map) must be the token map of the `store`.
+    /// - The `token` must exist in the token map and be enabled.
+    /// - The feed account must be provided in the remaining accounts and be a valid feed of
+    ///   the expected provider or the fallback provider of the token.
+    /// - If the feed account is of the fallback provider, the stale feed of the expected provider
+    ///   must be provided right after it.
+    /// - The price must pass the validations of the oracle, including the max age check.
+    pub fn get_display_price<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadTokenPrice<'info>>,
+        token: Pubkey,
+    ) -> Result<DisplayPrice> {
+        instructions::get_display_price(ctx, &token)
+    }
+
     /// Initialize a custom price feed account.
     ///
     /// Creates a new price feed account that can be used to provide custom price data for a token.
@@ -1247,6 +1836,11 @@ pub mod gmsol_store {
     ///   must match the PDA derived from the `store`, `index`, `feed_id`, and other expected seeds.
     /// - The `provider` index must correspond to a valid [`PriceProviderKind`] that supports
     ///   custom price feeds.
+    /// - The [`price_feed_counter`](InitializePriceFeed::price_feed_counter) must be the PDA
+    ///   derived from the `store` and `token`. It is created if it does not exist yet.
+    /// - The number of custom price feeds initialized for the `token`, including the new one, must
+    ///   not exceed the max number of custom price feeds per token of the store, unless it is
+    ///   unlimited (zero). Only the feeds initialized with the counter are counted.
     #[access_control(internal::Authenticate::only_price_keeper(&ctx))]
     pub fn initialize_price_feed(
         ctx: Context<InitializePriceFeed>,
@@ -1283,11 +1877,14 @@ pub mod gmsol_store {
     /// - The price feed must be configured to use [`ChainlinkDataStreams`](PriceProviderKind::ChainlinkDataStreams)
     ///   as its provider.
     /// - The `signed_report` must be:
+    ///   - No larger than the max Chainlink report size configured in the store
     ///   - Decodable as a valid Chainlink price report
     ///   - Verifiable by the Chainlink Verifier Program
     ///   - Contain valid data for creating a [`PriceFeedPrice`](states::oracle::PriceFeedPrice)
     /// - The current slot and timestamp must be >= the feed's last update.
     /// - The price data timestamp must be >= the feed's last price timestamp
+    /// - The price data must not be older than the [`max_report_age`](states::oracle::PriceFeed::max_report_age)
+    ///   of the feed if it is set.
     /// - The price data must meet all validity requirements (see the `update` method of [`PriceFeed`](states::oracle::PriceFeed)).
     #[access_control(internal::Authenticate::only_price_keeper(&ctx))]
     pub fn update_price_feed_with_chainlink(
@@ -1297,6 +1894,60 @@ pub mod gmsol_store {
         instructions::unchecked_update_price_feed_with_chainlink(ctx, compressed_report)
     }
 
+    /// Update a custom Pyth price feed with a verified Pyth price update.
+    ///
+    /// Updates the price data in a custom price feed account using a price update account
+    /// posted by the Pyth receiver program. The price feed must be configured to use the
+    /// Pyth provider.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](UpdatePriceFeedWithPyth)*
+    ///
+    /// # Errors
+    /// - The [`authority`](UpdatePriceFeedWithPyth::authority) must be a signer and have the
+    ///   PRICE_KEEPER role in the store.
+    /// - The [`store`](UpdatePriceFeedWithPyth::store) must be an initialized store account.
+    /// - The [`price_update`](UpdatePriceFeedWithPyth::price_update) must be a `PriceUpdateV2`
+    ///   account owned by the Pyth receiver program and fully verified.
+    /// - The [`price_feed`](UpdatePriceFeedWithPyth::price_feed) must be initialized, owned by
+    ///   the store, and authorized for the `authority`.
+    /// - The price feed must be configured to use [`Pyth`](PriceProviderKind::Pyth) as its provider.
+    /// - The price update must contain the price of the feed id of the price feed, and the price
+    ///   must be positive.
+    /// - The ratio of the confidence interval to the price must not exceed the
+    ///   [`MaxPythConfidenceRatio`](states::FactorKey::MaxPythConfidenceRatio) configured in the
+    ///   store, unless it is `0`.
+    /// - The current slot and timestamp must be >= the feed's last update.
+    /// - The price data timestamp must be >= the feed's last price timestamp.
+    /// - The price data must meet all validity requirements (see the `update` method of [`PriceFeed`](states::oracle::PriceFeed)).
+    #[access_control(internal::Authenticate::only_price_keeper(&ctx))]
+    pub fn update_price_feed_with_pyth(ctx: Context<UpdatePriceFeedWithPyth>) -> Result<()> {
+        instructions::unchecked_update_price_feed_with_pyth(ctx)
+    }
+
+    /// Set the max age of price reports accepted by a custom price feed.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](SetPriceFeedMaxReportAge)*
+    ///
+    /// # Arguments
+    /// - `max_report_age`: The max age of price reports in seconds, relative to the current
+    ///   timestamp. `0` means the age of price reports is not checked.
+    ///
+    /// # Errors
+    /// - The [`authority`](SetPriceFeedMaxReportAge::authority) must be a signer and have the
+    ///   MARKET_KEEPER role in the store.
+    /// - The [`store`](SetPriceFeedMaxReportAge::store) must be an initialized store account.
+    /// - The [`price_feed`](SetPriceFeedMaxReportAge::price_feed) must be initialized and owned by
+    ///   the store.
+    #[access_control(internal::Authenticate::only_market_keeper(&ctx))]
+    pub fn set_price_feed_max_report_age(
+        ctx: Context<SetPriceFeedMaxReportAge>,
+        max_report_age: u32,
+    ) -> Result<()> {
+        instructions::unchecked_set_price_feed_max_report_age(ctx, max_report_age)
+    }
+
     // ===========================================
     //              Market Management
     // ===========================================
@@ -1385,6 +2036,43 @@ pub mod gmsol_store {
         instructions::unchecked_market_transfer_in(ctx, amount)
     }
 
+    /// Transfer tokens into the insurance fund of the market.
+    ///
+    /// The insurance fund is a per-market backstop for bad debt. When the
+    /// `enable_insurance_fund` config flag of the market is set, the deficit left by an
+    /// insolvent close (e.g. a liquidation whose collateral cannot cover its losses) is
+    /// drawn from the insurance fund of the collateral token and paid into the pool, emitting
+    /// an [`InsuranceFundDrawn`](events::InsuranceFundDrawn) event. If the deficit is fully
+    /// covered, the close is no longer treated as insolvent. Otherwise, the remaining deficit is
+    /// left as bad debt of the insolvent close, whether the fund is partially drawn or empty.
+    ///
+    /// The transferred tokens are recorded in the market balance but not in the pools.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](MarketTransferIn)
+    ///
+    /// # Arguments
+    /// - `amount`: The amount of tokens to transfer into the insurance fund.
+    ///
+    /// # Errors
+    /// - The [`authority`](MarketTransferIn::authority) must be a signer and have the MARKET_KEEPER
+    ///   role in the store.
+    /// - The [`store`](MarketTransferIn::store) must be an initialized store account owned by this program.
+    /// - The [`from_authority`](MarketTransferIn::from_authority) must be a signer and have the
+    ///   permission to transfer.
+    /// - The [`market`](MarketTransferIn::market) must be an initialized market account owned by the store.
+    /// - The [`from`](MarketTransferIn::from) must be an initialized token account and cannot be the
+    ///   same as the destination vault.
+    /// - The [`vault`](MarketTransferIn::vault) must be an initialized and valid market vault token
+    ///   account owned by the store. It must have the same mint as the `from` token account.
+    /// - The market must be enabled and the token being transferred must be one of the market's
+    ///   configured pool tokens (long token or short token).
+    /// - The source token account must have sufficient balance for the transfer amount.
+    #[access_control(internal::Authenticate::only_market_keeper(&ctx))]
+    pub fn fund_market_insurance(ctx: Context<MarketTransferIn>, amount: u64) -> Result<()> {
+        instructions::unchecked_fund_market_insurance(ctx, amount)
+    }
+
     /// Update an item in the market config.
     ///
     /// This instruction allows a MARKET_KEEPER to update a single configuration value in the market's
@@ -1500,6 +2188,238 @@ pub mod gmsol_store {
         instructions::get_market_status(ctx, &prices, maximize_pnl, maximize_pool_value)
     }
 
+    /// Check whether ADL is required for the given side of the market with the given prices.
+    ///
+    /// ADL is required when the PnL factor of the side exceeds the max PnL factor for ADL,
+    /// which is the same condition checked by [`update_adl_state`] and when executing ADL
+    /// orders.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadMarket)
+    ///
+    /// # Arguments
+    /// - `prices`: The current unit prices of tokens in the market, used for calculations.
+    /// - `is_long`: The side to check.
+    ///
+    /// # Errors
+    /// - The [`market`](ReadMarket::market) account must be properly initialized.
+    /// - The provided prices must be non-zero.
+    /// - Any calculation errors.
+    pub fn is_adl_required(
+        ctx: Context<ReadMarket>,
+        prices: Prices<u128>,
+        is_long: bool,
+    ) -> Result<bool> {
+        instructions::is_adl_required(ctx, &prices, is_long)
+    }
+
+    /// Get the fee related parameters of the market config.
+    ///
+    /// This instruction returns the swap, order, liquidation, borrowing and funding fee
+    /// parameters currently applied to the market. Changes pushed to a
+    /// [`MarketConfigBuffer`](states::market::config::MarketConfigBuffer) are not reflected
+    /// until the buffer is applied.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadMarket)
+    ///
+    /// # Errors
+    /// - The [`market`](ReadMarket::market) account must be properly initialized.
+    pub fn get_market_fee_config(ctx: Context<ReadMarket>) -> Result<MarketFeeConfig> {
+        instructions::get_market_fee_config(ctx)
+    }
+
+    /// Get the liquidation and ADL related parameters of the market config.
+    ///
+    /// This instruction returns the maintenance margin (min collateral) factors and the ADL
+    /// trigger factors currently applied to the market.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadMarket)
+    ///
+    /// # Errors
+    /// - The [`market`](ReadMarket::market) account must be properly initialized.
+    pub fn get_market_risk_params(ctx: Context<ReadMarket>) -> Result<MarketRiskConfig> {
+        instructions::get_market_risk_params(ctx)
+    }
+
+    /// Get the tokens the market requires prices for.
+    ///
+    /// This instruction returns the deduplicated set of the index, long and short tokens of
+    /// the market, sorted by address, which is the order expected for the feed accounts when
+    /// executing single-market actions.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadMarket)
+    ///
+    /// # Errors
+    /// - The [`market`](ReadMarket::market) account must be properly initialized.
+    pub fn get_market_price_tokens(ctx: Context<ReadMarket>) -> Result<Vec<Pubkey>> {
+        instructions::get_market_price_tokens(ctx)
+    }
+
+    /// Get the borrowing and funding accrual state of the market.
+    ///
+    /// Returns the timestamps of the last borrowing and funding state updates, together with
+    /// the borrowing accrual that would be applied if the market were refreshed now. For funding,
+    /// the elapsed duration and the current funding factor per second are returned.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadMarket)
+    ///
+    /// # Arguments
+    /// - `prices`: The current unit prices of tokens in the market, used for calculations.
+    ///
+    /// # Errors
+    /// - The [`market`](ReadMarket::market) account must be properly initialized.
+    /// - The provided prices must be non-zero.
+    /// - Any calculation errors.
+    pub fn get_market_accrual_state(
+        ctx: Context<ReadMarket>,
+        prices: Prices<u128>,
+    ) -> Result<MarketAccrualState> {
+        instructions::get_market_accrual_state(ctx, &prices)
+    }
+
+    /// Get the insurance fund balances of the market.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadMarket)
+    ///
+    /// # Errors
+    /// - The [`market`](ReadMarket::market) account must be properly initialized.
+    pub fn get_market_insurance_fund(ctx: Context<ReadMarket>) -> Result<MarketInsuranceFund> {
+        instructions::get_market_insurance_fund(ctx)
+    }
+
+    /// Derive the canonical market and market token addresses for the given tokens.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadStore)
+    ///
+    /// # Arguments
+    /// - `index_token`: The address of the index token.
+    /// - `long_token`: The address of the long token.
+    /// - `short_token`: The address of the short token.
+    ///
+    /// # Errors
+    /// - The [`store`](ReadStore::store) must be an initialized store account.
+    pub fn derive_market_address(
+        ctx: Context<ReadStore>,
+        index_token: Pubkey,
+        long_token: Pubkey,
+        short_token: Pubkey,
+    ) -> Result<MarketAddresses> {
+        instructions::derive_market_address(ctx, &index_token, &long_token, &short_token)
+    }
+
+    /// Check whether a shift from one market to another is allowed.
+    ///
+    /// Returns whether [`create_shift`](gmsol_store::create_shift) would accept the given pair
+    /// of markets, and the reason if not.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadShiftMarkets)
+    ///
+    /// # Errors
+    /// - The [`store`](ReadShiftMarkets::store) must be an initialized store account.
+    /// - The [`from_market`](ReadShiftMarkets::from_market) and [`to_market`](ReadShiftMarkets::to_market)
+    ///   must be initialized market accounts owned by the `store`.
+    pub fn is_market_shiftable(ctx: Context<ReadShiftMarkets>) -> Result<MarketShiftability> {
+        instructions::is_market_shiftable(ctx)
+    }
+
+    /// Get the accrued borrowing and funding fees of a position.
+    ///
+    /// The fees are accrued up to the last borrowing and funding state update of the market,
+    /// and are valued with the provided prices.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadPosition)
+    ///
+    /// # Arguments
+    /// - `prices`: The current unit prices of tokens in the market, used for calculations.
+    ///
+    /// # Errors
+    /// - The [`market`](ReadPosition::market) must be an initialized market account.
+    /// - The [`position`](ReadPosition::position) must be an initialized position account
+    ///   of the `market`.
+    /// - The provided prices must be non-zero.
+    /// - Any calculation errors.
+    pub fn get_position_accrued_fees(
+        ctx: Context<ReadPosition>,
+        prices: Prices<u128>,
+    ) -> Result<PositionAccruedFees> {
+        instructions::get_position_accrued_fees(ctx, &prices)
+    }
+
+    /// Get the average entry price and cost basis of a position.
+    ///
+    /// The cost basis is increased by the size delta and the paid fees on each increase,
+    /// and is reduced proportionally to the size on each decrease.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadPosition)
+    ///
+    /// # Errors
+    /// - The [`market`](ReadPosition::market) must be an initialized market account.
+    /// - The [`position`](ReadPosition::position) must be an initialized position account
+    ///   of the `market`.
+    pub fn get_position_cost_basis(ctx: Context<ReadPosition>) -> Result<PositionCostBasis> {
+        instructions::get_position_cost_basis(ctx)
+    }
+
+    /// Get the max size delta that a position can be decreased by without being fully closed.
+    ///
+    /// The decrease model closes the position entirely if the remaining size or the remaining
+    /// collateral value would be too small. This instruction returns the largest size delta that
+    /// avoids it, assuming that no collateral is withdrawn.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadPosition)
+    ///
+    /// # Arguments
+    /// - `prices`: The current unit prices of tokens in the market, used for calculations.
+    ///
+    /// # Errors
+    /// - The [`market`](ReadPosition::market) must be an initialized market account.
+    /// - The [`position`](ReadPosition::position) must be an initialized position account
+    ///   of the `market`.
+    /// - The provided prices must be non-zero.
+    /// - Any calculation errors.
+    pub fn get_max_safe_decrease(
+        ctx: Context<ReadPosition>,
+        prices: Prices<u128>,
+    ) -> Result<MaxSafeDecrease> {
+        instructions::get_max_safe_decrease(ctx, &prices)
+    }
+
+    /// Get the current margin ratio and the maintenance margin ratio of a position.
+    ///
+    /// The margin ratio is the remaining collateral value after closing the whole position
+    /// (excluding liquidation fees) divided by its size in USD. The position is liquidatable
+    /// if the margin ratio is below the maintenance margin ratio (the min collateral factor),
+    /// or the remaining collateral value is below the min collateral value.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadPosition)
+    ///
+    /// # Arguments
+    /// - `prices`: The current unit prices of tokens in the market, used for calculations.
+    ///
+    /// # Errors
+    /// - The [`market`](ReadPosition::market) must be an initialized market account.
+    /// - The [`position`](ReadPosition::position) must be an initialized position account
+    ///   of the `market`.
+    /// - The provided prices must be non-zero.
+    /// - Any calculation errors.
+    pub fn get_position_margin(
+        ctx: Context<ReadPosition>,
+        prices: Prices<u128>,
+    ) -> Result<PositionMargin> {
+        instructions::get_position_margin(ctx, &prices)
+    }
+
     /// Get the current market token price based on the provided token prices and PnL factor.
     ///
     /// This instruction calculates and returns the current price of the market token, taking into
@@ -1537,6 +2457,78 @@ pub mod gmsol_store {
         )
     }
 
+    /// Preview a deposit of the given USD values into the market.
+    ///
+    /// The required token amounts are derived from the USD values with the min prices of the
+    /// tokens, and the market tokens to mint are calculated by simulating the deposit with the
+    /// deposit model, without changing the market.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadMarketWithToken)
+    ///
+    /// # Arguments
+    /// - `prices`: The current unit prices of tokens in the market, used for calculations.
+    /// - `long_token_value`: The USD value of long tokens to deposit.
+    /// - `short_token_value`: The USD value of short tokens to deposit.
+    ///
+    /// # Errors
+    /// - The [`market`](ReadMarketWithToken::market) must be an initialized market account.
+    /// - The [`market_token`](ReadMarketWithToken::market_token) must be the market token
+    ///   of the `market`.
+    /// - The provided prices must be non-zero.
+    /// - At least one of `long_token_value` and `short_token_value` must be non-zero.
+    /// - Any calculation errors, including the validations of the deposit model.
+    pub fn get_deposit_preview(
+        ctx: Context<ReadMarketWithToken>,
+        prices: Prices<u128>,
+        long_token_value: u128,
+        short_token_value: u128,
+    ) -> Result<DepositPreview> {
+        instructions::get_deposit_preview(ctx, &prices, long_token_value, short_token_value)
+    }
+
+    /// Preview the market token price change caused by minting or burning market tokens.
+    ///
+    /// If any of `long_token_amount` and `short_token_amount` is non-zero, a deposit of these
+    /// amounts is simulated with the deposit model. Otherwise, a withdrawal of `burn_amount`
+    /// market tokens is simulated with the withdrawal model. The market is not changed.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadMarketWithToken)
+    ///
+    /// # Arguments
+    /// - `prices`: The current unit prices of tokens in the market, used for calculations.
+    /// - `long_token_amount`: The amount of long tokens to deposit.
+    /// - `short_token_amount`: The amount of short tokens to deposit.
+    /// - `burn_amount`: The amount of market tokens to burn.
+    /// - `maximize`: Whether to maximize the market token prices.
+    ///
+    /// # Errors
+    /// - The [`market`](ReadMarketWithToken::market) must be an initialized market account.
+    /// - The [`market_token`](ReadMarketWithToken::market_token) must be the market token
+    ///   of the `market`.
+    /// - The provided prices must be non-zero.
+    /// - Exactly one of a deposit (non-zero token amounts) and a burn (non-zero `burn_amount`)
+    ///   must be provided.
+    /// - Any calculation errors, including the validations of the deposit or withdrawal model.
+    pub fn preview_market_token_price_change(
+        ctx: Context<ReadMarketWithToken>,
+        prices: Prices<u128>,
+        long_token_amount: u64,
+        short_token_amount: u64,
+        burn_amount: u64,
+        maximize: bool,
+    ) -> Result<MarketTokenPriceChange> {
+        instructions::preview_market_token_price_change(
+            ctx,
+            &prices,
+            long_token_amount,
+            short_token_amount,
+            burn_amount,
+            maximize,
+        )
+    }
+
     /// Returns the USD value for the given market token amount.
     ///
     /// # Accounts
@@ -1548,7 +2540,8 @@ pub mod gmsol_store {
     ///   [`PnlFactorKind`](gmsol_model::PnlFactorKind).
     /// - `maximize`: If true, uses the maximum possible values in calculations.
     ///   If false, uses minimum values.
-    /// - `max_age`: Maximum allowed age of the earliest oracle timestamp.
+    /// - `max_age`: Maximum allowed age of the earliest oracle timestamp. Defaults to the
+    ///   default oracle staleness of the store if not provided.
     /// - `emit_event`: Whether to emit a [`GlvTokenValue`](crate::events::GlvTokenValue) event.
     ///
     /// # Errors
@@ -1562,13 +2555,14 @@ pub mod gmsol_store {
     ///   in the required order.
     /// - The `pnl_factor` must be a valid [`PnlFactorKind`](gmsol_model::PnlFactorKind).
     /// - The earliest oracle timestamp must be within `max_age`.
+    /// - `max_age` must be provided if the default oracle staleness of the store is not set.
     /// - Any calculation errors.
     pub fn get_market_token_value<'info>(
         ctx: Context<'_, '_, 'info, 'info, GetMarketTokenValue<'info>>,
         amount: u64,
         pnl_factor: String,
         maximize: bool,
-        max_age: u32,
+        max_age: Option<u32>,
         emit_event: bool,
     ) -> Result<u128> {
         GetMarketTokenValue::invoke(
@@ -1604,6 +2598,8 @@ pub mod gmsol_store {
     /// - The [`buffer`](InitializeMarketConfigBuffer::buffer) must be an uninitialized account
     ///   that will store the market configuration data.
     /// - The expiration time must be greater than zero.
+    /// - The expiration time must be within the min and max expiration durations of market
+    ///   config buffers configured in the store.
     pub fn initialize_market_config_buffer(
         ctx: Context<InitializeMarketConfigBuffer>,
         expire_after_secs: u32,
@@ -1702,27 +2698,72 @@ pub mod gmsol_store {
         instructions::unchecked_toggle_gt_minting(ctx, enable)
     }
 
-    /// Claim fees from the given market.
+    /// Claim fees from the given market.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ClaimFeesFromMarket)
+    ///
+    /// # Return
+    /// - Returns the claimed amount in base units of the token.
+    ///
+    /// # Errors
+    /// - The [`authority`](ClaimFeesFromMarket::authority) must be a signer and be the designated
+    ///   fee receiver in the given store.
+    /// - The [`store`](ClaimFeesFromMarket::store) must be an initialized [`Store`](crate::states::Store)
+    ///   account owned by this program.
+    /// - The [`market`](ClaimFeesFromMarket::market) must be an initialized [`Market`](crate::states::Market)
+    ///   account owned by this program and associated with the given store.
+    /// - The token being claimed must be one of the market's configured collateral tokens.
+    /// - All provided token accounts must match their expected addresses.
+    /// - The market must maintain valid balance requirements after the claim.
+    pub fn claim_fees_from_market(ctx: Context<ClaimFeesFromMarket>) -> Result<u64> {
+        let claimed = instructions::claim_fees_from_market(ctx)?;
+        Ok(claimed)
+    }
+
+    /// Get the fee receiver of the store and the claimable fees of the given markets.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadStore)
+    ///
+    /// Remaining accounts expected by this instruction:
+    /// - `[]` the market accounts to query for.
+    ///
+    /// # Errors
+    /// - The [`store`](ReadStore::store) must be an initialized [`Store`](crate::states::Store)
+    ///   account owned by this program.
+    /// - Each of the remaining accounts must be an initialized [`Market`](crate::states::Market)
+    ///   account owned by the `store`.
+    pub fn get_fee_receiver_status<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadStore<'info>>,
+    ) -> Result<FeeReceiverStatus> {
+        instructions::get_fee_receiver_status(ctx)
+    }
+
+    /// Get the markets that accept the given token as collateral, from the given candidates.
+    ///
+    /// A market accepts the token as collateral if it is either the long token or the short
+    /// token of the market. Disabled markets are not returned.
     ///
     /// # Accounts
-    /// [*See the documentation for the accounts.*](ClaimFeesFromMarket)
+    /// [*See the documentation for the accounts.*](ReadStore)
     ///
-    /// # Return
-    /// - Returns the claimed amount in base units of the token.
+    /// Remaining accounts expected by this instruction:
+    /// - `[]` the candidate market accounts.
+    ///
+    /// # Arguments
+    /// - `token`: The collateral token to query for.
     ///
     /// # Errors
-    /// - The [`authority`](ClaimFeesFromMarket::authority) must be a signer and be the designated
-    ///   fee receiver in the given store.
-    /// - The [`store`](ClaimFeesFromMarket::store) must be an initialized [`Store`](crate::states::Store)
+    /// - The [`store`](ReadStore::store) must be an initialized [`Store`](crate::states::Store)
     ///   account owned by this program.
-    /// - The [`market`](ClaimFeesFromMarket::market) must be an initialized [`Market`](crate::states::Market)
-    ///   account owned by this program and associated with the given store.
-    /// - The token being claimed must be one of the market's configured collateral tokens.
-    /// - All provided token accounts must match their expected addresses.
-    /// - The market must maintain valid balance requirements after the claim.
-    pub fn claim_fees_from_market(ctx: Context<ClaimFeesFromMarket>) -> Result<u64> {
-        let claimed = instructions::claim_fees_from_market(ctx)?;
-        Ok(claimed)
+    /// - Each of the remaining accounts must be an initialized [`Market`](crate::states::Market)
+    ///   account owned by the `store`.
+    pub fn get_markets_for_collateral<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadStore<'info>>,
+        token: Pubkey,
+    ) -> Result<Vec<MarketForCollateral>> {
+        instructions::get_markets_for_collateral(ctx, &token)
     }
 
     /// Initialize a new market vault for a specific token.
@@ -1845,6 +2886,153 @@ pub mod gmsol_store {
         UpdateTokenMetadata::invoke_unchecked(ctx, name, symbol, uri)
     }
 
+    // ===========================================
+    //                  Actions
+    // ===========================================
+
+    /// Get the execution fees recorded in the given actions.
+    ///
+    /// The actions can be of any kinds, and the results are returned in the same
+    /// order as the provided action accounts.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadActions)*
+    ///
+    /// # Errors
+    /// - The remaining accounts must be initialized action accounts owned by the `store`.
+    pub fn get_pending_execution_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadActions<'info>>,
+    ) -> Result<Vec<ActionExecutionFee>> {
+        instructions::get_pending_execution_fees(ctx)
+    }
+
+    /// Get the escrow accounts and their mints recorded in the given actions.
+    ///
+    /// The actions can be of any kinds, and the results are returned in the same
+    /// order as the provided action accounts.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadActions)*
+    ///
+    /// # Errors
+    /// - The remaining accounts must be initialized action accounts owned by the `store`.
+    pub fn get_action_escrows<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadActions<'info>>,
+    ) -> Result<Vec<ActionEscrows>> {
+        instructions::get_action_escrows(ctx)
+    }
+
+    /// Get the balances of the escrow accounts recorded in the given actions.
+    ///
+    /// Each action account in the remaining accounts must be followed by its escrow
+    /// accounts, in the same order as returned by [`get_action_escrows`]. The balance of
+    /// an escrow account that does not exist is returned as `None`. The results are
+    /// returned in the same order as the provided action accounts.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadActions)*
+    ///
+    /// # Errors
+    /// - The action accounts must be initialized action accounts owned by the `store`.
+    /// - Each action account must be followed by all of its escrow accounts in the
+    ///   expected order.
+    /// - The existing escrow accounts must be token accounts of the recorded mints.
+    pub fn get_action_escrow_balances<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadActions<'info>>,
+    ) -> Result<Vec<ActionEscrowBalances>> {
+        instructions::get_action_escrow_balances(ctx)
+    }
+
+    /// Get the estimated compute requirements for executing the given actions.
+    ///
+    /// This is a heuristic intended to help keepers set compute budgets. It returns the
+    /// number of swap hops and tokens to price of each action, together with an estimated
+    /// compute requirement class. The results are returned in the same order as the
+    /// provided action accounts.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadActions)*
+    ///
+    /// # Errors
+    /// - The remaining accounts must be initialized action accounts owned by the `store`.
+    pub fn estimate_execution_compute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadActions<'info>>,
+    ) -> Result<Vec<ExecutionComputeEstimate>> {
+        instructions::estimate_execution_compute(ctx)
+    }
+
+    /// Get the swap parameters recorded in the given deposits and withdrawals.
+    ///
+    /// For a deposit, the swap paths are those of the initial long and short tokens. For a
+    /// withdrawal, they are those of the final long and short tokens. The results are returned
+    /// in the same order as the provided action accounts.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadActions)*
+    ///
+    /// # Errors
+    /// - The remaining accounts must be initialized deposit or withdrawal accounts owned by
+    ///   the `store`.
+    pub fn get_action_swap_params<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadActions<'info>>,
+    ) -> Result<Vec<ActionSwapParams>> {
+        instructions::get_action_swap_params(ctx)
+    }
+
+    /// Derive the address of the action account of the given kind.
+    ///
+    /// The address is derived from the `store`, the `owner` and the `nonce` in the same way
+    /// as the create instruction of the action, so that clients can check for collisions with
+    /// existing accounts before creating the action.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadStore).*
+    ///
+    /// # Arguments
+    /// - `owner`: The owner of the action. For GLV shifts, this is the keeper creating it.
+    /// - `kind`: The kind of the action.
+    /// - `nonce`: The nonce used to create the action.
+    pub fn derive_action_address(
+        ctx: Context<ReadStore>,
+        owner: Pubkey,
+        kind: ActionKind,
+        nonce: [u8; 32],
+    ) -> Result<Pubkey> {
+        instructions::derive_action_address(ctx, &owner, kind, &nonce)
+    }
+
+    /// Check whether the given deposit or withdrawal is currently executable with the given prices.
+    ///
+    /// This mirrors the preconditions of the execution, including the feature flag, the
+    /// market status, the action age, the first deposit requirements, the min market token
+    /// price, the pool caps and reserves checked by the model, and the min outputs of the
+    /// action. Returns a reason code together with the result.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadLiquidityAction)*
+    ///
+    /// # Arguments
+    /// - `prices`: The current unit prices of tokens in the market.
+    ///
+    /// # Notes
+    /// - The execution is simulated without the pre-execution updates (e.g., position impact
+    ///   distribution and borrowing state updates) and the virtual inventory impact.
+    /// - Actions with swap paths are reported with
+    ///   [`SwapNotSupported`](ActionExecutableReason::SwapNotSupported).
+    ///
+    /// # Errors
+    /// - The first remaining account must be a deposit or withdrawal account owned by
+    ///   the `store` and created for the `market`.
+    /// - The [`market_token`](ReadLiquidityAction::market_token) must be the market token
+    ///   of the `market`.
+    /// - The provided prices must be non-zero.
+    pub fn is_action_executable<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadLiquidityAction<'info>>,
+        prices: Prices<u128>,
+    ) -> Result<ActionExecutability> {
+        instructions::is_action_executable(ctx, &prices)
+    }
+
     // ===========================================
     //                  Deposit
     // ===========================================
@@ -1872,6 +3060,11 @@ pub mod gmsol_store {
     /// - Any source account has insufficient balance, does not match the initial tokens, or the
     ///   `owner` does not have the permission to transfer the tokens.
     /// - The remaining accounts do not form valid swap paths or reference disabled markets.
+    /// - The total length of the swap paths exceeds the max deposit swap path length of the store.
+    /// - The [`user`](CreateDeposit::user) is not initialized or does not correspond to the owner.
+    ///   The address must be a valid PDA derived from the `owner` and expected seeds.
+    /// - The number of pending actions of the [`user`](CreateDeposit::user) would exceed the
+    ///   max pending actions per user configured in the `store`.
     pub fn create_deposit<'info>(
         mut ctx: Context<'_, '_, 'info, 'info, CreateDeposit<'info>>,
         nonce: [u8; 32],
@@ -1887,6 +3080,8 @@ pub mod gmsol_store {
     ///
     /// # Arguments
     /// - `reason`: The reason for closing the deposit.
+    /// - `close_reason`: The structured reason for the close, emitted in the close event.
+    ///   Always recorded as `UserCancel` when the owner closes a pending action.
     ///
     /// # Errors
     /// This instruction will fail if:
@@ -1900,11 +3095,14 @@ pub mod gmsol_store {
     /// - Any escrow account is not owned by the `deposit` or does not match the `deposit` records.
     /// - Any associated token account address is invalid.
     /// - The deposit is not in a cancelled or completed state when closed by a non-owner.
+    /// - The [`user`](CloseDeposit::user) is not provided while the deposit has been recorded as a
+    ///   pending action of the owner, or does not correspond to the owner.
     pub fn close_deposit<'info>(
         ctx: Context<'_, '_, 'info, 'info, CloseDeposit<'info>>,
         reason: String,
+        close_reason: CloseReason,
     ) -> Result<()> {
-        internal::Close::close(&ctx, &reason)
+        internal::Close::close(&ctx, &reason, close_reason)
     }
 
     /// Execute a deposit by keepers.
@@ -1937,7 +3135,10 @@ pub mod gmsol_store {
     /// - Any market accounts in the remaining accounts are disabled, not owned by the store,
     ///   or do not match the swap parameters.
     /// - Any oracle prices from the feed accounts are incomplete or invalid.
-    /// - The execution fails and `throw_on_execution_error` is set to `true`.
+    /// - The execution fails and `throw_on_execution_error` is set to `true`. Note that the execution
+    ///   fails if the market token price is below the
+    ///   [`MinMarketTokenPrice`](states::market::config::MarketConfigKey::MinMarketTokenPrice)
+    ///   config of the market.
     #[access_control(internal::Authenticate::only_order_keeper(&ctx))]
     pub fn execute_deposit<'info>(
         ctx: Context<'_, '_, 'info, 'info, ExecuteDeposit<'info>>,
@@ -1976,6 +3177,11 @@ pub mod gmsol_store {
     ///   permission to transfer the tokens.
     /// - Any market accounts in the remaining accounts are disabled, not owned by the store,
     ///   or do not form valid swap paths.
+    /// - The total length of the swap paths exceeds the max withdrawal swap path length of the store.
+    /// - The [`user`](CreateWithdrawal::user) is not initialized or does not correspond to the owner.
+    ///   The address must be a valid PDA derived from the `owner` and expected seeds.
+    /// - The number of pending actions of the [`user`](CreateWithdrawal::user) would exceed the
+    ///   max pending actions per user configured in the `store`.
     pub fn create_withdrawal<'info>(
         mut ctx: Context<'_, '_, 'info, 'info, CreateWithdrawal<'info>>,
         nonce: [u8; 32],
@@ -1991,6 +3197,8 @@ pub mod gmsol_store {
     ///
     /// # Arguments
     /// - `reason`: The reason for closing the withdrawal.
+    /// - `close_reason`: The structured reason for the close, emitted in the close event.
+    ///   Always recorded as `UserCancel` when the owner closes a pending action.
     ///
     /// # Errors
     /// This instruction will fail if:
@@ -2004,11 +3212,14 @@ pub mod gmsol_store {
     /// - Any required escrow accounts are not properly initialized or not owned by the `withdrawal`.
     /// - Any associated token accounts have invalid addresses.
     /// - The withdrawal is not in a cancelled or completed state when the executor is not the owner
+    /// - The [`user`](CloseWithdrawal::user) is not provided while the withdrawal has been recorded as a
+    ///   pending action of the owner, or does not correspond to the owner.
     pub fn close_withdrawal<'info>(
         ctx: Context<'_, '_, 'info, 'info, CloseWithdrawal<'info>>,
         reason: String,
+        close_reason: CloseReason,
     ) -> Result<()> {
-        internal::Close::close(&ctx, &reason)
+        internal::Close::close(&ctx, &reason, close_reason)
     }
 
     /// Execute a withdrawal by keepers.
@@ -2117,6 +3328,8 @@ pub mod gmsol_store {
     ///     the `order`.
     ///   - The [`initial_collateral_token_source`](CreateOrder::initial_collateral_token_source)
     ///     is missing or not a valid source account with `owner` as the authority.
+    /// - For increase orders, the `size_delta_value` exceeds the max order size of the
+    ///   [`market`](CreateOrder::market).
     /// - For decrease/swap orders:
     ///   - The [`final_output_token`](CreateOrder::final_output_token) is invalid.
     ///   - The [`final_output_token_escrow`](CreateOrder::final_output_token_escrow) is missing,
@@ -2155,6 +3368,8 @@ pub mod gmsol_store {
     ///   the `store`.
     /// - The [`user`](CreateOrderV2::user) is not initialized or does not correspond to the owner.
     ///   The address must be a valid PDA derived from the `owner` and expected seeds.
+    /// - The number of pending actions of the [`user`](CreateOrderV2::user) would exceed the
+    ///   max pending actions per user configured in the `store`.
     /// - The [`order`](CreateOrderV2::order) is not uninitialized or the address is not a valid
     ///   PDA derived from the `owner`, `nonce` and expected seeds.
     /// - For increase/decrease orders:
@@ -2173,6 +3388,10 @@ pub mod gmsol_store {
     ///     the `order`.
     ///   - The [`initial_collateral_token_source`](CreateOrderV2::initial_collateral_token_source)
     ///     is missing or not a valid source account with `owner` as the authority.
+    /// - For increase orders, the `size_delta_value` exceeds the max order size of the
+    ///   [`market`](CreateOrderV2::market).
+    /// - For limit and stop-loss orders, the number of unclosed resting orders of the `position`
+    ///   would exceed [`Position::MAX_RESTING_ORDERS`](states::Position::MAX_RESTING_ORDERS).
     /// - For decrease/swap orders:
     ///   - The [`final_output_token`](CreateOrderV2::final_output_token) is invalid.
     ///   - The [`final_output_token_escrow`](CreateOrderV2::final_output_token_escrow) is missing,
@@ -2182,6 +3401,9 @@ pub mod gmsol_store {
     ///   by the `store`.
     /// - The accounts related to callback must be provided if
     ///   [`callback_authority`](CreateOrderV2::callback_authority) is provided.
+    /// - `pay_execution_fee_from_collateral` is enabled but the order is not a position order,
+    ///   or the number of tokens exceeds the limit after adding the native token, whose price is
+    ///   required to convert the execution fee into collateral tokens.
     pub fn create_order_v2<'info>(
         mut ctx: Context<'_, '_, 'info, 'info, CreateOrderV2<'info>>,
         nonce: [u8; 32],
@@ -2191,6 +3413,34 @@ pub mod gmsol_store {
         internal::Create::create(&mut ctx, &nonce, &params, callback_version)
     }
 
+    /// Check which prerequisites of creating an order with the given params are missing.
+    ///
+    /// Reports whether the user account, the position account (for increase and decrease
+    /// orders) and the escrow accounts of the order to be created have been prepared.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](CheckOrderPrerequisites)*
+    ///
+    /// # Arguments
+    /// - `nonce`: The nonce of the order to be created.
+    /// - `params`: The parameters of the order to be created.
+    ///
+    /// # Errors
+    /// - The [`market`](CheckOrderPrerequisites::market) must be an initialized market account
+    ///   owned by the `store`.
+    /// - The [`user`](CheckOrderPrerequisites::user) and the
+    ///   [`position`](CheckOrderPrerequisites::position) must have the expected addresses.
+    /// - The [`initial_collateral_token`](CheckOrderPrerequisites::initial_collateral_token)
+    ///   must be provided for increase and swap orders.
+    /// - The provided escrow accounts must be the associated token accounts of the order.
+    pub fn check_order_prerequisites(
+        ctx: Context<CheckOrderPrerequisites>,
+        nonce: [u8; 32],
+        params: CreateOrderParams,
+    ) -> Result<OrderPrerequisites> {
+        instructions::check_order_prerequisites(ctx, &nonce, &params)
+    }
+
     /// Close an order, either by the owner or by keepers.
     ///
     /// # Accounts
@@ -2198,6 +3448,8 @@ pub mod gmsol_store {
     ///
     /// # Arguments
     /// - `reason`: The reason for the close.
+    /// - `close_reason`: The structured reason for the close, emitted in the close event.
+    ///   Always recorded as `UserCancel` when the owner closes a pending action.
     ///
     /// # Errors
     /// - The [`executor`](CloseOrder::executor) must be a signer and either the owner
@@ -2214,12 +3466,15 @@ pub mod gmsol_store {
     /// - The addresses of the ATAs must be valid.
     /// - The `order` must be cancelled or completed if the `executor` is not the owner.
     /// - The feature must be enabled for closing the given kind of `order`.
+    /// - The `order` must not be recorded as a resting order of its position, which must be
+    ///   closed with [`close_order_v2`](gmsol_store::close_order_v2) instead.
     #[deprecated(since = "0.6.0", note = "use `close_order_v2` instead.")]
     pub fn close_order<'info>(
         ctx: Context<'_, '_, 'info, 'info, CloseOrder<'info>>,
         reason: String,
+        close_reason: CloseReason,
     ) -> Result<()> {
-        internal::Close::close(&ctx, &reason)
+        internal::Close::close(&ctx, &reason, close_reason)
     }
 
     /// Close an order, either by the owner or by keepers.
@@ -2229,6 +3484,8 @@ pub mod gmsol_store {
     ///
     /// # Arguments
     /// - `reason`: The reason for the close.
+    /// - `close_reason`: The structured reason for the close, emitted in the close event.
+    ///   Always recorded as `UserCancel` when the owner closes a pending action.
     ///
     /// # Errors
     /// - The [`executor`](CloseOrderV2::executor) must be a signer and either the owner
@@ -2247,11 +3504,19 @@ pub mod gmsol_store {
     /// - The feature must be enabled for closing the given kind of `order`.
     /// - The accounts related to callback must be provided if
     ///   [`callback_authority`](CloseOrderV2::callback_authority) is provided.
+    /// - If the [`position`](CloseOrderV2::position) is provided, it must be the position
+    ///   of the `order`, owned by the `store` and the `owner`, and must be empty unless the
+    ///   `order` is recorded as a resting order of it. It will be closed with its rent returned
+    ///   to the `owner` if it is empty and has no unclosed resting orders.
+    /// - The `position` must be provided if the `order` is recorded as a resting order of it.
+    /// - If the `order` is pending and queued for fair execution, the
+    ///   [`market`](CloseOrderV2::market) of the `order` must be provided.
     pub fn close_order_v2<'info>(
         ctx: Context<'_, '_, 'info, 'info, CloseOrderV2<'info>>,
         reason: String,
+        close_reason: CloseReason,
     ) -> Result<()> {
-        internal::Close::close(&ctx, &reason)
+        internal::Close::close(&ctx, &reason, close_reason)
     }
 
     /// Cancel order if the corresponding position does not exist.
@@ -2347,6 +3612,57 @@ pub mod gmsol_store {
         UpdateOrderV2::invoke(ctx, &params)
     }
 
+    /// Update the trigger price of an order and reprice its acceptable price atomically.
+    ///
+    /// The acceptable price is derived from the current index token price with the given
+    /// slippage: the max price is increased by the slippage when buying the index token
+    /// (increasing a long or decreasing a short), and the min price is decreased by the
+    /// slippage otherwise.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](UpdateOrderReprice)*
+    ///
+    /// # Arguments
+    /// - `trigger_price`: The new trigger price (in unit price).
+    /// - `slippage_bps`: The slippage in basis points, must not exceed `10_000`.
+    ///
+    /// # Errors
+    /// - The [`owner`](UpdateOrderReprice::owner) must be a signer, the owner of the `order`
+    ///   and the authority of the `oracle` buffer.
+    /// - The [`store`](UpdateOrderReprice::store) must be initialized.
+    /// - The [`token_map`](UpdateOrderReprice::token_map) must be initialized and authorized
+    ///   by the `store`.
+    /// - The [`oracle`](UpdateOrderReprice::oracle) must be initialized and cleared.
+    /// - The [`market`](UpdateOrderReprice::market) must be initialized, enabled and owned by the `store`.
+    /// - The [`order`](UpdateOrderReprice::order) must be:
+    ///   - Initialized and owned by both the `store` and the `owner`
+    ///   - Associated with the provided `market`
+    ///   - In a pending state
+    ///   - A limit increase, limit decrease or stop-loss decrease order without callback
+    /// - The feature must be enabled in the `store` for updating the given kind of `order`.
+    /// - The `trigger_price` must be non-zero.
+    /// - The remaining accounts must be valid feed accounts for the tokens of the `market`.
+    pub fn update_order_reprice<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateOrderReprice<'info>>,
+        trigger_price: u128,
+        slippage_bps: u16,
+    ) -> Result<()> {
+        UpdateOrderReprice::invoke(ctx, trigger_price, slippage_bps)
+    }
+
+    /// Get the decoded parameters of an order.
+    ///
+    /// The returned struct is decoupled from the account layout of [`Order`](states::Order).
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadOrder)*
+    ///
+    /// # Errors
+    /// - The [`order`](ReadOrder::order) must be an initialized order owned by the `store`.
+    pub fn get_order_params(ctx: Context<ReadOrder>) -> Result<OrderParamsInfo> {
+        instructions::get_order_params(ctx)
+    }
+
     /// Execute an increase/swap order by keepers.
     ///
     /// # Accounts
@@ -2419,6 +3735,16 @@ pub mod gmsol_store {
 
     /// Execute an increase/swap order by keepers.
     ///
+    /// If fair market order execution is enabled in the `store`, market orders (excluding delayed
+    /// orders) are queued in the market when created, and must be executed in the order of the
+    /// queue. Keepers should therefore execute the queued market orders of a market one by one
+    /// in the order of creation. An order is removed from the queue once it is executed or
+    /// cancelled, or closed while pending.
+    ///
+    /// Note that at most 256 unfinished market orders can be queued in a market, so a single user
+    /// creating many market orders can block the creation of market orders in that market until
+    /// the queued orders are executed or cancelled.
+    ///
     /// # Accounts
     /// *[See the documentation for the accounts.](ExecuteIncreaseOrSwapOrderV2)*
     ///
@@ -2462,9 +3788,15 @@ pub mod gmsol_store {
     ///   - The [`final_output_token`](ExecuteIncreaseOrSwapOrderV2::final_output_token) must be valid.
     ///   - The corresponding escrow and vault accounts must be valid, recorded in the `order` and
     ///     owned by the `order`.
+    /// - If the `order` pays the execution fee from the position's collateral, the
+    ///   [`execution_fee_token_account`](ExecuteIncreaseOrSwapOrderV2::execution_fee_token_account) must be
+    ///   provided and be a token account of the collateral token.
     /// - The remaining accounts must be valid. See the documentation for the accounts for more
     ///   details.
     /// - The feature for executing this order type must be enabled in the `store`.
+    /// - If the `order` is queued for fair execution, it must be the earliest unfinished order in
+    ///   the queue of the market. Otherwise, the execution fails even if `throw_on_execution_error`
+    ///   is false, so that the order can be executed later.
     /// - If `throw_on_execution_error` is true, any execution failure will throw an error
     // Note: There is a false positive lint for the doc link of `event`.
     #[allow(rustdoc::broken_intra_doc_links)]
@@ -2543,6 +3875,16 @@ pub mod gmsol_store {
 
     /// Execute a decrease order by keepers.
     ///
+    /// If fair market order execution is enabled in the `store`, market orders (excluding delayed
+    /// orders) are queued in the market when created, and must be executed in the order of the
+    /// queue. Keepers should therefore execute the queued market orders of a market one by one
+    /// in the order of creation. An order is removed from the queue once it is executed or
+    /// cancelled, or closed while pending.
+    ///
+    /// Note that at most 256 unfinished market orders can be queued in a market, so a single user
+    /// creating many market orders can block the creation of market orders in that market until
+    /// the queued orders are executed or cancelled.
+    ///
     /// # Accounts
     /// *[See the documentation for the accounts.](ExecuteDecreaseOrderV2)*
     ///
@@ -2577,9 +3919,15 @@ pub mod gmsol_store {
     /// - All escrow accounts must be valid, recorded in the `order` and owned by the `order`.
     /// - All vault accounts must be valid market vault accounts and owned by the `store`.
     /// - All claimable token accounts must be valid and properly delegated to their owners.
+    /// - If the `order` pays the execution fee from the position's collateral, the
+    ///   [`execution_fee_token_account`](ExecuteDecreaseOrderV2::execution_fee_token_account) must be
+    ///   provided and be a token account of the collateral token.
     /// - The remaining accounts must be valid. See the documentation for the accounts for more
     ///   details.
     /// - The feature for executing decrease orders must be enabled in the `store`.
+    /// - If the `order` is queued for fair execution, it must be the earliest unfinished order in
+    ///   the queue of the market. Otherwise, the execution fails even if `throw_on_execution_error`
+    ///   is false, so that the order can be executed later.
     /// - If `throw_on_execution_error` is true, any execution failure will throw an error.
     // Note: There is a false positive lint for the doc link of `event`.
     #[allow(rustdoc::broken_intra_doc_links)]
@@ -2600,6 +3948,21 @@ pub mod gmsol_store {
 
     /// Perform a liquidation by keepers.
     ///
+    /// If partial liquidation is enabled by the
+    /// [`PartialLiquidationBufferFactor`](FactorKey::PartialLiquidationBufferFactor) config,
+    /// the position is only reduced enough to restore its collateral to the min collateral
+    /// factor plus the buffer. It falls back to a full liquidation if this is not possible.
+    ///
+    /// If the [`MaxPositionImpactFactorForLiquidationExecution`](states::market::config::MarketConfigKey::MaxPositionImpactFactorForLiquidationExecution)
+    /// of the market is set, the total negative price impact (including the price impact diff)
+    /// charged to the position is bounded by the factor applied to the liquidated size, and the
+    /// excess is not charged. When partial liquidation is also enabled, the cap applies to the
+    /// reduced size only, and the size to reduce is determined independently of the cap.
+    ///
+    /// If the `enable_insurance_fund` config flag of the market is set, the bad debt of an
+    /// insolvent liquidation is covered by the insurance fund of the collateral token as much as
+    /// possible (see [`fund_market_insurance`](gmsol_store::fund_market_insurance)).
+    ///
     /// # Accounts
     /// *[See the documentation for the accounts.](PositionCut)*
     ///
@@ -2798,6 +4161,10 @@ pub mod gmsol_store {
     /// - The [`to_market_token_ata`](CreateShift::to_market_token_ata) must be a valid
     ///   associated token account for [`to_market_token`](CreateShift::to_market_token)
     ///   owned by `owner`.
+    /// - The [`user`](CreateShift::user) is not initialized or does not correspond to the owner.
+    ///   The address must be a valid PDA derived from the `owner` and expected seeds.
+    /// - The number of pending actions of the [`user`](CreateShift::user) would exceed the
+    ///   max pending actions per user configured in the `store`.
     pub fn create_shift<'info>(
         mut ctx: Context<'_, '_, 'info, 'info, CreateShift<'info>>,
         nonce: [u8; 32],
@@ -2860,6 +4227,8 @@ pub mod gmsol_store {
     ///
     /// # Arguments
     /// - `reason`: The reason for closing the shift.
+    /// - `close_reason`: The structured reason for the close, emitted in the close event.
+    ///   Always recorded as `UserCancel` when the owner closes a pending action.
     ///
     /// # Errors
     /// - The [`executor`](CloseShift::executor) must be a signer, and either the owner or have
@@ -2880,11 +4249,14 @@ pub mod gmsol_store {
     ///   the derived associated token account address for the `to_market_token` and `owner`.
     /// - If the `executor` is not the `owner`, the `shift` must be in either cancelled or completed
     ///   state.
+    /// - The [`user`](CloseShift::user) is not provided while the shift has been recorded as a
+    ///   pending action of the owner, or does not correspond to the owner.
     pub fn close_shift<'info>(
         ctx: Context<'_, '_, 'info, 'info, CloseShift<'info>>,
         reason: String,
+        close_reason: CloseReason,
     ) -> Result<()> {
-        internal::Close::close(&ctx, &reason)
+        internal::Close::close(&ctx, &reason, close_reason)
     }
 
     // ===========================================
@@ -2972,6 +4344,31 @@ pub mod gmsol_store {
         instructions::unchecked_gt_set_referral_reward_factors(ctx, &factors)
     }
 
+    /// Set whether to round up when growing the GT minting cost.
+    ///
+    /// The minting cost is multiplied by the grow factor at each grow step. By default the
+    /// result is rounded down, which makes the long-run minting cost drift slightly below the
+    /// exact exponential schedule; rounding up makes it drift slightly above instead. The drift
+    /// is bounded by one unit of minting cost per grow step.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ConfigureGt)*
+    ///
+    /// # Arguments
+    /// - `round_up`: Whether to round up the minting cost when it grows.
+    ///
+    /// # Errors
+    /// - The [`authority`](ConfigureGt::authority) must be a signer and have the GT_CONTROLLER role in the `store`.
+    /// - The [`store`](ConfigureGt::store) must be properly initialized.
+    /// - The GT state of the `store` must be initialized.
+    #[access_control(internal::Authenticate::only_gt_controller(&ctx))]
+    pub fn gt_set_minting_cost_grow_round_up(
+        ctx: Context<ConfigureGt>,
+        round_up: bool,
+    ) -> Result<()> {
+        instructions::unchecked_gt_set_minting_cost_grow_round_up(ctx, round_up)
+    }
+
     /// Set GT exchange time window (in seconds).
     ///
     /// # Accounts
@@ -3061,6 +4458,27 @@ pub mod gmsol_store {
         instructions::unchecked_confirm_gt_exchange_vault(ctx, Some(buyback_value), buyback_price)
     }
 
+    /// Confirm GT exchange vault permissionlessly.
+    ///
+    /// Any signer can confirm the vault once its deposit window has passed, provided that
+    /// auto-confirm is enabled by the [`AmountKey::GtVaultAutoConfirm`](crate::states::AmountKey::GtVaultAutoConfirm) config of the store.
+    /// No buyback information is recorded by this path.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ConfirmGtExchangeVault)*
+    ///
+    /// # Errors
+    /// - The [`authority`](ConfirmGtExchangeVault::authority) must be a signer.
+    /// - The [`store`](ConfirmGtExchangeVault::store) must be properly initialized.
+    /// - The GT state of the `store` must be initialized.
+    /// - Auto-confirm of GT exchange vaults must be enabled in the `store`.
+    /// - The [`vault`](ConfirmGtExchangeVault::vault) must be validly initialized and owned by
+    ///   the `store`.
+    /// - The `vault` must be in a confirmable state (deposit window has passed but not yet confirmed).
+    pub fn auto_confirm_gt_exchange_vault(ctx: Context<ConfirmGtExchangeVault>) -> Result<()> {
+        instructions::auto_confirm_gt_exchange_vault(ctx)
+    }
+
     /// Request a GT exchange.
     ///
     /// # Accounts
@@ -3131,6 +4549,86 @@ pub mod gmsol_store {
         MintGtReward::invoke_unchecked(ctx, amount)
     }
 
+    /// Get the amount of GT required for the user to reach the next rank.
+    ///
+    /// Returns `None` if the user is already at the top rank.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadUserGt)*
+    ///
+    /// # Errors
+    /// - The [`store`](ReadUserGt::store) must be properly initialized with an initialized GT state.
+    /// - The [`user`](ReadUserGt::user) must be properly initialized and belong to the `store`.
+    pub fn get_gt_to_next_rank(ctx: Context<ReadUserGt>) -> Result<Option<u64>> {
+        instructions::get_gt_to_next_rank(ctx)
+    }
+
+    /// Get the current rank of the user, together with the lower threshold of the current
+    /// rank and the threshold of the next rank.
+    ///
+    /// The rank is derived from the current GT balance of the user. The next rank threshold
+    /// is `None` if the user is already at the top rank.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadUserGt)*
+    ///
+    /// # Errors
+    /// - The [`store`](ReadUserGt::store) must be properly initialized with an initialized GT state.
+    /// - The [`user`](ReadUserGt::user) must be properly initialized and belong to the `store`.
+    pub fn get_user_rank(ctx: Context<ReadUserGt>) -> Result<GtUserRank> {
+        instructions::get_user_rank(ctx)
+    }
+
+    /// Get the GT state summary of the store.
+    ///
+    /// The initial minting cost is not retained after initialization, so the current minting
+    /// cost is returned together with the number of grow steps passed.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadStore)*
+    ///
+    /// # Errors
+    /// - The [`store`](ReadStore::store) must be properly initialized with an initialized GT state.
+    pub fn get_gt_state(ctx: Context<ReadStore>) -> Result<GtStateSummary> {
+        instructions::get_gt_state(ctx)
+    }
+
+    /// Get the order fee value recorded for GT holders and the part accumulated by the user.
+    ///
+    /// A fraction (`order_fee_to_gt_stakers_factor`) of the paid order fee value is recorded
+    /// for GT holders proportionally to their GT balances when orders are executed.
+    ///
+    /// Only the value is accounted: the fee tokens are not transferred to GT holders and stay
+    /// in the claimable fee pools of the markets, so paying out the recorded value is left to
+    /// the receiver of the claimed fees.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadUserGt)*
+    ///
+    /// # Errors
+    /// - The [`store`](ReadUserGt::store) must be properly initialized with an initialized GT state.
+    /// - The [`user`](ReadUserGt::user) must be properly initialized and belong to the `store`.
+    pub fn get_gt_staker_rewards(ctx: Context<ReadUserGt>) -> Result<GtStakerRewards> {
+        instructions::get_gt_staker_rewards(ctx)
+    }
+
+    /// Get the number of seconds until the given GT exchange vault becomes confirmable.
+    ///
+    /// A vault becomes confirmable at the start of the time window following the one it was
+    /// created in. A non-positive value means that the vault has been confirmable since that
+    /// many seconds ago.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadGtExchangeVault)*
+    ///
+    /// # Errors
+    /// - The [`store`](ReadGtExchangeVault::store) must be properly initialized with an initialized GT state.
+    /// - The [`vault`](ReadGtExchangeVault::vault) must be initialized, owned by the `store` and
+    ///   not yet confirmed.
+    pub fn get_gt_vault_confirm_eta(ctx: Context<ReadGtExchangeVault>) -> Result<i64> {
+        instructions::get_gt_vault_confirm_eta(ctx)
+    }
+
     // ===========================================
     //              User & Referral
     // ===========================================
@@ -3166,7 +4664,11 @@ pub mod gmsol_store {
     ///   - Properly initialized
     ///   - Correspond to the `owner`
     ///   - Not already have an associated referral code
+    ///   - Hold at least the min GT balance configured in the `store`
     /// - The provided `code` must not already be in use by another user.
+    /// - The encoded `code` string (base58 without the leading `1`s) must not exceed the max
+    ///   referral code length and must only contain characters in the referral code charset
+    ///   configured in the `store`.
     pub fn initialize_referral_code(
         ctx: Context<InitializeReferralCode>,
         code: [u8; 8],
@@ -3269,6 +4771,64 @@ pub mod gmsol_store {
         instructions::accept_referral_code(ctx)
     }
 
+    /// Block or unblock the given referral code.
+    ///
+    /// Blocked codes cannot be initialized by [`initialize_referral_code`] or used by
+    /// [`set_referrer`].
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](SetReferralCodeBlocked)*
+    ///
+    /// # Arguments
+    /// - `code`: The referral code to block or unblock.
+    /// - `blocked`: Whether to block the code.
+    ///
+    /// # Errors
+    /// - The [`authority`](SetReferralCodeBlocked::authority) must be a signer and have the
+    ///   CONFIG_KEEPER role in the store.
+    /// - The [`store`](SetReferralCodeBlocked::store) must be properly initialized.
+    /// - The `code` must not be all zeros.
+    /// - The blocklist must not be full when blocking a new code.
+    #[access_control(internal::Authenticate::only_config_keeper(&ctx))]
+    pub fn set_referral_code_blocked(
+        ctx: Context<SetReferralCodeBlocked>,
+        code: [u8; 8],
+        blocked: bool,
+    ) -> Result<()> {
+        instructions::unchecked_set_referral_code_blocked(ctx, code, blocked)
+    }
+
+    /// Get the blocked referral codes of the store.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadStore)*
+    ///
+    /// # Errors
+    /// - The [`store`](ReadStore::store) must be properly initialized.
+    pub fn get_blocked_referral_codes(ctx: Context<ReadStore>) -> Result<Vec<[u8; 8]>> {
+        instructions::get_blocked_referral_codes(ctx)
+    }
+
+    /// Get the referral summary of the given user.
+    ///
+    /// Returns the referrer, the referral code and the number of direct referees of the user.
+    /// Since the referred order fees are not tracked by the referrer's account, the paid order
+    /// fee values are only summed over the referee user accounts provided.
+    ///
+    /// # Accounts
+    /// *[See the documentation for the accounts.](ReadReferral)*
+    ///
+    /// # Errors
+    /// - The [`store`](ReadReferral::store) must be properly initialized.
+    /// - The [`user`](ReadReferral::user) must be properly initialized and belong to the `store`.
+    /// - Each remaining account must be a distinct, initialized user account of the `store`
+    ///   whose referrer is the owner of the `user`.
+    pub fn get_referral_summary<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadReferral<'info>>,
+    ) -> Result<ReferralSummary> {
+        instructions::get_referral_summary(ctx)
+    }
+
     // ===========================================
     //                GLV Operations
     // ===========================================
@@ -3447,6 +5007,19 @@ pub mod gmsol_store {
         instructions::unchecked_remove_glv_market(ctx)
     }
 
+    /// Get the minimum GLV token amount required for the first deposit into the GLV.
+    ///
+    /// A value of `0` means that the first deposit check is disabled.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadGlv)
+    ///
+    /// # Errors
+    /// - The [`glv`](ReadGlv::glv) must be an initialized GLV account.
+    pub fn get_glv_first_deposit_minimum(ctx: Context<ReadGlv>) -> Result<u64> {
+        instructions::get_glv_first_deposit_minimum(ctx)
+    }
+
     /// Create GLV deposit.
     ///
     /// # Accounts
@@ -3491,6 +5064,10 @@ pub mod gmsol_store {
     ///   - Must have sufficient balance
     ///   - Must have the `owner` as its authority
     /// - All token programs must match their corresponding token accounts
+    /// - The [`user`](CreateGlvDeposit::user) is not initialized or does not correspond to the owner.
+    ///   The address must be a valid PDA derived from the `owner` and expected seeds.
+    /// - The number of pending actions of the [`user`](CreateGlvDeposit::user) would exceed the
+    ///   max pending actions per user configured in the `store`.
     pub fn create_glv_deposit<'info>(
         mut ctx: Context<'_, '_, 'info, 'info, CreateGlvDeposit<'info>>,
         nonce: [u8; 32],
@@ -3506,6 +5083,8 @@ pub mod gmsol_store {
     ///
     /// # Arguments
     /// - `reason`: The reason for closing the GLV deposit.
+    /// - `close_reason`: The structured reason for the close, emitted in the close event.
+    ///   Always recorded as `UserCancel` when the owner closes a pending action.
     ///
     /// # Errors
     /// - The [`executor`](CloseGlvDeposit::executor) must be a signer, and must be
@@ -3526,11 +5105,14 @@ pub mod gmsol_store {
     ///   - Must be recorded in the [`glv_deposit`](CloseGlvDeposit::glv_deposit)
     /// - The addresses of the ATAs must be valid associated token addresses derived from the respective tokens and `owner`
     /// - All token programs must match their corresponding token accounts
+    /// - The [`user`](CloseGlvDeposit::user) is not provided while the GLV deposit has been recorded as a
+    ///   pending action of the owner, or does not correspond to the owner.
     pub fn close_glv_deposit<'info>(
         ctx: Context<'_, '_, 'info, 'info, CloseGlvDeposit<'info>>,
         reason: String,
+        close_reason: CloseReason,
     ) -> Result<()> {
-        internal::Close::close(&ctx, &reason)
+        internal::Close::close(&ctx, &reason, close_reason)
     }
 
     /// Execute GLV deposit.
@@ -3644,6 +5226,10 @@ pub mod gmsol_store {
     ///   - Must correspond to their respective tokens
     ///   - Must be owned by the [`glv_withdrawal`](CreateGlvWithdrawal::glv_withdrawal)
     /// - All token programs must match their corresponding token accounts
+    /// - The [`user`](CreateGlvWithdrawal::user) is not initialized or does not correspond to the owner.
+    ///   The address must be a valid PDA derived from the `owner` and expected seeds.
+    /// - The number of pending actions of the [`user`](CreateGlvWithdrawal::user) would exceed the
+    ///   max pending actions per user configured in the `store`.
     pub fn create_glv_withdrawal<'info>(
         mut ctx: Context<'_, '_, 'info, 'info, CreateGlvWithdrawal<'info>>,
         nonce: [u8; 32],
@@ -3659,6 +5245,8 @@ pub mod gmsol_store {
     ///
     /// # Arguments
     /// - `reason`: The reason for closing the GLV withdrawal.
+    /// - `close_reason`: The structured reason for the close, emitted in the close event.
+    ///   Always recorded as `UserCancel` when the owner closes a pending action.
     ///
     /// # Errors
     /// - The [`executor`](CloseGlvWithdrawal::executor) must be:
@@ -3681,11 +5269,14 @@ pub mod gmsol_store {
     /// - The addresses of the ATAs must be valid associated token addresses derived from the respective tokens and `owner`
     /// - All token programs must match their corresponding token accounts
     /// - If the `executor` is not the `owner`, the [`glv_withdrawal`](CloseGlvWithdrawal::glv_withdrawal) must be either cancelled or executed.
+    /// - The [`user`](CloseGlvWithdrawal::user) is not provided while the GLV withdrawal has been recorded as a
+    ///   pending action of the owner, or does not correspond to the owner.
     pub fn close_glv_withdrawal<'info>(
         ctx: Context<'_, '_, 'info, 'info, CloseGlvWithdrawal<'info>>,
         reason: String,
+        close_reason: CloseReason,
     ) -> Result<()> {
-        internal::Close::close(&ctx, &reason)
+        internal::Close::close(&ctx, &reason, close_reason)
     }
 
     /// Execute GLV withdrawal.
@@ -3794,6 +5385,9 @@ pub mod gmsol_store {
     ///   - [`to_market_token_vault`](CreateGlvShift::to_market_token_vault) must be:
     ///     - The market token vault for `to_market_token` in the [`glv`](CreateGlvShift::glv)
     ///     - Owned by the [`glv`](CreateGlvShift::glv)
+    /// - The number of pending shifts of the `glv` must be less than the
+    ///   [`MaxConcurrentGlvShifts`](crate::states::AmountKey::MaxConcurrentGlvShifts) of the
+    ///   `store`, unless it is `0`.
     #[access_control(internal::Authenticate::only_order_keeper(&ctx))]
     pub fn create_glv_shift<'info>(
         mut ctx: Context<'_, '_, 'info, 'info, CreateGlvShift<'info>>,
@@ -3810,6 +5404,8 @@ pub mod gmsol_store {
     ///
     /// # Arguments
     /// - `reason`: The reason for closing the GLV shift.
+    /// - `close_reason`: The structured reason for the close, emitted in the close event.
+    ///   Always recorded as `UserCancel` when the owner closes a pending action.
     ///
     /// # Errors
     /// - The [`authority`](CloseGlvShift::authority) must be:
@@ -3833,8 +5429,22 @@ pub mod gmsol_store {
     pub fn close_glv_shift<'info>(
         ctx: Context<'_, '_, 'info, 'info, CloseGlvShift<'info>>,
         reason: String,
+        close_reason: CloseReason,
     ) -> Result<()> {
-        internal::Close::close(&ctx, &reason)
+        internal::Close::close(&ctx, &reason, close_reason)
+    }
+
+    /// Returns the markets, tokens and amounts recorded in the GLV shift.
+    ///
+    /// The market addresses are derived from the recorded market tokens.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadGlvShift)
+    ///
+    /// # Errors
+    /// - The [`glv_shift`](ReadGlvShift::glv_shift) must be an initialized GLV shift account.
+    pub fn get_glv_shift_info(ctx: Context<ReadGlvShift>) -> Result<GlvShiftInfo> {
+        instructions::get_glv_shift_info(ctx)
     }
 
     /// Execute GLV shift.
@@ -3910,7 +5520,8 @@ pub mod gmsol_store {
     /// - `amount`: Amount of the market tokens to evaluate.
     /// - `maximize`: If true, uses the maximum possible values in calculations.
     ///   If false, uses minimum values.
-    /// - `max_age`: Maximum allowed age of the earliest oracle timestamp.
+    /// - `max_age`: Maximum allowed age of the earliest oracle timestamp. Defaults to the
+    ///   default oracle staleness of the store if not provided.
     /// - `emit_event`: Whether to emit a [`GlvTokenValue`](crate::events::GlvTokenValue) event.
     ///
     /// # Errors
@@ -3923,17 +5534,76 @@ pub mod gmsol_store {
     /// - The remaining accounts must be valid according to the accounts documentation.
     ///   in the required order.
     /// - The earliest oracle timestamp must be within `max_age`.
+    /// - `max_age` must be provided if the default oracle staleness of the store is not set.
+    /// - The oracle prices must be within the max price bounds of the tokens
+    ///   (see [`set_max_price`](crate::gmsol_store::set_max_price)).
     /// - Any calculation errors.
     pub fn get_glv_token_value<'info>(
         ctx: Context<'_, '_, 'info, 'info, GetGlvTokenValue<'info>>,
         amount: u64,
         maximize: bool,
-        max_age: u32,
+        max_age: Option<u32>,
         emit_event: bool,
     ) -> Result<u128> {
         GetGlvTokenValue::invoke(ctx, amount, maximize, max_age, emit_event)
     }
 
+    /// Returns the NAV of the GLV and the value and weight of each market in it.
+    ///
+    /// The market tokens held by the GLV are valued with the same logic as GLV pricing.
+    /// Markets with a negative pool value are reported with a zero value instead of failing.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadGlvWithMarkets)
+    ///
+    /// # Arguments
+    /// - `prices`: The unit prices of tokens for each market, in the same order as the
+    ///   markets in the GLV.
+    /// - `maximize`: If true, uses the maximum possible values in calculations.
+    ///   If false, uses minimum values.
+    ///
+    /// # Errors
+    /// - The [`glv`](ReadGlvWithMarkets::glv) must be an initialized GLV account.
+    /// - The length of `prices` must match the number of markets in the GLV.
+    /// - The remaining accounts must be valid according to the accounts documentation
+    ///   in the required order.
+    /// - The provided prices must be non-zero.
+    /// - Any calculation errors.
+    pub fn get_glv_nav<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadGlvWithMarkets<'info>>,
+        prices: Vec<Prices<u128>>,
+        maximize: bool,
+    ) -> Result<GlvNav> {
+        instructions::get_glv_nav(ctx, &prices, maximize)
+    }
+
+    /// Returns the balance and value of each market in the GLV, along with the fraction
+    /// of its configured max amount and max value caps that is used.
+    ///
+    /// Values are maximized, consistent with the cap validation performed on deposits.
+    /// A utilization of zero is reported for caps that are not set.
+    ///
+    /// # Accounts
+    /// [*See the documentation for the accounts.*](ReadGlvWithMarkets)
+    ///
+    /// # Arguments
+    /// - `prices`: The unit prices of tokens for each market, in the same order as the
+    ///   markets in the GLV.
+    ///
+    /// # Errors
+    /// - The [`glv`](ReadGlvWithMarkets::glv) must be an initialized GLV account.
+    /// - The length of `prices` must match the number of markets in the GLV.
+    /// - The remaining accounts must be valid according to the accounts documentation
+    ///   in the required order.
+    /// - The provided prices must be non-zero.
+    /// - Any calculation errors.
+    pub fn get_glv_utilization<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReadGlvWithMarkets<'info>>,
+        prices: Vec<Prices<u128>>,
+    ) -> Result<GlvUtilization> {
+        instructions::get_glv_utilization(ctx, &prices)
+    }
+
     // ===========================================
     //             Other Instructions
     // ===========================================
@@ -4455,6 +6125,81 @@ pub enum CoreError {
     /// Index overflow.
     #[msg("index overflow")]
     IndexOverflow,
+    /// Price provider is not allowed.
+    #[msg("price provider is not allowed for the token")]
+    PriceProviderNotAllowed,
+    /// Market token price is too low.
+    #[msg("market token price is below the configured min price")]
+    MarketTokenPriceTooLow,
+    /// Action is too old to be executed.
+    #[msg("action is too old to be executed")]
+    ActionTooOld,
+    /// Position increase is in cooldown.
+    #[msg("position increase is in cooldown")]
+    PositionIncreaseInCooldown,
+    /// Timestamp adjustment is out of range.
+    #[msg("timestamp adjustment is out of range")]
+    TimestampAdjustmentOutOfRange,
+    /// Rent recipient is not allowed.
+    #[msg("rent recipient is not allowed")]
+    RentRecipientNotAllowed,
+    /// Price report is too old.
+    #[msg("price report is too old")]
+    PriceReportTooOld,
+    /// Self-trade prevented.
+    #[msg("self-trade prevented")]
+    SelfTradePrevented,
+    /// Max resting orders exceeded.
+    #[msg("the number of resting orders of the position exceeds the max")]
+    MaxRestingOrdersExceeded,
+    /// Not enough GT to initialize a referral code.
+    #[msg("GT balance is below the min required to initialize a referral code")]
+    NotEnoughGtForReferralCode,
+    /// Max swap impact exceeded.
+    #[msg("swap price impact exceeds the max allowed for this hop")]
+    MaxSwapImpactExceeded,
+    /// Referral code is blocked.
+    #[msg("referral code is blocked")]
+    ReferralCodeBlocked,
+    /// Max concurrent GLV shifts exceeded.
+    #[msg("max number of concurrent GLV shifts exceeded")]
+    MaxConcurrentGlvShiftsExceeded,
+    /// Acceptable price is unreachable.
+    #[msg("the acceptable price of the limit order is beyond the auto-cancel tolerance")]
+    AcceptablePriceUnreachable,
+    /// Max order size exceeded.
+    #[msg("order size exceeds the max order size of the market")]
+    MaxOrderSizeExceeded,
+    /// The swap path of the deposit is too long.
+    #[msg("the swap path of the deposit exceeds the configured max length")]
+    DepositSwapPathTooLong,
+    /// The swap path of the withdrawal is too long.
+    #[msg("the swap path of the withdrawal exceeds the configured max length")]
+    WithdrawalSwapPathTooLong,
+    /// The swap path of the order is too long.
+    #[msg("the swap path of the order exceeds the configured max length")]
+    OrderSwapPathTooLong,
+    /// Max open positions exceeded.
+    #[msg("the number of open positions exceeds the max open positions of the market")]
+    MaxOpenPositionsExceeded,
+    /// Max custom price feeds per token exceeded.
+    #[msg("the number of custom price feeds of the token exceeds the max")]
+    MaxCustomPriceFeedsExceeded,
+    /// Market config buffer expiry out of range.
+    #[msg("the expiration duration of the market config buffer is out of the allowed range")]
+    MarketConfigBufferExpiryOutOfRange,
+    /// Max pending actions exceeded.
+    #[msg("the number of pending actions exceeds the max pending actions per user")]
+    MaxPendingActionsExceeded,
+    /// Market order executed out of order.
+    #[msg("an earlier queued market order has not been finished")]
+    MarketOrderExecutedOutOfOrder,
+    /// Price confidence interval too wide.
+    #[msg("the confidence interval of the price exceeds the configured max ratio")]
+    PriceConfidenceTooWide,
+    /// Too many queued market orders.
+    #[msg("too many unfinished market orders are queued for fair execution in the market")]
+    TooManyQueuedMarketOrders,
 }
 
 #[cfg(not(feature = "no-entrypoint"))]
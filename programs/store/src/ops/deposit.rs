@@ -125,6 +125,10 @@ impl CreateDepositOperation<'_, '_> {
         deposit.params.initial_short_token_amount = params.initial_short_token_amount;
         deposit.params.min_market_token_amount = params.min_market_token_amount;
 
+        store.load()?.validate_deposit_swap_path_length(
+            usize::from(params.long_token_swap_length)
+                + usize::from(params.short_token_swap_length),
+        )?;
         deposit.swap.validate_and_init(
             &*market.load()?,
             params.long_token_swap_length,
@@ -206,8 +210,15 @@ pub(crate) struct ExecuteDepositOperation<'a, 'info> {
 impl ExecuteDepositOperation<'_, '_> {
     pub(crate) fn execute(self) -> Result<bool> {
         let throw_on_execution_error = self.throw_on_execution_error;
-        match self.validate_oracle() {
+        match self
+            .validate_action_age()
+            .and_then(|()| self.validate_oracle())
+        {
             Ok(()) => {}
+            Err(CoreError::ActionTooOld) if !throw_on_execution_error => {
+                msg!("Deposit is too old to be executed");
+                return Ok(false);
+            }
             Err(CoreError::OracleTimestampsAreLargerThanRequired) if !throw_on_execution_error => {
                 msg!(
                     "Deposit expired at {}",
@@ -232,6 +243,10 @@ impl ExecuteDepositOperation<'_, '_> {
         }
     }
 
+    fn validate_action_age(&self) -> CoreResult<()> {
+        ActionExt::validate_age(self.deposit, self.store)
+    }
+
     fn validate_oracle(&self) -> CoreResult<()> {
         self.oracle.validate_time(self)
     }
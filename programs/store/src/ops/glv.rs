@@ -155,6 +155,10 @@ impl CreateGlvDepositOperation<'_, '_> {
         glv_deposit.params.min_glv_token_amount = self.params.min_glv_token_amount;
 
         // Init swap paths.
+        self.store.load()?.validate_deposit_swap_path_length(
+            usize::from(self.params.long_token_swap_length)
+                + usize::from(self.params.short_token_swap_length),
+        )?;
         glv_deposit.swap.validate_and_init(
             &*self.market.load()?,
             self.params.long_token_swap_length,
@@ -265,8 +269,15 @@ impl ExecuteGlvDepositOperation<'_, '_> {
     /// - The swap markets provided by `remaining_accounts` must be valid.
     pub(crate) fn unchecked_execute(mut self) -> Result<bool> {
         let throw_on_execution_error = self.throw_on_execution_error;
-        match self.validate_oracle() {
+        match self
+            .validate_action_age()
+            .and_then(|()| self.validate_oracle())
+        {
             Ok(()) => {}
+            Err(CoreError::ActionTooOld) if !throw_on_execution_error => {
+                msg!("GLV Deposit is too old to be executed");
+                return Ok(false);
+            }
             Err(CoreError::OracleTimestampsAreLargerThanRequired) if !throw_on_execution_error => {
                 msg!(
                     "GLV Deposit expired at {}",
@@ -295,6 +306,10 @@ impl ExecuteGlvDepositOperation<'_, '_> {
         Ok(executed)
     }
 
+    fn validate_action_age(&self) -> CoreResult<()> {
+        ActionExt::validate_age(&self.glv_deposit, &self.store)
+    }
+
     fn validate_oracle(&self) -> CoreResult<()> {
         self.oracle.validate_time(self)
     }
@@ -687,6 +702,10 @@ impl CreateGlvWithdrawalOperation<'_, '_> {
         params.min_final_short_token_amount = self.params.min_final_short_token_amount;
 
         // Init swap paths.
+        self.store.load()?.validate_withdrawal_swap_path_length(
+            usize::from(self.params.long_token_swap_length)
+                + usize::from(self.params.short_token_swap_length),
+        )?;
         glv_withdrawal.swap.validate_and_init(
             &*self.market.load()?,
             self.params.long_token_swap_length,
@@ -754,8 +773,15 @@ impl ExecuteGlvWithdrawalOperation<'_, '_> {
     ///
     pub(crate) fn unchecked_execute(mut self) -> Result<Option<(u64, u64)>> {
         let throw_on_execution_error = self.throw_on_execution_error;
-        match self.validate_oracle() {
+        match self
+            .validate_action_age()
+            .and_then(|()| self.validate_oracle())
+        {
             Ok(()) => {}
+            Err(CoreError::ActionTooOld) if !throw_on_execution_error => {
+                msg!("GLV Withdrawal is too old to be executed");
+                return Ok(None);
+            }
             Err(CoreError::OracleTimestampsAreLargerThanRequired) if !throw_on_execution_error => {
                 msg!(
                     "GLV Withdrawal expired at {}",
@@ -785,6 +811,10 @@ impl ExecuteGlvWithdrawalOperation<'_, '_> {
         Ok(executed)
     }
 
+    fn validate_action_age(&self) -> CoreResult<()> {
+        ActionExt::validate_age(&self.glv_withdrawal, &self.store)
+    }
+
     fn validate_oracle(&self) -> CoreResult<()> {
         self.oracle.validate_time(self)
     }
@@ -1133,26 +1163,29 @@ fn get_glv_value_for_market<M>(
 where
     M: gmsol_model::LiquidityMarket<{ constants::MARKET_DECIMALS }, Num = u128, Signed = i128>,
 {
-    use gmsol_model::{utils, LiquidityMarketExt};
-
-    let value = market
-        .pool_value(prices, PnlFactorKind::MaxAfterDeposit, maximize)
-        .map_err(ModelError::from)?;
+    let value = get_market_pool_value_for_glv(prices, market, maximize)?;
 
     let supply = market.total_supply();
 
-    if balance == 0 {
-        return Ok(GlvValueForMarket::new(0, value, supply));
-    }
+    GlvValueForMarket::from_pool_value(balance, value, supply)
+}
 
-    if value.is_negative() {
-        return err!(CoreError::GlvNegativeMarketPoolValue);
-    }
+/// Get the pool value of the market used for GLV valuation.
+pub(crate) fn get_market_pool_value_for_glv<M>(
+    prices: &Prices<u128>,
+    market: &M,
+    maximize: bool,
+) -> Result<i128>
+where
+    M: gmsol_model::LiquidityMarket<{ constants::MARKET_DECIMALS }, Num = u128, Signed = i128>,
+{
+    use gmsol_model::LiquidityMarketExt;
 
-    let glv_value = utils::market_token_amount_to_usd(&balance, &value.unsigned_abs(), &supply)
-        .ok_or_else(|| error!(CoreError::FailedToCalculateGlvValueForMarket))?;
+    let value = market
+        .pool_value(prices, PnlFactorKind::MaxAfterDeposit, maximize)
+        .map_err(ModelError::from)?;
 
-    Ok(GlvValueForMarket::new(glv_value, value, supply))
+    Ok(value)
 }
 
 pub(crate) struct GlvValueForMarket {
@@ -1169,6 +1202,30 @@ impl GlvValueForMarket {
             supply,
         }
     }
+
+    /// Calculate the value of the GLV balance from the market pool value.
+    ///
+    /// # Errors
+    /// - Returns [`CoreError::GlvNegativeMarketPoolValue`] if the balance is not zero
+    ///   and the pool value is negative.
+    pub(crate) fn from_pool_value(balance: u128, pool_value: i128, supply: u128) -> Result<Self> {
+        if balance == 0 {
+            return Ok(Self::new(0, pool_value, supply));
+        }
+
+        if pool_value.is_negative() {
+            return err!(CoreError::GlvNegativeMarketPoolValue);
+        }
+
+        let glv_value = gmsol_model::utils::market_token_amount_to_usd(
+            &balance,
+            &pool_value.unsigned_abs(),
+            &supply,
+        )
+        .ok_or_else(|| error!(CoreError::FailedToCalculateGlvValueForMarket))?;
+
+        Ok(Self::new(glv_value, pool_value, supply))
+    }
 }
 
 fn get_market_token_amount_for_glv_value<M>(
@@ -1236,8 +1293,15 @@ impl ExecuteGlvShiftOperation<'_, '_> {
     ///
     pub(crate) fn unchecked_execute(mut self) -> Result<bool> {
         let throw_on_execution_error = self.throw_on_execution_error;
-        match self.validate_oracle() {
+        match self
+            .validate_action_age()
+            .and_then(|()| self.validate_oracle())
+        {
             Ok(()) => {}
+            Err(CoreError::ActionTooOld) if !throw_on_execution_error => {
+                msg!("GLV Shift is too old to be executed");
+                return Ok(false);
+            }
             Err(CoreError::OracleTimestampsAreLargerThanRequired) if !throw_on_execution_error => {
                 msg!(
                     "GLV Shift expired at {}",
@@ -1267,6 +1331,10 @@ impl ExecuteGlvShiftOperation<'_, '_> {
         Ok(executed)
     }
 
+    fn validate_action_age(&self) -> CoreResult<()> {
+        ActionExt::validate_age(self.glv_shift, self.store)
+    }
+
     fn validate_oracle(&self) -> CoreResult<()> {
         self.oracle.validate_time(self)
     }
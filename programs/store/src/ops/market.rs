@@ -3,8 +3,8 @@ use std::collections::BTreeMap;
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, TokenAccount};
 use gmsol_model::{
-    price::Prices, Bank, BorrowingFeeMarketMutExt, LiquidityMarketMutExt, MarketAction,
-    PerpMarketMutExt, PositionImpactMarketMutExt,
+    price::Prices, Bank, BorrowingFeeMarketMutExt, LiquidityMarketExt, LiquidityMarketMutExt,
+    MarketAction, PerpMarketMutExt, PnlFactorKind, PositionImpactMarketMutExt,
 };
 use typed_builder::TypedBuilder;
 
@@ -25,7 +25,7 @@ use crate::{
             HasMarketMeta,
         },
         withdrawal::WithdrawalActionParams,
-        Deposit, Market, Oracle, ShiftActionParams, Store,
+        Deposit, Market, MarketConfigKey, Oracle, ShiftActionParams, Store,
     },
     CoreError, ModelError,
 };
@@ -350,6 +350,57 @@ impl<'a, 'info, T> Execute<'a, 'info, T> {
         Ok(())
     }
 
+    fn validate_market_token_price(&self, prices: &Prices<u128>) -> Result<()> {
+        let min_market_token_price = *self
+            .market()
+            .base()
+            .as_ref()
+            .get_config_by_key(MarketConfigKey::MinMarketTokenPrice)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?;
+
+        // Skip the check if the min price is zero.
+        if min_market_token_price == 0 {
+            return Ok(());
+        }
+
+        let market_token_price = self
+            .market()
+            .market_token_price(prices, PnlFactorKind::MaxAfterDeposit, false)
+            .map_err(ModelError::from)?;
+
+        if market_token_price < min_market_token_price {
+            msg!(
+                "[Deposit] market token price {} is below the min price {}",
+                market_token_price,
+                min_market_token_price
+            );
+            return err!(CoreError::MarketTokenPriceTooLow);
+        }
+
+        Ok(())
+    }
+
+    /// Get the min fee value in USD configured by the given key.
+    ///
+    /// Returns `0` unless `swap_pricing_kind` is the expected one (or not provided),
+    /// so that the floor is only applied to the matching kind of actions.
+    fn min_fee_value(
+        &self,
+        key: MarketConfigKey,
+        swap_pricing_kind: Option<SwapPricingKind>,
+        expected_kind: SwapPricingKind,
+    ) -> Result<u128> {
+        if swap_pricing_kind.is_some_and(|kind| kind != expected_kind) {
+            return Ok(0);
+        }
+        Ok(*self
+            .market()
+            .base()
+            .as_ref()
+            .get_config_by_key(key)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?)
+    }
+
     /// Swap and deposit into the current market.
     ///
     /// # CHECK
@@ -358,6 +409,7 @@ impl<'a, 'info, T> Execute<'a, 'info, T> {
     ///
     /// # Errors
     /// - Error if first deposit validation failed.
+    /// - Error if the market token price is below the configured min price.
     #[inline(never)]
     pub(crate) fn unchecked_deposit(
         mut self,
@@ -370,6 +422,12 @@ impl<'a, 'info, T> Execute<'a, 'info, T> {
     ) -> Result<Execute<'a, 'info, u64>> {
         self.validate_first_deposit(receiver, params)?;
 
+        let min_fee_value = self.min_fee_value(
+            MarketConfigKey::MinDepositFeeUsd,
+            swap_pricing_kind,
+            SwapPricingKind::Deposit,
+        )?;
+
         self.market = self
             .market
             .enable_mint(market_token_receiver)
@@ -379,6 +437,8 @@ impl<'a, 'info, T> Execute<'a, 'info, T> {
 
         self.pre_execute(&prices)?;
 
+        self.validate_market_token_price(&prices)?;
+
         // Swap tokens into the target market.
         let (long_token_amount, short_token_amount) = {
             let meta = self.market.market_meta();
@@ -418,6 +478,7 @@ impl<'a, 'info, T> Execute<'a, 'info, T> {
                 .deposit(long_token_amount.into(), short_token_amount.into(), prices)
                 .and_then(|d| {
                     d.with_virtual_inventory_impact(include_virtual_inventory_impact)
+                        .with_min_fee_value(min_fee_value)
                         .execute()
                 })
                 .map_err(ModelError::from)?;
@@ -455,6 +516,12 @@ impl<'a, 'info, T> Execute<'a, 'info, T> {
         final_tokens: (Pubkey, Pubkey),
         swap_pricing_kind: Option<SwapPricingKind>,
     ) -> Result<Execute<'a, 'info, (u64, u64)>> {
+        let min_fee_value = self.min_fee_value(
+            MarketConfigKey::MinWithdrawalFeeUsd,
+            swap_pricing_kind,
+            SwapPricingKind::Withdrawal,
+        )?;
+
         self.market = self
             .market
             .enable_burn(market_token_vault)
@@ -469,7 +536,7 @@ impl<'a, 'info, T> Execute<'a, 'info, T> {
             let report = self
                 .market
                 .withdraw(params.market_token_amount.into(), prices)
-                .and_then(|w| w.execute())
+                .and_then(|w| w.with_min_fee_value(min_fee_value).execute())
                 .map_err(ModelError::from)?;
             let (long_amount, short_amount) = (
                 (*report.long_token_output())
@@ -1,21 +1,23 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, Discriminator};
 use anchor_spl::token::{Mint, TokenAccount};
 use gmsol_callback::interface::ActionKind;
 use gmsol_model::{
     action::decrease_position::{DecreasePositionFlags, DecreasePositionSwapType},
     num::Unsigned,
     price::Prices,
+    utils::apply_factor,
     BaseMarket, BaseMarketExt, BorrowingFeeMarketMutExt, MarketAction, PerpMarketMutExt,
-    PnlFactorKind, Position as _, PositionImpactMarketMutExt, PositionMut, PositionMutExt,
-    PositionState, PositionStateExt,
+    PnlFactorKind, Position as _, PositionExt, PositionImpactMarketMutExt, PositionMut,
+    PositionMutExt, PositionStateExt,
 };
 use gmsol_utils::action::ActionCallbackKind;
 use typed_builder::TypedBuilder;
 
 use crate::{
+    constants,
     events::{
         EventEmitter, MarketFeesUpdated, OrderUpdated, PositionDecreased, PositionIncreased,
-        TradeData,
+        PositionLiquidatable, TradeData,
     },
     states::{
         callback::CallbackAuthority,
@@ -47,6 +49,25 @@ use super::{
 
 pub use gmsol_utils::order::PositionCutKind;
 
+/// Split the trailing linked order accounts from the remaining accounts.
+///
+/// Linked orders are the unclosed resting orders of the order owner, provided after
+/// the other remaining accounts for self-trade prevention.
+fn split_linked_orders<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<(&'info [AccountInfo<'info>], &'info [AccountInfo<'info>])> {
+    let mut split_at = remaining_accounts.len();
+    for info in remaining_accounts.iter().rev() {
+        let is_order =
+            *info.owner == crate::ID && info.try_borrow_data()?.starts_with(Order::DISCRIMINATOR);
+        if !is_order {
+            break;
+        }
+        split_at -= 1;
+    }
+    Ok(remaining_accounts.split_at(split_at))
+}
+
 /// Create Order Arguments.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
 pub struct CreateOrderParams {
@@ -76,6 +97,18 @@ pub struct CreateOrderParams {
     pub should_unwrap_native_token: bool,
     /// Valid from timestamp.
     pub valid_from_ts: Option<i64>,
+    /// Whether to pay the execution fee from the position's collateral
+    /// instead of the lamports deposited into the order account.
+    /// Only position orders are supported, and the execution fee is converted into
+    /// collateral tokens at the oracle prices. Defaults to `false`.
+    pub pay_execution_fee_from_collateral: Option<bool>,
+}
+
+impl CreateOrderParams {
+    /// Returns whether the execution fee should be paid from the position's collateral.
+    pub fn should_pay_execution_fee_from_collateral(&self) -> bool {
+        self.pay_execution_fee_from_collateral.unwrap_or(false)
+    }
 }
 
 impl ActionParams for CreateOrderParams {
@@ -167,7 +200,23 @@ impl<'a, 'info> CreateOrderOperation<'a, 'info> {
 
     fn validate(&self) -> Result<()> {
         self.market.load()?.validate(&self.store.key())?;
-        ActionExt::validate_balance(&self.order, self.params.execution_lamports)?;
+        if self.params.should_pay_execution_fee_from_collateral() {
+            self.validate_pay_execution_fee_from_collateral()?;
+            // No lamports will be taken from the order account for the execution fee.
+            ActionExt::validate_rent_exemption(&self.order, 0)?;
+        } else {
+            ActionExt::validate_balance(&self.order, self.params.execution_lamports)?;
+        }
+        Ok(())
+    }
+
+    fn validate_pay_execution_fee_from_collateral(&self) -> Result<()> {
+        let is_position_order =
+            self.params.kind.is_increase_position() || self.params.kind.is_decrease_position();
+        if !is_position_order {
+            msg!("[Order] only position orders can pay execution fee from collateral");
+        }
+        require!(is_position_order, CoreError::InvalidArgument);
         Ok(())
     }
 
@@ -190,6 +239,7 @@ impl<'a, 'info> CreateOrderOperation<'a, 'info> {
                 tokens,
                 params,
                 swap,
+                market_order_seq,
                 ..
             } = &mut *order;
 
@@ -204,6 +254,9 @@ impl<'a, 'info> CreateOrderOperation<'a, 'info> {
                 self.params.execution_lamports,
                 self.params.should_unwrap_native_token,
             )?;
+            header.set_should_pay_execution_fee_from_collateral(
+                self.params.should_pay_execution_fee_from_collateral(),
+            );
 
             if let Some(creator) = self.creator.as_ref() {
                 header.unchecked_set_creator(creator.key());
@@ -213,9 +266,20 @@ impl<'a, 'info> CreateOrderOperation<'a, 'info> {
 
             let (from, to) = (f)(self.params, tokens, params)?;
 
+            if self.should_queue_for_fair_execution(params)? {
+                *market_order_seq = self
+                    .market
+                    .load_mut()?
+                    .indexer_mut()
+                    .next_market_order_seq()?;
+            }
+
             let market = self.market.load()?;
             let meta = market.meta();
             let swap_path = self.swap_path;
+            self.store
+                .load()?
+                .validate_order_swap_path_length(usize::from(self.params.swap_path_length))?;
             // The secondary path is ignored.
             swap.validate_and_init(
                 meta,
@@ -226,10 +290,29 @@ impl<'a, 'info> CreateOrderOperation<'a, 'info> {
                 (&from, &from),
                 (&to, &from),
             )?;
+
+            // The price of the native token is required to convert the execution fee
+            // into collateral tokens.
+            if self.params.should_pay_execution_fee_from_collateral() {
+                swap.insert_token(&anchor_spl::token::spl_token::native_mint::ID)
+                    .map_err(CoreError::from)?;
+            }
         }
         self.handle_created(position)
     }
 
+    /// Returns whether the order should be queued for fair execution, i.e., fair market
+    /// order execution is enabled and the order is a market order that is not delayed.
+    fn should_queue_for_fair_execution(&self, params: &OrderActionParams) -> Result<bool> {
+        let is_enabled = *self
+            .store
+            .load()?
+            .get_amount_by_key(AmountKey::FairMarketOrderExecution)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?
+            != 0;
+        Ok(is_enabled && params.kind()?.is_market() && !params.is_delayed())
+    }
+
     #[inline(never)]
     fn handle_created(&self, position: Option<&AccountInfo<'info>>) -> Result<()> {
         // Ensure that the discriminator is written to the account data.
@@ -423,6 +506,7 @@ impl CreateIncreaseOrderOperation<'_, '_> {
                 .load()?
                 .validate_for_market(&market)
                 .map_err(ModelError::from)?;
+            market.validate_order_size(self.common.params.size_delta_value)?;
         }
 
         Ok(())
@@ -850,6 +934,9 @@ pub(crate) struct ExecuteOrderOperation<'a, 'info> {
     throw_on_execution_error: bool,
     #[builder(default)]
     refund: u64,
+    /// The execution fee in lamports requested by the executor.
+    #[builder(default)]
+    execution_fee: u64,
     #[builder(setter(into))]
     event_emitter: EventEmitter<'a, 'info>,
     callback_authority: Option<&'a Account<'info, CallbackAuthority>>,
@@ -866,17 +953,33 @@ enum SecondaryOrderType {
     AutoDeleveraging,
 }
 
-impl ExecuteOrderOperation<'_, '_> {
+impl<'info> ExecuteOrderOperation<'_, 'info> {
     #[inline(never)]
     pub(crate) fn execute(
         self,
     ) -> Result<(RemovePosition, Box<TransferOut>, ShouldSendTradeEvent)> {
+        let output = self.execute_impl()?;
+        // The order is no longer pending whether it is executed or cancelled.
+        self.finish_fair_execution()?;
+        Ok(output)
+    }
+
+    #[inline(never)]
+    fn execute_impl(&self) -> Result<(RemovePosition, Box<TransferOut>, ShouldSendTradeEvent)> {
         let mut remove_position = false;
 
         self.order.load()?.validate_valid_from_ts()?;
 
-        match self.validate_oracle_and_adl() {
+        match self
+            .validate_fair_execution()
+            .and_then(|()| self.validate_action_age())
+            .and_then(|()| self.validate_oracle_and_adl())
+        {
             Ok(()) => {}
+            Err(CoreError::ActionTooOld) if !self.throw_on_execution_error => {
+                msg!("Order is too old to be executed");
+                return Ok((false, Box::new(TransferOut::new_failed()), false));
+            }
             Err(CoreError::OracleTimestampsAreLargerThanRequired)
                 if !self.throw_on_execution_error =>
             {
@@ -894,8 +997,20 @@ impl ExecuteOrderOperation<'_, '_> {
             }
         }
 
-        let mut should_throw_error = false;
         let prices = self.market.load()?.prices(self.oracle)?;
+
+        match self.validate_acceptable_price_tolerance(&prices) {
+            Ok(()) => {}
+            Err(CoreError::AcceptablePriceUnreachable) if !self.throw_on_execution_error => {
+                msg!("Order is cancelled since its acceptable price is unreachable");
+                return Ok((false, Box::new(TransferOut::new_failed()), false));
+            }
+            Err(err) => {
+                return Err(error!(err));
+            }
+        }
+
+        let mut should_throw_error = false;
         let discount = self.validate_and_get_order_fee_discount()?;
         let res = match self.perform_execution(&mut should_throw_error, prices, discount) {
             Ok((should_remove_position, mut transfer_out, should_send_trade_event)) => {
@@ -918,6 +1033,9 @@ impl ExecuteOrderOperation<'_, '_> {
 
         let (transfer_out, should_send_trade_event) = res?;
 
+        // The position is kept until all its resting orders are closed.
+        let remove_position = self.remove_resting_order()? == 0 && remove_position;
+
         self.handle_executed(
             transfer_out.executed(),
             !remove_position,
@@ -967,13 +1085,18 @@ impl ExecuteOrderOperation<'_, '_> {
     ) -> Result<(RemovePosition, Box<TransferOut>, ShouldSendTradeEvent)> {
         self.validate_market()?;
         self.validate_order(should_throw_error, &prices)?;
+        self.validate_order_size()?;
+
+        let (remaining_accounts, linked_orders) = split_linked_orders(self.remaining_accounts)?;
+        self.validate_self_trade(should_throw_error, &prices, linked_orders)?;
+        let execution_fee_from_collateral = self.execution_fee_from_collateral()?;
 
         // Prepare execution context.
         let gt_minting_enabled = self.market.load()?.is_gt_minting_enabled();
         let current_market_token = self.market.load()?.market_meta().market_token_mint;
 
         let remaining_accounts = RemainingAccountsForMarket::new(
-            self.remaining_accounts,
+            remaining_accounts,
             current_market_token,
             Some(self.order.load()?.swap()),
         )?;
@@ -1077,6 +1200,24 @@ impl ExecuteOrderOperation<'_, '_> {
 
                 position.on_validate().map_err(ModelError::from)?;
 
+                // Only trader-initiated orders are tracked for becoming liquidatable.
+                let should_check_liquidatable =
+                    !matches!(kind, OrderKind::Liquidation | OrderKind::AutoDeleveraging);
+                let was_liquidatable =
+                    should_check_liquidatable && position.is_liquidatable(&prices)?;
+
+                // The execution fee is paid before a decrease, and after an increase
+                // so that it can be paid by the newly added collateral.
+                if let Some(amount) = execution_fee_from_collateral {
+                    if !kind.is_increase_position() {
+                        position.pay_execution_fee_from_collateral(amount)?;
+                        msg!(
+                            "[Order] paid execution fee from collateral: amount = {}",
+                            amount
+                        );
+                    }
+                }
+
                 let (should_remove_position, paid_fee_value) = match kind {
                     OrderKind::MarketIncrease | OrderKind::LimitIncrease => {
                         let paid_fee_value = execute_increase_position(
@@ -1128,6 +1269,35 @@ impl ExecuteOrderOperation<'_, '_> {
                     _ => unreachable!(),
                 };
 
+                if let Some(amount) = execution_fee_from_collateral {
+                    if kind.is_increase_position() {
+                        position.pay_execution_fee_from_collateral(amount)?;
+                        msg!(
+                            "[Order] paid execution fee from collateral: amount = {}",
+                            amount
+                        );
+                        // The position must remain valid after paying the execution fee.
+                        position
+                            .validate(&prices, true, true)
+                            .map_err(ModelError::from)?;
+                    }
+                }
+
+                if should_check_liquidatable
+                    && !should_remove_position
+                    && !was_liquidatable
+                    && position.is_liquidatable(&prices)?
+                {
+                    position
+                        .event_emitter()
+                        .emit_cpi(&PositionLiquidatable::new(
+                            &self.store.key(),
+                            &position_loader.key(),
+                            &current_market_token,
+                        )?)?;
+                    msg!("[Position] became liquidatable");
+                }
+
                 position.write_to_event(&mut *event_loader.load_mut()?)?;
                 event_loader
                     .load_mut()?
@@ -1161,6 +1331,9 @@ impl ExecuteOrderOperation<'_, '_> {
         };
         swap_markets.commit();
         virtual_inventories.commit();
+        if let Some(amount) = execution_fee_from_collateral {
+            self.order.load_mut()?.execution_fee_from_collateral = amount;
+        }
         Ok((
             should_remove_position,
             transfer_out,
@@ -1168,6 +1341,57 @@ impl ExecuteOrderOperation<'_, '_> {
         ))
     }
 
+    /// Returns the amount of collateral tokens to be paid for the execution fee,
+    /// or `None` if the order pays the execution fee in lamports.
+    ///
+    /// The execution fee is converted into collateral tokens at the oracle prices
+    /// if the collateral token is not the native token.
+    fn execution_fee_from_collateral(&self) -> Result<Option<u64>> {
+        let order = self.order.load()?;
+        if !order.header.should_pay_execution_fee_from_collateral() {
+            return Ok(None);
+        }
+
+        let execution_lamports = order.execution_lamports(self.execution_fee);
+        let collateral_token = order.params.collateral_token;
+        let native_mint = anchor_spl::token::spl_token::native_mint::ID;
+        if collateral_token == native_mint {
+            return Ok(Some(execution_lamports));
+        }
+
+        let amount = execution_fee_in_collateral_tokens(
+            execution_lamports,
+            &self.oracle.get_primary_price(&native_mint, false)?,
+            &self.oracle.get_primary_price(&collateral_token, false)?,
+        )?;
+        msg!(
+            "[Order] converted execution fee into collateral tokens: lamports = {}, amount = {}",
+            execution_lamports,
+            amount
+        );
+        Ok(Some(amount))
+    }
+
+    /// Remove the order from the resting orders of its position if it is recorded,
+    /// since it is no longer resting once it is executed or cancelled.
+    ///
+    /// Returns the number of the remaining resting orders of the position.
+    fn remove_resting_order(&self) -> Result<u32> {
+        let Some(position) = self.position else {
+            return Ok(0);
+        };
+        let is_recorded = self
+            .order
+            .load_mut()?
+            .header
+            .set_resting_order_recorded(false);
+        let mut position = position.load_mut()?;
+        if is_recorded {
+            position.remove_resting_order()?;
+        }
+        Ok(position.num_resting_orders())
+    }
+
     fn close_position(&self) -> Result<()> {
         let Some(position) = self.position else {
             return err!(CoreError::PositionIsRequired);
@@ -1203,6 +1427,106 @@ impl ExecuteOrderOperation<'_, '_> {
         Ok(())
     }
 
+    /// Validate that the order is the earliest unfinished market order in the fair
+    /// execution queue of the market, if it is queued.
+    fn validate_fair_execution(&self) -> crate::CoreResult<()> {
+        let Some(seq) = self
+            .order
+            .load()
+            .map_err(|_| CoreError::LoadAccountError)?
+            .market_order_seq()
+        else {
+            return Ok(());
+        };
+        self.market
+            .load()
+            .map_err(|_| CoreError::LoadAccountError)?
+            .indexer()
+            .validate_market_order_seq(seq)
+    }
+
+    /// Remove the order from the fair execution queue of the market, if it is queued.
+    fn finish_fair_execution(&self) -> Result<()> {
+        let Some(seq) = self.order.load()?.market_order_seq() else {
+            return Ok(());
+        };
+        self.market
+            .load_mut()?
+            .indexer_mut()
+            .finish_market_order_seq(seq)
+    }
+
+    /// Only market orders are subject to the max action age.
+    fn validate_action_age(&self) -> crate::CoreResult<()> {
+        let kind = self
+            .order
+            .load()
+            .map_err(|_| CoreError::LoadAccountError)?
+            .params
+            .kind()
+            .map_err(|_| CoreError::InvalidArgument)?;
+        match kind {
+            OrderKind::MarketSwap | OrderKind::MarketIncrease | OrderKind::MarketDecrease => {
+                ActionExt::validate_age(self.order, self.store)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Validate that the acceptable price of a triggered limit order is
+    /// still reachable within the auto-cancel tolerance.
+    ///
+    /// Only limit increase and limit decrease orders are subject to this check,
+    /// and it is skipped if the order is not triggered yet or auto-cancel
+    /// is disabled in the store.
+    fn validate_acceptable_price_tolerance(&self, prices: &Prices<u128>) -> crate::CoreResult<()> {
+        let Some(tolerance) = self
+            .store
+            .load()
+            .map_err(|_| CoreError::LoadAccountError)?
+            .limit_order_auto_cancel_tolerance()
+        else {
+            return Ok(());
+        };
+        let order = self.order.load().map_err(|_| CoreError::LoadAccountError)?;
+        let params = &order.params;
+        let is_increase = match params.kind().map_err(|_| CoreError::InvalidArgument)? {
+            OrderKind::LimitIncrease => true,
+            OrderKind::LimitDecrease => false,
+            _ => return Ok(()),
+        };
+        if order
+            .validate_trigger_price(&prices.index_token_price)
+            .is_err()
+        {
+            return Ok(());
+        }
+        let is_long = params
+            .side()
+            .map_err(|_| CoreError::InvalidArgument)?
+            .is_long();
+
+        // The acceptable price is an upper bound for long increases and short decreases,
+        // and a lower bound otherwise.
+        let is_upper_bound = is_increase == is_long;
+        let acceptable_price = params.acceptable_price;
+        let index_price = *prices.index_token_price.pick_price(is_upper_bound);
+        if is_acceptable_price_beyond_tolerance(
+            acceptable_price,
+            index_price,
+            is_upper_bound,
+            tolerance,
+        )? {
+            msg!(
+                "[Order] acceptable price {} is unreachable at index price {}",
+                acceptable_price,
+                index_price
+            );
+            return Err(CoreError::AcceptablePriceUnreachable);
+        }
+        Ok(())
+    }
+
     fn validate_oracle_and_adl(&self) -> crate::CoreResult<()> {
         self.oracle.validate_time(self)?;
         let (kind, is_long) = {
@@ -1256,6 +1580,33 @@ impl ExecuteOrderOperation<'_, '_> {
         }
     }
 
+    /// Validate that the size of an increase or swap order does not exceed
+    /// the max order size of the market.
+    ///
+    /// The size of a swap order is the value of its swap-in amount, so it
+    /// can only be validated on execution.
+    fn validate_order_size(&self) -> Result<()> {
+        let order = self.order.load()?;
+        let params = &order.params;
+        let kind = params.kind()?;
+        let size_in_usd = if kind.is_increase_position() {
+            params.size_delta_value
+        } else if kind.is_swap() {
+            let token = order
+                .tokens
+                .initial_collateral
+                .token()
+                .ok_or_else(|| error!(CoreError::MissingInitialCollateralToken))?;
+            let price = self.oracle.get_primary_price(&token, false)?.min;
+            u128::from(params.initial_collateral_delta_amount)
+                .checked_mul(price)
+                .ok_or_else(|| error!(CoreError::ValueOverflow))?
+        } else {
+            return Ok(());
+        };
+        self.market.load()?.validate_order_size(size_in_usd)
+    }
+
     fn validate_non_empty_order(&self) -> Result<()> {
         let order = self.order.load()?;
         let params = &order.params;
@@ -1287,6 +1638,115 @@ impl ExecuteOrderOperation<'_, '_> {
             .validate_trigger_price(&prices.index_token_price)
     }
 
+    /// Validate that the order will not fill against an opposing resting order
+    /// of the same owner on the same position.
+    ///
+    /// Only the position orders of the owner are checked, i.e., liquidation and
+    /// auto-deleveraging orders are never prevented. All the unclosed resting orders
+    /// recorded in the position, except the order being executed, must be provided as
+    /// linked orders, so that the check cannot be skipped by omitting them. Their number
+    /// is bounded by [`Position::MAX_RESTING_ORDERS`]. The check is skipped if
+    /// self-trade prevention is disabled in the store.
+    fn validate_self_trade(
+        &self,
+        should_throw_error: &mut bool,
+        prices: &Prices<u128>,
+        linked_orders: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        if !self.store.load()?.is_self_trade_prevention_enabled() {
+            return Ok(());
+        }
+
+        let order_address = self.order.key();
+        let (owner, position, kind, is_recorded) = {
+            let order = self.order.load()?;
+            let kind = order.params.kind()?;
+            let Some(position) = order.params.position().copied() else {
+                return Ok(());
+            };
+            if !(kind.is_increase_position() || kind.is_decrease_position())
+                || matches!(kind, OrderKind::Liquidation | OrderKind::AutoDeleveraging)
+            {
+                return Ok(());
+            }
+            (
+                *order.header().owner(),
+                position,
+                kind,
+                order.header().is_resting_order_recorded(),
+            )
+        };
+        let expected = {
+            let account = self
+                .position
+                .ok_or_else(|| error!(CoreError::PositionIsRequired))?;
+            require_keys_eq!(account.key(), position, CoreError::PositionMismatched);
+            account
+                .load()?
+                .num_resting_orders()
+                .checked_sub(u32::from(is_recorded))
+                .ok_or_else(|| error!(CoreError::Internal))?
+        };
+        let store = self.store.key();
+
+        let mut provided = 0u32;
+        let mut conflicting = None;
+        for (idx, info) in linked_orders.iter().enumerate() {
+            require!(
+                info.key() != order_address
+                    && !linked_orders[..idx]
+                        .iter()
+                        .any(|other| other.key == info.key),
+                CoreError::InvalidArgument
+            );
+            let linked = AccountLoader::<Order>::try_from(info)?;
+            let linked = linked.load()?;
+            let header = linked.header();
+            require_keys_eq!(*header.store(), store, CoreError::StoreMismatched);
+            require_keys_eq!(*header.owner(), owner, CoreError::OwnerMismatched);
+            require!(
+                linked.params.position() == Some(&position),
+                CoreError::PositionMismatched
+            );
+            require!(
+                header.is_resting_order_recorded(),
+                CoreError::InvalidArgument
+            );
+            provided += 1;
+
+            if conflicting.is_some() || !header.action_state()?.is_pending() {
+                continue;
+            }
+            if kind.is_self_trade_against(&linked.params.kind()?)
+                && linked
+                    .validate_trigger_price(&prices.index_token_price)
+                    .is_ok()
+            {
+                conflicting = Some(info.key);
+            }
+        }
+
+        if provided != expected {
+            msg!(
+                "[Order] all resting orders of the position must be provided for self-trade prevention, expected = {}, provided = {}",
+                expected,
+                provided
+            );
+            return err!(CoreError::InvalidArgument);
+        }
+
+        if let Some(address) = conflicting {
+            msg!(
+                "[Order] self-trade prevented: the order would fill against the resting order {}",
+                address
+            );
+            *should_throw_error = true;
+            return err!(CoreError::SelfTradePrevented);
+        }
+
+        Ok(())
+    }
+
     #[inline(never)]
     fn handle_executed(
         &self,
@@ -1438,6 +1898,30 @@ impl ValidateOracleTime for ExecuteOrderOperation<'_, '_> {
     }
 }
 
+/// Returns whether the index price deviates from the acceptable price by more than
+/// `tolerance` (a factor of the acceptable price) in the unfavorable direction.
+///
+/// The acceptable price is an upper bound if `is_upper_bound` is `true`, and a lower bound otherwise.
+fn is_acceptable_price_beyond_tolerance(
+    acceptable_price: u128,
+    index_price: u128,
+    is_upper_bound: bool,
+    tolerance: u128,
+) -> crate::CoreResult<bool> {
+    let deviation = if is_upper_bound {
+        index_price.saturating_sub(acceptable_price)
+    } else {
+        acceptable_price.saturating_sub(index_price)
+    };
+    if deviation == 0 {
+        return Ok(false);
+    }
+    let max_deviation =
+        apply_factor::<_, { constants::MARKET_DECIMALS }>(&acceptable_price, &tolerance)
+            .ok_or(CoreError::ValueOverflow)?;
+    Ok(deviation > max_deviation)
+}
+
 #[inline(never)]
 fn execute_swap(
     should_throw_error: &mut bool,
@@ -1518,6 +2002,9 @@ fn execute_increase_position(
     // after the swap.
     order.validate_output_amount(collateral_increment_amount.into())?;
 
+    position.validate_increase_cooldown()?;
+    position.record_opened_if_empty()?;
+
     // Increase position.
     let (long_amount, short_amount, paid_order_fee_value) = {
         let size_delta_usd = params.size_delta_value;
@@ -1536,6 +2023,12 @@ fn execute_increase_position(
         let paid_fee_value = *report.fees().paid_order_and_borrowing_fee_value();
         event.update_with_increase_report(&report)?;
 
+        position.add_cost_basis_value(
+            size_delta_usd
+                .checked_add(paid_fee_value)
+                .ok_or_else(|| error!(CoreError::ValueOverflow))?,
+        )?;
+
         position
             .event_emitter()
             .emit_cpi(&PositionIncreased::from_report(
@@ -1563,6 +2056,21 @@ fn execute_increase_position(
     Ok(paid_order_fee_value)
 }
 
+/// Convert the execution fee in lamports into collateral tokens, rounding up
+/// in favor of the executor.
+fn execution_fee_in_collateral_tokens(
+    execution_lamports: u64,
+    native_token_price: &gmsol_model::price::Price<u128>,
+    collateral_token_price: &gmsol_model::price::Price<u128>,
+) -> Result<u64> {
+    use gmsol_model::num::MulDiv;
+
+    let amount = u128::from(execution_lamports)
+        .checked_mul_div_ceil(&native_token_price.max, &collateral_token_price.min)
+        .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+    u64::try_from(amount).map_err(|_| error!(CoreError::TokenAmountOverflow))
+}
+
 #[allow(clippy::too_many_arguments)]
 #[inline(never)]
 fn execute_decrease_position(
@@ -1598,13 +2106,11 @@ fn execute_decrease_position(
         // Only required when the order is an ADL order.
         let mut pnl_factor_before_execution = None;
 
-        // Validate the liquidation is a fully close.
+        // The size delta of a liquidation is determined by the position cut, which is
+        // either a full close or a partial close that restores the position to health.
+        // Insolvent close is only allowed if it ends up as a full close.
         if is_liquidation_order {
-            require_gte!(
-                size_delta_usd,
-                *position.size_in_usd(),
-                CoreError::InvalidArgument
-            );
+            require_neq!(size_delta_usd, 0, CoreError::InvalidArgument);
         }
 
         // Validate that ADL is required.
@@ -1663,6 +2169,7 @@ fn execute_decrease_position(
         report
     };
     let should_remove_position = report.should_remove();
+    position.record_closed_if_empty();
 
     // Perform swaps.
     {
@@ -1839,7 +2346,8 @@ impl PositionCutOperation<'_, '_> {
                 is_collateral_token_long,
             )
         };
-        self.create_order(size_in_usd, is_long, is_collateral_long)?;
+        let size_delta_usd = self.size_delta_usd(size_in_usd)?;
+        self.create_order(size_delta_usd, is_long, is_collateral_long)?;
         let (is_position_removed, transfer_out, should_send_trade_event) = self.execute_order()?;
         require!(transfer_out.executed(), CoreError::Internal);
         self.order.load_mut()?.header.completed()?;
@@ -1858,10 +2366,43 @@ impl PositionCutOperation<'_, '_> {
         Ok(should_send_trade_event)
     }
 
+    /// Get the size delta of the position cut.
+    ///
+    /// A liquidation only reduces the position just enough to restore its health if
+    /// partial liquidation is enabled, and falls back to a full liquidation if that
+    /// is not possible.
+    #[inline(never)]
+    fn size_delta_usd(&self, size_in_usd: u128) -> Result<u128> {
+        let PositionCutKind::Liquidate = self.kind else {
+            return Ok(self.kind.size_delta_usd(size_in_usd));
+        };
+        let Some(buffer_factor) = self.store.load()?.partial_liquidation_buffer_factor() else {
+            return Ok(size_in_usd);
+        };
+        let market = self.market.load()?;
+        let prices = market.prices(self.oracle)?;
+        let position = self.position.load()?;
+        let size_delta_usd = position
+            .as_position(&market)?
+            .partial_liquidation_size_delta_usd(&prices, &buffer_factor)
+            .map_err(ModelError::from)?;
+        match size_delta_usd {
+            Some(size_delta_usd) => {
+                msg!(
+                    "[Position] partially liquidating {} of {}",
+                    size_delta_usd,
+                    size_in_usd
+                );
+                Ok(size_delta_usd)
+            }
+            None => Ok(size_in_usd),
+        }
+    }
+
     #[inline(never)]
     fn create_order(
         &self,
-        size_in_usd: u128,
+        size_delta_usd: u128,
         is_long: bool,
         is_collateral_long: bool,
     ) -> Result<()> {
@@ -1878,7 +2419,7 @@ impl PositionCutOperation<'_, '_> {
             execution_lamports: Order::MIN_EXECUTION_LAMPORTS,
             swap_path_length: 0,
             initial_collateral_delta_amount: 0,
-            size_delta_value: self.kind.size_delta_usd(size_in_usd),
+            size_delta_value: size_delta_usd,
             is_long,
             is_collateral_long,
             min_output: None,
@@ -1886,6 +2427,7 @@ impl PositionCutOperation<'_, '_> {
             acceptable_price: None,
             should_unwrap_native_token: self.should_unwrap_native_token,
             valid_from_ts: None,
+            pay_execution_fee_from_collateral: None,
         };
         let output_token_account = if is_collateral_long {
             self.long_token_account
@@ -1917,6 +2459,12 @@ impl PositionCutOperation<'_, '_> {
             .short_token(self.short_token_account)
             .build()
             .execute()?;
+        // Position cut orders are not subject to the max pending actions limit.
+        self.user.load_mut()?.add_pending_action(0)?;
+        self.order
+            .load_mut()?
+            .header
+            .set_pending_action_recorded(true);
         // Make sure the discriminator is written to the account data.
         self.order.exit(&crate::ID)?;
         Ok(())
@@ -1997,3 +2545,93 @@ impl PositionCutOperation<'_, '_> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gmsol_model::price::Price;
+
+    use super::*;
+
+    #[test]
+    fn convert_execution_fee_into_collateral_tokens() {
+        // SOL (9 decimals) at $150-$151, USDC (6 decimals) at $0.999-$1.
+        let unit = 10u128.pow(20);
+        let native_token_price = Price {
+            min: 150 * unit / 10u128.pow(9),
+            max: 151 * unit / 10u128.pow(9),
+        };
+        let collateral_token_price = Price {
+            min: 999 * unit / 1000 / 10u128.pow(6),
+            max: unit / 10u128.pow(6),
+        };
+
+        // 0.001 SOL is worth at most $0.151, i.e. 151_152 units of USDC (rounded up).
+        let amount = execution_fee_in_collateral_tokens(
+            1_000_000,
+            &native_token_price,
+            &collateral_token_price,
+        )
+        .unwrap();
+        assert_eq!(amount, 151_152);
+
+        // The amount must fit in `u64`.
+        assert!(execution_fee_in_collateral_tokens(
+            u64::MAX,
+            &native_token_price,
+            &Price { min: 1, max: 1 },
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn acceptable_price_beyond_tolerance() {
+        // 1%
+        let tolerance = 10u128.pow(18);
+        let acceptable_price = 100 * 10u128.pow(20);
+        let price = |percent: u128| percent * 10u128.pow(20);
+
+        // The acceptable price is an upper bound.
+        for (index_price, expected) in [
+            (price(90), false),
+            (price(100), false),
+            (price(101), false),
+            (price(101) + 1, true),
+        ] {
+            assert_eq!(
+                is_acceptable_price_beyond_tolerance(
+                    acceptable_price,
+                    index_price,
+                    true,
+                    tolerance
+                )
+                .unwrap(),
+                expected
+            );
+        }
+
+        // The acceptable price is a lower bound.
+        for (index_price, expected) in [
+            (price(110), false),
+            (price(100), false),
+            (price(99), false),
+            (price(99) - 1, true),
+        ] {
+            assert_eq!(
+                is_acceptable_price_beyond_tolerance(
+                    acceptable_price,
+                    index_price,
+                    false,
+                    tolerance
+                )
+                .unwrap(),
+                expected
+            );
+        }
+
+        // Any unfavorable deviation is beyond a zero tolerance.
+        assert!(
+            is_acceptable_price_beyond_tolerance(acceptable_price, price(100) + 1, true, 0)
+                .unwrap()
+        );
+    }
+}
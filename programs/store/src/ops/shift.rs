@@ -168,8 +168,15 @@ impl ExecuteShiftOperation<'_, '_> {
     pub(crate) fn execute(self) -> Result<bool> {
         let throw_on_execution_error = self.throw_on_execution_error;
 
-        match self.validate_oracle() {
+        match self
+            .validate_action_age()
+            .and_then(|()| self.validate_oracle())
+        {
             Ok(()) => {}
+            Err(CoreError::ActionTooOld) if !throw_on_execution_error => {
+                msg!("shift is too old to be executed");
+                return Ok(false);
+            }
             Err(CoreError::OracleTimestampsAreLargerThanRequired) if !throw_on_execution_error => {
                 msg!(
                     "shift expired at {}",
@@ -194,6 +201,10 @@ impl ExecuteShiftOperation<'_, '_> {
         }
     }
 
+    fn validate_action_age(&self) -> CoreResult<()> {
+        ActionExt::validate_age(self.shift, self.store)
+    }
+
     fn validate_oracle(&self) -> CoreResult<()> {
         self.oracle.validate_time(self)
     }
@@ -6,7 +6,7 @@ use crate::{
     events::EventEmitter,
     states::{
         common::{
-            action::{Action, ActionParams},
+            action::{Action, ActionExt, ActionParams},
             swap::SwapActionParamsExt,
         },
         market::revertible::Revertible,
@@ -109,6 +109,10 @@ impl CreateWithdrawalOperation<'_, '_> {
         withdrawal.params.min_short_token_amount = params.min_short_token_amount;
 
         // Initialize swap paths.
+        store.load()?.validate_withdrawal_swap_path_length(
+            usize::from(params.long_token_swap_path_length)
+                + usize::from(params.short_token_swap_path_length),
+        )?;
         let market = market.load()?;
         let meta = market.meta();
         withdrawal.swap.validate_and_init(
@@ -156,8 +160,15 @@ pub(crate) struct ExecuteWithdrawalOperation<'a, 'info> {
 impl ExecuteWithdrawalOperation<'_, '_> {
     pub(crate) fn execute(self) -> Result<Option<(u64, u64)>> {
         let throw_on_execution_error = self.throw_on_execution_error;
-        match self.validate_oracle() {
+        match self
+            .validate_action_age()
+            .and_then(|()| self.validate_oracle())
+        {
             Ok(()) => {}
+            Err(CoreError::ActionTooOld) if !throw_on_execution_error => {
+                msg!("Withdrawal is too old to be executed");
+                return Ok(None);
+            }
             Err(CoreError::OracleTimestampsAreLargerThanRequired) if !throw_on_execution_error => {
                 msg!(
                     "Withdrawal expired at {}",
@@ -182,6 +193,10 @@ impl ExecuteWithdrawalOperation<'_, '_> {
         }
     }
 
+    fn validate_action_age(&self) -> CoreResult<()> {
+        ActionExt::validate_age(self.withdrawal, self.store)
+    }
+
     fn validate_oracle(&self) -> CoreResult<()> {
         self.oracle.validate_time(self)
     }
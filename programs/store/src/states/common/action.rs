@@ -7,12 +7,12 @@ use gmsol_utils::{
 
 use crate::{
     events::Event,
-    states::{callback::CallbackAuthority, NonceBytes, Seed},
+    states::{callback::CallbackAuthority, NonceBytes, Seed, Store},
     utils::pubkey::optional_address,
-    CoreError,
+    CoreError, CoreResult,
 };
 
-pub use gmsol_utils::action::{ActionFlag, ActionState};
+pub use gmsol_utils::action::{ActionFlag, ActionState, CloseReason};
 
 /// Action Header.
 #[zero_copy]
@@ -369,6 +369,46 @@ impl ActionHeader {
         self.flags
             .set_flag(ActionFlag::ShouldUnwrapNativeToken, should_unwrap)
     }
+
+    /// Returns whether the execution fee should be paid from the position's collateral.
+    pub fn should_pay_execution_fee_from_collateral(&self) -> bool {
+        self.flags
+            .get_flag(ActionFlag::PayExecutionFeeFromCollateral)
+    }
+
+    /// Set whether the execution fee should be paid from the position's collateral.
+    ///
+    /// Returns the previous value.
+    pub(crate) fn set_should_pay_execution_fee_from_collateral(&mut self, enable: bool) -> bool {
+        self.flags
+            .set_flag(ActionFlag::PayExecutionFeeFromCollateral, enable)
+    }
+
+    /// Returns whether the action has been recorded as a resting order of its position.
+    pub fn is_resting_order_recorded(&self) -> bool {
+        self.flags.get_flag(ActionFlag::RestingOrderRecorded)
+    }
+
+    /// Set whether the action has been recorded as a resting order of its position.
+    ///
+    /// Returns the previous value.
+    pub(crate) fn set_resting_order_recorded(&mut self, recorded: bool) -> bool {
+        self.flags
+            .set_flag(ActionFlag::RestingOrderRecorded, recorded)
+    }
+
+    /// Returns whether the action has been recorded as a pending action of the owner.
+    pub fn is_pending_action_recorded(&self) -> bool {
+        self.flags.get_flag(ActionFlag::PendingActionRecorded)
+    }
+
+    /// Set whether the action has been recorded as a pending action of the owner.
+    ///
+    /// Returns the previous value.
+    pub(crate) fn set_pending_action_recorded(&mut self, recorded: bool) -> bool {
+        self.flags
+            .set_flag(ActionFlag::PendingActionRecorded, recorded)
+    }
 }
 
 /// Action Signer.
@@ -444,6 +484,30 @@ pub trait ActionExt: Action {
             Self::MIN_EXECUTION_LAMPORTS,
             CoreError::NotEnoughExecutionFee
         );
+        Self::validate_rent_exemption(account, execution_lamports)
+    }
+
+    /// Validate that the action has not exceeded the max action age of the store.
+    fn validate_age(account: &AccountLoader<Self>, store: &AccountLoader<Store>) -> CoreResult<()>
+    where
+        Self: ZeroCopy + Owner,
+    {
+        let updated_at = account
+            .load()
+            .map_err(|_| CoreError::LoadAccountError)?
+            .header()
+            .updated_at;
+        store
+            .load()
+            .map_err(|_| CoreError::LoadAccountError)?
+            .validate_action_age(updated_at)
+    }
+
+    /// Validate that the account stays rent-exempt after paying the given execution fee.
+    fn validate_rent_exemption(account: &AccountLoader<Self>, execution_lamports: u64) -> Result<()>
+    where
+        Self: ZeroCopy + Owner + InitSpace,
+    {
         let balance = account.get_lamports().saturating_sub(execution_lamports);
         let rent = Rent::get()?;
         require!(
@@ -468,7 +532,12 @@ pub trait Closable {
     type ClosedEvent: Event + InitSpace;
 
     /// To closed event.
-    fn to_closed_event(&self, address: &Pubkey, reason: &str) -> Result<Self::ClosedEvent>;
+    fn to_closed_event(
+        &self,
+        address: &Pubkey,
+        reason: &str,
+        close_reason: CloseReason,
+    ) -> Result<Self::ClosedEvent>;
 }
 
 impl From<ActionError> for CoreError {
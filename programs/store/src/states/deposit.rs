@@ -5,7 +5,7 @@ use crate::{events::DepositRemoved, states::MarketConfigKey, CoreError};
 
 use super::{
     common::{
-        action::{Action, ActionHeader, Closable},
+        action::{Action, ActionHeader, Closable, CloseReason},
         swap::SwapActionParams,
         token::TokenAndAccount,
     },
@@ -44,7 +44,12 @@ impl InitSpace for Deposit {
 impl Closable for Deposit {
     type ClosedEvent = DepositRemoved;
 
-    fn to_closed_event(&self, address: &Pubkey, reason: &str) -> Result<Self::ClosedEvent> {
+    fn to_closed_event(
+        &self,
+        address: &Pubkey,
+        reason: &str,
+        close_reason: CloseReason,
+    ) -> Result<Self::ClosedEvent> {
         DepositRemoved::new(
             self.header.id,
             self.header.store,
@@ -53,6 +58,7 @@ impl Closable for Deposit {
             self.header.owner,
             self.header.action_state()?,
             reason,
+            close_reason,
         )
     }
 }
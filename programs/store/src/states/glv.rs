@@ -16,7 +16,7 @@ use crate::{
 
 use super::{
     common::{
-        action::{Action, ActionHeader, Closable},
+        action::{Action, ActionHeader, Closable, CloseReason},
         swap::{unpack_markets, HasSwapParams, SwapActionParams},
         token::{TokenAndAccount, TokensCollector},
     },
@@ -48,8 +48,7 @@ pub struct Glv {
     shift_last_executed_at: i64,
     pub(crate) min_tokens_for_first_deposit: u64,
     shift_min_interval_secs: u32,
-    #[cfg_attr(feature = "debug", debug(skip))]
-    padding_1: [u8; 4],
+    num_active_shifts: u32,
     shift_max_price_impact_factor: u128,
     shift_min_value: u128,
     #[cfg_attr(feature = "debug", debug(skip))]
@@ -501,6 +500,30 @@ impl Glv {
         }
     }
 
+    /// Record a newly created GLV shift.
+    ///
+    /// `max_active_shifts` of `0` means unlimited.
+    pub(crate) fn on_shift_created(&mut self, max_active_shifts: u64) -> Result<()> {
+        if max_active_shifts != 0 {
+            require_gt!(
+                max_active_shifts,
+                u64::from(self.num_active_shifts),
+                CoreError::MaxConcurrentGlvShiftsExceeded
+            );
+        }
+        self.num_active_shifts = self
+            .num_active_shifts
+            .checked_add(1)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        Ok(())
+    }
+
+    /// Record a GLV shift that is no longer pending.
+    pub(crate) fn on_shift_finished(&mut self) {
+        // Saturating since shifts created before the counter was introduced are not counted.
+        self.num_active_shifts = self.num_active_shifts.saturating_sub(1);
+    }
+
     pub(crate) fn validate_shift_price_impact(
         &self,
         from_market_token_value: u128,
@@ -556,6 +579,11 @@ impl Glv {
         self.shift_min_interval_secs
     }
 
+    /// Get the number of pending GLV shifts.
+    pub fn num_active_shifts(&self) -> u32 {
+        self.num_active_shifts
+    }
+
     /// Get max shift price impact factor.
     pub fn shift_max_price_impact_factor(&self) -> u128 {
         self.shift_max_price_impact_factor
@@ -686,7 +714,6 @@ impl GlvMarketConfig {
     }
 }
 
-#[cfg(feature = "utils")]
 impl GlvMarketConfig {
     /// Get max amount.
     pub fn max_amount(&self) -> u64 {
@@ -737,7 +764,12 @@ impl Action for GlvDeposit {
 impl Closable for GlvDeposit {
     type ClosedEvent = GlvDepositRemoved;
 
-    fn to_closed_event(&self, address: &Pubkey, reason: &str) -> Result<Self::ClosedEvent> {
+    fn to_closed_event(
+        &self,
+        address: &Pubkey,
+        reason: &str,
+        close_reason: CloseReason,
+    ) -> Result<Self::ClosedEvent> {
         GlvDepositRemoved::new(
             self.header.id(),
             *self.header.store(),
@@ -747,6 +779,7 @@ impl Closable for GlvDeposit {
             *self.header.owner(),
             self.header.action_state()?,
             reason,
+            close_reason,
         )
     }
 }
@@ -958,7 +991,12 @@ impl Action for GlvWithdrawal {
 impl Closable for GlvWithdrawal {
     type ClosedEvent = GlvWithdrawalRemoved;
 
-    fn to_closed_event(&self, address: &Pubkey, reason: &str) -> Result<Self::ClosedEvent> {
+    fn to_closed_event(
+        &self,
+        address: &Pubkey,
+        reason: &str,
+        close_reason: CloseReason,
+    ) -> Result<Self::ClosedEvent> {
         GlvWithdrawalRemoved::new(
             self.header.id,
             self.header.store,
@@ -968,6 +1006,7 @@ impl Closable for GlvWithdrawal {
             self.header.owner,
             self.header.action_state()?,
             reason,
+            close_reason,
         )
     }
 }
@@ -1100,7 +1139,12 @@ impl Action for GlvShift {
 impl Closable for GlvShift {
     type ClosedEvent = ShiftRemoved;
 
-    fn to_closed_event(&self, address: &Pubkey, reason: &str) -> Result<Self::ClosedEvent> {
+    fn to_closed_event(
+        &self,
+        address: &Pubkey,
+        reason: &str,
+        close_reason: CloseReason,
+    ) -> Result<Self::ClosedEvent> {
         let header = self.header();
         let tokens = self.tokens();
         ShiftRemoved::new(
@@ -1111,6 +1155,7 @@ impl Closable for GlvShift {
             header.owner,
             header.action_state()?,
             reason,
+            close_reason,
         )
     }
 }
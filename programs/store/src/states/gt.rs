@@ -44,8 +44,10 @@ const MAX_RANK: usize = 15;
 #[cfg_attr(feature = "debug", derive(derive_more::Debug))]
 pub struct GtState {
     decimals: u8,
+    /// Whether to round up when growing the minting cost.
+    minting_cost_grow_round_up: u8,
     #[cfg_attr(feature = "debug", debug(skip))]
-    padding_0: [u8; 7],
+    padding_0: [u8; 6],
     /* States */
     pub(crate) last_minted_at: i64,
     total_minted: u64,
@@ -72,8 +74,10 @@ pub struct GtState {
     ranks: [u64; MAX_RANK],
     order_fee_discount_factors: [u128; MAX_RANK + 1],
     referral_reward_factors: [u128; MAX_RANK + 1],
-    #[cfg_attr(feature = "debug", debug(skip))]
-    padding_5: [u8; 32],
+    /// Cumulative staker reward value per unit GT.
+    staker_reward_value_per_gt: u128,
+    /// Total staker reward value recorded.
+    total_staker_reward_value: u128,
     #[cfg_attr(feature = "debug", debug(skip))]
     reserved: [u8; 256],
 }
@@ -212,6 +216,16 @@ impl GtState {
         self.grow_steps
     }
 
+    /// Get grow step amount.
+    pub fn grow_step_amount(&self) -> u64 {
+        self.grow_step_amount
+    }
+
+    /// Get minting cost grow factor.
+    pub fn minting_cost_grow_factor(&self) -> u128 {
+        self.minting_cost_grow_factor
+    }
+
     /// Get GT supply.
     pub fn supply(&self) -> u64 {
         self.supply
@@ -229,19 +243,41 @@ impl GtState {
         Ok(())
     }
 
+    /// Returns whether the minting cost is rounded up when it grows.
+    pub fn is_minting_cost_grow_round_up(&self) -> bool {
+        self.minting_cost_grow_round_up != 0
+    }
+
+    /// Set whether to round up when growing the minting cost.
+    ///
+    /// Rounding down (the default) makes the minting cost drift slightly below the
+    /// exact exponential schedule as the grow steps accumulate, while rounding up
+    /// makes it drift slightly above. The drift is at most one unit per grow step.
+    pub fn set_minting_cost_grow_round_up(&mut self, round_up: bool) {
+        self.minting_cost_grow_round_up = u8::from(round_up);
+    }
+
     fn next_minting_cost(&self, next_minted: u64) -> Result<Option<(u64, u128)>> {
-        use gmsol_model::utils::apply_factor;
+        use gmsol_model::{num::MulDiv, utils::apply_factor};
 
         require!(self.grow_step_amount != 0, CoreError::InvalidGTConfig);
         let new_steps = next_minted / self.grow_step_amount;
 
         if new_steps != self.grow_steps {
+            let round_up = self.is_minting_cost_grow_round_up();
             let mut minting_cost = self.minting_cost;
             for _ in self.grow_steps..new_steps {
-                minting_cost = apply_factor::<_, { constants::MARKET_DECIMALS }>(
-                    &minting_cost,
-                    &self.minting_cost_grow_factor,
-                )
+                minting_cost = if round_up {
+                    minting_cost.checked_mul_div_ceil(
+                        &self.minting_cost_grow_factor,
+                        &constants::MARKET_USD_UNIT,
+                    )
+                } else {
+                    apply_factor::<_, { constants::MARKET_DECIMALS }>(
+                        &minting_cost,
+                        &self.minting_cost_grow_factor,
+                    )
+                }
                 .ok_or_else(|| error!(CoreError::Internal))?;
             }
             Ok(Some((new_steps, minting_cost)))
@@ -252,13 +288,7 @@ impl GtState {
 
     /// CHECK: the user must be owned by this store.
     fn unchecked_update_rank(&self, user: &mut UserHeader) {
-        debug_assert!(self.ranks().len() < u8::MAX as usize);
-        let rank = match self.ranks().binary_search(&user.gt.amount) {
-            Ok(rank) => rank + 1,
-            Err(rank) => rank,
-        };
-
-        let rank = rank as u8;
+        let rank = self.rank_of(user.gt.amount);
         if user.gt.rank != rank {
             user.gt.rank = rank;
             msg!("[GT] user rank updated, new rank = {}", rank);
@@ -303,8 +333,17 @@ impl GtState {
         self.cumulative_inv_cost_factor
     }
 
+    /// Mint GT to the given `user`.
+    ///
+    /// The minted GT will be locked for `lockup_seconds`, the lockup is
+    /// disabled if it is zero.
     #[inline(never)]
-    pub(crate) fn mint_to(&mut self, user: &mut UserHeader, amount: u64) -> Result<()> {
+    pub(crate) fn mint_to(
+        &mut self,
+        user: &mut UserHeader,
+        amount: u64,
+        lockup_seconds: u64,
+    ) -> Result<()> {
         if amount != 0 {
             let clock = Clock::get()?;
 
@@ -334,6 +373,12 @@ impl GtState {
             // Update `cumulative_inv_cost_factor` before updating `minting_cost`.
             self.update_cumulative_inv_cost_factor()?;
 
+            // Settle staker rewards before the balance changes.
+            self.settle_staker_reward(user)?;
+
+            // Lock the newly minted GT.
+            user.gt.lock(amount, clock.unix_timestamp, lockup_seconds)?;
+
             /* The following steps should be infallible. */
 
             if let Some((new_steps, new_minting_cost)) = next_minting_cost {
@@ -359,10 +404,15 @@ impl GtState {
     /// - The `user` must be owned by this store.
     ///
     /// # Errors
-    /// - `user` must have enough amount of GT.
+    /// - `user` must have enough amount of unlocked GT.
     pub(crate) fn unchecked_burn_from(&mut self, user: &mut UserHeader, amount: u64) -> Result<()> {
         if amount != 0 {
-            require_gte!(user.gt.amount, amount, CoreError::NotEnoughTokenAmount);
+            let now = Clock::get()?.unix_timestamp;
+            require_gte!(
+                user.gt.unlocked_amount(now),
+                amount,
+                CoreError::NotEnoughTokenAmount
+            );
             let next_amount = user
                 .gt
                 .amount
@@ -374,6 +424,9 @@ impl GtState {
                 .checked_sub(amount)
                 .ok_or_else(|| error!(CoreError::Internal))?;
 
+            // Settle staker rewards before the balance changes.
+            self.settle_staker_reward(user)?;
+
             /* The following steps should be infallible. */
 
             user.gt.amount = next_amount;
@@ -384,6 +437,81 @@ impl GtState {
         Ok(())
     }
 
+    /// Get the cumulative staker reward value per unit GT.
+    pub fn staker_reward_value_per_gt(&self) -> u128 {
+        self.staker_reward_value_per_gt
+    }
+
+    /// Get the total staker reward value recorded.
+    pub fn total_staker_reward_value(&self) -> u128 {
+        self.total_staker_reward_value
+    }
+
+    /// Record the given value as staker rewards of GT holders, proportionally to their GT balances.
+    ///
+    /// Only the value is accounted; no tokens are moved. Returns the recorded value,
+    /// which is zero if there is no GT supply.
+    pub(crate) fn accrue_staker_reward_value(&mut self, value: u128) -> Result<u128> {
+        use gmsol_model::num::MulDiv;
+
+        if value == 0 || self.supply == 0 {
+            return Ok(0);
+        }
+        let delta = value
+            .checked_mul_div(&self.gt_unit(), &u128::from(self.supply))
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        if delta == 0 {
+            return Ok(0);
+        }
+        let distributed = delta
+            .checked_mul_div(&u128::from(self.supply), &self.gt_unit())
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        let next_value_per_gt = self
+            .staker_reward_value_per_gt
+            .checked_add(delta)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        let next_total = self
+            .total_staker_reward_value
+            .checked_add(distributed)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+
+        /* The following steps should be infallible. */
+
+        self.staker_reward_value_per_gt = next_value_per_gt;
+        self.total_staker_reward_value = next_total;
+        Ok(distributed)
+    }
+
+    /// Get the staker reward value of the `user` that has not been settled.
+    pub fn pending_staker_reward(&self, user: &UserHeader) -> Result<u128> {
+        use gmsol_model::num::MulDiv;
+
+        let delta = self
+            .staker_reward_value_per_gt
+            .checked_sub(user.gt.staker_reward_value_per_gt_checkpoint)
+            .ok_or_else(|| error!(CoreError::InvalidUserAccount))?;
+        u128::from(user.gt.amount)
+            .checked_mul_div(&delta, &self.gt_unit())
+            .ok_or_else(|| error!(CoreError::ValueOverflow))
+    }
+
+    /// Settle the staker reward of the `user` into its accrued staker reward value.
+    pub(crate) fn settle_staker_reward(&self, user: &mut UserHeader) -> Result<()> {
+        let pending = self.pending_staker_reward(user)?;
+        let next_accrued = user
+            .gt
+            .accrued_staker_reward_value
+            .checked_add(pending)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        user.gt.accrued_staker_reward_value = next_accrued;
+        user.gt.staker_reward_value_per_gt_checkpoint = self.staker_reward_value_per_gt;
+        Ok(())
+    }
+
+    fn gt_unit(&self) -> u128 {
+        10u128.pow(u32::from(self.decimals))
+    }
+
     #[inline(never)]
     pub(crate) fn get_mint_amount(&self, size_in_value: u128) -> Result<(u64, u128, u128)> {
         let minting_cost = self.minting_cost;
@@ -400,10 +528,40 @@ impl GtState {
         Ok((minted, minted_value, minting_cost))
     }
 
-    pub(crate) fn ranks(&self) -> &[u64] {
+    /// Get the rank thresholds.
+    pub fn ranks(&self) -> &[u64] {
         &self.ranks[0..(self.max_rank as usize)]
     }
 
+    /// Get the rank of the given amount of GT.
+    pub fn rank_of(&self, amount: u64) -> u8 {
+        debug_assert!(self.ranks().len() < u8::MAX as usize);
+        let rank = match self.ranks().binary_search(&amount) {
+            Ok(rank) => rank + 1,
+            Err(rank) => rank,
+        };
+        rank as u8
+    }
+
+    /// Get the lower threshold of the given rank.
+    ///
+    /// Returns `None` if the rank does not exist.
+    pub fn rank_threshold(&self, rank: u8) -> Option<u64> {
+        match rank.checked_sub(1) {
+            None => Some(0),
+            Some(index) => self.ranks().get(index as usize).copied(),
+        }
+    }
+
+    /// Get the amount of GT required to reach the next rank from the given amount.
+    ///
+    /// Returns `None` if the given amount is already at the top rank.
+    pub fn amount_to_next_rank(&self, amount: u64) -> Option<u64> {
+        let next_rank = self.rank_of(amount).saturating_add(1);
+        self.rank_threshold(next_rank)
+            .map(|threshold| threshold.saturating_sub(amount))
+    }
+
     /// Request an exchange.
     ///
     /// # CHECK
@@ -412,7 +570,7 @@ impl GtState {
     /// # Errors
     /// - `user`, `vault` and `exchange` must have been initialized.
     /// - `vault` must be depositable.
-    /// - `user` must have enough amount of GT.
+    /// - `user` must have enough amount of unlocked GT.
     ///
     /// # Notes
     /// - This is not an atomic operation.
@@ -539,6 +697,15 @@ impl GtExchangeVault {
         self.time_window.try_into().expect("invalid vault")
     }
 
+    /// Get the timestamp from which this vault can be confirmed,
+    /// i.e. the start of the next time window.
+    pub fn confirmable_at(&self) -> Result<i64> {
+        self.time_window_index()
+            .checked_add(1)
+            .and_then(|index| index.checked_mul(self.time_window))
+            .ok_or_else(|| error!(CoreError::ValueOverflow))
+    }
+
     /// Validate that this vault is confirmable.
     pub fn validate_confirmable(&self) -> Result<()> {
         require!(self.is_initialized(), CoreError::PreconditionsAreNotMet);
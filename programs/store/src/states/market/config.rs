@@ -85,7 +85,15 @@ pub struct MarketConfig {
     pub(super) max_open_interest_for_long: Factor,
     pub(super) max_open_interest_for_short: Factor,
     pub(super) min_tokens_for_first_deposit: Factor,
-    reserved: [Factor; 32],
+    pub(super) min_market_token_price: Factor,
+    pub(super) increase_cooldown_seconds: Factor,
+    pub(super) min_deposit_fee_usd: Factor,
+    pub(super) min_withdrawal_fee_usd: Factor,
+    pub(super) max_swap_impact_factor: Factor,
+    pub(super) max_order_size_usd: Factor,
+    pub(super) max_open_positions: Factor,
+    pub(super) max_position_impact_factor_for_liquidation_execution: Factor,
+    reserved: [Factor; 24],
 }
 
 impl MarketConfig {
@@ -192,6 +200,15 @@ impl MarketConfig {
         self.max_open_interest_for_short = constants::DEFAULT_MAX_OPEN_INTEREST_FOR_SHORT;
 
         self.min_tokens_for_first_deposit = constants::DEFAULT_MIN_TOKENS_FOR_FIRST_DEPOSIT;
+        self.min_market_token_price = constants::DEFAULT_MIN_MARKET_TOKEN_PRICE;
+        self.increase_cooldown_seconds = constants::DEFAULT_INCREASE_COOLDOWN_SECONDS;
+        self.min_deposit_fee_usd = constants::DEFAULT_MIN_DEPOSIT_FEE_USD;
+        self.min_withdrawal_fee_usd = constants::DEFAULT_MIN_WITHDRAWAL_FEE_USD;
+        self.max_swap_impact_factor = constants::DEFAULT_MAX_SWAP_IMPACT_FACTOR;
+        self.max_order_size_usd = constants::DEFAULT_MAX_ORDER_SIZE_USD;
+        self.max_open_positions = constants::DEFAULT_MAX_OPEN_POSITIONS;
+        self.max_position_impact_factor_for_liquidation_execution =
+            constants::DEFAULT_MAX_POSITION_IMPACT_FACTOR_FOR_LIQUIDATION_EXECUTION;
 
         self.set_flag(
             MarketConfigFlag::SkipBorrowingFeeForSmallerSide,
@@ -201,6 +218,14 @@ impl MarketConfig {
             MarketConfigFlag::IgnoreOpenInterestForUsageFactor,
             constants::DEFAULT_IGNORE_OPEN_INTEREST_FOR_USAGE_FACTOR,
         );
+        self.set_flag(
+            MarketConfigFlag::EnforceReserves,
+            constants::DEFAULT_ENFORCE_RESERVES,
+        );
+        self.set_flag(
+            MarketConfigFlag::EnableInsuranceFund,
+            constants::DEFAULT_ENABLE_INSURANCE_FUND,
+        );
     }
 
     pub(super) fn get(&self, key: MarketConfigKey) -> Option<&Factor> {
@@ -319,6 +344,16 @@ impl MarketConfig {
             MarketConfigKey::MaxOpenInterestForLong => &self.max_open_interest_for_long,
             MarketConfigKey::MaxOpenInterestForShort => &self.max_open_interest_for_short,
             MarketConfigKey::MinTokensForFirstDeposit => &self.min_tokens_for_first_deposit,
+            MarketConfigKey::MinMarketTokenPrice => &self.min_market_token_price,
+            MarketConfigKey::IncreaseCooldownSeconds => &self.increase_cooldown_seconds,
+            MarketConfigKey::MinDepositFeeUsd => &self.min_deposit_fee_usd,
+            MarketConfigKey::MinWithdrawalFeeUsd => &self.min_withdrawal_fee_usd,
+            MarketConfigKey::MaxSwapImpactFactor => &self.max_swap_impact_factor,
+            MarketConfigKey::MaxOrderSizeUsd => &self.max_order_size_usd,
+            MarketConfigKey::MaxOpenPositions => &self.max_open_positions,
+            MarketConfigKey::MaxPositionImpactFactorForLiquidationExecution => {
+                &self.max_position_impact_factor_for_liquidation_execution
+            }
             _ => return None,
         };
         Some(value)
@@ -460,6 +495,16 @@ impl MarketConfig {
             MarketConfigKey::MaxOpenInterestForLong => &mut self.max_open_interest_for_long,
             MarketConfigKey::MaxOpenInterestForShort => &mut self.max_open_interest_for_short,
             MarketConfigKey::MinTokensForFirstDeposit => &mut self.min_tokens_for_first_deposit,
+            MarketConfigKey::MinMarketTokenPrice => &mut self.min_market_token_price,
+            MarketConfigKey::IncreaseCooldownSeconds => &mut self.increase_cooldown_seconds,
+            MarketConfigKey::MinDepositFeeUsd => &mut self.min_deposit_fee_usd,
+            MarketConfigKey::MinWithdrawalFeeUsd => &mut self.min_withdrawal_fee_usd,
+            MarketConfigKey::MaxSwapImpactFactor => &mut self.max_swap_impact_factor,
+            MarketConfigKey::MaxOrderSizeUsd => &mut self.max_order_size_usd,
+            MarketConfigKey::MaxOpenPositions => &mut self.max_open_positions,
+            MarketConfigKey::MaxPositionImpactFactorForLiquidationExecution => {
+                &mut self.max_position_impact_factor_for_liquidation_execution
+            }
             _ => return None,
         };
         Some(value)
@@ -480,6 +525,152 @@ impl MarketConfig {
 
 gmsol_utils::flags!(MarketConfigFlag, MAX_MARKET_CONFIG_FLAGS, u128);
 
+/// Fee related parameters of the market config.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct MarketFeeConfig {
+    /// Swap fee receiver factor.
+    pub swap_fee_receiver_factor: Factor,
+    /// Swap fee factor for positive impact.
+    pub swap_fee_factor_for_positive_impact: Factor,
+    /// Swap fee factor for negative impact.
+    pub swap_fee_factor_for_negative_impact: Factor,
+    /// Order fee receiver factor.
+    pub order_fee_receiver_factor: Factor,
+    /// Order fee factor for positive impact.
+    pub order_fee_factor_for_positive_impact: Factor,
+    /// Order fee factor for negative impact.
+    pub order_fee_factor_for_negative_impact: Factor,
+    /// Liquidation fee receiver factor.
+    pub liquidation_fee_receiver_factor: Factor,
+    /// Liquidation fee factor.
+    pub liquidation_fee_factor: Factor,
+    /// Borrowing fee receiver factor.
+    pub borrowing_fee_receiver_factor: Factor,
+    /// Borrowing fee factor for long.
+    pub borrowing_fee_factor_for_long: Factor,
+    /// Borrowing fee factor for short.
+    pub borrowing_fee_factor_for_short: Factor,
+    /// Borrowing fee exponent for long.
+    pub borrowing_fee_exponent_for_long: Factor,
+    /// Borrowing fee exponent for short.
+    pub borrowing_fee_exponent_for_short: Factor,
+    /// Borrowing fee optimal usage factor for long.
+    pub borrowing_fee_optimal_usage_factor_for_long: Factor,
+    /// Borrowing fee optimal usage factor for short.
+    pub borrowing_fee_optimal_usage_factor_for_short: Factor,
+    /// Borrowing fee base factor for long.
+    pub borrowing_fee_base_factor_for_long: Factor,
+    /// Borrowing fee base factor for short.
+    pub borrowing_fee_base_factor_for_short: Factor,
+    /// Borrowing fee above optimal usage factor for long.
+    pub borrowing_fee_above_optimal_usage_factor_for_long: Factor,
+    /// Borrowing fee above optimal usage factor for short.
+    pub borrowing_fee_above_optimal_usage_factor_for_short: Factor,
+    /// Funding fee exponent.
+    pub funding_fee_exponent: Factor,
+    /// Funding fee factor.
+    pub funding_fee_factor: Factor,
+    /// Funding fee max factor per second.
+    pub funding_fee_max_factor_per_second: Factor,
+    /// Funding fee min factor per second.
+    pub funding_fee_min_factor_per_second: Factor,
+    /// Funding fee increase factor per second.
+    pub funding_fee_increase_factor_per_second: Factor,
+    /// Funding fee decrease factor per second.
+    pub funding_fee_decrease_factor_per_second: Factor,
+    /// Funding fee threshold for stable funding.
+    pub funding_fee_threshold_for_stable_funding: Factor,
+    /// Funding fee threshold for decrease funding.
+    pub funding_fee_threshold_for_decrease_funding: Factor,
+}
+
+impl From<&MarketConfig> for MarketFeeConfig {
+    fn from(config: &MarketConfig) -> Self {
+        Self {
+            swap_fee_receiver_factor: config.swap_fee_receiver_factor,
+            swap_fee_factor_for_positive_impact: config.swap_fee_factor_for_positive_impact,
+            swap_fee_factor_for_negative_impact: config.swap_fee_factor_for_negative_impact,
+            order_fee_receiver_factor: config.order_fee_receiver_factor,
+            order_fee_factor_for_positive_impact: config.order_fee_factor_for_positive_impact,
+            order_fee_factor_for_negative_impact: config.order_fee_factor_for_negative_impact,
+            liquidation_fee_receiver_factor: config.liquidation_fee_receiver_factor,
+            liquidation_fee_factor: config.liquidation_fee_factor,
+            borrowing_fee_receiver_factor: config.borrowing_fee_receiver_factor,
+            borrowing_fee_factor_for_long: config.borrowing_fee_factor_for_long,
+            borrowing_fee_factor_for_short: config.borrowing_fee_factor_for_short,
+            borrowing_fee_exponent_for_long: config.borrowing_fee_exponent_for_long,
+            borrowing_fee_exponent_for_short: config.borrowing_fee_exponent_for_short,
+            borrowing_fee_optimal_usage_factor_for_long: config
+                .borrowing_fee_optimal_usage_factor_for_long,
+            borrowing_fee_optimal_usage_factor_for_short: config
+                .borrowing_fee_optimal_usage_factor_for_short,
+            borrowing_fee_base_factor_for_long: config.borrowing_fee_base_factor_for_long,
+            borrowing_fee_base_factor_for_short: config.borrowing_fee_base_factor_for_short,
+            borrowing_fee_above_optimal_usage_factor_for_long: config
+                .borrowing_fee_above_optimal_usage_factor_for_long,
+            borrowing_fee_above_optimal_usage_factor_for_short: config
+                .borrowing_fee_above_optimal_usage_factor_for_short,
+            funding_fee_exponent: config.funding_fee_exponent,
+            funding_fee_factor: config.funding_fee_factor,
+            funding_fee_max_factor_per_second: config.funding_fee_max_factor_per_second,
+            funding_fee_min_factor_per_second: config.funding_fee_min_factor_per_second,
+            funding_fee_increase_factor_per_second: config.funding_fee_increase_factor_per_second,
+            funding_fee_decrease_factor_per_second: config.funding_fee_decrease_factor_per_second,
+            funding_fee_threshold_for_stable_funding: config
+                .funding_fee_threshold_for_stable_funding,
+            funding_fee_threshold_for_decrease_funding: config
+                .funding_fee_threshold_for_decrease_funding,
+        }
+    }
+}
+
+/// Liquidation and ADL related parameters of the market config.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct MarketRiskConfig {
+    /// Min collateral value.
+    pub min_collateral_value: Factor,
+    /// Min collateral factor, i.e., the maintenance margin factor.
+    /// A position becomes liquidatable when its remaining collateral falls below it.
+    pub min_collateral_factor: Factor,
+    /// Min collateral factor for open interest multiplier for long.
+    pub min_collateral_factor_for_open_interest_multiplier_for_long: Factor,
+    /// Min collateral factor for open interest multiplier for short.
+    pub min_collateral_factor_for_open_interest_multiplier_for_short: Factor,
+    /// Max position impact factor for liquidations.
+    pub max_position_impact_factor_for_liquidations: Factor,
+    /// Max PnL factor for long ADL, i.e., the ADL trigger factor for long.
+    pub max_pnl_factor_for_long_adl: Factor,
+    /// Max PnL factor for short ADL, i.e., the ADL trigger factor for short.
+    pub max_pnl_factor_for_short_adl: Factor,
+    /// Min PnL factor after long ADL.
+    pub min_pnl_factor_after_long_adl: Factor,
+    /// Min PnL factor after short ADL.
+    pub min_pnl_factor_after_short_adl: Factor,
+}
+
+impl From<&MarketConfig> for MarketRiskConfig {
+    fn from(config: &MarketConfig) -> Self {
+        Self {
+            min_collateral_value: config.min_collateral_value,
+            min_collateral_factor: config.min_collateral_factor,
+            min_collateral_factor_for_open_interest_multiplier_for_long: config
+                .min_collateral_factor_for_open_interest_multiplier_for_long,
+            min_collateral_factor_for_open_interest_multiplier_for_short: config
+                .min_collateral_factor_for_open_interest_multiplier_for_short,
+            max_position_impact_factor_for_liquidations: config
+                .max_position_impact_factor_for_liquidations,
+            max_pnl_factor_for_long_adl: config.max_pnl_factor_for_long_adl,
+            max_pnl_factor_for_short_adl: config.max_pnl_factor_for_short_adl,
+            min_pnl_factor_after_long_adl: config.min_pnl_factor_after_long_adl,
+            min_pnl_factor_after_short_adl: config.min_pnl_factor_after_short_adl,
+        }
+    }
+}
+
 /// An entry of the config buffer.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 #[cfg_attr(feature = "debug", derive(Debug))]
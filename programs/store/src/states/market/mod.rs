@@ -46,18 +46,31 @@ use virtual_inventory::VirtualInventory;
 use crate::{
     constants::MARKET_DECIMALS,
     utils::fixed_str::{bytes_to_fixed_str, fixed_str_to_bytes},
-    CoreError, ModelError,
+    CoreError, CoreResult, ModelError,
 };
 
 use super::{Factor, InitSpace, Oracle, Seed};
 
 use self::{
-    config::{MarketConfig, MarketConfigBuffer, MarketConfigKey},
+    config::{
+        MarketConfig, MarketConfigBuffer, MarketConfigKey, MarketFeeConfig, MarketRiskConfig,
+    },
     pool::{Pool, Pools},
 };
 
 pub use gmsol_utils::market::{HasMarketMeta, MarketMeta};
-pub use model::AsLiquidityMarket;
+
+/// The canonical addresses of a market.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct MarketAddresses {
+    /// The address of the market account.
+    pub market: Pubkey,
+    /// The address of the market token mint.
+    pub market_token: Pubkey,
+}
+pub use model::{AsLiquidityMarket, SimulatedLiquidityMarket};
 
 /// Market Utils.
 pub mod utils;
@@ -161,6 +174,26 @@ impl Market {
         )
     }
 
+    /// Find PDA for the market token mint.
+    pub fn find_market_token_address(
+        store: &Pubkey,
+        index_token: &Pubkey,
+        long_token: &Pubkey,
+        short_token: &Pubkey,
+        store_program_id: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                crate::constants::MARKET_TOKEN_MINT_SEED,
+                store.as_ref(),
+                index_token.as_ref(),
+                long_token.as_ref(),
+                short_token.as_ref(),
+            ],
+            store_program_id,
+        )
+    }
+
     /// Initialize the market.
     #[allow(clippy::too_many_arguments)]
     pub fn init(
@@ -311,6 +344,17 @@ impl Market {
         Ok(())
     }
 
+    /// Validate that the given order size does not exceed the max order size of the market.
+    pub fn validate_order_size(&self, size_in_usd: u128) -> Result<()> {
+        let max_size = *self
+            .get_config_by_key(MarketConfigKey::MaxOrderSizeUsd)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?;
+        if max_size != 0 {
+            require_gte!(max_size, size_in_usd, CoreError::MaxOrderSizeExceeded);
+        }
+        Ok(())
+    }
+
     /// Get config.
     pub fn get_config(&self, key: &str) -> Result<&Factor> {
         let key = MarketConfigKey::from_str(key)
@@ -334,6 +378,16 @@ impl Market {
             .ok_or_else(|| error!(CoreError::Unimplemented))
     }
 
+    /// Get the fee related parameters of the market config.
+    pub fn fee_config(&self) -> MarketFeeConfig {
+        MarketFeeConfig::from(&self.config)
+    }
+
+    /// Get liquidation and ADL related config.
+    pub fn risk_config(&self) -> MarketRiskConfig {
+        MarketRiskConfig::from(&self.config)
+    }
+
     /// Get config flag.
     pub fn get_config_flag(&self, key: &str) -> Result<bool> {
         let key = MarketConfigFlag::from_str(key)
@@ -633,9 +687,12 @@ pub struct OtherState {
     long_token_balance: u64,
     short_token_balance: u64,
     funding_factor_per_second: i128,
+    open_positions: u64,
+    long_token_insurance_fund: u64,
+    short_token_insurance_fund: u64,
     #[cfg_attr(feature = "debug", debug(skip))]
     #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
-    reserved: [u8; 256],
+    reserved: [u8; 232],
 }
 
 impl OtherState {
@@ -654,6 +711,55 @@ impl OtherState {
         self.funding_factor_per_second
     }
 
+    /// Get the number of open positions.
+    pub fn open_positions(&self) -> u64 {
+        self.open_positions
+    }
+
+    /// Record a newly opened position.
+    ///
+    /// # Errors
+    /// - The number of open positions would exceed `max_open_positions` (if non-zero).
+    pub(crate) fn record_position_opened(&mut self, max_open_positions: u128) -> Result<()> {
+        let next = self
+            .open_positions
+            .checked_add(1)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        if max_open_positions != 0 {
+            require_gte!(
+                max_open_positions,
+                u128::from(next),
+                CoreError::MaxOpenPositionsExceeded
+            );
+        }
+        self.open_positions = next;
+        Ok(())
+    }
+
+    /// Record a fully closed position that was counted when opened.
+    ///
+    /// This never fails, so that the counter cannot block closes or liquidations.
+    pub(crate) fn record_position_closed(&mut self) {
+        self.open_positions = self.open_positions.saturating_sub(1);
+    }
+
+    /// Get the insurance fund amount of the given side.
+    pub fn insurance_fund(&self, is_long_token: bool) -> u64 {
+        if is_long_token {
+            self.long_token_insurance_fund
+        } else {
+            self.short_token_insurance_fund
+        }
+    }
+
+    pub(crate) fn insurance_fund_mut(&mut self, is_long_token: bool) -> &mut u64 {
+        if is_long_token {
+            &mut self.long_token_insurance_fund
+        } else {
+            &mut self.short_token_insurance_fund
+        }
+    }
+
     /// Get current trade count.
     pub fn trade_count(&self) -> u64 {
         self.trade_count
@@ -751,12 +857,21 @@ pub struct Indexer {
     glv_withdrawal_count: u64,
     #[cfg_attr(feature = "debug", debug(skip))]
     padding_0: [u8; 8],
+    market_order_seq: u64,
+    finished_market_order_seq: u64,
+    finished_market_order_bitmap: [u64; 4],
     #[cfg_attr(feature = "debug", debug(skip))]
     #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
-    reserved: [u8; 128],
+    reserved: [u8; 80],
 }
 
 impl Indexer {
+    /// Max number of unfinished market orders queued for fair execution.
+    ///
+    /// The cap is shared by all users of the market, so a single user can block the creation
+    /// of market orders in the market by keeping this many market orders unfinished.
+    pub const MAX_QUEUED_MARKET_ORDERS: u64 = 256;
+
     /// Get current deposit count.
     pub fn deposit_count(&self) -> u64 {
         self.deposit_count
@@ -787,6 +902,76 @@ impl Indexer {
         self.glv_withdrawal_count
     }
 
+    /// Get the sequence of the last market order queued for fair execution.
+    pub fn market_order_seq(&self) -> u64 {
+        self.market_order_seq
+    }
+
+    /// Get the sequence up to which all the queued market orders are finished.
+    pub fn finished_market_order_seq(&self) -> u64 {
+        self.finished_market_order_seq
+    }
+
+    /// Returns whether the queued market order with the given sequence is finished.
+    pub fn is_market_order_finished(&self, seq: u64) -> bool {
+        if seq <= self.finished_market_order_seq {
+            return true;
+        }
+        let offset = seq - self.finished_market_order_seq - 1;
+        offset < Self::MAX_QUEUED_MARKET_ORDERS
+            && self.finished_market_order_bitmap[(offset / 64) as usize] & (1 << (offset % 64)) != 0
+    }
+
+    /// Queue a new market order for fair execution and return its sequence.
+    ///
+    /// # Errors
+    /// Returns error if there are too many unfinished queued market orders.
+    pub(crate) fn next_market_order_seq(&mut self) -> Result<u64> {
+        let next_seq = self
+            .market_order_seq
+            .checked_add(1)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        require_gte!(
+            self.finished_market_order_seq
+                .saturating_add(Self::MAX_QUEUED_MARKET_ORDERS),
+            next_seq,
+            CoreError::TooManyQueuedMarketOrders
+        );
+        self.market_order_seq = next_seq;
+        Ok(next_seq)
+    }
+
+    /// Validate that the queued market order with the given sequence is the
+    /// earliest unfinished one.
+    pub(crate) fn validate_market_order_seq(&self, seq: u64) -> CoreResult<()> {
+        if self.finished_market_order_seq.checked_add(1) != Some(seq) {
+            return Err(CoreError::MarketOrderExecutedOutOfOrder);
+        }
+        Ok(())
+    }
+
+    /// Mark the queued market order with the given sequence as finished.
+    pub(crate) fn finish_market_order_seq(&mut self, seq: u64) -> Result<()> {
+        require!(
+            seq > self.finished_market_order_seq && seq <= self.market_order_seq,
+            CoreError::Internal
+        );
+        let offset = seq - self.finished_market_order_seq - 1;
+        require_gt!(Self::MAX_QUEUED_MARKET_ORDERS, offset, CoreError::Internal);
+        self.finished_market_order_bitmap[(offset / 64) as usize] |= 1 << (offset % 64);
+
+        // Advance past the finished market orders at the front of the queue.
+        while self.finished_market_order_bitmap[0] & 1 != 0 {
+            let bitmap = &mut self.finished_market_order_bitmap;
+            for idx in 0..bitmap.len() {
+                let carry = bitmap.get(idx + 1).map(|next| next & 1).unwrap_or(0);
+                bitmap[idx] = (bitmap[idx] >> 1) | (carry << 63);
+            }
+            self.finished_market_order_seq += 1;
+        }
+        Ok(())
+    }
+
     /// Next deposit id.
     pub fn next_deposit_id(&mut self) -> Result<u64> {
         let next_id = self
@@ -862,6 +1047,72 @@ mod tests {
     use super::*;
     use crate::events::{EventClocks, EventOtherState};
 
+    #[test]
+    fn queue_market_orders_for_fair_execution() {
+        use bytemuck::Zeroable;
+
+        let mut indexer = Indexer::zeroed();
+        for expected in 1..=3 {
+            assert_eq!(indexer.next_market_order_seq().unwrap(), expected);
+        }
+
+        // Only the earliest unfinished market order can be executed.
+        assert!(indexer.validate_market_order_seq(1).is_ok());
+        assert!(indexer.validate_market_order_seq(2).is_err());
+
+        // Finishing a later market order (e.g. cancelled) does not advance the queue.
+        indexer.finish_market_order_seq(2).unwrap();
+        assert_eq!(indexer.finished_market_order_seq(), 0);
+        assert!(indexer.is_market_order_finished(2));
+        assert!(!indexer.is_market_order_finished(3));
+        assert!(indexer.finish_market_order_seq(2).is_ok());
+
+        indexer.finish_market_order_seq(1).unwrap();
+        assert_eq!(indexer.finished_market_order_seq(), 2);
+        assert!(indexer.validate_market_order_seq(3).is_ok());
+        assert!(indexer.finish_market_order_seq(2).is_err());
+        indexer.finish_market_order_seq(3).unwrap();
+        assert_eq!(indexer.finished_market_order_seq(), 3);
+
+        // The number of unfinished queued market orders is bounded.
+        for _ in 0..Indexer::MAX_QUEUED_MARKET_ORDERS {
+            indexer.next_market_order_seq().unwrap();
+        }
+        assert!(indexer.next_market_order_seq().is_err());
+        indexer.finish_market_order_seq(4 + 64).unwrap();
+        indexer.finish_market_order_seq(4).unwrap();
+        assert_eq!(indexer.finished_market_order_seq(), 4);
+        assert!(indexer.is_market_order_finished(4 + 64));
+        assert!(indexer.next_market_order_seq().is_ok());
+    }
+
+    #[test]
+    fn count_open_positions() {
+        use bytemuck::Zeroable;
+
+        let mut other = OtherState::zeroed();
+        other.record_position_opened(2).unwrap();
+        other.record_position_opened(2).unwrap();
+        assert!(other.record_position_opened(2).is_err());
+        assert_eq!(other.open_positions(), 2);
+
+        // Zero means unlimited.
+        other.record_position_opened(0).unwrap();
+        assert_eq!(other.open_positions(), 3);
+
+        // Recording closes never fails, even if the counter is already zero.
+        for _ in 0..4 {
+            other.record_position_closed();
+        }
+        assert_eq!(other.open_positions(), 0);
+
+        let mut position = crate::states::Position::default();
+        assert!(!position.is_open_recorded());
+        assert!(!position.set_open_recorded(true));
+        assert!(position.is_open_recorded());
+        assert!(position.set_open_recorded(false));
+    }
+
     #[test]
     fn test_event_clocks() {
         let clocks = Clocks {
@@ -908,7 +1159,10 @@ mod tests {
             long_token_balance: u64::MAX,
             short_token_balance: u64::MAX,
             funding_factor_per_second: i128::MAX,
-            reserved: [0; 256],
+            open_positions: u64::MAX,
+            long_token_insurance_fund: u64::MAX,
+            short_token_insurance_fund: u64::MAX,
+            reserved: [0; 232],
         };
 
         let event_clocks = EventOtherState {
@@ -918,6 +1172,9 @@ mod tests {
             long_token_balance: clocks.long_token_balance,
             short_token_balance: clocks.short_token_balance,
             funding_factor_per_second: clocks.funding_factor_per_second,
+            open_positions: clocks.open_positions,
+            long_token_insurance_fund: clocks.long_token_insurance_fund,
+            short_token_insurance_fund: clocks.short_token_insurance_fund,
             reserved: clocks.reserved,
         };
 
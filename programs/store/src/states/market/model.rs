@@ -1,4 +1,4 @@
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
 
 use anchor_spl::token::Mint;
 use gmsol_model::{
@@ -150,6 +150,10 @@ impl gmsol_model::BaseMarket<{ constants::MARKET_DECIMALS }> for Market {
             .config
             .flag(MarketConfigFlag::IgnoreOpenInterestForUsageFactor))
     }
+
+    fn enforce_reserves(&self) -> gmsol_model::Result<bool> {
+        Ok(self.config.flag(MarketConfigFlag::EnforceReserves))
+    }
 }
 
 impl gmsol_model::SwapMarket<{ constants::MARKET_DECIMALS }> for Market {
@@ -305,6 +309,16 @@ impl gmsol_model::PerpMarket<{ constants::MARKET_DECIMALS }> for Market {
             self.config.max_positive_position_impact_factor,
             self.config.max_negative_position_impact_factor,
             self.config.max_position_impact_factor_for_liquidations,
+        )
+        .with_max_position_impact_factor_for_liquidation_execution(
+            (self
+                .config
+                .max_position_impact_factor_for_liquidation_execution
+                != 0)
+                .then_some(
+                    self.config
+                        .max_position_impact_factor_for_liquidation_execution,
+                ),
         ))
     }
 
@@ -450,6 +464,10 @@ where
     fn ignore_open_interest_for_usage_factor(&self) -> gmsol_model::Result<bool> {
         self.market.ignore_open_interest_for_usage_factor()
     }
+
+    fn enforce_reserves(&self) -> gmsol_model::Result<bool> {
+        self.market.enforce_reserves()
+    }
 }
 
 impl<M> gmsol_model::PositionImpactMarket<{ constants::MARKET_DECIMALS }>
@@ -540,3 +558,234 @@ where
             .max_pool_value_for_deposit(is_long_token)
     }
 }
+
+/// A liquidity market for simulation.
+///
+/// The pools that may be changed by deposits and withdrawals are copied from the market,
+/// so the changes will never be written back.
+pub struct SimulatedLiquidityMarket<'a> {
+    market: &'a Market,
+    liquidity_pool: Pool,
+    claimable_fee_pool: Pool,
+    swap_impact_pool: Pool,
+    supply: u128,
+}
+
+impl<'a> SimulatedLiquidityMarket<'a> {
+    /// Create a new [`SimulatedLiquidityMarket`].
+    pub fn try_new(market: &'a Market, market_token: &Mint) -> gmsol_model::Result<Self> {
+        Ok(Self {
+            market,
+            liquidity_pool: *market.try_pool(PoolKind::Primary)?,
+            claimable_fee_pool: *market.try_pool(PoolKind::ClaimableFee)?,
+            swap_impact_pool: *market.try_pool(PoolKind::SwapImpact)?,
+            supply: market_token.supply.into(),
+        })
+    }
+}
+
+impl HasMarketMeta for SimulatedLiquidityMarket<'_> {
+    fn is_pure(&self) -> bool {
+        self.market.is_pure()
+    }
+
+    fn market_meta(&self) -> &super::MarketMeta {
+        self.market.market_meta()
+    }
+}
+
+impl gmsol_model::BaseMarket<{ constants::MARKET_DECIMALS }> for SimulatedLiquidityMarket<'_> {
+    type Num = u128;
+
+    type Signed = i128;
+
+    type Pool = Pool;
+
+    fn liquidity_pool(&self) -> gmsol_model::Result<&Self::Pool> {
+        Ok(&self.liquidity_pool)
+    }
+
+    fn claimable_fee_pool(&self) -> gmsol_model::Result<&Self::Pool> {
+        Ok(&self.claimable_fee_pool)
+    }
+
+    fn swap_impact_pool(&self) -> gmsol_model::Result<&Self::Pool> {
+        Ok(&self.swap_impact_pool)
+    }
+
+    fn open_interest_pool(&self, is_long: bool) -> gmsol_model::Result<&Self::Pool> {
+        self.market.open_interest_pool(is_long)
+    }
+
+    fn open_interest_in_tokens_pool(&self, is_long: bool) -> gmsol_model::Result<&Self::Pool> {
+        self.market.open_interest_in_tokens_pool(is_long)
+    }
+
+    fn collateral_sum_pool(&self, is_long: bool) -> gmsol_model::Result<&Self::Pool> {
+        self.market.collateral_sum_pool(is_long)
+    }
+
+    fn virtual_inventory_for_swaps_pool(
+        &self,
+    ) -> gmsol_model::Result<Option<impl Deref<Target = Self::Pool>>> {
+        self.market.virtual_inventory_for_swaps_pool()
+    }
+
+    fn virtual_inventory_for_positions_pool(
+        &self,
+    ) -> gmsol_model::Result<Option<impl Deref<Target = Self::Pool>>> {
+        self.market.virtual_inventory_for_positions_pool()
+    }
+
+    fn usd_to_amount_divisor(&self) -> Self::Num {
+        self.market.usd_to_amount_divisor()
+    }
+
+    fn max_pool_amount(&self, is_long_token: bool) -> gmsol_model::Result<Self::Num> {
+        self.market.max_pool_amount(is_long_token)
+    }
+
+    fn pnl_factor_config(
+        &self,
+        kind: gmsol_model::PnlFactorKind,
+        is_long: bool,
+    ) -> gmsol_model::Result<Self::Num> {
+        self.market.pnl_factor_config(kind, is_long)
+    }
+
+    fn reserve_factor(&self) -> gmsol_model::Result<Self::Num> {
+        self.market.reserve_factor()
+    }
+
+    fn open_interest_reserve_factor(&self) -> gmsol_model::Result<Self::Num> {
+        self.market.open_interest_reserve_factor()
+    }
+
+    fn max_open_interest(&self, is_long: bool) -> gmsol_model::Result<Self::Num> {
+        self.market.max_open_interest(is_long)
+    }
+
+    fn ignore_open_interest_for_usage_factor(&self) -> gmsol_model::Result<bool> {
+        self.market.ignore_open_interest_for_usage_factor()
+    }
+
+    fn enforce_reserves(&self) -> gmsol_model::Result<bool> {
+        self.market.enforce_reserves()
+    }
+}
+
+impl gmsol_model::BaseMarketMut<{ constants::MARKET_DECIMALS }> for SimulatedLiquidityMarket<'_> {
+    fn liquidity_pool_mut(&mut self) -> gmsol_model::Result<&mut Self::Pool> {
+        Ok(&mut self.liquidity_pool)
+    }
+
+    fn claimable_fee_pool_mut(&mut self) -> gmsol_model::Result<&mut Self::Pool> {
+        Ok(&mut self.claimable_fee_pool)
+    }
+
+    fn virtual_inventory_for_swaps_pool_mut(
+        &mut self,
+    ) -> gmsol_model::Result<Option<impl DerefMut<Target = Self::Pool>>> {
+        match self.market.virtual_inventory_for_swaps() {
+            Some(_) => Err(gmsol_model::Error::InvalidArgument(
+                "virtual inventory for the swaps feature is not enabled in simulation",
+            )),
+            None => Ok(None::<&mut Self::Pool>),
+        }
+    }
+}
+
+impl gmsol_model::SwapMarket<{ constants::MARKET_DECIMALS }> for SimulatedLiquidityMarket<'_> {
+    fn swap_impact_params(&self) -> gmsol_model::Result<PriceImpactParams<Self::Num>> {
+        self.market.swap_impact_params()
+    }
+
+    fn swap_fee_params(&self) -> gmsol_model::Result<FeeParams<Self::Num>> {
+        self.market.swap_fee_params()
+    }
+}
+
+impl gmsol_model::SwapMarketMut<{ constants::MARKET_DECIMALS }> for SimulatedLiquidityMarket<'_> {
+    fn swap_impact_pool_mut(&mut self) -> gmsol_model::Result<&mut Self::Pool> {
+        Ok(&mut self.swap_impact_pool)
+    }
+}
+
+impl gmsol_model::PositionImpactMarket<{ constants::MARKET_DECIMALS }>
+    for SimulatedLiquidityMarket<'_>
+{
+    fn position_impact_pool(&self) -> gmsol_model::Result<&Self::Pool> {
+        self.market.position_impact_pool()
+    }
+
+    fn position_impact_params(&self) -> gmsol_model::Result<PriceImpactParams<Self::Num>> {
+        self.market.position_impact_params()
+    }
+
+    fn position_impact_distribution_params(
+        &self,
+    ) -> gmsol_model::Result<PositionImpactDistributionParams<Self::Num>> {
+        self.market.position_impact_distribution_params()
+    }
+
+    fn passed_in_seconds_for_position_impact_distribution(&self) -> gmsol_model::Result<u64> {
+        self.market
+            .passed_in_seconds_for_position_impact_distribution()
+    }
+}
+
+impl gmsol_model::BorrowingFeeMarket<{ constants::MARKET_DECIMALS }>
+    for SimulatedLiquidityMarket<'_>
+{
+    fn borrowing_factor_pool(&self) -> gmsol_model::Result<&Self::Pool> {
+        self.market.borrowing_factor_pool()
+    }
+
+    fn total_borrowing_pool(&self) -> gmsol_model::Result<&Self::Pool> {
+        self.market.total_borrowing_pool()
+    }
+
+    fn borrowing_fee_params(&self) -> gmsol_model::Result<BorrowingFeeParams<Self::Num>> {
+        self.market.borrowing_fee_params()
+    }
+
+    fn passed_in_seconds_for_borrowing(&self) -> gmsol_model::Result<u64> {
+        self.market.passed_in_seconds_for_borrowing()
+    }
+
+    fn borrowing_fee_kink_model_params(
+        &self,
+    ) -> gmsol_model::Result<BorrowingFeeKinkModelParams<Self::Num>> {
+        self.market.borrowing_fee_kink_model_params()
+    }
+}
+
+impl gmsol_model::LiquidityMarket<{ constants::MARKET_DECIMALS }> for SimulatedLiquidityMarket<'_> {
+    fn total_supply(&self) -> Self::Num {
+        self.supply
+    }
+
+    fn max_pool_value_for_deposit(&self, is_long_token: bool) -> gmsol_model::Result<Self::Num> {
+        self.market.max_pool_value_for_deposit(is_long_token)
+    }
+}
+
+impl gmsol_model::LiquidityMarketMut<{ constants::MARKET_DECIMALS }>
+    for SimulatedLiquidityMarket<'_>
+{
+    fn mint(&mut self, amount: &Self::Num) -> gmsol_model::Result<()> {
+        self.supply = self
+            .supply
+            .checked_add(*amount)
+            .ok_or(gmsol_model::Error::Overflow)?;
+        Ok(())
+    }
+
+    fn burn(&mut self, amount: &Self::Num) -> gmsol_model::Result<()> {
+        self.supply = self
+            .supply
+            .checked_sub(*amount)
+            .ok_or(gmsol_model::Error::Computation("burn: not enough supply"))?;
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use gmsol_model::params::{position::PositionImpactDistributionParams, PriceImpac
 
 use crate::{
     constants,
-    states::{HasMarketMeta, Store},
+    states::{HasMarketMeta, Market, Store},
     utils::internal::TransferUtils,
 };
 
@@ -88,6 +88,12 @@ impl Key for RevertibleLiquidityMarket<'_, '_> {
     }
 }
 
+impl AsRef<Market> for RevertibleLiquidityMarket<'_, '_> {
+    fn as_ref(&self) -> &Market {
+        self.base.as_ref()
+    }
+}
+
 impl Revision for RevertibleLiquidityMarket<'_, '_> {
     fn rev(&self) -> u64 {
         self.base().rev()
@@ -227,6 +233,10 @@ impl<'a, 'info> gmsol_model::BaseMarket<{ constants::MARKET_DECIMALS }>
     fn ignore_open_interest_for_usage_factor(&self) -> gmsol_model::Result<bool> {
         self.base.ignore_open_interest_for_usage_factor()
     }
+
+    fn enforce_reserves(&self) -> gmsol_model::Result<bool> {
+        self.base.enforce_reserves()
+    }
 }
 
 impl gmsol_model::BaseMarketMut<{ constants::MARKET_DECIMALS }>
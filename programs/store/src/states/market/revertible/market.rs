@@ -16,10 +16,11 @@ use gmsol_model::{
 
 use crate::{
     constants, debug_msg,
-    events::{EventEmitter, InsufficientFundingFeePayment},
+    events::{EventEmitter, InsufficientFundingFeePayment, InsuranceFundDrawn},
     states::{
         market::{
             clock::{AsClock, AsClockMut},
+            config::{MarketConfigFlag, MarketConfigKey},
             Clocks, Pool,
         },
         Factor, HasMarketMeta, Market, MarketMeta, OtherState,
@@ -33,7 +34,7 @@ use super::{
 };
 
 /// Swap Pricing Kind.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum SwapPricingKind {
     /// Swap.
     Swap,
@@ -154,6 +155,40 @@ impl<'a, 'info> RevertibleMarket<'a, 'info> {
         buffer.other_mut(state)
     }
 
+    /// Record a newly opened position.
+    ///
+    /// # Errors
+    /// - The number of open positions would exceed the max open positions of the market.
+    pub(crate) fn record_position_opened(&mut self) -> Result<()> {
+        let max_open_positions = *self
+            .market
+            .get_config_by_key(MarketConfigKey::MaxOpenPositions)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?;
+        self.other_mut().record_position_opened(max_open_positions)
+    }
+
+    /// Record a fully closed position that was counted when opened.
+    ///
+    /// This never fails, so that the counter cannot block closes or liquidations.
+    pub(crate) fn record_position_closed(&mut self) {
+        self.other_mut().record_position_closed();
+    }
+
+    /// Record a deposit into the insurance fund of the given side.
+    ///
+    /// The deposited tokens must have been recorded as transferred in.
+    pub(crate) fn record_insurance_fund_deposit(
+        &mut self,
+        is_long_token: bool,
+        amount: u64,
+    ) -> Result<()> {
+        let fund = self.other_mut().insurance_fund_mut(is_long_token);
+        *fund = fund
+            .checked_add(amount)
+            .ok_or_else(|| error!(CoreError::TokenAmountOverflow))?;
+        Ok(())
+    }
+
     fn clocks(&self) -> &Clocks {
         let Market { state, buffer, .. } = &*self.market;
         buffer.clocks(state)
@@ -427,6 +462,10 @@ impl gmsol_model::BaseMarket<{ constants::MARKET_DECIMALS }> for RevertibleMarke
     fn ignore_open_interest_for_usage_factor(&self) -> gmsol_model::Result<bool> {
         self.market.ignore_open_interest_for_usage_factor()
     }
+
+    fn enforce_reserves(&self) -> gmsol_model::Result<bool> {
+        self.market.enforce_reserves()
+    }
 }
 
 impl gmsol_model::BaseMarketMut<{ constants::MARKET_DECIMALS }> for RevertibleMarket<'_, '_> {
@@ -707,4 +746,43 @@ impl gmsol_model::PerpMarketMut<{ constants::MARKET_DECIMALS }> for RevertibleMa
             gmsol_model::Error::InvalidArgument("emitting insufficient funding fee payment event")
         })
     }
+
+    fn on_insufficient_pnl_payment(
+        &mut self,
+        unpaid_amount: &Self::Num,
+        is_collateral_token_long: bool,
+    ) -> gmsol_model::Result<Self::Num> {
+        if !self
+            .market
+            .get_config_flag_by_key(MarketConfigFlag::EnableInsuranceFund)
+            || *unpaid_amount == 0
+        {
+            return Ok(0);
+        }
+
+        // The unpaid pnl not covered by the fund is left to the insolvent close.
+        let fund = self
+            .other_mut()
+            .insurance_fund_mut(is_collateral_token_long);
+        if *fund == 0 {
+            return Ok(0);
+        }
+        let drawn_amount = (*fund).min((*unpaid_amount).try_into().unwrap_or(u64::MAX));
+        *fund -= drawn_amount;
+        let remaining_amount = *fund;
+
+        let event = InsuranceFundDrawn::new(
+            &self.market.store,
+            &self.market_meta().market_token_mint,
+            *unpaid_amount,
+            drawn_amount,
+            remaining_amount,
+            is_collateral_token_long,
+        )?;
+        self.event_emitter().emit_cpi(&event).map_err(|_err| {
+            gmsol_model::Error::InvalidArgument("emitting insurance fund drawn event")
+        })?;
+
+        Ok(drawn_amount.into())
+    }
 }
@@ -1,13 +1,19 @@
 use std::cell::RefMut;
 
 use anchor_lang::prelude::*;
-use gmsol_model::{action::decrease_position::DecreasePositionSwapType, num::Unsigned};
+use gmsol_model::{
+    action::decrease_position::DecreasePositionSwapType, num::Unsigned, price::Prices,
+};
 
 use crate::{
     constants,
     events::{EventEmitter, SwapExecuted, TradeData},
-    states::{market::revertible::Revision, position::PositionState, HasMarketMeta, Position},
-    CoreError,
+    states::{
+        market::{config::MarketConfigKey, revertible::Revision},
+        position::PositionState,
+        HasMarketMeta, Position,
+    },
+    CoreError, ModelError,
 };
 
 use super::{market::RevertibleMarket, Revertible};
@@ -17,6 +23,8 @@ pub struct RevertiblePosition<'a, 'info> {
     market: RevertibleMarket<'a, 'info>,
     storage: RefMut<'a, Position>,
     state: PositionState,
+    cost_basis_value: u128,
+    is_open_recorded: bool,
     is_collateral_token_long: bool,
     is_long: bool,
 }
@@ -44,6 +52,8 @@ impl<'a, 'info> RevertiblePosition<'a, 'info> {
             is_long,
             is_collateral_token_long,
             state: storage.state,
+            cost_basis_value: storage.cost_basis_value,
+            is_open_recorded: storage.is_open_recorded(),
             market,
             storage,
         })
@@ -60,12 +70,125 @@ impl<'a, 'info> RevertiblePosition<'a, 'info> {
     pub(crate) fn event_emitter(&self) -> &EventEmitter<'a, 'info> {
         self.market.event_emitter()
     }
+
+    /// Validate that the increase cooldown of the market has passed since the last increase.
+    pub(crate) fn validate_increase_cooldown(&self) -> Result<()> {
+        let cooldown = *self
+            .market
+            .market
+            .get_config_by_key(MarketConfigKey::IncreaseCooldownSeconds)
+            .ok_or_else(|| error!(CoreError::Unimplemented))?;
+
+        // Skip the check if the cooldown is zero or the position is empty.
+        if cooldown == 0 || self.state.size_in_usd == 0 {
+            return Ok(());
+        }
+
+        let cooldown = i64::try_from(cooldown).map_err(|_| error!(CoreError::ValueOverflow))?;
+        let ends_at = self
+            .state
+            .increased_at
+            .checked_add(cooldown)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        let current = Clock::get()?.unix_timestamp;
+        if current < ends_at {
+            msg!("[Position] increase cooldown ends at {}", ends_at);
+            return err!(CoreError::PositionIncreaseInCooldown);
+        }
+        Ok(())
+    }
+}
+
+impl RevertiblePosition<'_, '_> {
+    /// Returns whether the position is liquidatable with the given prices.
+    ///
+    /// An empty position is never liquidatable.
+    pub(crate) fn is_liquidatable(&self, prices: &Prices<u128>) -> Result<bool> {
+        use gmsol_model::PositionExt;
+
+        if self.state.size_in_usd == 0 {
+            return Ok(false);
+        }
+
+        let reason = self
+            .check_liquidatable(prices, true)
+            .map_err(ModelError::from)?;
+        Ok(reason.is_some())
+    }
+
+    /// Record the opening of this position in the market if it is empty.
+    pub(crate) fn record_opened_if_empty(&mut self) -> Result<()> {
+        if self.state.size_in_usd == 0 && !self.is_open_recorded {
+            self.market.record_position_opened()?;
+            self.is_open_recorded = true;
+        }
+        Ok(())
+    }
+
+    /// Record the closing of this position in the market if it has become empty.
+    ///
+    /// Only the positions counted when opened are uncounted, so positions opened before the
+    /// counter was introduced can always be closed.
+    pub(crate) fn record_closed_if_empty(&mut self) {
+        if self.state.size_in_usd == 0 && self.is_open_recorded {
+            self.market.record_position_closed();
+            self.is_open_recorded = false;
+        }
+    }
+
+    /// Add the given value to the cost basis of this position.
+    pub(crate) fn add_cost_basis_value(&mut self, value: u128) -> Result<()> {
+        self.cost_basis_value = self
+            .cost_basis_value
+            .checked_add(value)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        Ok(())
+    }
+
+    /// Pay the execution fee of the given amount from the collateral of this position.
+    ///
+    /// The fee amount is removed from the collateral and the collateral sum pool,
+    /// and is expected to be transferred out of the market vault to the executor.
+    pub(crate) fn pay_execution_fee_from_collateral(&mut self, amount: u64) -> Result<()> {
+        use gmsol_model::{PerpMarketMut, PoolExt};
+
+        let amount = u128::from(amount);
+        self.state.collateral_amount = self
+            .state
+            .collateral_amount
+            .checked_sub(amount)
+            .ok_or_else(|| error!(CoreError::NotEnoughExecutionFee))?;
+
+        let delta = amount.to_signed().map_err(ModelError::from)?;
+        let is_long = self.is_long;
+        let is_collateral_token_long = self.is_collateral_token_long;
+        self.market
+            .collateral_sum_pool_mut(is_long)
+            .and_then(|pool| pool.apply_delta_amount(is_collateral_token_long, &-delta))
+            .map_err(ModelError::from)?;
+
+        Ok(())
+    }
 }
 
 impl Revertible for RevertiblePosition<'_, '_> {
     fn commit(mut self) {
+        use gmsol_model::num::MulDiv;
+
+        // The cost basis is reduced proportionally when the position is decreased.
+        let prev_size = self.storage.state.size_in_usd;
+        let next_size = self.state.size_in_usd;
+        if next_size < prev_size {
+            self.cost_basis_value = self
+                .cost_basis_value
+                .checked_mul_div(&next_size, &prev_size)
+                .unwrap_or(0);
+        }
+
         self.market.commit();
         self.storage.state = self.state;
+        self.storage.cost_basis_value = self.cost_basis_value;
+        self.storage.set_open_recorded(self.is_open_recorded);
     }
 }
 
@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
-use gmsol_model::{Bank, BorrowingFeeMarketMutExt, MarketAction, SwapMarketMutExt};
+use gmsol_model::{
+    action::swap::SwapReport, utils::apply_factor, Bank, BorrowingFeeMarketMutExt, MarketAction,
+    SwapMarketMutExt,
+};
 use indexmap::{map::Entry, IndexMap};
 
 use crate::{
     constants,
     events::{BorrowingFeesUpdated, EventEmitter, SwapExecuted},
     states::{
-        common::swap::SwapActionParams, market::utils::ValidateMarketBalances, HasMarketMeta,
-        Market, Oracle,
+        common::swap::SwapActionParams,
+        market::{config::MarketConfigKey, utils::ValidateMarketBalances},
+        HasMarketMeta, Market, Oracle,
     },
     CoreError, ModelError,
 };
@@ -76,6 +80,7 @@ impl<'a, 'info> SwapMarkets<'a, 'info> {
         M: Key
             + Revision
             + HasMarketMeta
+            + AsRef<Market>
             + gmsol_model::Bank<Pubkey, Num = u64>
             + gmsol_model::SwapMarketMut<{ constants::MARKET_DECIMALS }, Num = u128>
             + gmsol_model::BorrowingFeeMarketMut<{ constants::MARKET_DECIMALS }>,
@@ -240,6 +245,7 @@ impl<'a, 'info> SwapMarkets<'a, 'info> {
                 .map_err(ModelError::from)?
                 .execute()
                 .map_err(ModelError::from)?;
+            validate_max_swap_impact(market.as_ref(), &report)?;
             *token_in = *market
                 .market_meta()
                 .opposite_token(token_in)
@@ -283,7 +289,8 @@ impl<'a, 'info> SwapMarkets<'a, 'info> {
             + Revision
             + gmsol_model::Bank<Pubkey, Num = u64>
             + gmsol_model::SwapMarketMut<{ constants::MARKET_DECIMALS }, Num = u128>
-            + HasMarketMeta,
+            + HasMarketMeta
+            + AsRef<Market>,
     {
         require!(
             self.get_mut(&direction.current()).is_none(),
@@ -462,6 +469,7 @@ where
     M: Key
         + Revision
         + HasMarketMeta
+        + AsRef<Market>
         + gmsol_model::SwapMarketMut<{ constants::MARKET_DECIMALS }, Num = u128>,
 {
     fn swap_with_current(
@@ -484,6 +492,7 @@ where
             .map_err(ModelError::from)?
             .execute()
             .map_err(ModelError::from)?;
+        validate_max_swap_impact((**current).as_ref(), &report)?;
         *token_in_amount = (*report.token_out_amount())
             .try_into()
             .map_err(|_| error!(CoreError::TokenAmountOverflow))?;
@@ -496,3 +505,39 @@ where
         Ok(())
     }
 }
+
+/// Validate that the negative price impact of the swap does not exceed
+/// the max swap impact factor of the market.
+fn validate_max_swap_impact(market: &Market, report: &SwapReport<u128, i128>) -> Result<()> {
+    let max_factor = *market
+        .get_config_by_key(MarketConfigKey::MaxSwapImpactFactor)
+        .ok_or_else(|| error!(CoreError::Unimplemented))?;
+    let price_impact = *report.price_impact();
+    if max_factor == 0 || !price_impact.is_negative() {
+        return Ok(());
+    }
+
+    let params = report.params();
+    let token_in_price = if params.is_token_in_long() {
+        params.long_token_price()
+    } else {
+        params.short_token_price()
+    };
+    let token_in_value = params
+        .token_in_amount()
+        .checked_mul(*token_in_price.pick_price(false))
+        .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+    let max_impact_value =
+        apply_factor::<_, { constants::MARKET_DECIMALS }>(&token_in_value, &max_factor)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+
+    if price_impact.unsigned_abs() > max_impact_value {
+        msg!(
+            "[Swap] price impact {} exceeds the max {} for this hop",
+            price_impact,
+            max_impact_value
+        );
+        return err!(CoreError::MaxSwapImpactExceeded);
+    }
+    Ok(())
+}
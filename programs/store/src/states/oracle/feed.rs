@@ -27,7 +27,8 @@ pub struct PriceFeed {
     last_published_at_slot: u64,
     last_published_at: i64,
     price: PriceFeedPrice,
-    reserved: [u8; 256],
+    max_report_age: u32,
+    reserved: [u8; 252],
 }
 
 impl InitSpace for PriceFeed {
@@ -83,6 +84,13 @@ impl PriceFeed {
         );
 
         require_gte!(price.ts(), self.price.ts(), CoreError::InvalidArgument);
+        if self.max_report_age != 0 {
+            let expires_at = price
+                .ts()
+                .checked_add(i64::from(self.max_report_age))
+                .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+            require_gte!(expires_at, current_ts, CoreError::PriceReportTooOld);
+        }
         require_gte!(
             current_ts.saturating_add_unsigned(max_future_excess),
             price.ts(),
@@ -132,17 +140,25 @@ impl PriceFeed {
         &self.feed_id
     }
 
+    /// Get the max age of price reports in seconds.
+    ///
+    /// `0` means the age of price reports is not checked.
+    pub fn max_report_age(&self) -> u32 {
+        self.max_report_age
+    }
+
+    pub(crate) fn set_max_report_age(&mut self, max_report_age: u32) {
+        self.max_report_age = max_report_age;
+    }
+
     pub(crate) fn check_and_get_price(
         &self,
         clock: &Clock,
         token_config: &TokenConfig,
     ) -> Result<OraclePriceParts> {
+        // The provider is validated by the caller, since the feed can be supplied
+        // by either the expected provider or the fallback provider of the token.
         let provider = self.provider()?;
-        require_eq!(
-            token_config.expected_provider().map_err(CoreError::from)?,
-            provider
-        );
-
         let feed_id = token_config.get_feed(&provider).map_err(CoreError::from)?;
         require_keys_eq!(self.feed_id, feed_id, CoreError::InvalidPriceFeedAccount);
 
@@ -181,3 +197,239 @@ impl PriceFeed {
             .map_err(|_| error!(CoreError::InvalidPriceFeedPrice))
     }
 }
+
+/// Counter of the custom price feeds of a token.
+#[account(zero_copy)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct PriceFeedCounter {
+    pub(crate) bump: u8,
+    padding_0: [u8; 7],
+    pub(crate) store: Pubkey,
+    pub(crate) token: Pubkey,
+    count: u64,
+    reserved: [u8; 64],
+}
+
+impl InitSpace for PriceFeedCounter {
+    const INIT_SPACE: usize = std::mem::size_of::<Self>();
+}
+
+impl Seed for PriceFeedCounter {
+    const SEED: &'static [u8] = b"price_feed_counter";
+}
+
+impl Default for PriceFeedCounter {
+    fn default() -> Self {
+        Zeroable::zeroed()
+    }
+}
+
+impl PriceFeedCounter {
+    pub(crate) fn init(&mut self, bump: u8, store: &Pubkey, token: &Pubkey) {
+        self.bump = bump;
+        self.store = *store;
+        self.token = *token;
+    }
+
+    /// Get the number of custom price feeds initialized for the token.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Record a newly initialized custom price feed of the token.
+    ///
+    /// # Errors
+    /// Returns error if `max_feeds` is not zero and the number of feeds would exceed it.
+    pub(crate) fn record_new_feed(&mut self, max_feeds: u64) -> Result<()> {
+        let next_count = self
+            .count
+            .checked_add(1)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        if max_feeds != 0 {
+            require_gte!(
+                max_feeds,
+                next_count,
+                CoreError::MaxCustomPriceFeedsExceeded
+            );
+        }
+        self.count = next_count;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::Discriminator;
+    use gmsol_utils::{price::PriceFlag, token_config::FeedConfig};
+
+    use super::*;
+    use crate::states::oracle::OraclePrice;
+
+    const HEARTBEAT_DURATION: u32 = 60;
+    const NOW: i64 = 1_000;
+
+    const DATA_LEN: usize = 8 + std::mem::size_of::<PriceFeed>();
+
+    fn feed_data(provider: PriceProviderKind, feed_id: &Pubkey, ts: i64) -> Vec<u128> {
+        let mut feed = PriceFeed::default();
+        feed.init(
+            0,
+            0,
+            provider,
+            &Pubkey::default(),
+            &Pubkey::default(),
+            &Pubkey::default(),
+            feed_id,
+        )
+        .unwrap();
+        let mut price = PriceFeedPrice::new(8, ts, 100_000_000, 99_000_000, 101_000_000, 0);
+        price.set_flag(PriceFlag::Open, true);
+        feed.price = price;
+        feed.last_published_at = ts;
+        // Offset the data so that the account is aligned after the discriminator.
+        let mut buffer = vec![0u128; DATA_LEN.div_ceil(16) + 1];
+        let data = account_data(&mut buffer);
+        data[..8].copy_from_slice(PriceFeed::DISCRIMINATOR);
+        data[8..].copy_from_slice(bytemuck::bytes_of(&feed));
+        buffer
+    }
+
+    fn account_data(buffer: &mut [u128]) -> &mut [u8] {
+        &mut bytemuck::cast_slice_mut(buffer)[8..(8 + DATA_LEN)]
+    }
+
+    fn token_config(expected_feed: &Pubkey, fallback_feed: &Pubkey) -> TokenConfig {
+        let mut config = TokenConfig::zeroed();
+        config.set_enabled(true);
+        config.token_decimals = 6;
+        config.precision = 4;
+        config.heartbeat_duration = HEARTBEAT_DURATION;
+        config
+            .set_feed_config(&PriceProviderKind::Pyth, FeedConfig::new(*expected_feed))
+            .unwrap();
+        config
+            .set_feed_config(
+                &PriceProviderKind::ChainlinkDataStreams,
+                FeedConfig::new(*fallback_feed),
+            )
+            .unwrap();
+        config.set_expected_provider(PriceProviderKind::Pyth);
+        config.set_fallback_provider(Some(PriceProviderKind::ChainlinkDataStreams));
+        config
+    }
+
+    #[test]
+    fn accept_custom_fallback_feed_only_if_expected_feed_is_stale() {
+        let expected_feed_id = Pubkey::new_unique();
+        let fallback_feed_id = Pubkey::new_unique();
+        let config = token_config(&expected_feed_id, &fallback_feed_id);
+        let clock = Clock {
+            unix_timestamp: NOW,
+            ..Default::default()
+        };
+
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = feed_data(
+            PriceProviderKind::ChainlinkDataStreams,
+            &fallback_feed_id,
+            NOW,
+        );
+        let fallback = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            account_data(&mut data),
+            &crate::ID,
+            false,
+            0,
+        );
+
+        let stale_ts = NOW - i64::from(HEARTBEAT_DURATION) - 1;
+        let stale_key = Pubkey::new_unique();
+        let mut stale_lamports = 0;
+        let mut stale_data = feed_data(PriceProviderKind::Pyth, &expected_feed_id, stale_ts);
+        let stale = AccountInfo::new(
+            &stale_key,
+            false,
+            false,
+            &mut stale_lamports,
+            account_data(&mut stale_data),
+            &crate::ID,
+            false,
+            0,
+        );
+
+        let fresh_key = Pubkey::new_unique();
+        let mut fresh_lamports = 0;
+        let mut fresh_data = feed_data(PriceProviderKind::Pyth, &expected_feed_id, NOW);
+        let fresh = AccountInfo::new(
+            &fresh_key,
+            false,
+            false,
+            &mut fresh_lamports,
+            account_data(&mut fresh_data),
+            &crate::ID,
+            false,
+            0,
+        );
+
+        // The feed of the expected provider must be provided.
+        assert!(
+            OraclePrice::parse_from_feed_account(&clock, &config, &fallback, &mut [].iter())
+                .is_err()
+        );
+
+        // The feed of the expected provider must be stale.
+        assert!(OraclePrice::parse_from_feed_account(
+            &clock,
+            &config,
+            &fallback,
+            &mut std::slice::from_ref(&fresh).iter()
+        )
+        .is_err());
+
+        // The feed of the expected provider cannot be replaced with the fallback feed.
+        assert!(OraclePrice::parse_from_feed_account(
+            &clock,
+            &config,
+            &fallback,
+            &mut std::slice::from_ref(&fallback).iter()
+        )
+        .is_err());
+
+        let price = OraclePrice::parse_from_feed_account(
+            &clock,
+            &config,
+            &fallback,
+            &mut std::slice::from_ref(&stale).iter(),
+        )
+        .unwrap();
+        assert!(price.provider == PriceProviderKind::ChainlinkDataStreams);
+        assert_eq!(price.parts.oracle_ts, NOW);
+
+        // The expected feed is not consumed if it is used directly.
+        let mut expected_feeds = std::slice::from_ref(&stale).iter();
+        let price =
+            OraclePrice::parse_from_feed_account(&clock, &config, &fresh, &mut expected_feeds)
+                .unwrap();
+        assert!(price.provider == PriceProviderKind::Pyth);
+        assert_eq!(expected_feeds.len(), 1);
+    }
+
+    #[test]
+    fn record_new_feeds_up_to_the_max() {
+        let mut counter = PriceFeedCounter::default();
+        for expected in 1..=3 {
+            counter.record_new_feed(3).unwrap();
+            assert_eq!(counter.count(), expected);
+        }
+        assert!(counter.record_new_feed(3).is_err());
+        assert_eq!(counter.count(), 3);
+
+        // Zero means unlimited.
+        counter.record_new_feed(0).unwrap();
+        assert_eq!(counter.count(), 4);
+    }
+}
@@ -38,7 +38,7 @@ use super::{HasMarketMeta, Seed, Store, TokenConfig, TokenMapHeader, TokenMapRef
 
 pub use self::{
     chainlink::Chainlink,
-    feed::{PriceFeed, PriceFeedPrice},
+    feed::{PriceFeed, PriceFeedCounter, PriceFeedPrice},
     pyth::Pyth,
     switchboard::Switchboard,
     time::{ValidateOracleTime, ValidateOracleTimeExt},
@@ -94,13 +94,15 @@ impl Oracle {
     }
 
     /// Set prices from remaining accounts.
+    ///
+    /// Returns the number of the remaining accounts consumed.
     pub(crate) fn set_prices_from_remaining_accounts<'info>(
         &mut self,
         mut validator: PriceValidator,
         map: &TokenMapRef,
         tokens: &[Pubkey],
         remaining_accounts: &'info [AccountInfo<'info>],
-    ) -> Result<()> {
+    ) -> Result<usize> {
         require!(self.is_cleared(), CoreError::PricesAreAlreadySet);
         require!(self.primary.is_empty(), CoreError::PricesAreAlreadySet);
         require!(
@@ -112,29 +114,29 @@ impl Oracle {
             ErrorCode::AccountNotEnoughKeys
         );
         // Assume the remaining accounts are arranged in the following way:
-        // [token_config, feed; tokens.len()] [..remaining]
-        for (idx, token) in tokens.iter().enumerate() {
-            let feed = &remaining_accounts[idx];
+        // [feed; tokens.len()] [expected_feed; number of fallback feeds] [..remaining]
+        // where each `expected_feed` is the stale feed of the expected provider of the token
+        // whose price is supplied by the feed of its fallback provider, in the same order.
+        let (feeds, expected_feeds) = remaining_accounts.split_at(tokens.len());
+        let mut expected_feeds = expected_feeds.iter();
+        for (token, feed) in tokens.iter().zip(feeds) {
             let token_config = map.get(token).ok_or_else(|| error!(CoreError::NotFound))?;
 
-            require!(token_config.is_enabled(), CoreError::TokenConfigDisabled);
-
-            let oracle_price =
-                OraclePrice::parse_from_feed_account(validator.clock(), token_config, feed)?;
-
-            validator.validate_one(
+            let oracle_price = OraclePrice::parse_and_validate(
+                &mut validator,
                 token_config,
-                &oracle_price.provider,
-                oracle_price.parts.oracle_ts,
-                oracle_price.parts.oracle_slot,
-                &oracle_price.parts.price,
-                oracle_price.parts.ref_price.as_ref(),
+                feed,
+                &mut expected_feeds,
+            )?;
+            self.primary.set(
+                token,
+                oracle_price.parts.price,
+                token_config.is_synthetic(),
+                oracle_price.provider,
             )?;
-            self.primary
-                .set(token, oracle_price.parts.price, token_config.is_synthetic())?;
         }
         self.update_oracle_ts_and_slot(validator)?;
-        Ok(())
+        Ok(remaining_accounts.len() - expected_feeds.len())
     }
 
     /// Get min oracle slot.
@@ -195,15 +197,18 @@ impl Oracle {
             tokens.len(),
             CoreError::NotEnoughTokenFeeds,
         );
-        let feeds = &remaining_accounts[..tokens.len()];
-        let remaining_accounts = &remaining_accounts[tokens.len()..];
         let res = {
             let token_map = token_map.load_token_map()?;
-            self.set_prices_from_remaining_accounts(validator, &token_map, tokens, feeds)
+            self.set_prices_from_remaining_accounts(
+                validator,
+                &token_map,
+                tokens,
+                remaining_accounts,
+            )
         };
         match res {
-            Ok(()) => {
-                let output = f(self, remaining_accounts);
+            Ok(consumed) => {
+                let output = f(self, &remaining_accounts[consumed..]);
                 self.clear_all_prices();
                 output
             }
@@ -258,6 +263,14 @@ impl Oracle {
         })
     }
 
+    /// Get the provider that supplied the primary price of the given token.
+    pub fn get_primary_price_provider(&self, token: &Pubkey) -> Result<PriceProviderKind> {
+        self.primary
+            .get(token)
+            .ok_or_else(|| error!(CoreError::MissingOraclePrice))?
+            .provider()
+    }
+
     /// Get prices for the market
     pub(crate) fn market_prices(
         &self,
@@ -299,10 +312,38 @@ pub(crate) struct OraclePriceParts {
 }
 
 impl OraclePrice {
+    fn parse_and_validate<'info>(
+        validator: &mut PriceValidator,
+        token_config: &TokenConfig,
+        account: &'info AccountInfo<'info>,
+        expected_feeds: &mut impl Iterator<Item = &'info AccountInfo<'info>>,
+    ) -> Result<Self> {
+        require!(token_config.is_enabled(), CoreError::TokenConfigDisabled);
+
+        let oracle_price = Self::parse_from_feed_account(
+            validator.clock(),
+            token_config,
+            account,
+            expected_feeds,
+        )?;
+
+        validator.validate_one(
+            token_config,
+            &oracle_price.provider,
+            oracle_price.parts.oracle_ts,
+            oracle_price.parts.oracle_slot,
+            &oracle_price.parts.price,
+            oracle_price.parts.ref_price.as_ref(),
+        )?;
+
+        Ok(oracle_price)
+    }
+
     fn parse_from_feed_account<'info>(
         clock: &Clock,
         token_config: &TokenConfig,
         account: &'info AccountInfo<'info>,
+        expected_feeds: &mut impl Iterator<Item = &'info AccountInfo<'info>>,
     ) -> Result<Self> {
         let (provider, parsed) = match from_program_id(account.owner) {
             Some(provider) => (provider, None),
@@ -315,10 +356,29 @@ impl OraclePrice {
             None => return Err(error!(CoreError::InvalidPriceFeedAccount)),
         };
 
-        require_eq!(
-            token_config.expected_provider().map_err(CoreError::from)?,
-            provider
-        );
+        let expected_provider = token_config.expected_provider().map_err(CoreError::from)?;
+        if provider != expected_provider {
+            // The fallback provider is only used when the feed of the expected provider is stale.
+            // Its price still goes through all the validations.
+            let fallback_provider = token_config.fallback_provider().map_err(CoreError::from)?;
+            require!(
+                fallback_provider == Some(provider),
+                CoreError::InvalidPriceFeedAccount
+            );
+            require!(
+                token_config.is_provider_allowed(&provider),
+                CoreError::PriceProviderNotAllowed
+            );
+            let expected_feed = expected_feeds
+                .next()
+                .ok_or_else(|| error!(CoreError::NotEnoughTokenFeeds))?;
+            Self::validate_expected_feed_is_stale(clock, token_config, expected_feed)?;
+            msg!(
+                "[Oracle] using fallback provider {} instead of {}",
+                provider,
+                expected_provider
+            );
+        }
 
         let feed_config = token_config
             .get_feed_config(&provider)
@@ -329,9 +389,11 @@ impl OraclePrice {
             PriceProviderKind::ChainlinkDataStreams => {
                 parsed.ok_or_else(|| error!(CoreError::Internal))?
             }
-            PriceProviderKind::Pyth => {
-                Pyth::check_and_get_price(clock, token_config, account, feed_id)?
-            }
+            PriceProviderKind::Pyth => match parsed {
+                // Custom price feed updated with Pyth price updates.
+                Some(parts) => parts,
+                None => Pyth::check_and_get_price(clock, token_config, account, feed_id)?,
+            },
             PriceProviderKind::Chainlink => {
                 msg!("[Oracle] Chainlink Data Feeds are no longer supported as of this version");
                 return err!(CoreError::Deprecated);
@@ -355,6 +417,89 @@ impl OraclePrice {
 
         Ok(Self { provider, parts })
     }
+
+    /// Validate that the given account is the feed of the expected provider of the token
+    /// and that it has not been updated within the heartbeat duration.
+    fn validate_expected_feed_is_stale<'info>(
+        clock: &Clock,
+        token_config: &TokenConfig,
+        account: &'info AccountInfo<'info>,
+    ) -> Result<()> {
+        let expected_provider = token_config.expected_provider().map_err(CoreError::from)?;
+        let feed_id = token_config
+            .get_feed(&expected_provider)
+            .map_err(CoreError::from)?;
+        let published_ts = match from_program_id(account.owner) {
+            Some(PriceProviderKind::Pyth) if expected_provider == PriceProviderKind::Pyth => {
+                Pyth::published_ts(account, &feed_id)?
+            }
+            Some(PriceProviderKind::Switchboard)
+                if expected_provider == PriceProviderKind::Switchboard =>
+            {
+                require_keys_eq!(feed_id, account.key(), CoreError::InvalidPriceFeedAccount);
+                Switchboard::published_ts(account)?
+            }
+            None if *account.owner == crate::ID => {
+                let loader = AccountLoader::<'info, PriceFeed>::try_from(account)?;
+                let feed = loader.load()?;
+                require_eq!(
+                    feed.provider()?,
+                    expected_provider,
+                    CoreError::InvalidPriceFeedAccount
+                );
+                require_keys_eq!(*feed.feed_id(), feed_id, CoreError::InvalidPriceFeedAccount);
+                feed.price().ts()
+            }
+            _ => return err!(CoreError::InvalidPriceFeedAccount),
+        };
+        let heartbeat_duration = token_config.heartbeat_duration();
+        let is_stale =
+            clock.unix_timestamp.saturating_sub(published_ts) > i64::from(heartbeat_duration);
+        if !is_stale {
+            msg!(
+                "[Oracle] the feed of the expected provider is not stale, published_ts = {}",
+                published_ts
+            );
+            return err!(CoreError::InvalidPriceFeedAccount);
+        }
+        Ok(())
+    }
+}
+
+/// A validated price of a token read from a feed account.
+pub(crate) struct ValidatedPrice {
+    pub(crate) provider: PriceProviderKind,
+    pub(crate) price: gmsol_utils::Price,
+    pub(crate) oracle_ts: i64,
+    pub(crate) oracle_slot: u64,
+}
+
+/// Read the price of the token from the given feed account and validate it in the same way
+/// as when the prices are set to an [`Oracle`].
+///
+/// The feeds are expected to be arranged as `[feed, expected_feed?]`, where the `expected_feed`
+/// is required only when the `feed` is of the fallback provider of the token.
+pub(crate) fn get_validated_price<'info>(
+    store: &Store,
+    token_config: &TokenConfig,
+    feeds: &'info [AccountInfo<'info>],
+) -> Result<ValidatedPrice> {
+    let mut validator = PriceValidator::try_from(store)?;
+    let (feed, expected_feeds) = feeds
+        .split_first()
+        .ok_or_else(|| error!(CoreError::NotEnoughTokenFeeds))?;
+    let OraclePrice { provider, parts } = OraclePrice::parse_and_validate(
+        &mut validator,
+        token_config,
+        feed,
+        &mut expected_feeds.iter(),
+    )?;
+    Ok(ValidatedPrice {
+        provider,
+        price: parts.price,
+        oracle_ts: parts.oracle_ts,
+        oracle_slot: parts.oracle_slot,
+    })
 }
 
 fn try_adjust_price(feed_config: &FeedConfig, parts: &mut OraclePriceParts) -> Result<bool> {
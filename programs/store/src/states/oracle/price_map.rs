@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use gmsol_utils::price::Decimal;
 
-use crate::{utils::pubkey::to_bytes, CoreError};
+use crate::{states::PriceProviderKind, utils::pubkey::to_bytes, CoreError};
 
 /// Zero-copy price structure for storing min max prices.
 #[zero_copy]
@@ -9,8 +9,9 @@ use crate::{utils::pubkey::to_bytes, CoreError};
 pub struct SmallPrices {
     decimal_multiplier: u8,
     flags: u8,
+    provider: u8,
     #[cfg_attr(feature = "debug", debug(skip))]
-    padding_0: [u8; 2],
+    padding_0: [u8; 1],
     min: u32,
     max: u32,
 }
@@ -40,7 +41,17 @@ impl SmallPrices {
         }
     }
 
-    pub(crate) fn from_price(price: &gmsol_utils::Price, is_synthetic: bool) -> Result<Self> {
+    /// Get the provider that supplied the price.
+    pub fn provider(&self) -> Result<PriceProviderKind> {
+        PriceProviderKind::try_from(self.provider)
+            .map_err(|_| error!(CoreError::InvalidProviderKindIndex))
+    }
+
+    pub(crate) fn from_price(
+        price: &gmsol_utils::Price,
+        is_synthetic: bool,
+        provider: PriceProviderKind,
+    ) -> Result<Self> {
         // Validate price data.
         require_eq!(
             price.min.decimal_multiplier,
@@ -59,7 +70,8 @@ impl SmallPrices {
         Ok(SmallPrices {
             decimal_multiplier: price.min.decimal_multiplier,
             flags,
-            padding_0: [0; 2],
+            provider: provider as u8,
+            padding_0: [0; 1],
             min: price.min.value,
             max: price.max.value,
         })
@@ -92,8 +104,12 @@ impl PriceMap {
         token: &Pubkey,
         price: gmsol_utils::Price,
         is_synthetic: bool,
+        provider: PriceProviderKind,
     ) -> Result<()> {
-        self.insert(token, SmallPrices::from_price(&price, is_synthetic)?);
+        self.insert(
+            token,
+            SmallPrices::from_price(&price, is_synthetic, provider)?,
+        );
         Ok(())
     }
 }
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
-use gmsol_utils::oracle::OracleError;
-use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
+use gmsol_utils::{oracle::OracleError, price::PriceFlag};
+use pyth_solana_receiver_sdk::price_update::{Price, PriceUpdateV2};
 
-use crate::{states::TokenConfig, CoreError};
+use crate::{
+    states::{PriceFeedPrice, TokenConfig},
+    CoreError,
+};
 
 pub use gmsol_utils::oracle::pyth_price_with_confidence_to_price;
 
@@ -58,6 +61,84 @@ impl Pyth {
             ref_price: None,
         })
     }
+
+    /// Get the publish time of the price of the given feed id in the price update account.
+    pub(super) fn published_ts<'info>(
+        feed: &'info AccountInfo<'info>,
+        feed_id: &Pubkey,
+    ) -> Result<i64> {
+        let feed = Account::<PriceUpdateV2>::try_from(feed)?;
+        require!(
+            feed.price_message.feed_id == feed_id.to_bytes(),
+            CoreError::InvalidPriceFeedAccount
+        );
+        Ok(feed.price_message.publish_time)
+    }
+
+    /// Convert a Pyth price into [`PriceFeedPrice`].
+    ///
+    /// The min and max prices are the price minus and plus the confidence interval.
+    ///
+    /// # Errors
+    /// - The price must be positive.
+    /// - The ratio of the confidence interval to the price must not exceed
+    ///   `max_confidence_ratio`, unless it is `0`.
+    pub(crate) fn to_price_feed_price(
+        price: &Price,
+        max_confidence_ratio: u128,
+    ) -> Result<PriceFeedPrice> {
+        require_gt!(price.price, 0, CoreError::NegativePriceIsNotSupported);
+        let (scale, decimals) = match price.exponent.checked_neg() {
+            Some(decimals) if decimals >= 0 => (
+                1,
+                u8::try_from(decimals).map_err(|_| error!(CoreError::InvalidPriceFeedPrice))?,
+            ),
+            _ => (
+                10u128
+                    .checked_pow(price.exponent.unsigned_abs())
+                    .ok_or_else(|| error!(CoreError::PriceOverflow))?,
+                0,
+            ),
+        };
+        let mid_price = u128::from(price.price.unsigned_abs())
+            .checked_mul(scale)
+            .ok_or_else(|| error!(CoreError::PriceOverflow))?;
+        let confidence = u128::from(price.conf)
+            .checked_mul(scale)
+            .ok_or_else(|| error!(CoreError::PriceOverflow))?;
+
+        if max_confidence_ratio != 0 {
+            let ratio =
+                gmsol_model::utils::div_to_factor::<_, { crate::constants::MARKET_DECIMALS }>(
+                    &confidence,
+                    &mid_price,
+                    true,
+                )
+                .ok_or_else(|| error!(CoreError::PriceOverflow))?;
+            require_gte!(
+                max_confidence_ratio,
+                ratio,
+                CoreError::PriceConfidenceTooWide
+            );
+        }
+
+        let min_price = mid_price.saturating_sub(confidence);
+        let max_price = mid_price
+            .checked_add(confidence)
+            .ok_or_else(|| error!(CoreError::PriceOverflow))?;
+
+        let mut feed_price = PriceFeedPrice::new(
+            decimals,
+            price.publish_time,
+            mid_price,
+            min_price,
+            max_price,
+            0,
+        );
+        // Pyth prices carry no market status.
+        feed_price.set_flag(PriceFlag::Open, true);
+        Ok(feed_price)
+    }
 }
 
 impl From<OracleError> for CoreError {
@@ -68,3 +149,84 @@ impl From<OracleError> for CoreError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBLISH_TIME: i64 = 1_000;
+
+    fn pyth_price(price: i64, conf: u64, exponent: i32) -> Price {
+        Price {
+            price,
+            conf,
+            exponent,
+            publish_time: PUBLISH_TIME,
+        }
+    }
+
+    #[test]
+    fn convert_price_with_negative_exponent() {
+        // 123.45 ± 0.12
+        let price = Pyth::to_price_feed_price(&pyth_price(12_345, 12, -2), 0).unwrap();
+        assert_eq!(price.decimals(), 2);
+        assert_eq!(*price.price(), 12_345);
+        assert_eq!(*price.min_price(), 12_333);
+        assert_eq!(*price.max_price(), 12_357);
+        assert_eq!(price.ts(), PUBLISH_TIME);
+        assert!(price.is_market_open(PUBLISH_TIME, 0));
+    }
+
+    #[test]
+    fn convert_price_with_non_negative_exponent() {
+        // 5 ± 1
+        let price = Pyth::to_price_feed_price(&pyth_price(5, 1, 0), 0).unwrap();
+        assert_eq!(price.decimals(), 0);
+        assert_eq!(*price.price(), 5);
+        assert_eq!(*price.min_price(), 4);
+        assert_eq!(*price.max_price(), 6);
+
+        // 12_300 ± 100, scaled into a price with no decimals.
+        let price = Pyth::to_price_feed_price(&pyth_price(123, 1, 2), 0).unwrap();
+        assert_eq!(price.decimals(), 0);
+        assert_eq!(*price.price(), 12_300);
+        assert_eq!(*price.min_price(), 12_200);
+        assert_eq!(*price.max_price(), 12_400);
+    }
+
+    #[test]
+    fn min_price_saturates_if_confidence_exceeds_price() {
+        let price = Pyth::to_price_feed_price(&pyth_price(10, 20, -1), 0).unwrap();
+        assert_eq!(*price.min_price(), 0);
+        assert_eq!(*price.max_price(), 30);
+    }
+
+    #[test]
+    fn reject_invalid_prices() {
+        // Non-positive prices.
+        assert!(Pyth::to_price_feed_price(&pyth_price(0, 0, -2), 0).is_err());
+        assert!(Pyth::to_price_feed_price(&pyth_price(-1, 0, -2), 0).is_err());
+        // The number of decimals does not fit in `u8`.
+        assert!(Pyth::to_price_feed_price(&pyth_price(1, 0, -256), 0).is_err());
+        // The scale overflows.
+        assert!(Pyth::to_price_feed_price(&pyth_price(1, 0, 39), 0).is_err());
+        // The scaled price overflows.
+        assert!(Pyth::to_price_feed_price(&pyth_price(i64::MAX, 0, 20), 0).is_err());
+        // The scaled confidence overflows.
+        assert!(Pyth::to_price_feed_price(&pyth_price(1, u64::MAX, 20), 0).is_err());
+    }
+
+    #[test]
+    fn check_confidence_ratio() {
+        // The confidence ratio is 12 / 12_345, about 0.097%.
+        let price = pyth_price(12_345, 12, -2);
+        let unit = 10u128.pow(u32::from(crate::constants::MARKET_DECIMALS));
+
+        // 0.1%
+        assert!(Pyth::to_price_feed_price(&price, unit / 1_000).is_ok());
+        // 0.09%
+        assert!(Pyth::to_price_feed_price(&price, unit * 9 / 10_000).is_err());
+        // Disabled.
+        assert!(Pyth::to_price_feed_price(&price, 0).is_ok());
+    }
+}
@@ -86,6 +86,13 @@ impl Switchboard {
         })
     }
 
+    /// Get the timestamp of the latest result of the feed.
+    pub(super) fn published_ts<'info>(feed: &'info AccountInfo<'info>) -> Result<i64> {
+        let feed = AccountLoader::<SbFeed>::try_from(feed)?;
+        let result_ts = feed.load()?.result_ts();
+        Ok(result_ts)
+    }
+
     fn price_from(feed: &SbFeed, token_config: &TokenConfig) -> Result<Price> {
         let min_price = feed
             .min_value()
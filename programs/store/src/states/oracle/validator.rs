@@ -100,6 +100,14 @@ impl PriceValidator {
             }
         }
 
+        // Validate max price bound.
+        if let Some(max_price) = token_config.max_price() {
+            if price.max.to_unit_price() > max_price.to_unit_price() {
+                msg!("[Price Validator] the price exceeds the max price bound of the token");
+                return err!(CoreError::InvalidPriceFeedPrice);
+            }
+        }
+
         self.merge_range(Some(oracle_slot), ts, ts);
 
         Ok(())
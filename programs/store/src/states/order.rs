@@ -3,10 +3,12 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use gmsol_model::{
     action::decrease_position::{DecreasePositionReport, DecreasePositionSwapType},
     price::Price,
+    utils::apply_factor,
 };
 use gmsol_utils::InitSpace as _;
 
 use crate::{
+    constants,
     events::{EventEmitter, GtUpdated, OrderRemoved},
     utils::pubkey::optional_address,
     CoreError,
@@ -14,7 +16,7 @@ use crate::{
 
 use super::{
     common::{
-        action::{Action, ActionHeader, ActionSigner, Closable},
+        action::{Action, ActionHeader, ActionSigner, Closable, CloseReason},
         swap::SwapActionParams,
         token::TokenAndAccount,
     },
@@ -318,11 +320,14 @@ pub struct Order {
     /// Order params.
     pub(crate) params: OrderActionParams,
     pub(crate) gt_reward: u64,
-    #[cfg_attr(feature = "debug", debug(skip))]
-    padding_1: [u8; 8],
+    /// The amount of collateral tokens paid for the execution fee.
+    pub(crate) execution_fee_from_collateral: u64,
+    /// The sequence of the order in the fair execution queue of the market.
+    /// Zero means the order is not queued.
+    pub(crate) market_order_seq: u64,
     #[cfg_attr(feature = "debug", debug(skip))]
     #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
-    reserved: [u8; 128],
+    reserved: [u8; 120],
 }
 
 impl Seed for Order {
@@ -345,7 +350,12 @@ impl Action for Order {
 impl Closable for Order {
     type ClosedEvent = OrderRemoved;
 
-    fn to_closed_event(&self, address: &Pubkey, reason: &str) -> Result<Self::ClosedEvent> {
+    fn to_closed_event(
+        &self,
+        address: &Pubkey,
+        reason: &str,
+        close_reason: CloseReason,
+    ) -> Result<Self::ClosedEvent> {
         OrderRemoved::new(
             self.header.id,
             self.header.store,
@@ -355,6 +365,7 @@ impl Closable for Order {
             self.header.owner,
             self.header.action_state()?,
             reason,
+            close_reason,
         )
     }
 }
@@ -390,6 +401,12 @@ impl Order {
         Ok(())
     }
 
+    /// Get the sequence of the order in the fair execution queue of the market,
+    /// or `None` if it is not queued.
+    pub fn market_order_seq(&self) -> Option<u64> {
+        (self.market_order_seq != 0).then_some(self.market_order_seq)
+    }
+
     /// Validate trigger price.
     pub fn validate_trigger_price(&self, index_price: &Price<u128>) -> Result<()> {
         let params = &self.params;
@@ -551,6 +568,22 @@ impl Order {
 
         let value_to_mint_for = next_paid_fee_value.saturating_sub(minted_fee_value);
 
+        // Record a fraction of the paid order fee value as staker rewards of GT holders.
+        let staker_reward_value = apply_factor::<_, { constants::MARKET_DECIMALS }>(
+            &paid_fee_value,
+            &store.order_fee_to_gt_stakers_factor(),
+        )
+        .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        let recorded = store
+            .gt_mut()
+            .accrue_staker_reward_value(staker_reward_value)?;
+        if recorded != 0 {
+            msg!(
+                "[GT] recorded {} of order fee value for GT holders",
+                recorded
+            );
+        }
+
         let (minted, delta_minted_value, minting_cost) =
             store.gt().get_mint_amount(value_to_mint_for)?;
 
@@ -558,7 +591,8 @@ impl Order {
             .checked_add(delta_minted_value)
             .ok_or_else(|| error!(CoreError::ValueOverflow))?;
 
-        store.gt_mut().mint_to(user, minted)?;
+        let lockup_seconds = store.gt_mint_lockup_seconds();
+        store.gt_mut().mint_to(user, minted, lockup_seconds)?;
 
         self.gt_reward = minted;
         user.gt.paid_fee_value = next_paid_fee_value;
@@ -700,6 +734,11 @@ pub struct OrderActionParams {
 impl OrderActionParams {
     const DEFAULT_VALID_FROM_TS: i64 = 0;
 
+    /// Returns whether the order is delayed by a `valid_from_ts`.
+    pub(crate) fn is_delayed(&self) -> bool {
+        self.valid_from_ts != Self::DEFAULT_VALID_FROM_TS
+    }
+
     pub(crate) fn init_swap(
         &mut self,
         kind: OrderKind,
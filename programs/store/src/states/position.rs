@@ -1,6 +1,8 @@
 use crate::{constants, CoreError};
 use anchor_lang::prelude::*;
 use borsh::{BorshDeserialize, BorshSerialize};
+use gmsol_model::price::Prices;
+use gmsol_utils::order::{PositionFlag, MAX_POSITION_FLAGS};
 use num_enum::TryFromPrimitive;
 
 use super::{Market, Seed};
@@ -19,9 +21,12 @@ pub struct Position {
     pub store: Pubkey,
     /// Position kind (the representation of [`PositionKind`]).
     pub kind: u8,
+    flags: PositionFlagContainer,
     /// Padding.
     #[cfg_attr(feature = "debug", debug(skip))]
-    pub padding_0: [u8; 13],
+    pub padding_0: [u8; 8],
+    /// The number of unclosed resting orders of the position.
+    num_resting_orders: u32,
     /// Owner.
     pub owner: Pubkey,
     /// The market token of the position market.
@@ -30,12 +35,17 @@ pub struct Position {
     pub collateral_token: Pubkey,
     /// Position State.
     pub state: PositionState,
+    /// Cost basis value of the position, i.e. the total value paid for the current size,
+    /// including the fees paid on increases.
+    pub cost_basis_value: u128,
     /// Reserved.
     #[cfg_attr(feature = "debug", debug(skip))]
     #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
-    reserved: [u8; 256],
+    reserved: [u8; 240],
 }
 
+gmsol_utils::flags!(PositionFlag, MAX_POSITION_FLAGS, u8);
+
 impl Default for Position {
     fn default() -> Self {
         use bytemuck::Zeroable;
@@ -54,6 +64,9 @@ impl Seed for Position {
 }
 
 impl Position {
+    /// Max number of unclosed resting orders of a position.
+    pub const MAX_RESTING_ORDERS: u32 = 8;
+
     /// Get position kind.
     ///
     /// Note that `Uninitialized` kind will also be returned without error.
@@ -105,6 +118,54 @@ impl Position {
         Ok(())
     }
 
+    /// Returns whether the position is counted in the open positions of its market.
+    pub fn is_open_recorded(&self) -> bool {
+        self.flags.get_flag(PositionFlag::OpenRecorded)
+    }
+
+    pub(crate) fn set_open_recorded(&mut self, recorded: bool) -> bool {
+        self.flags.set_flag(PositionFlag::OpenRecorded, recorded)
+    }
+
+    /// Get the number of unclosed resting orders (limit and stop-loss orders) of the position.
+    pub fn num_resting_orders(&self) -> u32 {
+        self.num_resting_orders
+    }
+
+    /// Record a new resting order.
+    pub(crate) fn add_resting_order(&mut self) -> Result<()> {
+        let next = self
+            .num_resting_orders
+            .checked_add(1)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        require_gte!(
+            Self::MAX_RESTING_ORDERS,
+            next,
+            CoreError::MaxRestingOrdersExceeded
+        );
+        self.num_resting_orders = next;
+        Ok(())
+    }
+
+    /// Record the removal of a resting order.
+    pub(crate) fn remove_resting_order(&mut self) -> Result<()> {
+        self.num_resting_orders = self
+            .num_resting_orders
+            .checked_sub(1)
+            .ok_or_else(|| error!(CoreError::Internal))?;
+        Ok(())
+    }
+
+    /// Get the average entry price (unit price) of the position.
+    ///
+    /// Returns zero if the position is empty.
+    pub fn average_entry_price(&self) -> u128 {
+        match self.state.size_in_tokens {
+            0 => 0,
+            size_in_tokens => self.state.size_in_usd / size_in_tokens,
+        }
+    }
+
     /// Convert to a type that implements [`Position`](gmsol_model::Position).
     pub fn as_position<'a>(&'a self, market: &'a Market) -> Result<AsPosition<'a>> {
         AsPosition::try_new(self, market)
@@ -351,3 +412,99 @@ impl gmsol_model::Position<{ constants::MARKET_DECIMALS }> for AsPosition<'_> {
         self.position.validate_for_market(self.market)
     }
 }
+
+/// Accrued fees of a position.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct PositionAccruedFees {
+    /// Accrued borrowing fee value.
+    pub borrowing_fee_value: u128,
+    /// Accrued borrowing fee amount in collateral tokens.
+    pub borrowing_fee_amount: u128,
+    /// Accrued funding fee amount to pay in collateral tokens.
+    pub funding_fee_amount: u128,
+    /// Accrued claimable funding fee amount in long tokens.
+    pub claimable_funding_long_token_amount: u128,
+    /// Accrued claimable funding fee amount in short tokens.
+    pub claimable_funding_short_token_amount: u128,
+    /// Net funding value. Positive if the position receives funding,
+    /// negative if it pays.
+    pub net_funding_value: i128,
+}
+
+impl PositionAccruedFees {
+    /// Create from position and prices.
+    ///
+    /// The fees are accrued up to the last borrowing and funding state update of the market.
+    pub fn from_position(
+        position: &AsPosition<'_>,
+        prices: &Prices<u128>,
+    ) -> gmsol_model::Result<Self> {
+        use gmsol_model::{num::Unsigned, Position, PositionExt};
+
+        let collateral_token_price =
+            prices.collateral_token_price(position.is_collateral_token_long());
+        let borrowing_fee_value = position.pending_borrowing_fee_value()?;
+        let borrowing_fee_amount = borrowing_fee_value
+            .checked_div(*collateral_token_price.pick_price(false))
+            .ok_or(gmsol_model::Error::Computation(
+                "calculating borrowing fee amount",
+            ))?;
+
+        let funding_fees = position.pending_funding_fees()?;
+        let value = |amount: &u128, price: &u128| {
+            amount
+                .checked_mul(*price)
+                .ok_or(gmsol_model::Error::Computation("calculating funding value"))
+        };
+        let claimable_value = value(
+            funding_fees.claimable_long_token_amount(),
+            prices.long_token_price.pick_price(false),
+        )?
+        .checked_add(value(
+            funding_fees.claimable_short_token_amount(),
+            prices.short_token_price.pick_price(false),
+        )?)
+        .ok_or(gmsol_model::Error::Computation(
+            "calculating claimable funding value",
+        ))?;
+        let paid_value = value(
+            funding_fees.amount(),
+            collateral_token_price.pick_price(true),
+        )?;
+
+        Ok(Self {
+            borrowing_fee_value,
+            borrowing_fee_amount,
+            funding_fee_amount: *funding_fees.amount(),
+            claimable_funding_long_token_amount: *funding_fees.claimable_long_token_amount(),
+            claimable_funding_short_token_amount: *funding_fees.claimable_short_token_amount(),
+            net_funding_value: claimable_value.checked_signed_sub(paid_value)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_resting_orders_up_to_the_max() {
+        let mut position = Position::default();
+        assert!(position.remove_resting_order().is_err());
+        for expected in 1..=Position::MAX_RESTING_ORDERS {
+            position.add_resting_order().unwrap();
+            assert_eq!(position.num_resting_orders(), expected);
+        }
+        assert!(position.add_resting_order().is_err());
+        assert_eq!(position.num_resting_orders(), Position::MAX_RESTING_ORDERS);
+
+        position.remove_resting_order().unwrap();
+        assert_eq!(
+            position.num_resting_orders(),
+            Position::MAX_RESTING_ORDERS - 1
+        );
+        position.add_resting_order().unwrap();
+    }
+}
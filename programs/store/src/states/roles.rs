@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use bytemuck::Zeroable;
 use gmsol_utils::bitmaps::Bitmap;
 
 use crate::CoreError;
@@ -110,6 +111,133 @@ gmsol_utils::fixed_map!(
     0
 );
 
+/// Max number of time-limited role grants.
+pub const MAX_EXPIRING_ROLE_GRANTS: usize = 8;
+
+/// Expiry of a time-limited role grant.
+#[zero_copy]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct RoleGrantExpiry {
+    /// The member.
+    pub member: Pubkey,
+    /// The timestamp at which the grant expires.
+    pub expires_at: i64,
+    role_index: u8,
+    padding_0: [u8; 7],
+}
+
+impl RoleGrantExpiry {
+    /// Get the index of the role.
+    pub fn role_index(&self) -> u8 {
+        self.role_index
+    }
+
+    fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// Expiries of time-limited role grants.
+#[zero_copy]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct RoleGrantExpiries {
+    len: u8,
+    padding_0: [u8; 7],
+    entries: [RoleGrantExpiry; MAX_EXPIRING_ROLE_GRANTS],
+}
+
+impl InitSpace for RoleGrantExpiries {
+    const INIT_SPACE: usize = std::mem::size_of::<RoleGrantExpiries>();
+}
+
+impl RoleGrantExpiries {
+    /// Get all expiries.
+    pub fn entries(&self) -> &[RoleGrantExpiry] {
+        &self.entries[..usize::from(self.len)]
+    }
+
+    fn position(&self, member: &Pubkey, role_index: u8) -> Option<usize> {
+        self.entries()
+            .iter()
+            .position(|entry| entry.member == *member && entry.role_index == role_index)
+    }
+
+    /// Get the expiry of the grant of the given role to the member.
+    ///
+    /// Returns `None` if the grant is not time-limited.
+    pub fn expires_at(&self, member: &Pubkey, role_index: u8) -> Option<i64> {
+        self.position(member, role_index)
+            .map(|idx| self.entries[idx].expires_at)
+    }
+
+    /// Insert or update the expiry of a grant.
+    pub(crate) fn insert(
+        &mut self,
+        member: &Pubkey,
+        role_index: u8,
+        expires_at: i64,
+    ) -> Result<()> {
+        if let Some(idx) = self.position(member, role_index) {
+            self.entries[idx].expires_at = expires_at;
+            return Ok(());
+        }
+        let len = usize::from(self.len);
+        require_gt!(
+            MAX_EXPIRING_ROLE_GRANTS,
+            len,
+            CoreError::ExceedMaxLengthLimit
+        );
+        self.entries[len] = RoleGrantExpiry {
+            member: *member,
+            expires_at,
+            role_index,
+            padding_0: Default::default(),
+        };
+        self.len += 1;
+        Ok(())
+    }
+
+    fn remove_at(&mut self, idx: usize) -> RoleGrantExpiry {
+        let len = usize::from(self.len);
+        let entry = self.entries[idx];
+        self.entries.copy_within((idx + 1)..len, idx);
+        self.entries[len - 1] = RoleGrantExpiry::zeroed();
+        self.len -= 1;
+        entry
+    }
+
+    /// Remove the expiry of a grant.
+    pub(crate) fn remove(&mut self, member: &Pubkey, role_index: u8) {
+        if let Some(idx) = self.position(member, role_index) {
+            self.remove_at(idx);
+        }
+    }
+
+    /// Remove all expiries of the member.
+    pub(crate) fn remove_member(&mut self, member: &Pubkey) {
+        while let Some(idx) = self
+            .entries()
+            .iter()
+            .position(|entry| entry.member == *member)
+        {
+            self.remove_at(idx);
+        }
+    }
+
+    /// Remove and return all expired entries.
+    pub(crate) fn take_expired(&mut self, now: i64) -> Vec<RoleGrantExpiry> {
+        let mut expired = Vec::new();
+        while let Some(idx) = self
+            .entries()
+            .iter()
+            .position(|entry| entry.is_expired(now))
+        {
+            expired.push(self.remove_at(idx));
+        }
+        expired
+    }
+}
+
 /// Roles Store.
 #[zero_copy]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -222,6 +350,11 @@ impl RoleStore {
         let Some(index) = self.role_index(role)? else {
             return err!(CoreError::NotFound);
         };
+        self.revoke_by_index(authority, index)
+    }
+
+    /// Revoke a role from the pubkey by the index of the role.
+    pub(crate) fn revoke_by_index(&mut self, authority: &Pubkey, index: u8) -> Result<()> {
         let Some(value) = self.members.get_mut(authority) else {
             return err!(CoreError::PermissionDenied);
         };
@@ -239,6 +372,27 @@ impl RoleStore {
         Ok(())
     }
 
+    /// Revoke all roles from the pubkey and remove its membership.
+    ///
+    /// Returns the names of the revoked roles.
+    ///
+    /// # Errors
+    /// - The `authority` must be a member.
+    pub fn revoke_all(&mut self, authority: &Pubkey) -> Result<Vec<String>> {
+        let Some(value) = self.members.get(authority) else {
+            return err!(CoreError::NotFound);
+        };
+        let bitmap = RoleBitmap::from_value(*value);
+        let roles = self
+            .roles
+            .entries()
+            .filter(|(_, metadata)| bitmap.get(metadata.index as usize))
+            .map(|(_, metadata)| metadata.name().map(ToString::to_string))
+            .collect::<Result<Vec<_>>>()?;
+        self.members.remove(authority);
+        Ok(roles)
+    }
+
     /// Get the number of roles.
     pub fn num_roles(&self) -> usize {
         self.roles.len()
@@ -265,6 +419,31 @@ impl RoleStore {
     pub fn roles(&self) -> impl Iterator<Item = Result<&str>> + '_ {
         self.roles.entries().map(|(_, value)| value.name())
     }
+
+    /// Get all enabled roles.
+    pub fn enabled_roles(&self) -> impl Iterator<Item = Result<&str>> + '_ {
+        self.roles
+            .entries()
+            .filter(|(_, value)| value.is_enabled())
+            .map(|(_, value)| value.name())
+    }
+
+    /// Get the members that have the given role.
+    ///
+    /// # Errors
+    /// - The `role` must be enabled.
+    pub fn role_members(&self, role: &str) -> Result<Vec<Pubkey>> {
+        let Some(index) = self.enabled_role_index(role)? else {
+            return err!(CoreError::NotFound);
+        };
+        let members = self
+            .members
+            .entries()
+            .filter(|(_, value)| RoleBitmap::from_value(**value).get(index as usize))
+            .map(|(key, _)| Pubkey::new_from_array(*key))
+            .collect();
+        Ok(members)
+    }
 }
 
 #[cfg(test)]
@@ -311,6 +490,92 @@ mod tests {
         assert_eq!(store.has_role(&authority, RoleKey::MARKET_KEEPER), Ok(true));
     }
 
+    #[test]
+    fn role_grant_expiries() {
+        let mut expiries = RoleGrantExpiries::zeroed();
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+
+        expiries.insert(&alice, 0, 100).unwrap();
+        expiries.insert(&alice, 1, 200).unwrap();
+        expiries.insert(&bob, 0, 300).unwrap();
+        expiries.insert(&alice, 0, 150).unwrap();
+        assert_eq!(expiries.entries().len(), 3);
+        assert_eq!(expiries.expires_at(&alice, 0), Some(150));
+        assert_eq!(expiries.expires_at(&bob, 1), None);
+
+        let expired = expiries.take_expired(200);
+        assert_eq!(expired.len(), 2);
+        assert!(expired.iter().all(|entry| entry.member == alice));
+        assert_eq!(expiries.entries().len(), 1);
+
+        expiries.remove_member(&bob);
+        assert!(expiries.entries().is_empty());
+
+        for idx in 0..MAX_EXPIRING_ROLE_GRANTS {
+            expiries.insert(&alice, idx as u8, 100).unwrap();
+        }
+        assert!(expiries.insert(&bob, 0, 100).is_err());
+        expiries.remove(&alice, 0);
+        expiries.insert(&bob, 0, 100).unwrap();
+    }
+
+    #[test]
+    fn list_roles_and_members() {
+        let mut store = RoleStore::zeroed();
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+
+        store.enable_role(RoleKey::GT_CONTROLLER).unwrap();
+        store.enable_role(RoleKey::MARKET_KEEPER).unwrap();
+        store.enable_role(RoleKey::ORDER_KEEPER).unwrap();
+        store.disable_role(RoleKey::ORDER_KEEPER).unwrap();
+
+        let mut roles = store.enabled_roles().collect::<Result<Vec<_>>>().unwrap();
+        roles.sort();
+        assert_eq!(roles, [RoleKey::GT_CONTROLLER, RoleKey::MARKET_KEEPER]);
+
+        store.grant(&alice, RoleKey::GT_CONTROLLER).unwrap();
+        store.grant(&alice, RoleKey::MARKET_KEEPER).unwrap();
+        store.grant(&bob, RoleKey::MARKET_KEEPER).unwrap();
+
+        assert_eq!(store.role_members(RoleKey::GT_CONTROLLER).unwrap(), [alice]);
+        let mut members = store.role_members(RoleKey::MARKET_KEEPER).unwrap();
+        members.sort();
+        let mut expected = vec![alice, bob];
+        expected.sort();
+        assert_eq!(members, expected);
+        assert!(store.role_members(RoleKey::ORDER_KEEPER).is_err());
+    }
+
+    #[test]
+    fn revoke_all_roles() {
+        let mut store = RoleStore::zeroed();
+        let authority = Pubkey::new_unique();
+
+        assert!(store.revoke_all(&authority).is_err());
+
+        store.enable_role(RoleKey::GT_CONTROLLER).unwrap();
+        store.enable_role(RoleKey::MARKET_KEEPER).unwrap();
+        store.enable_role(RoleKey::ORDER_KEEPER).unwrap();
+
+        store.grant(&authority, RoleKey::GT_CONTROLLER).unwrap();
+        store.grant(&authority, RoleKey::ORDER_KEEPER).unwrap();
+
+        let mut revoked = store.revoke_all(&authority).unwrap();
+        revoked.sort();
+        assert_eq!(
+            revoked,
+            [
+                RoleKey::GT_CONTROLLER.to_string(),
+                RoleKey::ORDER_KEEPER.to_string()
+            ]
+        );
+        assert_eq!(store.num_members(), 0);
+        assert!(store.has_role(&authority, RoleKey::ORDER_KEEPER).is_err());
+        assert!(store.revoke_all(&authority).is_err());
+    }
+
     #[test]
     fn enable_and_disable_role() {
         let mut store = RoleStore::zeroed();
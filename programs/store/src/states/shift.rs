@@ -4,7 +4,7 @@ use crate::events::ShiftRemoved;
 
 use super::{
     common::{
-        action::{Action, ActionHeader, Closable},
+        action::{Action, ActionHeader, Closable, CloseReason},
         token::TokenAndAccount,
     },
     Seed,
@@ -41,7 +41,12 @@ impl Action for Shift {
 impl Closable for Shift {
     type ClosedEvent = ShiftRemoved;
 
-    fn to_closed_event(&self, address: &Pubkey, reason: &str) -> Result<Self::ClosedEvent> {
+    fn to_closed_event(
+        &self,
+        address: &Pubkey,
+        reason: &str,
+        close_reason: CloseReason,
+    ) -> Result<Self::ClosedEvent> {
         ShiftRemoved::new(
             self.header.id,
             self.header.store,
@@ -50,6 +55,7 @@ impl Closable for Shift {
             self.header.owner,
             self.header.action_state()?,
             reason,
+            close_reason,
         )
     }
 }
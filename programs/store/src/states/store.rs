@@ -7,9 +7,12 @@ use gmsol_utils::to_seed;
 use crate::{constants, states::feature::display_feature, CoreError, CoreResult};
 
 use super::{
+    common::swap::SwapActionParams,
     feature::{ActionDisabledFlag, DisabledFeatures, DomainDisabledFlag},
     gt::GtState,
-    Amount, Factor, InitSpace, RoleKey, RoleStore, Seed,
+    oracle::price_map::PriceMap,
+    user::{ReferralCodeBlocklist, ReferralCodeCharset},
+    Amount, Factor, InitSpace, RoleGrantExpiries, RoleKey, RoleStore, Seed,
 };
 
 pub use gmsol_utils::config::{AddressKey, AmountKey, FactorKey};
@@ -51,8 +54,14 @@ pub struct Store {
     pub(crate) address: Addresses,
     /// GT State.
     gt: GtState,
+    /// Referral code blocklist.
+    referral_code_blocklist: ReferralCodeBlocklist,
+    /// Expiries of time-limited role grants.
+    role_expiries: RoleGrantExpiries,
+    /// Allowed recipients of the rent of actions closed by keepers.
+    rent_recipient_allowlist: RentRecipientAllowlist,
     #[cfg_attr(feature = "debug", debug(skip))]
-    reserved: [u8; 1024],
+    reserved: [u8; 104],
 }
 
 static_assertions::const_assert!(Store::INIT_SPACE + 8 <= 10240);
@@ -147,26 +156,104 @@ impl Store {
     /// # Note
     /// - If the cluster [has restarted](Self::has_restarted), this function returns `true` if and only if
     ///   the `authority` has the [`RESTART_ADMIN`](RoleKey::RESTART_ADMIN) role.
+    ///
+    /// An expired time-limited grant is treated as absent.
     pub fn has_role(&self, authority: &Pubkey, role: &str) -> Result<bool> {
         if self.has_restarted()? {
-            if self.role.has_role(authority, RoleKey::RESTART_ADMIN)? {
+            if self.has_unexpired_role(authority, RoleKey::RESTART_ADMIN)? {
                 Ok(true)
             } else {
                 err!(CoreError::StoreOutdated)
             }
         } else {
-            self.role.has_role(authority, role)
+            self.has_unexpired_role(authority, role)
         }
     }
 
+    fn has_unexpired_role(&self, authority: &Pubkey, role: &str) -> Result<bool> {
+        Ok(self.role.has_role(authority, role)? && !self.is_role_expired(authority, role)?)
+    }
+
+    /// Returns whether the grant of the given role to the authority is time-limited and has expired.
+    pub fn is_role_expired(&self, authority: &Pubkey, role: &str) -> Result<bool> {
+        let Some(index) = self.role.role_index(role)? else {
+            return Ok(false);
+        };
+        match self.role_expiries.expires_at(authority, index) {
+            Some(expires_at) => Ok(Clock::get()?.unix_timestamp >= expires_at),
+            None => Ok(false),
+        }
+    }
+
+    /// Get the expiries of time-limited role grants.
+    pub fn role_expiries(&self) -> &RoleGrantExpiries {
+        &self.role_expiries
+    }
+
     /// Grant a role.
     pub fn grant(&mut self, authority: &Pubkey, role: &str) -> Result<()> {
-        self.role.grant(authority, role)
+        self.grant_with_expiry(authority, role, None)
+    }
+
+    /// Grant a role, optionally expiring at the given timestamp.
+    ///
+    /// # Errors
+    /// - `expires_at` must be in the future if provided.
+    pub fn grant_with_expiry(
+        &mut self,
+        authority: &Pubkey,
+        role: &str,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        if let Some(expires_at) = expires_at {
+            require_gt!(
+                expires_at,
+                Clock::get()?.unix_timestamp,
+                CoreError::InvalidArgument
+            );
+        }
+        // An expired grant that has not been pruned is treated as absent.
+        if self.is_role_expired(authority, role)? {
+            self.revoke(authority, role)?;
+        }
+        self.role.grant(authority, role)?;
+        if let Some(expires_at) = expires_at {
+            let index = self
+                .role
+                .role_index(role)?
+                .ok_or_else(|| error!(CoreError::NotFound))?;
+            self.role_expiries.insert(authority, index, expires_at)?;
+        }
+        Ok(())
     }
 
     /// Revoke a role.
     pub fn revoke(&mut self, authority: &Pubkey, role: &str) -> Result<()> {
-        self.role.revoke(authority, role)
+        self.role.revoke(authority, role)?;
+        if let Some(index) = self.role.role_index(role)? {
+            self.role_expiries.remove(authority, index);
+        }
+        Ok(())
+    }
+
+    /// Revoke all roles.
+    pub fn revoke_all(&mut self, authority: &Pubkey) -> Result<Vec<String>> {
+        let roles = self.role.revoke_all(authority)?;
+        self.role_expiries.remove_member(authority);
+        Ok(roles)
+    }
+
+    /// Revoke all expired time-limited role grants.
+    ///
+    /// Returns the number of revoked grants.
+    pub(crate) fn prune_expired_roles(&mut self) -> Result<usize> {
+        let now = Clock::get()?.unix_timestamp;
+        let expired = self.role_expiries.take_expired(now);
+        for entry in expired.iter() {
+            self.role
+                .revoke_by_index(&entry.member, entry.role_index())?;
+        }
+        Ok(expired.len())
     }
 
     /// Check if the given pubkey is the authority of the store.
@@ -183,7 +270,7 @@ impl Store {
         if self.is_authority(authority) {
             Ok(true)
         } else if self.has_restarted()? {
-            self.role.has_role(authority, RoleKey::RESTART_ADMIN)
+            self.has_unexpired_role(authority, RoleKey::RESTART_ADMIN)
         } else {
             Ok(false)
         }
@@ -199,6 +286,16 @@ impl Store {
         Ok(())
     }
 
+    pub(crate) fn cancel_next_authority(&mut self) -> Result<()> {
+        require_keys_neq!(
+            self.authority,
+            self.next_authority,
+            CoreError::PreconditionsAreNotMet
+        );
+        self.next_authority = self.authority;
+        Ok(())
+    }
+
     pub(crate) fn update_authority(&mut self) -> Result<Pubkey> {
         require_keys_neq!(
             self.authority,
@@ -296,6 +393,142 @@ impl Store {
             .ok_or(CoreError::InvalidArgument)
     }
 
+    /// Get the min amount of native tokens to be unwrapped automatically.
+    pub fn min_auto_unwrap_amount(&self) -> u64 {
+        self.amount.min_auto_unwrap_amount
+    }
+
+    /// Get the expiration time of an action last updated at `updated_at`.
+    ///
+    /// Returns `None` if the max action age is `0`.
+    pub fn action_expires_at(&self, updated_at: i64) -> CoreResult<Option<i64>> {
+        let max_age = self.amount.max_action_age;
+        if max_age == 0 {
+            return Ok(None);
+        }
+        let expires_at = updated_at
+            .checked_add_unsigned(max_age)
+            .ok_or(CoreError::ValueOverflow)?;
+        Ok(Some(expires_at))
+    }
+
+    /// Validate that an action last updated at `updated_at` has not exceeded the max action age.
+    ///
+    /// The validation is skipped if the max action age is `0`.
+    pub fn validate_action_age(&self, updated_at: i64) -> CoreResult<()> {
+        let Some(expires_at) = self.action_expires_at(updated_at)? else {
+            return Ok(());
+        };
+        let current = Clock::get()
+            .map_err(|err| {
+                msg!("Failed to get `Clock`. Error Message: {}", err);
+                CoreError::Internal
+            })?
+            .unix_timestamp;
+        if current > expires_at {
+            msg!("the action is too old, expired at {}", expires_at);
+            return Err(CoreError::ActionTooOld);
+        }
+        Ok(())
+    }
+
+    /// Validate that the given timestamp adjustment is within the configured bounds.
+    ///
+    /// The upper bound is not checked if the max timestamp adjustment is `0`.
+    pub fn validate_timestamp_adjustment(&self, timestamp_adjustment: u32) -> CoreResult<()> {
+        let adjustment = Amount::from(timestamp_adjustment);
+        let min = self.amount.min_timestamp_adjustment;
+        let max = self.amount.max_timestamp_adjustment;
+        if adjustment < min || (max != 0 && adjustment > max) {
+            msg!(
+                "timestamp adjustment {} is out of range [{}, {}]",
+                adjustment,
+                min,
+                max
+            );
+            return Err(CoreError::TimestampAdjustmentOutOfRange);
+        }
+        Ok(())
+    }
+
+    /// Get the max number of tokens allowed to be priced in a single price set.
+    ///
+    /// Falls back to [`PriceMap::MAX_TOKENS`] if unset or greater than it.
+    pub fn max_tokens_per_price_set(&self) -> usize {
+        match usize::try_from(self.amount.max_tokens_per_price_set) {
+            Ok(0) | Err(_) => PriceMap::MAX_TOKENS,
+            Ok(max) => max.min(PriceMap::MAX_TOKENS),
+        }
+    }
+
+    /// Get the min GT balance required to initialize a referral code.
+    pub fn min_gt_for_referral_code(&self) -> u64 {
+        self.amount.min_gt_for_referral_code
+    }
+
+    /// Returns whether self-trade prevention for orders is enabled.
+    pub fn is_self_trade_prevention_enabled(&self) -> bool {
+        self.amount.self_trade_prevention != 0
+    }
+
+    /// Get the max number of pending GLV shifts allowed for each GLV. `0` means unlimited.
+    pub fn max_concurrent_glv_shifts(&self) -> u64 {
+        self.amount.max_concurrent_glv_shifts
+    }
+
+    /// Get the lockup period (in seconds) of newly minted GT.
+    ///
+    /// Returns zero if the lockup is disabled.
+    pub fn gt_mint_lockup_seconds(&self) -> u64 {
+        self.amount.gt_mint_lockup_seconds
+    }
+
+    /// Returns whether permissionless confirmation of GT exchange vaults is enabled.
+    pub fn is_gt_vault_auto_confirm_enabled(&self) -> bool {
+        self.amount.gt_vault_auto_confirm != 0
+    }
+
+    /// Get the oracle staleness (max age in seconds) to use for read instructions.
+    ///
+    /// Falls back to the configured default oracle staleness if `max_age` is not provided.
+    ///
+    /// # Errors
+    /// - Error if `max_age` is not provided and the default oracle staleness is not set.
+    pub fn oracle_staleness(&self, max_age: Option<u32>) -> Result<u32> {
+        if let Some(max_age) = max_age {
+            return Ok(max_age);
+        }
+        match self.amount.default_oracle_staleness {
+            0 => {
+                msg!("[Oracle] the default oracle staleness is not set");
+                err!(CoreError::InvalidArgument)
+            }
+            staleness => u32::try_from(staleness).map_err(|_| error!(CoreError::ValueOverflow)),
+        }
+    }
+
+    /// Get the buffer factor for partial liquidations.
+    ///
+    /// Returns `None` if partial liquidation is disabled.
+    pub fn partial_liquidation_buffer_factor(&self) -> Option<Factor> {
+        let factor = self.factor.partial_liquidation_buffer_factor;
+        (factor != 0).then_some(factor)
+    }
+
+    /// Get the tolerance factor for auto-cancelling limit orders whose
+    /// acceptable price cannot be met.
+    ///
+    /// Returns `None` if auto-cancel is disabled.
+    pub fn limit_order_auto_cancel_tolerance(&self) -> Option<Factor> {
+        let factor = self.factor.limit_order_auto_cancel_tolerance;
+        (factor != 0).then_some(factor)
+    }
+
+    /// Get the fraction of paid order fee value recorded as staker rewards of GT holders.
+    pub fn order_fee_to_gt_stakers_factor(&self) -> Factor {
+        self.factor.order_fee_to_gt_stakers_factor
+    }
+
     /// Get claimable time window size.
     pub fn claimable_time_window(&self) -> Result<NonZeroU64> {
         NonZeroU64::new(self.amount.claimable_time_window)
@@ -363,6 +596,84 @@ impl Store {
         &mut self.gt
     }
 
+    /// Validate the total length of the swap paths of a deposit (or a GLV deposit).
+    pub fn validate_deposit_swap_path_length(&self, length: usize) -> Result<()> {
+        require_gte!(
+            Self::max_swap_path_length(self.amount.max_deposit_swap_path_length),
+            length,
+            CoreError::DepositSwapPathTooLong
+        );
+        Ok(())
+    }
+
+    /// Validate the total length of the swap paths of a withdrawal (or a GLV withdrawal).
+    pub fn validate_withdrawal_swap_path_length(&self, length: usize) -> Result<()> {
+        require_gte!(
+            Self::max_swap_path_length(self.amount.max_withdrawal_swap_path_length),
+            length,
+            CoreError::WithdrawalSwapPathTooLong
+        );
+        Ok(())
+    }
+
+    /// Validate the length of the swap path of an order.
+    pub fn validate_order_swap_path_length(&self, length: usize) -> Result<()> {
+        require_gte!(
+            Self::max_swap_path_length(self.amount.max_order_swap_path_length),
+            length,
+            CoreError::OrderSwapPathTooLong
+        );
+        Ok(())
+    }
+
+    /// Falls back to the global max if the configured value is zero.
+    fn max_swap_path_length(configured: Amount) -> usize {
+        match configured {
+            0 => SwapActionParams::MAX_TOTAL_LENGTH,
+            len => usize::try_from(len)
+                .unwrap_or(usize::MAX)
+                .min(SwapActionParams::MAX_TOTAL_LENGTH),
+        }
+    }
+
+    /// Get the max length of encoded referral code strings.
+    ///
+    /// Returns `None` if there is no limit.
+    pub fn max_referral_code_length(&self) -> Option<usize> {
+        match self.amount.max_referral_code_length {
+            0 => None,
+            len => Some(usize::try_from(len).unwrap_or(usize::MAX)),
+        }
+    }
+
+    /// Get the charset of encoded referral code strings.
+    pub fn referral_code_charset(&self) -> Result<ReferralCodeCharset> {
+        u8::try_from(self.amount.referral_code_charset)
+            .ok()
+            .and_then(|charset| ReferralCodeCharset::try_from(charset).ok())
+            .ok_or_else(|| error!(CoreError::InvalidArgument))
+    }
+
+    /// Get the referral code blocklist.
+    pub fn referral_code_blocklist(&self) -> &ReferralCodeBlocklist {
+        &self.referral_code_blocklist
+    }
+
+    /// Get the referral code blocklist mutably.
+    pub(crate) fn referral_code_blocklist_mut(&mut self) -> &mut ReferralCodeBlocklist {
+        &mut self.referral_code_blocklist
+    }
+
+    /// Get the rent recipient allowlist.
+    pub fn rent_recipient_allowlist(&self) -> &RentRecipientAllowlist {
+        &self.rent_recipient_allowlist
+    }
+
+    /// Get the rent recipient allowlist mutably.
+    pub(crate) fn rent_recipient_allowlist_mut(&mut self) -> &mut RentRecipientAllowlist {
+        &mut self.rent_recipient_allowlist
+    }
+
     /// Get feature disabled.
     pub fn get_feature_disabled(
         &self,
@@ -550,8 +861,30 @@ pub struct Amounts {
     pub(crate) oracle_max_timestamp_range: Amount,
     pub(crate) oracle_max_future_timestamp_excess: Amount,
     pub(crate) adl_prices_max_staleness: Amount,
+    pub(crate) min_auto_unwrap_amount: Amount,
+    pub(crate) max_action_age: Amount,
+    pub(crate) min_timestamp_adjustment: Amount,
+    pub(crate) max_timestamp_adjustment: Amount,
+    pub(crate) max_tokens_per_price_set: Amount,
+    pub(crate) self_trade_prevention: Amount,
+    pub(crate) min_gt_for_referral_code: Amount,
+    pub(crate) default_oracle_staleness: Amount,
+    pub(crate) gt_vault_auto_confirm: Amount,
+    pub(crate) max_concurrent_glv_shifts: Amount,
+    pub(crate) gt_mint_lockup_seconds: Amount,
+    pub(crate) max_referral_code_length: Amount,
+    pub(crate) referral_code_charset: Amount,
+    pub(crate) max_deposit_swap_path_length: Amount,
+    pub(crate) max_withdrawal_swap_path_length: Amount,
+    pub(crate) max_order_swap_path_length: Amount,
+    pub(crate) max_custom_price_feeds_per_token: Amount,
+    pub(crate) min_market_config_buffer_expire_after: Amount,
+    pub(crate) max_market_config_buffer_expire_after: Amount,
+    pub(crate) max_chainlink_report_size: Amount,
+    pub(crate) max_pending_actions_per_user: Amount,
+    pub(crate) fair_market_order_execution: Amount,
     #[cfg_attr(feature = "debug", debug(skip))]
-    reserved: [Amount; 126],
+    reserved: [Amount; 104],
 }
 
 impl Amounts {
@@ -564,6 +897,30 @@ impl Amounts {
         self.oracle_max_future_timestamp_excess =
             constants::DEFAULT_ORACLE_MAX_FUTURE_TIMESTAMP_EXCESS;
         self.adl_prices_max_staleness = constants::DEFAULT_ADL_PRICES_MAX_STALENESS;
+        self.min_auto_unwrap_amount = constants::DEFAULT_MIN_AUTO_UNWRAP_AMOUNT;
+        self.max_action_age = constants::DEFAULT_MAX_ACTION_AGE;
+        self.min_timestamp_adjustment = constants::DEFAULT_MIN_TIMESTAMP_ADJUSTMENT;
+        self.max_timestamp_adjustment = constants::DEFAULT_MAX_TIMESTAMP_ADJUSTMENT;
+        self.max_tokens_per_price_set = constants::DEFAULT_MAX_TOKENS_PER_PRICE_SET;
+        self.self_trade_prevention = constants::DEFAULT_SELF_TRADE_PREVENTION;
+        self.min_gt_for_referral_code = constants::DEFAULT_MIN_GT_FOR_REFERRAL_CODE;
+        self.default_oracle_staleness = constants::DEFAULT_DEFAULT_ORACLE_STALENESS;
+        self.gt_vault_auto_confirm = constants::DEFAULT_GT_VAULT_AUTO_CONFIRM;
+        self.max_concurrent_glv_shifts = constants::DEFAULT_MAX_CONCURRENT_GLV_SHIFTS;
+        self.gt_mint_lockup_seconds = constants::DEFAULT_GT_MINT_LOCKUP_SECONDS;
+        self.max_referral_code_length = constants::DEFAULT_MAX_REFERRAL_CODE_LENGTH;
+        self.referral_code_charset = constants::DEFAULT_REFERRAL_CODE_CHARSET;
+        self.max_deposit_swap_path_length = constants::DEFAULT_MAX_DEPOSIT_SWAP_PATH_LENGTH;
+        self.max_withdrawal_swap_path_length = constants::DEFAULT_MAX_WITHDRAWAL_SWAP_PATH_LENGTH;
+        self.max_order_swap_path_length = constants::DEFAULT_MAX_ORDER_SWAP_PATH_LENGTH;
+        self.max_custom_price_feeds_per_token = constants::DEFAULT_MAX_CUSTOM_PRICE_FEEDS_PER_TOKEN;
+        self.min_market_config_buffer_expire_after =
+            constants::DEFAULT_MIN_MARKET_CONFIG_BUFFER_EXPIRE_AFTER;
+        self.max_market_config_buffer_expire_after =
+            constants::DEFAULT_MAX_MARKET_CONFIG_BUFFER_EXPIRE_AFTER;
+        self.max_chainlink_report_size = constants::DEFAULT_MAX_CHAINLINK_REPORT_SIZE;
+        self.max_pending_actions_per_user = constants::DEFAULT_MAX_PENDING_ACTIONS_PER_USER;
+        self.fair_market_order_execution = constants::DEFAULT_FAIR_MARKET_ORDER_EXECUTION;
     }
 
     /// Get.
@@ -576,6 +933,32 @@ impl Amounts {
             AmountKey::OracleMaxTimestampRange => &self.oracle_max_timestamp_range,
             AmountKey::OracleMaxFutureTimestampExcess => &self.oracle_max_future_timestamp_excess,
             AmountKey::AdlPricesMaxStaleness => &self.adl_prices_max_staleness,
+            AmountKey::MinAutoUnwrapAmount => &self.min_auto_unwrap_amount,
+            AmountKey::MaxActionAge => &self.max_action_age,
+            AmountKey::MinTimestampAdjustment => &self.min_timestamp_adjustment,
+            AmountKey::MaxTimestampAdjustment => &self.max_timestamp_adjustment,
+            AmountKey::MaxTokensPerPriceSet => &self.max_tokens_per_price_set,
+            AmountKey::SelfTradePrevention => &self.self_trade_prevention,
+            AmountKey::MinGtForReferralCode => &self.min_gt_for_referral_code,
+            AmountKey::DefaultOracleStaleness => &self.default_oracle_staleness,
+            AmountKey::GtVaultAutoConfirm => &self.gt_vault_auto_confirm,
+            AmountKey::MaxConcurrentGlvShifts => &self.max_concurrent_glv_shifts,
+            AmountKey::GtMintLockupSeconds => &self.gt_mint_lockup_seconds,
+            AmountKey::MaxReferralCodeLength => &self.max_referral_code_length,
+            AmountKey::ReferralCodeCharset => &self.referral_code_charset,
+            AmountKey::MaxDepositSwapPathLength => &self.max_deposit_swap_path_length,
+            AmountKey::MaxWithdrawalSwapPathLength => &self.max_withdrawal_swap_path_length,
+            AmountKey::MaxOrderSwapPathLength => &self.max_order_swap_path_length,
+            AmountKey::MaxCustomPriceFeedsPerToken => &self.max_custom_price_feeds_per_token,
+            AmountKey::MinMarketConfigBufferExpireAfter => {
+                &self.min_market_config_buffer_expire_after
+            }
+            AmountKey::MaxMarketConfigBufferExpireAfter => {
+                &self.max_market_config_buffer_expire_after
+            }
+            AmountKey::MaxChainlinkReportSize => &self.max_chainlink_report_size,
+            AmountKey::MaxPendingActionsPerUser => &self.max_pending_actions_per_user,
+            AmountKey::FairMarketOrderExecution => &self.fair_market_order_execution,
             _ => return None,
         };
         Some(value)
@@ -593,6 +976,32 @@ impl Amounts {
                 &mut self.oracle_max_future_timestamp_excess
             }
             AmountKey::AdlPricesMaxStaleness => &mut self.adl_prices_max_staleness,
+            AmountKey::MinAutoUnwrapAmount => &mut self.min_auto_unwrap_amount,
+            AmountKey::MaxActionAge => &mut self.max_action_age,
+            AmountKey::MinTimestampAdjustment => &mut self.min_timestamp_adjustment,
+            AmountKey::MaxTimestampAdjustment => &mut self.max_timestamp_adjustment,
+            AmountKey::MaxTokensPerPriceSet => &mut self.max_tokens_per_price_set,
+            AmountKey::SelfTradePrevention => &mut self.self_trade_prevention,
+            AmountKey::MinGtForReferralCode => &mut self.min_gt_for_referral_code,
+            AmountKey::DefaultOracleStaleness => &mut self.default_oracle_staleness,
+            AmountKey::GtVaultAutoConfirm => &mut self.gt_vault_auto_confirm,
+            AmountKey::MaxConcurrentGlvShifts => &mut self.max_concurrent_glv_shifts,
+            AmountKey::GtMintLockupSeconds => &mut self.gt_mint_lockup_seconds,
+            AmountKey::MaxReferralCodeLength => &mut self.max_referral_code_length,
+            AmountKey::ReferralCodeCharset => &mut self.referral_code_charset,
+            AmountKey::MaxDepositSwapPathLength => &mut self.max_deposit_swap_path_length,
+            AmountKey::MaxWithdrawalSwapPathLength => &mut self.max_withdrawal_swap_path_length,
+            AmountKey::MaxOrderSwapPathLength => &mut self.max_order_swap_path_length,
+            AmountKey::MaxCustomPriceFeedsPerToken => &mut self.max_custom_price_feeds_per_token,
+            AmountKey::MinMarketConfigBufferExpireAfter => {
+                &mut self.min_market_config_buffer_expire_after
+            }
+            AmountKey::MaxMarketConfigBufferExpireAfter => {
+                &mut self.max_market_config_buffer_expire_after
+            }
+            AmountKey::MaxChainlinkReportSize => &mut self.max_chainlink_report_size,
+            AmountKey::MaxPendingActionsPerUser => &mut self.max_pending_actions_per_user,
+            AmountKey::FairMarketOrderExecution => &mut self.fair_market_order_execution,
             _ => return None,
         };
         Some(value)
@@ -605,13 +1014,23 @@ impl Amounts {
 pub struct Factors {
     pub(crate) oracle_ref_price_deviation: Factor,
     pub(crate) order_fee_discount_for_referred_user: Factor,
+    pub(crate) partial_liquidation_buffer_factor: Factor,
+    pub(crate) limit_order_auto_cancel_tolerance: Factor,
+    pub(crate) order_fee_to_gt_stakers_factor: Factor,
+    pub(crate) max_pyth_confidence_ratio: Factor,
     #[cfg_attr(feature = "debug", debug(skip))]
-    reserved: [Factor; 64],
+    reserved: [Factor; 60],
 }
 
 impl Factors {
     fn init(&mut self) {
         self.oracle_ref_price_deviation = constants::DEFAULT_ORACLE_REF_PRICE_DEVIATION;
+        self.partial_liquidation_buffer_factor =
+            constants::DEFAULT_PARTIAL_LIQUIDATION_BUFFER_FACTOR;
+        self.limit_order_auto_cancel_tolerance =
+            constants::DEFAULT_LIMIT_ORDER_AUTO_CANCEL_TOLERANCE;
+        self.order_fee_to_gt_stakers_factor = constants::DEFAULT_ORDER_FEE_TO_GT_STAKERS_FACTOR;
+        self.max_pyth_confidence_ratio = constants::DEFAULT_MAX_PYTH_CONFIDENCE_RATIO;
     }
 
     /// Get.
@@ -621,6 +1040,10 @@ impl Factors {
             FactorKey::OrderFeeDiscountForReferredUser => {
                 &self.order_fee_discount_for_referred_user
             }
+            FactorKey::PartialLiquidationBufferFactor => &self.partial_liquidation_buffer_factor,
+            FactorKey::LimitOrderAutoCancelTolerance => &self.limit_order_auto_cancel_tolerance,
+            FactorKey::OrderFeeToGtStakersFactor => &self.order_fee_to_gt_stakers_factor,
+            FactorKey::MaxPythConfidenceRatio => &self.max_pyth_confidence_ratio,
             _ => return None,
         };
         Some(value)
@@ -633,6 +1056,12 @@ impl Factors {
             FactorKey::OrderFeeDiscountForReferredUser => {
                 &mut self.order_fee_discount_for_referred_user
             }
+            FactorKey::PartialLiquidationBufferFactor => {
+                &mut self.partial_liquidation_buffer_factor
+            }
+            FactorKey::LimitOrderAutoCancelTolerance => &mut self.limit_order_auto_cancel_tolerance,
+            FactorKey::OrderFeeToGtStakersFactor => &mut self.order_fee_to_gt_stakers_factor,
+            FactorKey::MaxPythConfidenceRatio => &mut self.max_pyth_confidence_ratio,
             _ => return None,
         };
         Some(value)
@@ -671,3 +1100,54 @@ impl Addresses {
         Some(value)
     }
 }
+
+/// Max number of allowed rent recipients.
+pub const MAX_RENT_RECIPIENTS: usize = 8;
+
+/// Rent Recipient Allowlist.
+#[zero_copy]
+#[cfg_attr(feature = "debug", derive(derive_more::Debug))]
+pub struct RentRecipientAllowlist {
+    len: u8,
+    #[cfg_attr(feature = "debug", debug(skip))]
+    padding_0: [u8; 7],
+    recipients: [Pubkey; MAX_RENT_RECIPIENTS],
+}
+
+impl RentRecipientAllowlist {
+    /// Get the allowed recipients.
+    pub fn recipients(&self) -> &[Pubkey] {
+        &self.recipients[..usize::from(self.len)]
+    }
+
+    /// Returns whether the given address is an allowed rent recipient.
+    pub fn is_allowed(&self, recipient: &Pubkey) -> bool {
+        self.recipients().contains(recipient)
+    }
+
+    /// Allow or disallow the given recipient.
+    ///
+    /// Returns whether the allowlist is changed.
+    pub(crate) fn set_allowed(&mut self, recipient: &Pubkey, allowed: bool) -> Result<bool> {
+        require!(*recipient != Pubkey::default(), CoreError::InvalidArgument);
+        let len = usize::from(self.len);
+        match (
+            self.recipients().iter().position(|r| r == recipient),
+            allowed,
+        ) {
+            (Some(_), true) | (None, false) => Ok(false),
+            (None, true) => {
+                require_gt!(MAX_RENT_RECIPIENTS, len, CoreError::ExceedMaxLengthLimit);
+                self.recipients[len] = *recipient;
+                self.len += 1;
+                Ok(true)
+            }
+            (Some(idx), false) => {
+                self.recipients.copy_within((idx + 1)..len, idx);
+                self.recipients[len - 1] = Pubkey::default();
+                self.len -= 1;
+                Ok(true)
+            }
+        }
+    }
+}
@@ -109,6 +109,10 @@ impl TokenConfigExt for TokenConfig {
             .try_into()
             .map_err(|_| error!(CoreError::InvalidArgument))?;
         self.expected_provider = expected_provider.unwrap_or(PriceProviderKind::default() as u8);
+        require!(
+            self.is_provider_allowed(&self.expected_provider().map_err(CoreError::from)?),
+            CoreError::PriceProviderNotAllowed
+        );
         self.heartbeat_duration = heartbeat_duration;
         Ok(())
     }
@@ -188,6 +192,11 @@ impl TokenMapHeader {
         self.tokens.is_empty()
     }
 
+    /// Get the max number of tokens.
+    pub fn capacity(&self) -> usize {
+        self.tokens.capacity()
+    }
+
     fn get_token_config_unchecked<'a>(
         &self,
         token: &Pubkey,
@@ -258,6 +267,13 @@ impl<'info> TokenMapLoader<'info> for AccountLoader<'info, TokenMapHeader> {
     }
 }
 
+impl TokenMapRef<'_> {
+    /// Get tokens.
+    pub fn tokens(&self) -> impl Iterator<Item = Pubkey> + '_ {
+        self.header.tokens()
+    }
+}
+
 impl TokenMapAccess for TokenMapRef<'_> {
     fn get(&self, token: &Pubkey) -> Option<&TokenConfig> {
         self.header.get_token_config_unchecked(token, &self.configs)
@@ -367,6 +383,11 @@ pub mod utils {
             self.header.len()
         }
 
+        /// Get the max number of tokens in the map.
+        pub fn capacity(&self) -> usize {
+            self.header.capacity()
+        }
+
         /// Get all tokens.
         pub fn tokens(&self) -> impl Iterator<Item = Pubkey> + '_ {
             self.header.tokens()
@@ -31,9 +31,11 @@ pub struct UserHeader {
     pub(crate) referral: Referral,
     /// GT State.
     pub(crate) gt: UserGtState,
+    /// The number of pending actions created by the user.
+    pub(crate) num_pending_actions: u64,
     #[cfg_attr(feature = "debug", debug(skip))]
     #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
-    reserved: [u8; 128],
+    reserved: [u8; 120],
 }
 
 gmsol_utils::flags!(UserFlag, MAX_USER_FLAGS, u8);
@@ -69,6 +71,38 @@ impl UserHeader {
         &self.referral
     }
 
+    /// Get the number of pending actions created by the user.
+    pub fn num_pending_actions(&self) -> u64 {
+        self.num_pending_actions
+    }
+
+    /// Record a new pending action, validating against the given max number of
+    /// pending actions (`0` means unlimited).
+    pub(crate) fn add_pending_action(&mut self, max_pending_actions: u64) -> Result<()> {
+        let next = self
+            .num_pending_actions
+            .checked_add(1)
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        if max_pending_actions != 0 {
+            require_gte!(
+                max_pending_actions,
+                next,
+                CoreError::MaxPendingActionsExceeded
+            );
+        }
+        self.num_pending_actions = next;
+        Ok(())
+    }
+
+    /// Record the removal of a pending action.
+    pub(crate) fn remove_pending_action(&mut self) -> Result<()> {
+        self.num_pending_actions = self
+            .num_pending_actions
+            .checked_sub(1)
+            .ok_or_else(|| error!(CoreError::Internal))?;
+        Ok(())
+    }
+
     /// Transfer the ownership of the given code from this user to the receiver.
     /// # CHECK
     /// - `code` must be owned by current user.
@@ -209,6 +243,66 @@ impl Referral {
     pub fn code(&self) -> Option<&Pubkey> {
         optional_address(&self.code)
     }
+
+    /// Get the number of direct referees.
+    pub fn referee_count(&self) -> u128 {
+        self.referee_count
+    }
+}
+
+/// Max number of blocked referral codes.
+pub const MAX_BLOCKED_REFERRAL_CODES: usize = 32;
+
+/// Referral Code Blocklist.
+#[zero_copy]
+#[cfg_attr(feature = "debug", derive(derive_more::Debug))]
+pub struct ReferralCodeBlocklist {
+    len: u8,
+    #[cfg_attr(feature = "debug", debug(skip))]
+    padding_0: [u8; 7],
+    codes: [ReferralCodeBytes; MAX_BLOCKED_REFERRAL_CODES],
+}
+
+impl ReferralCodeBlocklist {
+    /// Get the blocked codes.
+    pub fn codes(&self) -> &[ReferralCodeBytes] {
+        &self.codes[..usize::from(self.len)]
+    }
+
+    /// Returns whether the given code is blocked.
+    pub fn is_blocked(&self, code: &ReferralCodeBytes) -> bool {
+        self.codes().contains(code)
+    }
+
+    /// Block or unblock the given code.
+    ///
+    /// Returns whether the blocklist is changed.
+    pub(crate) fn set_blocked(&mut self, code: &ReferralCodeBytes, blocked: bool) -> Result<bool> {
+        require!(
+            *code != ReferralCodeBytes::default(),
+            CoreError::InvalidArgument
+        );
+        let len = usize::from(self.len);
+        match (self.codes().iter().position(|c| c == code), blocked) {
+            (Some(_), true) | (None, false) => Ok(false),
+            (None, true) => {
+                require_gt!(
+                    MAX_BLOCKED_REFERRAL_CODES,
+                    len,
+                    CoreError::ExceedMaxLengthLimit
+                );
+                self.codes[len] = *code;
+                self.len += 1;
+                Ok(true)
+            }
+            (Some(idx), false) => {
+                self.codes.copy_within((idx + 1)..len, idx);
+                self.codes[len - 1] = ReferralCodeBytes::default();
+                self.len -= 1;
+                Ok(true)
+            }
+        }
+    }
 }
 
 /// Referral Code.
@@ -236,6 +330,44 @@ impl ReferralCodeV2 {
     /// The length of referral code.
     pub const LEN: usize = std::mem::size_of::<ReferralCodeBytes>();
 
+    /// The max length of the encoded code string.
+    const MAX_ENCODED_LEN: usize = 11;
+
+    /// The alphabet of the base58 encoding.
+    const BASE58_ALPHABET: &'static [u8; 58] =
+        b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    /// Validate the given code bytes with the given constraints.
+    ///
+    /// The constraints are checked against the encoded code string, i.e., the base58 encoding
+    /// of the code bytes without the leading `1`s, which is the form shown to users.
+    pub(crate) fn validate_code(
+        code: &ReferralCodeBytes,
+        max_len: Option<usize>,
+        charset: ReferralCodeCharset,
+    ) -> Result<()> {
+        let mut value = u64::from_be_bytes(*code);
+        require_neq!(value, 0, CoreError::InvalidArgument);
+
+        let mut encoded = [0u8; Self::MAX_ENCODED_LEN];
+        let mut start = encoded.len();
+        while value != 0 {
+            start -= 1;
+            encoded[start] = Self::BASE58_ALPHABET[(value % 58) as usize];
+            value /= 58;
+        }
+        let code = &encoded[start..];
+
+        if let Some(max_len) = max_len {
+            require_gte!(max_len, code.len(), CoreError::InvalidArgument);
+        }
+        require!(
+            code.iter().all(|byte| charset.contains(*byte)),
+            CoreError::InvalidArgument
+        );
+        Ok(())
+    }
+
     pub(crate) fn init(
         &mut self,
         bump: u8,
@@ -300,6 +432,32 @@ impl Seed for ReferralCodeV2 {
     const SEED: &'static [u8] = b"referral_code";
 }
 
+/// Charset of encoded referral code strings.
+#[derive(Clone, Copy, Default, PartialEq, Eq, num_enum::TryFromPrimitive)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum ReferralCodeCharset {
+    /// Any characters.
+    #[default]
+    Any,
+    /// ASCII alphanumeric characters.
+    Alphanumeric,
+    /// ASCII uppercase letters and digits.
+    UppercaseAlphanumeric,
+}
+
+impl ReferralCodeCharset {
+    /// Returns whether the given character is in the charset.
+    pub fn contains(&self, byte: u8) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Alphanumeric => byte.is_ascii_alphanumeric(),
+            Self::UppercaseAlphanumeric => byte.is_ascii_uppercase() || byte.is_ascii_digit(),
+        }
+    }
+}
+
 /// GT State.
 #[zero_copy]
 #[cfg_attr(feature = "debug", derive(derive_more::Debug))]
@@ -310,13 +468,17 @@ pub struct UserGtState {
     pub(crate) last_minted_at: i64,
     pub(crate) total_minted: u64,
     pub(crate) amount: u64,
+    pub(crate) locked_amount: u64,
+    pub(crate) unlock_at: i64,
     #[cfg_attr(feature = "debug", debug(skip))]
-    padding_1: [u8; 32],
+    padding_1: [u8; 16],
     pub(crate) paid_fee_value: u128,
     pub(crate) minted_fee_value: u128,
+    pub(crate) staker_reward_value_per_gt_checkpoint: u128,
+    pub(crate) accrued_staker_reward_value: u128,
     #[cfg_attr(feature = "debug", debug(skip))]
     #[cfg_attr(feature = "serde", serde(with = "serde_bytes"))]
-    reserved: [u8; 64],
+    reserved: [u8; 32],
 }
 
 impl UserGtState {
@@ -330,6 +492,11 @@ impl UserGtState {
         self.minted_fee_value
     }
 
+    /// Get the staker reward value that has been settled for this user.
+    pub fn accrued_staker_reward_value(&self) -> u128 {
+        self.accrued_staker_reward_value
+    }
+
     /// Get current rank.
     pub fn rank(&self) -> u8 {
         self.rank
@@ -339,4 +506,207 @@ impl UserGtState {
     pub fn amount(&self) -> u64 {
         self.amount
     }
+
+    /// Get the timestamp at which the locked GT becomes spendable.
+    pub fn unlock_at(&self) -> i64 {
+        self.unlock_at
+    }
+
+    /// Get the amount of GT that is still locked at the given timestamp.
+    pub fn locked_amount(&self, now: i64) -> u64 {
+        if now < self.unlock_at {
+            self.locked_amount
+        } else {
+            0
+        }
+    }
+
+    /// Get the amount of GT that is spendable at the given timestamp.
+    pub fn unlocked_amount(&self, now: i64) -> u64 {
+        self.amount.saturating_sub(self.locked_amount(now))
+    }
+
+    /// Lock the given amount of newly minted GT until `lockup_seconds` after `now`.
+    ///
+    /// The GT that is still locked is merged with the newly locked GT, and the new unlock
+    /// timestamp is the average of their unlock timestamps weighted by amount (rounded up),
+    /// so that frequent mints do not keep postponing the unlock of earlier minted GT.
+    pub(crate) fn lock(&mut self, amount: u64, now: i64, lockup_seconds: u64) -> Result<()> {
+        if amount == 0 || lockup_seconds == 0 {
+            return Ok(());
+        }
+        let locked_amount = self.locked_amount(now);
+        let next_locked_amount = locked_amount
+            .checked_add(amount)
+            .ok_or_else(|| error!(CoreError::TokenAmountOverflow))?;
+        // `unlock_at` is after `now` if there is still locked GT.
+        let remaining_seconds = if locked_amount == 0 {
+            0
+        } else {
+            self.unlock_at.abs_diff(now)
+        };
+        let weighted_lockup_seconds = u128::from(remaining_seconds)
+            .checked_mul(u128::from(locked_amount))
+            .and_then(|locked| {
+                u128::from(lockup_seconds)
+                    .checked_mul(u128::from(amount))?
+                    .checked_add(locked)
+            })
+            .map(|total| total.div_ceil(u128::from(next_locked_amount)))
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        let next_unlock_at = i64::try_from(weighted_lockup_seconds)
+            .ok()
+            .and_then(|lockup| now.checked_add(lockup))
+            .ok_or_else(|| error!(CoreError::ValueOverflow))?;
+        self.locked_amount = next_locked_amount;
+        self.unlock_at = next_unlock_at;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::Zeroable;
+
+    use super::*;
+
+    #[test]
+    fn block_and_unblock_referral_codes() {
+        let mut blocklist = ReferralCodeBlocklist::zeroed();
+        let code_1 = *b"code0001";
+        let code_2 = *b"code0002";
+
+        assert!(blocklist
+            .set_blocked(&ReferralCodeBytes::default(), true)
+            .is_err());
+        assert!(blocklist.set_blocked(&code_1, true).unwrap());
+        assert!(!blocklist.set_blocked(&code_1, true).unwrap());
+        assert!(blocklist.set_blocked(&code_2, true).unwrap());
+        assert_eq!(blocklist.codes(), &[code_1, code_2]);
+
+        assert!(blocklist.set_blocked(&code_1, false).unwrap());
+        assert!(!blocklist.set_blocked(&code_1, false).unwrap());
+        assert!(!blocklist.is_blocked(&code_1));
+        assert!(blocklist.is_blocked(&code_2));
+        assert_eq!(blocklist.codes(), &[code_2]);
+
+        for idx in 0..(MAX_BLOCKED_REFERRAL_CODES - 1) {
+            let mut code = *b"filled00";
+            code[7] = idx as u8;
+            assert!(blocklist.set_blocked(&code, true).unwrap());
+        }
+        assert!(blocklist.set_blocked(&code_1, true).is_err());
+    }
+
+    #[test]
+    fn lock_newly_minted_gt_with_weighted_unlock_time() {
+        let mut state = UserGtState::zeroed();
+        state.amount = 300;
+
+        state.lock(100, 1_000, 100).unwrap();
+        assert_eq!(state.unlock_at(), 1_100);
+        assert_eq!(state.locked_amount(1_000), 100);
+        assert_eq!(state.unlocked_amount(1_000), 200);
+
+        // Minting again does not reset the unlock time of the earlier minted GT.
+        state.lock(100, 1_050, 100).unwrap();
+        assert_eq!(state.unlock_at(), 1_125);
+        assert_eq!(state.locked_amount(1_124), 200);
+        assert_eq!(state.locked_amount(1_125), 0);
+        assert_eq!(state.unlocked_amount(1_125), 300);
+
+        // The weighted unlock time is rounded up.
+        state.lock(2, 1_200, 100).unwrap();
+        state.lock(1, 1_201, 100).unwrap();
+        assert_eq!(state.unlock_at(), 1_301);
+
+        // GT that has been unlocked is not locked again.
+        state.lock(10, 2_000, 100).unwrap();
+        assert_eq!(state.unlock_at(), 2_100);
+        assert_eq!(state.locked_amount(2_000), 10);
+
+        // Zero lockup disables locking.
+        state.lock(10, 2_000, 0).unwrap();
+        assert_eq!(state.locked_amount(2_000), 10);
+    }
+
+    #[test]
+    fn validate_referral_code() {
+        let validate = |code: &[u8; 8], max_len, charset| {
+            ReferralCodeV2::validate_code(code, max_len, charset).is_ok()
+        };
+        let decode = |code: &str| {
+            code.bytes()
+                .fold(0u64, |value, byte| {
+                    let digit = ReferralCodeV2::BASE58_ALPHABET
+                        .iter()
+                        .position(|c| *c == byte)
+                        .unwrap();
+                    value * 58 + digit as u64
+                })
+                .to_be_bytes()
+        };
+
+        assert!(!validate(&[0; 8], None, ReferralCodeCharset::Any));
+        assert!(validate(
+            &[0, 0, 0, 0, 0xff, 1, 2, 3],
+            None,
+            ReferralCodeCharset::Any
+        ));
+
+        assert!(validate(
+            &decode("Gmx12"),
+            Some(5),
+            ReferralCodeCharset::Alphanumeric
+        ));
+        assert!(!validate(
+            &decode("Gmx12"),
+            Some(4),
+            ReferralCodeCharset::Alphanumeric
+        ));
+        assert!(!validate(
+            &decode("Gmx12"),
+            None,
+            ReferralCodeCharset::UppercaseAlphanumeric
+        ));
+        assert!(validate(
+            &decode("GMX12"),
+            Some(5),
+            ReferralCodeCharset::UppercaseAlphanumeric
+        ));
+
+        // The constraints apply to the encoded string, not the raw bytes.
+        assert!(!validate(
+            b"\0\0\0GMX12",
+            Some(5),
+            ReferralCodeCharset::UppercaseAlphanumeric
+        ));
+    }
+
+    #[test]
+    fn lock_minted_gt() {
+        let mut gt = UserGtState::zeroed();
+        gt.amount = 100;
+        gt.lock(100, 10, 0).unwrap();
+        assert_eq!(gt.unlocked_amount(10), 100);
+
+        gt.amount = 150;
+        gt.lock(50, 10, 60).unwrap();
+        assert_eq!(gt.locked_amount(10), 50);
+        assert_eq!(gt.unlocked_amount(10), 100);
+
+        gt.amount = 180;
+        gt.lock(30, 40, 60).unwrap();
+        // ceil((30 * 50 + 60 * 30) / 80) = 42
+        assert_eq!(gt.unlock_at(), 82);
+        assert_eq!(gt.locked_amount(81), 80);
+        assert_eq!(gt.unlocked_amount(81), 100);
+        assert_eq!(gt.locked_amount(82), 0);
+        assert_eq!(gt.unlocked_amount(82), 180);
+
+        gt.amount = 200;
+        gt.lock(20, 100, 60).unwrap();
+        assert_eq!(gt.locked_amount(100), 20);
+        assert_eq!(gt.unlocked_amount(100), 180);
+    }
 }
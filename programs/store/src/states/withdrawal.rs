@@ -4,7 +4,7 @@ use crate::{events::WithdrawalRemoved, CoreError};
 
 use super::{
     common::{
-        action::{Action, ActionHeader, Closable},
+        action::{Action, ActionHeader, Closable, CloseReason},
         swap::SwapActionParams,
         token::TokenAndAccount,
     },
@@ -63,7 +63,12 @@ impl Action for Withdrawal {
 impl Closable for Withdrawal {
     type ClosedEvent = WithdrawalRemoved;
 
-    fn to_closed_event(&self, address: &Pubkey, reason: &str) -> Result<Self::ClosedEvent> {
+    fn to_closed_event(
+        &self,
+        address: &Pubkey,
+        reason: &str,
+        close_reason: CloseReason,
+    ) -> Result<Self::ClosedEvent> {
         WithdrawalRemoved::new(
             self.header.id,
             self.header.store,
@@ -72,6 +77,7 @@ impl Closable for Withdrawal {
             self.header.owner,
             self.header.action_state()?,
             reason,
+            close_reason,
         )
     }
 }
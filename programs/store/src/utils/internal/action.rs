@@ -3,8 +3,8 @@ use anchor_lang::{prelude::*, ZeroCopy};
 use crate::{
     events::EventEmitter,
     states::{
-        common::action::{Action, ActionParams, Closable},
-        NonceBytes, StoreWalletSigner,
+        common::action::{Action, ActionHeader, ActionParams, Closable, CloseReason},
+        AmountKey, NonceBytes, Store, StoreWalletSigner, UserHeader,
     },
     CoreError,
 };
@@ -83,6 +83,25 @@ pub(crate) trait Create<'info, A>: Sized + anchor_lang::Bumps {
     }
 }
 
+/// Record the action with the given header as a pending action of the owner.
+///
+/// # Errors
+/// - The number of pending actions of the owner would exceed the max pending actions
+///   per user configured in the `store`.
+pub(crate) fn record_pending_action(
+    store: &AccountLoader<Store>,
+    user: &AccountLoader<UserHeader>,
+    header: &mut ActionHeader,
+) -> Result<()> {
+    let max_pending_actions = *store
+        .load()?
+        .get_amount_by_key(AmountKey::MaxPendingActionsPerUser)
+        .ok_or_else(|| error!(CoreError::Unimplemented))?;
+    user.load_mut()?.add_pending_action(max_pending_actions)?;
+    header.set_pending_action_recorded(true);
+    Ok(())
+}
+
 type IsCallerOwner = bool;
 pub(crate) type Success = bool;
 
@@ -97,12 +116,22 @@ where
     /// Rent receiver.
     fn rent_receiver(&self) -> AccountInfo<'info>;
 
+    /// Optional rent recipient of the action account specified by the caller.
+    fn rent_recipient(&self) -> Option<AccountInfo<'info>> {
+        None
+    }
+
     /// Get event authority.
     fn event_authority(&self, bumps: &Self::Bumps) -> (AccountInfo<'info>, u8);
 
     /// Get store wallet bump.
     fn store_wallet_bump(&self, bumps: &Self::Bumps) -> u8;
 
+    /// The user account of the owner.
+    ///
+    /// Must be provided if the action has been recorded as a pending action of the owner.
+    fn user(&self) -> Option<&AccountLoader<'info, UserHeader>>;
+
     /// Whether to skip the completion check when the authority is keeper.
     fn skip_completion_check_for_keeper(&self) -> Result<bool> {
         Ok(false)
@@ -120,10 +149,31 @@ where
     ) -> Result<Success>;
 
     /// Close Action.
-    fn close(ctx: &Context<'_, '_, '_, 'info, Self>, reason: &str) -> Result<()> {
+    ///
+    /// The `close_reason` is always recorded as [`CloseReason::UserCancel`] when the owner
+    /// closes a pending action.
+    fn close(
+        ctx: &Context<'_, '_, '_, 'info, Self>,
+        reason: &str,
+        close_reason: CloseReason,
+    ) -> Result<()> {
         let accounts = &ctx.accounts;
         accounts.validate()?;
         let is_caller_owner = accounts.preprocess()?;
+        let action_rent_receiver = accounts.action_rent_receiver(is_caller_owner)?;
+
+        let close_reason = if is_caller_owner
+            && accounts
+                .action()
+                .load()?
+                .header()
+                .action_state()?
+                .is_pending()
+        {
+            CloseReason::UserCancel
+        } else {
+            close_reason
+        };
 
         let store_wallet_signer = StoreWalletSigner::new(
             accounts.store().key(),
@@ -134,13 +184,14 @@ where
         let event_emitter = EventEmitter::new(&authority, bump);
 
         if accounts.process(is_caller_owner, &store_wallet_signer, &event_emitter)? {
+            accounts.remove_pending_action()?;
             {
                 let action_address = accounts.action().key();
                 let action = accounts.action().load()?;
-                let event = action.to_closed_event(&action_address, reason)?;
+                let event = action.to_closed_event(&action_address, reason, close_reason)?;
                 event_emitter.emit_cpi(&event)?;
             }
-            accounts.close_action_account()?;
+            accounts.action().close(action_rent_receiver)?;
         } else {
             msg!("Some ATAs are not initialized, skip the close");
         }
@@ -169,8 +220,35 @@ where
         }
     }
 
-    /// Close the action account.
-    fn close_action_account(&self) -> Result<()> {
-        self.action().close(self.rent_receiver())
+    /// Remove the action from the pending actions of the owner if it has been recorded.
+    fn remove_pending_action(&self) -> Result<()> {
+        if !self.action().load()?.header().is_pending_action_recorded() {
+            return Ok(());
+        }
+        let Some(user) = self.user() else {
+            msg!("[Action] the user account of the owner must be provided");
+            return err!(CoreError::InvalidUserAccount);
+        };
+        user.load_mut()?.remove_pending_action()
+    }
+
+    /// Get the receiver of the rent of the action account.
+    ///
+    /// The rent recipient specified by the caller is used only if the caller is not
+    /// the owner, and it must be in the rent recipient allowlist of the store.
+    fn action_rent_receiver(&self, is_caller_owner: IsCallerOwner) -> Result<AccountInfo<'info>> {
+        match self.rent_recipient() {
+            Some(recipient) if !is_caller_owner => {
+                require!(
+                    self.store()
+                        .load()?
+                        .rent_recipient_allowlist()
+                        .is_allowed(recipient.key),
+                    CoreError::RentRecipientNotAllowed
+                );
+                Ok(recipient)
+            }
+            _ => Ok(self.rent_receiver()),
+        }
     }
 }
@@ -3,7 +3,7 @@ mod authentication;
 mod transfer;
 
 pub(crate) use self::{
-    action::{Close, Create, Success},
+    action::{record_pending_action, Close, Create, Success},
     authentication::{Authenticate, Authentication},
     transfer::TransferUtils,
 };
@@ -121,6 +121,10 @@ pub struct TransferAllFromEscrowToATA<'a, 'info> {
     keep_escrow: bool,
     rent_receiver: AccountInfo<'info>,
     should_unwrap_native: bool,
+    /// Native token amounts below this value are transferred as wrapped tokens
+    /// to the ATA instead of being unwrapped, unless no ATA is provided.
+    #[builder(default)]
+    min_unwrap_amount: u64,
 }
 
 impl TransferAllFromEscrowToATA<'_, '_> {
@@ -234,6 +238,7 @@ impl TransferAllFromEscrowToATA<'_, '_> {
             keep_escrow,
             rent_receiver,
             should_unwrap_native,
+            min_unwrap_amount,
             mint,
             system_program,
             ..
@@ -243,8 +248,11 @@ impl TransferAllFromEscrowToATA<'_, '_> {
 
         let amount = anchor_spl::token::accessor::amount(escrow)?;
 
+        // Dust amounts are left wrapped if an ATA is provided.
+        let is_dust = amount < *min_unwrap_amount && ata.key != owner.key;
+
         // Unwrap native.
-        if is_native_token && *should_unwrap_native && amount != 0 {
+        if is_native_token && *should_unwrap_native && amount != 0 && !is_dust {
             // The escrow will be closed after unwrap.
             require!(!keep_escrow, CoreError::InvalidArgument);
 
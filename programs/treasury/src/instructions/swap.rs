@@ -10,7 +10,11 @@ use gmsol_store::{
     },
     ops::order::CreateOrderParams,
     program::GmsolStore,
-    states::{common::action::Action, order::OrderKind, NonceBytes, Order},
+    states::{
+        common::action::{Action, CloseReason},
+        order::OrderKind,
+        NonceBytes, Order,
+    },
     utils::{CpiAuthentication, WithStore},
     CoreError,
 };
@@ -164,6 +168,7 @@ impl<'info> CreateSwapV2<'info> {
             acceptable_price: None,
             should_unwrap_native_token: false,
             valid_from_ts: None,
+            pay_execution_fee_from_collateral: None,
         };
         create_order_v2(
             cpi_ctx
@@ -293,6 +298,11 @@ pub struct CancelSwap<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     /// The system program.
     pub system_program: Program<'info, System>,
+    /// The market of the order.
+    /// Required only if the order is queued for fair execution.
+    /// CHECK: check by CPI.
+    #[account(mut)]
+    pub market: Option<UncheckedAccount<'info>>,
 }
 
 /// Cancel a swap with the store program.
@@ -304,6 +314,7 @@ pub(crate) fn unchecked_cancel_swap(ctx: Context<CancelSwap>) -> Result<()> {
     close_order_v2(
         cpi_ctx.with_signer(&[&signer.as_seeds()]),
         "cancel".to_string(),
+        CloseReason::UserCancel,
     )?;
     Ok(())
 }
@@ -365,6 +376,9 @@ impl<'info> CancelSwap<'info> {
                 callback_program: None,
                 callback_shared_data_account: None,
                 callback_partitioned_data_account: None,
+                position: None,
+                rent_recipient: None,
+                market: self.market.as_ref().map(|a| a.to_account_info()),
             },
         )
     }
@@ -480,6 +494,7 @@ mod deprecated {
             acceptable_price: None,
             should_unwrap_native_token: false,
             valid_from_ts: None,
+            pay_execution_fee_from_collateral: None,
         };
         create_order(
             cpi_ctx
@@ -2,13 +2,16 @@ use crate::anchor_test::setup::{current_deployment, Deployment};
 use anchor_spl::token::spl_token;
 use gmsol_liquidity_provider as lp;
 use gmsol_programs::gmsol_store;
-use gmsol_sdk::ops::UserOps;
+use gmsol_sdk::ops::{ExchangeOps, UserOps};
 use solana_sdk::{
     pubkey::Pubkey, signer::keypair::Keypair, signer::Signer, system_instruction, system_program,
 };
 
 // Test helpers ----------------------------------------------------------------
 
+/// Serializes the tests that stake, since they check the aggregate staked value.
+static STAKING: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
 // Tests -----------------------------------------------------------------------
 
 #[tokio::test]
@@ -272,6 +275,7 @@ use std::time::Duration;
 async fn stake_claim_unstake_flow() -> eyre::Result<()> {
     let deployment = current_deployment().await?;
     let _guard = deployment.use_accounts().await?;
+    let _staking = STAKING.lock().await;
     let client = deployment.user_client(Deployment::DEFAULT_KEEPER)?;
 
     // Use global state from deployment
@@ -443,6 +447,8 @@ async fn stake_claim_unstake_flow() -> eyre::Result<()> {
 
     tracing::info!("Values validation passed");
 
+    let total_staked_value_before = gs.total_staked_value_usd;
+
     // --- Stake ---
     tracing::info!(
         "Attempting to stake {} LP tokens with value {}",
@@ -474,6 +480,15 @@ async fn stake_claim_unstake_flow() -> eyre::Result<()> {
     let stake_sig = stake_ix.send().await?;
     tracing::info!(%stake_sig, "Successfully staked {} LP tokens", lp_staked_amount);
 
+    let gs = client
+        .account::<lp::GlobalState>(&global_state)
+        .await?
+        .expect("global_state must exist");
+    assert_eq!(
+        gs.total_staked_value_usd,
+        total_staked_value_before + lp_staked_value
+    );
+
     // --- Sleep before claim to ensure reward accrual across time ---
     tokio::time::sleep(Duration::from_secs(3)).await;
 
@@ -572,6 +587,15 @@ async fn stake_claim_unstake_flow() -> eyre::Result<()> {
         position_account.staked_value_usd
     );
 
+    let gs = client
+        .account::<lp::GlobalState>(&global_state)
+        .await?
+        .expect("global_state must exist");
+    assert_eq!(
+        gs.total_staked_value_usd,
+        total_staked_value_before + position_account.staked_value_usd
+    );
+
     // --- Full unstake (remaining) ---
     let remaining_unstake: u64 = lp_staked_amount - partial_unstake;
     tracing::info!(
@@ -603,6 +627,12 @@ async fn stake_claim_unstake_flow() -> eyre::Result<()> {
     let full_unstake_sig = full_unstake_ix.send().await?;
     tracing::info!(%full_unstake_sig, "Fully unstaked remaining {} LP tokens", remaining_unstake);
 
+    let gs = client
+        .account::<lp::GlobalState>(&global_state)
+        .await?
+        .expect("global_state must exist");
+    assert_eq!(gs.total_staked_value_usd, total_staked_value_before);
+
     // --- Verify position is closed after full unstake ---
     let position_account_after_full = client.account::<lp::Position>(&position_pda).await;
 
@@ -618,3 +648,180 @@ async fn stake_claim_unstake_flow() -> eyre::Result<()> {
     tracing::info!("✓ stake_claim_unstake_flow test completed successfully!");
     Ok(())
 }
+
+/// Stake the GM tokens received from a deposit whose receiver is the deposit receiver PDA.
+#[tokio::test]
+async fn stake_deposited_lp() -> eyre::Result<()> {
+    let deployment = current_deployment().await?;
+    let _guard = deployment.use_accounts().await?;
+    let _staking = STAKING.lock().await;
+    let span = tracing::info_span!("stake_deposited_lp");
+    let _enter = span.enter();
+
+    let client = deployment.user_client(Deployment::DEFAULT_KEEPER)?;
+    let store = &deployment.store;
+    let oracle = &deployment.oracle();
+    let global_state = deployment.liquidity_provider_global_state;
+    let gt_program = gmsol_store::ID;
+    let owner = client.payer();
+    let position_id: u64 = 43;
+
+    let market_token = *deployment
+        .prepare_market(["fBTC", "fBTC", "USDG"], 1_000_017, 6_000_000_000_013, true)
+        .await?;
+
+    let (deposit_receiver, _) = Pubkey::find_program_address(
+        &[
+            lp::DEPOSIT_RECEIVER_SEED,
+            global_state.as_ref(),
+            owner.as_ref(),
+        ],
+        &lp::id(),
+    );
+    let deposit_receiver_lp_token = anchor_spl::associated_token::get_associated_token_address(
+        &deposit_receiver,
+        &market_token,
+    );
+    let (position, _) = Pubkey::find_program_address(
+        &[
+            lp::POSITION_SEED,
+            global_state.as_ref(),
+            owner.as_ref(),
+            &position_id.to_le_bytes(),
+        ],
+        &lp::id(),
+    );
+    let (position_vault, _) = Pubkey::find_program_address(
+        &[
+            lp::POSITION_SEED,
+            global_state.as_ref(),
+            owner.as_ref(),
+            &position_id.to_le_bytes(),
+            lp::VAULT_SEED,
+        ],
+        &lp::id(),
+    );
+
+    // Create a deposit with the deposit receiver PDA as its receiver.
+    let amount = 100 * 100_000_000;
+    deployment
+        .mint_or_transfer_to("USDG", &owner, amount)
+        .await?;
+    let (rpc, deposit) = client
+        .create_deposit(store, &market_token)
+        .short_token(amount, None, None)
+        .receiver(Some(deposit_receiver))
+        .build_with_address()
+        .await?;
+    let signature = rpc.send().await?;
+    tracing::info!(%signature, %deposit, "created a deposit for the deposit receiver");
+
+    let lp_staked_value: u128 = 60_000_000_000_000_000_000_000u128;
+    let stake = || {
+        client
+            .store_transaction()
+            .program(lp::id())
+            .anchor_args(lp::instruction::StakeDepositedLp {
+                position_id,
+                min_lp_amount: 1,
+                lp_staked_value,
+            })
+            .anchor_accounts(lp::accounts::StakeDepositedLp {
+                global_state,
+                lp_mint: market_token,
+                position,
+                position_vault,
+                gt_store: *store,
+                gt_program,
+                owner,
+                deposit_receiver,
+                deposit_receiver_lp_token,
+                system_program: system_program::ID,
+                token_program: spl_token::ID,
+            })
+    };
+
+    // Staking fails before the deposit is executed.
+    assert!(stake().send().await.is_err());
+
+    let mut builder = client.execute_deposit(store, oracle, &deposit, false);
+    deployment
+        .execute_with_pyth(&mut builder, None, true, true)
+        .await?;
+
+    let received = deployment
+        .get_ata_amount(&market_token, &deposit_receiver)
+        .await?
+        .expect("must exist");
+    assert!(received > 0);
+
+    let gs = client
+        .account::<lp::GlobalState>(&global_state)
+        .await?
+        .expect("global_state must exist");
+    let total_staked_value_before = gs.total_staked_value_usd;
+
+    let signature = stake().send().await?;
+    tracing::info!(%signature, %received, "staked the deposited LP tokens");
+
+    let position_account = client
+        .account::<lp::Position>(&position)
+        .await?
+        .expect("position must exist");
+    assert_eq!(position_account.staked_amount, received);
+    assert_eq!(position_account.staked_value_usd, lp_staked_value);
+    assert_eq!(
+        deployment
+            .get_ata_amount(&market_token, &deposit_receiver)
+            .await?,
+        Some(0)
+    );
+    let gs = client
+        .account::<lp::GlobalState>(&global_state)
+        .await?
+        .expect("global_state must exist");
+    assert_eq!(
+        gs.total_staked_value_usd,
+        total_staked_value_before + lp_staked_value
+    );
+
+    // Unstake all to clean up.
+    let signature = client.prepare_user(store)?.send().await?;
+    tracing::info!(%signature, "prepared GT user account");
+    let user_lp_token =
+        anchor_spl::associated_token::get_associated_token_address(&owner, &market_token);
+    let signature = client
+        .store_transaction()
+        .pre_instruction(
+            anchor_spl::associated_token::spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                &owner,
+                &owner,
+                &market_token,
+                &spl_token::ID,
+            ),
+            true,
+        )
+        .program(lp::id())
+        .anchor_args(lp::instruction::UnstakeLp {
+            _position_id: position_id,
+            unstake_amount: received,
+        })
+        .anchor_accounts(lp::accounts::UnstakeLp {
+            global_state,
+            lp_mint: market_token,
+            store: *store,
+            gt_program,
+            position,
+            position_vault,
+            owner,
+            gt_user: client.find_user_address(store, &owner),
+            user_lp_token,
+            event_authority: client.store_event_authority(),
+            token_program: spl_token::ID,
+        })
+        .send()
+        .await?;
+    tracing::info!(%signature, "unstaked the deposited LP tokens");
+
+    Ok(())
+}
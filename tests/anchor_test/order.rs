@@ -2,10 +2,10 @@ use std::time::Duration;
 
 use gmsol_programs::gmsol_store::types::{DecreasePositionSwapType, UpdateOrderParams};
 use gmsol_sdk::{
-    client::ops::{ExchangeOps, MarketOps},
+    client::ops::{ConfigOps, ExchangeOps, MarketOps},
     constants::MARKET_USD_UNIT,
 };
-use gmsol_utils::market::MarketConfigKey;
+use gmsol_utils::{config::FactorKey, market::MarketConfigKey};
 use tracing::Instrument;
 
 use crate::anchor_test::setup::{current_deployment, Deployment};
@@ -693,6 +693,201 @@ async fn liquidation() -> eyre::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn partial_liquidation() -> eyre::Result<()> {
+    let deployment = current_deployment().await?;
+    let _guard = deployment.use_accounts().await?;
+    let span = tracing::info_span!("partial_liquidation");
+    let _enter = span.enter();
+
+    let long_token_amount = 123000 * 100_000_000;
+    let short_token_amount = 123000 * 100_000_000;
+    let market_token = deployment
+        .prepare_market(
+            Deployment::SELECT_PARTIAL_LIQUIDATION_MARKET,
+            long_token_amount,
+            short_token_amount,
+            true,
+        )
+        .await?;
+
+    let store = &deployment.store;
+    let oracle = &deployment.oracle();
+
+    {
+        let client = deployment.locked_user_client().await?;
+        let keeper = deployment.user_client(Deployment::DEFAULT_KEEPER)?;
+
+        let usd = 125u64;
+        let collateral_amount = usd * 100_000_000;
+        let leverage = 50;
+        let size = leverage * usd as u128 * MARKET_USD_UNIT;
+
+        deployment
+            .mint_or_transfer_to("USDG", &client.payer(), collateral_amount)
+            .await?;
+
+        // Open position.
+        let (rpc, order, position) = client
+            .market_increase(store, market_token, true, collateral_amount, false, size)
+            .build_with_addresses()
+            .await?;
+        let position = position.expect("must have position");
+        let signature = rpc.send().await?;
+        tracing::info!(%order, %signature, %size, "created an order to increase position");
+
+        let mut builder = keeper.execute_order(store, oracle, &order, false)?;
+        deployment
+            .execute_with_pyth(&mut builder, None, true, true)
+            .instrument(tracing::info_span!("execute", order=%order))
+            .await?;
+
+        let size_before = client.position(&position).await?.state.size_in_usd;
+
+        let signature = keeper
+            .insert_global_factor_by_key(
+                store,
+                FactorKey::PartialLiquidationBufferFactor,
+                &(MARKET_USD_UNIT / 100),
+            )
+            .send_without_preflight()
+            .await?;
+        tracing::info!(%signature, "enabled partial liquidation");
+
+        let signature = keeper
+            .update_market_config_by_key(
+                store,
+                market_token,
+                MarketConfigKey::MinCollateralFactor,
+                &(3 * MARKET_USD_UNIT / 100),
+            )?
+            .send_without_preflight()
+            .await?;
+        tracing::info!(%signature, %market_token, "increased min collateral factor");
+
+        // Liquidate.
+        let mut builder = keeper.liquidate(oracle, &position)?;
+
+        deployment
+            .execute_with_pyth(
+                builder
+                    .add_alt(deployment.common_alt().clone())
+                    .add_alt(deployment.market_alt().clone()),
+                None,
+                true,
+                true,
+            )
+            .instrument(tracing::info_span!("liquidate", position=%position))
+            .await?;
+
+        // The position is only partially liquidated.
+        let size_after = client.position(&position).await?.state.size_in_usd;
+        tracing::info!(%size_before, %size_after, "partially liquidated");
+        assert_ne!(size_after, 0);
+        assert!(size_after < size_before);
+
+        let signature = keeper
+            .update_market_config_by_key(
+                store,
+                market_token,
+                MarketConfigKey::MinCollateralFactor,
+                &(MARKET_USD_UNIT / 100),
+            )?
+            .send_without_preflight()
+            .await?;
+        tracing::info!(%signature, %market_token, "restore min collateral factor");
+
+        let signature = keeper
+            .insert_global_factor_by_key(store, FactorKey::PartialLiquidationBufferFactor, &0)
+            .send_without_preflight()
+            .await?;
+        tracing::info!(%signature, "disabled partial liquidation");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pay_execution_fee_from_collateral() -> eyre::Result<()> {
+    let deployment = current_deployment().await?;
+    let _guard = deployment.use_accounts().await?;
+    let span = tracing::info_span!("pay_execution_fee_from_collateral");
+    let _enter = span.enter();
+
+    let keeper = deployment.user_client(Deployment::DEFAULT_KEEPER)?;
+    let store = &deployment.store;
+    let oracle = &deployment.oracle();
+    let usdg = deployment.token("USDG").expect("must exist");
+
+    let market_token = deployment
+        .prepare_market(["fBTC", "fBTC", "USDG"], 1_000_013, 6_000_000_000_011, true)
+        .await?;
+
+    let client = deployment.locked_user_client().await?;
+
+    let collateral_amount = 100 * 100_000_000;
+    let size = 500 * MARKET_USD_UNIT;
+
+    deployment
+        .mint_or_transfer_to("USDG", &client.payer(), collateral_amount)
+        .await?;
+
+    let (rpc, order) = client
+        .market_increase(store, market_token, false, collateral_amount, true, size)
+        .pay_execution_fee_from_collateral(true)
+        .build_with_address()
+        .await?;
+    let signature = rpc.send().await?;
+    tracing::info!(%order, %signature, %size, "created an order paying the execution fee from collateral");
+
+    let before = deployment
+        .get_user_ata_amount(&usdg.address, Some(Deployment::DEFAULT_KEEPER))
+        .await?
+        .unwrap_or_default();
+
+    let mut builder = keeper.execute_order(store, oracle, &order, false)?;
+    deployment
+        .execute_with_pyth(
+            builder
+                .add_alt(deployment.common_alt().clone())
+                .add_alt(deployment.market_alt().clone()),
+            None,
+            true,
+            true,
+        )
+        .instrument(tracing::info_span!("execute", order=%order))
+        .await?;
+
+    let after = deployment
+        .get_user_ata_amount(&usdg.address, Some(Deployment::DEFAULT_KEEPER))
+        .await?
+        .unwrap_or_default();
+    assert!(after > before);
+
+    // Close the position.
+    let (rpc, order) = client
+        .market_decrease(store, market_token, false, 0, true, size)
+        .build_with_address()
+        .await?;
+    let signature = rpc.send().await?;
+    tracing::info!(%order, %signature, %size, "created an order to close the position");
+
+    let mut builder = keeper.execute_order(store, oracle, &order, false)?;
+    deployment
+        .execute_with_pyth(
+            builder
+                .add_alt(deployment.common_alt().clone())
+                .add_alt(deployment.market_alt().clone()),
+            None,
+            true,
+            true,
+        )
+        .instrument(tracing::info_span!("execute", order=%order))
+        .await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn update_order() -> eyre::Result<()> {
     let deployment = current_deployment().await?;
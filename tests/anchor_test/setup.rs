@@ -157,6 +157,9 @@ impl Deployment {
     /// Market selector for liquidation test.
     pub const SELECT_LIQUIDATION_MARKET: [&'static str; 3] = ["fBTC", "USDG", "fBTC"];
 
+    /// Market selector for partial liquidation test.
+    pub const SELECT_PARTIAL_LIQUIDATION_MARKET: [&'static str; 3] = ["fBTC", "USDG", "USDG"];
+
     /// Market selector for ADL test.
     pub const SELECT_ADL_MARKET: [&'static str; 3] = ["SOL", "USDG", "fBTC"];
 
@@ -335,6 +338,8 @@ impl Deployment {
             ["SOL", "fBTC", "USDG"],
             // For liquidation test only
             Self::SELECT_LIQUIDATION_MARKET,
+            // For partial liquidation test only
+            Self::SELECT_PARTIAL_LIQUIDATION_MARKET,
             // For ADL test only
             Self::SELECT_ADL_MARKET,
             // For first deposit test only